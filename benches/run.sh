@@ -0,0 +1,32 @@
+#!/usr/bin/env bash
+# Compare the register machine against the legacy instructions executers.
+#
+# Usage: benches/run.sh [engine binary]
+
+set -e
+
+ENGINE=${1:-target/release/engine}
+BENCHES_DIR=$(dirname "$0")
+
+if [ ! -x "$ENGINE" ]; then
+    cargo build --release
+fi
+
+for file in "$BENCHES_DIR"/*.en; do
+    "$ENGINE" "$file" -b
+
+    start=$(date +%s%N)
+    "$ENGINE" "$file.byte" --vm --legacy-vm > /dev/null
+    legacy=$(( $(date +%s%N) - start ))
+
+    start=$(date +%s%N)
+    "$ENGINE" "$file.byte" --vm > /dev/null
+    machine=$(( $(date +%s%N) - start ))
+
+    awk -v name="$(basename "$file")" -v legacy="$legacy" -v machine="$machine" 'BEGIN {
+        printf "%-24s legacy: %8.3fs  machine: %8.3fs  speedup: %6.2fx\n",
+            name, legacy / 1e9, machine / 1e9, legacy / machine
+    }'
+
+    rm -f "$file.byte"
+done
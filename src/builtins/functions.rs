@@ -0,0 +1,1100 @@
+#![allow(dead_code)]
+
+/*
+    Built-in Functions Shared By The Interpreter, The VM And The Compiled Programs.
+    Only `std` Is Used Here, The Code Generator Embeds This File Into The Generated Rust.
+*/
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuiltinType{
+    Bool,
+    Int,
+    Double,
+    Char,
+    String,
+    List,
+}
+
+impl std::fmt::Display for BuiltinType{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuiltinType::Bool => write!(f, "bool"),
+            BuiltinType::Int => write!(f, "int"),
+            BuiltinType::Double => write!(f, "double"),
+            BuiltinType::Char => write!(f, "char"),
+            BuiltinType::String => write!(f, "string"),
+            BuiltinType::List => write!(f, "list<string>"),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuiltinValue{
+    Bool(bool),
+    Int(i64),
+    Double(f64),
+    Char(char),
+    String(String),
+    List(Vec<String>),
+}
+
+impl BuiltinValue{
+    pub fn get_type(&self) -> BuiltinType{
+        return match self {
+            BuiltinValue::Bool(_) => BuiltinType::Bool,
+            BuiltinValue::Int(_) => BuiltinType::Int,
+            BuiltinValue::Double(_) => BuiltinType::Double,
+            BuiltinValue::Char(_) => BuiltinType::Char,
+            BuiltinValue::String(_) => BuiltinType::String,
+            BuiltinValue::List(_) => BuiltinType::List,
+        };
+    }
+
+    /* The Analyzers Check The Types, So The Compiled Programs Unwrap The Results Directly */
+    pub fn into_bool(self) -> bool{
+        if let BuiltinValue::Bool(value) = self{
+            return value;
+        }
+        panic!("Engine Builtins: Type Error -> Expected a `bool` value, found `{}`.", self.get_type());
+    }
+
+    pub fn into_int(self) -> i64{
+        if let BuiltinValue::Int(value) = self{
+            return value;
+        }
+        panic!("Engine Builtins: Type Error -> Expected an `int` value, found `{}`.", self.get_type());
+    }
+
+    pub fn into_double(self) -> f64{
+        if let BuiltinValue::Double(value) = self{
+            return value;
+        }
+        panic!("Engine Builtins: Type Error -> Expected a `double` value, found `{}`.", self.get_type());
+    }
+
+    pub fn into_char(self) -> char{
+        if let BuiltinValue::Char(value) = self{
+            return value;
+        }
+        panic!("Engine Builtins: Type Error -> Expected a `char` value, found `{}`.", self.get_type());
+    }
+
+    pub fn into_string(self) -> String{
+        if let BuiltinValue::String(value) = self{
+            return value;
+        }
+        panic!("Engine Builtins: Type Error -> Expected a `string` value, found `{}`.", self.get_type());
+    }
+
+    pub fn into_list(self) -> Vec<String>{
+        if let BuiltinValue::List(value) = self{
+            return value;
+        }
+        panic!("Engine Builtins: Type Error -> Expected a `list<string>` value, found `{}`.", self.get_type());
+    }
+}
+
+
+/* Name, Parameters Types And Return Type, `char` Arguments Are Accepted As `string` */
+const STRING_FUNCTIONS: [(&'static str, &'static [BuiltinType], BuiltinType); 12] = [
+    ("len", &[BuiltinType::String], BuiltinType::Int),
+    ("substring", &[BuiltinType::String, BuiltinType::Int, BuiltinType::Int], BuiltinType::String),
+    ("find", &[BuiltinType::String, BuiltinType::String], BuiltinType::Int),
+    ("replace", &[BuiltinType::String, BuiltinType::String, BuiltinType::String], BuiltinType::String),
+    ("split", &[BuiltinType::String, BuiltinType::String, BuiltinType::Int], BuiltinType::String),
+    ("trim", &[BuiltinType::String], BuiltinType::String),
+    ("to_upper", &[BuiltinType::String], BuiltinType::String),
+    ("to_lower", &[BuiltinType::String], BuiltinType::String),
+    ("starts_with", &[BuiltinType::String, BuiltinType::String], BuiltinType::Bool),
+    ("ends_with", &[BuiltinType::String, BuiltinType::String], BuiltinType::Bool),
+    ("contains", &[BuiltinType::String, BuiltinType::String], BuiltinType::Bool),
+    ("repeat", &[BuiltinType::String, BuiltinType::Int], BuiltinType::String),
+];
+
+
+/* Lists Are Only Read, `len` Is Also A String Function */
+const LIST_FUNCTIONS: [(&'static str, &'static [BuiltinType], BuiltinType); 2] = [
+    ("len", &[BuiltinType::List], BuiltinType::Int),
+    ("get", &[BuiltinType::List, BuiltinType::Int], BuiltinType::String),
+];
+
+
+/* Failures Don't Stop The Program, They Are Returned By `file_error` */
+const FILE_FUNCTIONS: [(&'static str, &'static [BuiltinType], BuiltinType); 7] = [
+    ("read_file", &[BuiltinType::String], BuiltinType::String),
+    ("write_file", &[BuiltinType::String, BuiltinType::String], BuiltinType::Bool),
+    ("append_file", &[BuiltinType::String, BuiltinType::String], BuiltinType::Bool),
+    ("read_lines", &[BuiltinType::String], BuiltinType::List),
+    ("file_exists", &[BuiltinType::String], BuiltinType::Bool),
+    ("delete_file", &[BuiltinType::String], BuiltinType::Bool),
+    ("file_error", &[], BuiltinType::String),
+];
+
+
+thread_local!{
+    /* The Error Of The Last File Function, Empty When It Succeeded */
+    static FILE_ERROR: std::cell::RefCell<String> = std::cell::RefCell::new(String::new());
+}
+
+
+/* The End Of The Input Is Returned By `input_eof` */
+const INPUT_FUNCTIONS: [(&'static str, &'static [BuiltinType], BuiltinType); 5] = [
+    ("input_line", &[], BuiltinType::String),
+    ("input_int", &[], BuiltinType::Int),
+    ("input_double", &[], BuiltinType::Double),
+    ("read_all_stdin", &[], BuiltinType::String),
+    ("input_eof", &[], BuiltinType::Bool),
+];
+
+
+thread_local!{
+    /* Set When The Last Input Function Reached The End Of The Input */
+    static INPUT_EOF: std::cell::Cell<bool> = std::cell::Cell::new(false);
+}
+
+
+thread_local!{
+    /* Set By The Sandbox Limits, Larger Strings Are Not Created By The Functions */
+    static MEMORY_LIMIT: std::cell::Cell<Option<usize>> = std::cell::Cell::new(None);
+}
+
+
+/* Arguments After The Script Path Or The Binary Name And The Environment */
+const PROGRAM_FUNCTIONS: [(&'static str, &'static [BuiltinType], BuiltinType); 3] = [
+    ("args", &[], BuiltinType::List),
+    ("env", &[BuiltinType::String], BuiltinType::String),
+    ("exit", &[BuiltinType::Int], BuiltinType::Int),
+];
+
+
+/* Set By The Engine Binary, The Compiled Programs Read Their Own Arguments */
+static PROGRAM_ARGUMENTS: std::sync::OnceLock<Vec<String>> = std::sync::OnceLock::new();
+
+
+thread_local!{
+    /* Set By `exit`, Which Stops The Program Through An Error */
+    static EXIT_CODE: std::cell::Cell<Option<i32>> = std::cell::Cell::new(None);
+}
+
+
+/* Takes A Format String Then Any Number Of Values */
+const FORMAT_FUNCTION: &'static str = "format";
+
+
+/* Name And Parameters Count, `int` Arguments Are Promoted To `double` Like In Operations */
+const MATH_FUNCTIONS: [(&'static str, usize); 13] = [
+    ("abs", 1),
+    ("min", 2),
+    ("max", 2),
+    ("sqrt", 1),
+    ("pow", 2),
+    ("floor", 1),
+    ("ceil", 1),
+    ("round", 1),
+    ("sin", 1),
+    ("cos", 1),
+    ("tan", 1),
+    ("log", 1),
+    ("exp", 1),
+];
+
+
+/* Constants Are Replaced By Their Values When No Variable Has Their Name */
+const MATH_CONSTANTS: [(&'static str, f64); 2] = [
+    ("PI", std::f64::consts::PI),
+    ("E", std::f64::consts::E),
+];
+
+
+fn get_string_function(
+    name: &str
+) -> Option<&'static (&'static str, &'static [BuiltinType], BuiltinType)>{
+
+    return STRING_FUNCTIONS.iter().find(|function| function.0 == name);
+}
+
+
+fn get_list_function(
+    name: &str
+) -> Option<&'static (&'static str, &'static [BuiltinType], BuiltinType)>{
+
+    return LIST_FUNCTIONS.iter().find(|function| function.0 == name);
+}
+
+
+fn get_file_function(
+    name: &str
+) -> Option<&'static (&'static str, &'static [BuiltinType], BuiltinType)>{
+
+    return FILE_FUNCTIONS.iter().find(|function| function.0 == name);
+}
+
+
+fn get_input_function(
+    name: &str
+) -> Option<&'static (&'static str, &'static [BuiltinType], BuiltinType)>{
+
+    return INPUT_FUNCTIONS.iter().find(|function| function.0 == name);
+}
+
+
+fn get_program_function(
+    name: &str
+) -> Option<&'static (&'static str, &'static [BuiltinType], BuiltinType)>{
+
+    return PROGRAM_FUNCTIONS.iter().find(|function| function.0 == name);
+}
+
+
+fn get_math_function(name: &str) -> Option<&'static (&'static str, usize)>{
+    return MATH_FUNCTIONS.iter().find(|function| function.0 == name);
+}
+
+
+pub fn is_builtin_function(name: &str) -> bool{
+    return name == FORMAT_FUNCTION ||
+        get_string_function(name) != None ||
+        get_list_function(name) != None ||
+        get_file_function(name) != None ||
+        get_input_function(name) != None ||
+        get_program_function(name) != None ||
+        get_math_function(name) != None;
+}
+
+
+pub fn is_file_function(name: &str) -> bool{
+    return get_file_function(name) != None;
+}
+
+
+pub fn is_input_function(name: &str) -> bool{
+    return get_input_function(name) != None;
+}
+
+
+/* Functions Without Side Effects, Their Results Depend Only On The Arguments */
+pub fn is_pure_function(name: &str) -> bool{
+    return name == FORMAT_FUNCTION ||
+        get_string_function(name) != None ||
+        get_list_function(name) != None ||
+        get_math_function(name) != None;
+}
+
+
+pub fn set_program_arguments(arguments: Vec<String>){
+    PROGRAM_ARGUMENTS.set(arguments).unwrap();
+}
+
+
+fn get_program_arguments() -> &'static Vec<String>{
+    return PROGRAM_ARGUMENTS.get_or_init(|| std::env::args().skip(1).collect());
+}
+
+
+pub fn get_exit_code() -> Option<i32>{
+    return EXIT_CODE.with(|exit_code| exit_code.get());
+}
+
+
+pub fn get_builtin_constant(name: &str) -> Option<f64>{
+    return MATH_CONSTANTS.iter().find(|constant| constant.0 == name).map(|constant| constant.1);
+}
+
+
+fn check_arguments_count(
+    name: &str, parameters_count: usize, arguments_count: usize
+) -> Result<(), String>{
+
+    if parameters_count != arguments_count{
+        return Err(format!(
+            "Function `{}` expects {} arguments, found {}",
+            name, parameters_count, arguments_count));
+    }
+    return Ok(());
+}
+
+
+fn is_argument_type_matched(parameter_type: &BuiltinType, argument_type: &BuiltinType) -> bool{
+    if parameter_type == &BuiltinType::String{
+        return argument_type == &BuiltinType::String || argument_type == &BuiltinType::Char;
+    }
+    return parameter_type == argument_type;
+}
+
+
+/* Check The Arguments Types Of A Call And Return Its Result Type */
+pub fn get_builtin_return_type(
+    name: &str, arguments_types: &Vec<BuiltinType>
+) -> Result<BuiltinType, String>{
+
+    if get_math_function(name) != None{
+        return get_math_return_type(name, arguments_types);
+    }
+    else if name == FORMAT_FUNCTION{
+        if arguments_types.len() == 0 || !is_argument_type_matched(&BuiltinType::String, &arguments_types[0]){
+            return Err(format!("Function `{}` expects a `string` format as its first argument", name));
+        }
+        return Ok(BuiltinType::String);
+    }
+
+    /* `len` Of A List Is Looked Up Before `len` Of A String */
+    let function = if arguments_types.first() == Some(&BuiltinType::List){
+        get_list_function(name).or(get_string_function(name))
+    }
+    else{
+        get_string_function(name).or(get_list_function(name))
+    };
+
+    let function = function
+        .or(get_file_function(name))
+        .or(get_input_function(name))
+        .or(get_program_function(name));
+    if function == None{
+        return Err(format!("Unknown function `{}`", name));
+    }
+    let (_, parameters_types, return_type) = function.unwrap();
+
+    check_arguments_count(name, parameters_types.len(), arguments_types.len())?;
+
+    for index in 0..parameters_types.len(){
+        if !is_argument_type_matched(&parameters_types[index], &arguments_types[index]){
+            return Err(format!(
+                "Function `{}` argument {} must be of type `{}`, found `{}`",
+                name, index + 1, parameters_types[index], arguments_types[index]));
+        }
+    }
+
+    return Ok(return_type.clone());
+}
+
+
+fn get_math_return_type(
+    name: &str, arguments_types: &Vec<BuiltinType>
+) -> Result<BuiltinType, String>{
+
+    let (_, parameters_count) = get_math_function(name).unwrap();
+    check_arguments_count(name, *parameters_count, arguments_types.len())?;
+
+    for index in 0..arguments_types.len(){
+        if arguments_types[index] != BuiltinType::Int && arguments_types[index] != BuiltinType::Double{
+            return Err(format!(
+                "Function `{}` argument {} must be of type `int` or `double`, found `{}`",
+                name, index + 1, arguments_types[index]));
+        }
+    }
+
+    let is_int = arguments_types.iter().all(|argument_type| argument_type == &BuiltinType::Int);
+
+    if name == "abs" || name == "min" || name == "max" || name == "pow"{
+        return Ok(if is_int {BuiltinType::Int} else {BuiltinType::Double});
+    }
+    else if name == "floor" || name == "ceil" || name == "round"{
+        return Ok(BuiltinType::Int);
+    }
+    return Ok(BuiltinType::Double);
+}
+
+
+fn get_string_argument(arguments: &Vec<BuiltinValue>, index: usize) -> String{
+    return match &arguments[index] {
+        BuiltinValue::String(value) => value.clone(),
+        BuiltinValue::Char(value) => value.to_string(),
+        value => panic!(
+            "Engine Builtins: Type Error -> Expected a `string` argument, found `{}`.",
+            value.get_type()),
+    };
+}
+
+
+fn get_int_argument(arguments: &Vec<BuiltinValue>, index: usize) -> i64{
+    return match &arguments[index] {
+        BuiltinValue::Int(value) => *value,
+        value => panic!(
+            "Engine Builtins: Type Error -> Expected an `int` argument, found `{}`.",
+            value.get_type()),
+    };
+}
+
+
+fn get_list_argument(arguments: &Vec<BuiltinValue>, index: usize) -> &Vec<String>{
+    return match &arguments[index] {
+        BuiltinValue::List(value) => value,
+        value => panic!(
+            "Engine Builtins: Type Error -> Expected a `list<string>` argument, found `{}`.",
+            value.get_type()),
+    };
+}
+
+
+fn get_double_argument(arguments: &Vec<BuiltinValue>, index: usize) -> f64{
+    return match &arguments[index] {
+        BuiltinValue::Double(value) => *value,
+        BuiltinValue::Int(value) => *value as f64,
+        value => panic!(
+            "Engine Builtins: Type Error -> Expected a `double` argument, found `{}`.",
+            value.get_type()),
+    };
+}
+
+
+/* Indexes Count Characters, Not Bytes */
+fn get_char_index(text: &String, byte_index: usize) -> i64{
+    return text[..byte_index].chars().count() as i64;
+}
+
+
+fn call_string_function(
+    name: &str, arguments: &Vec<BuiltinValue>
+) -> Result<BuiltinValue, String>{
+
+    let text = get_string_argument(arguments, 0);
+
+    if name == "len"{
+        return Ok(BuiltinValue::Int(text.chars().count() as i64));
+    }
+    else if name == "substring"{
+        let start = get_int_argument(arguments, 1);
+        let end = get_int_argument(arguments, 2);
+        let length = text.chars().count() as i64;
+
+        if start < 0 || end < start || end > length{
+            return Err(format!(
+                "`substring` range {}..{} is out of bounds for a string of length {}",
+                start, end, length));
+        }
+
+        return Ok(BuiltinValue::String(
+            text.chars().skip(start as usize).take((end - start) as usize).collect()));
+    }
+    else if name == "find"{
+        let pattern = get_string_argument(arguments, 1);
+
+        let byte_index = text.find(&pattern);
+        if byte_index == None{
+            return Ok(BuiltinValue::Int(-1));
+        }
+        return Ok(BuiltinValue::Int(get_char_index(&text, byte_index.unwrap())));
+    }
+    else if name == "replace"{
+        let from = get_string_argument(arguments, 1);
+        let to = get_string_argument(arguments, 2);
+
+        if from.is_empty(){
+            return Err(String::from("`replace` pattern can't be empty"));
+        }
+
+        let matches_count = text.matches(&from).count();
+        let size = matches_count.checked_mul(to.len()).and_then(
+            |size| size.checked_add(text.len() - matches_count * from.len()));
+        check_memory_limit(name, size)?;
+
+        return Ok(BuiltinValue::String(text.replace(&from, &to)));
+    }
+    else if name == "split"{
+        let separator = get_string_argument(arguments, 1);
+        let index = get_int_argument(arguments, 2);
+
+        if separator.is_empty(){
+            return Err(String::from("`split` separator can't be empty"));
+        }
+
+        let parts: Vec<&str> = text.split(&separator).collect();
+        if index < 0 || index >= parts.len() as i64{
+            return Err(format!(
+                "`split` index {} is out of bounds for {} parts",
+                index, parts.len()));
+        }
+        return Ok(BuiltinValue::String(String::from(parts[index as usize])));
+    }
+    else if name == "trim"{
+        return Ok(BuiltinValue::String(String::from(text.trim())));
+    }
+    else if name == "to_upper"{
+        return Ok(BuiltinValue::String(text.to_uppercase()));
+    }
+    else if name == "to_lower"{
+        return Ok(BuiltinValue::String(text.to_lowercase()));
+    }
+    else if name == "starts_with"{
+        return Ok(BuiltinValue::Bool(text.starts_with(&get_string_argument(arguments, 1))));
+    }
+    else if name == "ends_with"{
+        return Ok(BuiltinValue::Bool(text.ends_with(&get_string_argument(arguments, 1))));
+    }
+    else if name == "contains"{
+        return Ok(BuiltinValue::Bool(text.contains(&get_string_argument(arguments, 1))));
+    }
+    else if name == "repeat"{
+        let count = get_int_argument(arguments, 1);
+        if count < 0{
+            return Err(format!("`repeat` count {} can't be negative", count));
+        }
+
+        check_memory_limit(name, text.len().checked_mul(count as usize))?;
+        return Ok(BuiltinValue::String(text.repeat(count as usize)));
+    }
+
+    return Err(format!("Unknown function `{}`", name));
+}
+
+
+fn call_list_function(
+    name: &str, arguments: &Vec<BuiltinValue>
+) -> Result<BuiltinValue, String>{
+
+    let items = get_list_argument(arguments, 0);
+
+    if name == "len"{
+        return Ok(BuiltinValue::Int(items.len() as i64));
+    }
+    else if name == "get"{
+        let index = get_int_argument(arguments, 1);
+        if index < 0 || index >= items.len() as i64{
+            return Err(format!(
+                "`get` index {} is out of bounds for {} items",
+                index, items.len()));
+        }
+        return Ok(BuiltinValue::String(items[index as usize].clone()));
+    }
+
+    return Err(format!("Unknown function `{}`", name));
+}
+
+
+pub fn set_memory_limit(limit: Option<usize>){
+    MEMORY_LIMIT.with(|memory_limit| memory_limit.set(limit));
+}
+
+
+/* The Result Size Is Checked Before It Is Created, `None` When It Overflows */
+fn check_memory_limit(name: &str, size: Option<usize>) -> Result<(), String>{
+    let limit = MEMORY_LIMIT.with(|memory_limit| memory_limit.get());
+
+    if limit != None && (size == None || size.unwrap() > limit.unwrap()){
+        return Err(format!(
+            "`{}` needs more than the {} bytes memory limit",
+            name, limit.unwrap()));
+    }
+
+    return Ok(());
+}
+
+
+/* Reads One Byte More Than The Memory Limit, Enough To Know It Is Exceeded */
+fn read_limited_string(
+    name: &str, reader: std::io::Result<impl std::io::Read>
+) -> Result<std::io::Result<String>, String>{
+
+    use std::io::Read;
+
+    if reader.is_err(){
+        return Ok(Err(reader.err().unwrap()));
+    }
+
+    let limit = MEMORY_LIMIT.with(|memory_limit| memory_limit.get());
+    let read_limit = limit.map(|limit| limit as u64 + 1).unwrap_or(u64::MAX);
+
+    let mut content = vec![];
+    let result = reader.unwrap().take(read_limit).read_to_end(&mut content);
+    if result.is_err(){
+        return Ok(Err(result.unwrap_err()));
+    }
+
+    check_memory_limit(name, Some(content.len()))?;
+
+    return Ok(String::from_utf8(content).map_err(|_| std::io::Error::new(
+        std::io::ErrorKind::InvalidData, "stream did not contain valid UTF-8")));
+}
+
+
+pub fn set_file_error(error: String){
+    FILE_ERROR.with(|file_error| *file_error.borrow_mut() = error);
+}
+
+
+pub fn get_file_error() -> String{
+    return FILE_ERROR.with(|file_error| file_error.borrow().clone());
+}
+
+
+pub fn set_input_eof(is_eof: bool){
+    INPUT_EOF.with(|input_eof| input_eof.set(is_eof));
+}
+
+
+pub fn get_input_eof() -> bool{
+    return INPUT_EOF.with(|input_eof| input_eof.get());
+}
+
+
+/* Keep The Failed Call Result And Its Error Message */
+fn file_result<T>(
+    name: &str, path: &String, result: std::io::Result<T>, failed_value: BuiltinValue,
+    construct_value: impl FnOnce(T) -> BuiltinValue
+) -> BuiltinValue{
+
+    if result.is_err(){
+        set_file_error(format!("`{}` failed for `{}`: {}", name, path, result.err().unwrap()));
+        return failed_value;
+    }
+
+    set_file_error(String::new());
+    return construct_value(result.unwrap());
+}
+
+
+fn call_file_function(
+    name: &str, arguments: &Vec<BuiltinValue>
+) -> Result<BuiltinValue, String>{
+
+    if name == "file_error"{
+        return Ok(BuiltinValue::String(FILE_ERROR.with(|file_error| file_error.borrow().clone())));
+    }
+
+    let path = get_string_argument(arguments, 0);
+
+    if name == "read_file"{
+        return Ok(file_result(
+            name, &path, read_limited_string(name, std::fs::File::open(&path))?,
+            BuiltinValue::String(String::new()), |content| BuiltinValue::String(content)));
+    }
+    else if name == "write_file"{
+        return Ok(file_result(
+            name, &path, std::fs::write(&path, get_string_argument(arguments, 1)),
+            BuiltinValue::Bool(false), |_| BuiltinValue::Bool(true)));
+    }
+    else if name == "append_file"{
+        use std::io::Write;
+
+        let text = get_string_argument(arguments, 1);
+        let result = std::fs::OpenOptions::new().create(true).append(true).open(&path)
+            .and_then(|mut file| file.write_all(text.as_bytes()));
+
+        return Ok(file_result(
+            name, &path, result, BuiltinValue::Bool(false), |_| BuiltinValue::Bool(true)));
+    }
+    else if name == "read_lines"{
+        return Ok(file_result(
+            name, &path, read_limited_string(name, std::fs::File::open(&path))?,
+            BuiltinValue::List(vec![]),
+            |content| BuiltinValue::List(content.lines().map(|line| String::from(line)).collect())));
+    }
+    else if name == "file_exists"{
+        return Ok(file_result(
+            name, &path, std::fs::metadata(&path).map(|_| true).or_else(
+                |error| if error.kind() == std::io::ErrorKind::NotFound {Ok(false)} else {Err(error)}),
+            BuiltinValue::Bool(false), |exists| BuiltinValue::Bool(exists)));
+    }
+    else if name == "delete_file"{
+        return Ok(file_result(
+            name, &path, std::fs::remove_file(&path),
+            BuiltinValue::Bool(false), |_| BuiltinValue::Bool(true)));
+    }
+
+    return Err(format!("Unknown function `{}`", name));
+}
+
+
+/* `None` At The End Of The Input, The Line Ending Is Removed */
+fn read_input_line() -> Result<Option<String>, String>{
+    let mut line = String::new();
+
+    let result = std::io::stdin().read_line(&mut line);
+    if result.is_err(){
+        return Err(format!("Failed to read line: {}", result.unwrap_err()));
+    }
+    if result.unwrap() == 0{
+        return Ok(None);
+    }
+
+    if line.ends_with('\n'){
+        line.pop();
+        if line.ends_with('\r'){
+            line.pop();
+        }
+    }
+    return Ok(Some(line));
+}
+
+
+fn call_input_function(name: &str) -> Result<BuiltinValue, String>{
+    if name == "input_eof"{
+        return Ok(BuiltinValue::Bool(INPUT_EOF.with(|input_eof| input_eof.get())));
+    }
+    else if name == "read_all_stdin"{
+        let content = read_limited_string(name, Ok(std::io::stdin()))?;
+        if content.is_err(){
+            return Err(format!("Failed to read the input: {}", content.unwrap_err()));
+        }
+
+        INPUT_EOF.with(|input_eof| input_eof.set(true));
+        return Ok(BuiltinValue::String(content.unwrap()));
+    }
+
+    let line = read_input_line()?;
+    INPUT_EOF.with(|input_eof| input_eof.set(line == None));
+
+    if name == "input_line"{
+        return Ok(BuiltinValue::String(line.unwrap_or(String::new())));
+    }
+    else if name == "input_int"{
+        if line == None{
+            return Ok(BuiltinValue::Int(0));
+        }
+
+        let value = line.as_ref().unwrap().trim().parse::<i64>();
+        if value.is_err(){
+            return Err(format!("`input_int` can't convert `{}` to int", line.unwrap()));
+        }
+        return Ok(BuiltinValue::Int(value.unwrap()));
+    }
+    else if name == "input_double"{
+        if line == None{
+            return Ok(BuiltinValue::Double(0.0));
+        }
+
+        let value = line.as_ref().unwrap().trim().parse::<f64>();
+        if value.is_err(){
+            return Err(format!("`input_double` can't convert `{}` to double", line.unwrap()));
+        }
+        return Ok(BuiltinValue::Double(value.unwrap()));
+    }
+
+    return Err(format!("Unknown function `{}`", name));
+}
+
+
+fn call_program_function(
+    name: &str, arguments: &Vec<BuiltinValue>
+) -> Result<BuiltinValue, String>{
+
+    if name == "args"{
+        return Ok(BuiltinValue::List(get_program_arguments().clone()));
+    }
+    else if name == "env"{
+        return Ok(BuiltinValue::String(
+            std::env::var(get_string_argument(arguments, 0)).unwrap_or(String::new())));
+    }
+    else if name == "exit"{
+        let code = get_int_argument(arguments, 0);
+        if code < i32::MIN as i64 || code > i32::MAX as i64{
+            return Err(format!("`exit` code {} does not fit into i32", code));
+        }
+
+        EXIT_CODE.with(|exit_code| exit_code.set(Some(code as i32)));
+        return Err(format!("Program exited with code {}", code));
+    }
+
+    return Err(format!("Unknown function `{}`", name));
+}
+
+
+/* The Same Text As `print` */
+fn get_display_text(value: &BuiltinValue) -> String{
+    return match value {
+        BuiltinValue::Bool(value) => String::from(if *value {"True"} else {"False"}),
+        BuiltinValue::Int(value) => value.to_string(),
+        BuiltinValue::Double(value) => value.to_string(),
+        BuiltinValue::Char(value) => value.to_string(),
+        BuiltinValue::String(value) => value.clone(),
+        BuiltinValue::List(value) => format!("{:?}", value),
+    };
+}
+
+
+/* `[[fill]align][0][width][.precision]`, Like Rust Without The Arguments Names And Types */
+fn format_value(value: &BuiltinValue, specifier: &str) -> Result<String, String>{
+    let characters: Vec<char> = specifier.chars().collect();
+    let is_align = |character: char| character == '<' || character == '>' || character == '^';
+    let invalid_specifier = || format!("`format` placeholder `{{:{}}}` is invalid", specifier);
+
+    let mut index = 0;
+    let mut fill = ' ';
+    let mut align: Option<char> = None;
+    if characters.len() >= 2 && is_align(characters[1]){
+        fill = characters[0];
+        align = Some(characters[1]);
+        index = 2;
+    }
+    else if characters.len() >= 1 && is_align(characters[0]){
+        align = Some(characters[0]);
+        index = 1;
+    }
+
+    let is_zero_padded = index < characters.len() && characters[index] == '0';
+    if is_zero_padded{
+        index += 1;
+    }
+
+    let mut width = String::new();
+    while index < characters.len() && characters[index].is_ascii_digit(){
+        width.push(characters[index]);
+        index += 1;
+    }
+
+    let mut precision: Option<usize> = None;
+    if index < characters.len() && characters[index] == '.'{
+        index += 1;
+
+        let mut digits = String::new();
+        while index < characters.len() && characters[index].is_ascii_digit(){
+            digits.push(characters[index]);
+            index += 1;
+        }
+        if digits.is_empty(){
+            return Err(invalid_specifier());
+        }
+        precision = Some(digits.parse::<usize>().map_err(|_| invalid_specifier())?);
+    }
+
+    if index != characters.len(){
+        return Err(invalid_specifier());
+    }
+    let width = if width.is_empty() {0} else {width.parse::<usize>().map_err(|_| invalid_specifier())?};
+
+    let text = match (value, precision) {
+        (BuiltinValue::Double(value), Some(precision)) => format!("{:.*}", precision, value),
+        (BuiltinValue::String(_), Some(precision)) | (BuiltinValue::Char(_), Some(precision)) =>
+            get_display_text(value).chars().take(precision).collect(),
+        _ => get_display_text(value),
+    };
+
+    let length = text.chars().count();
+    if length >= width{
+        return Ok(text);
+    }
+    let padding = width - length;
+
+    let is_number = match value {
+        BuiltinValue::Int(_) | BuiltinValue::Double(_) => true,
+        _ => false,
+    };
+
+    /* Zeros Are Put After The Sign */
+    if is_zero_padded && is_number && align == None{
+        let (sign, digits) = if text.starts_with('-') {text.split_at(1)} else {("", text.as_str())};
+        return Ok(format!("{}{}{}", sign, "0".repeat(padding), digits));
+    }
+
+    let fill = fill.to_string();
+    let align = align.unwrap_or(if is_number {'>'} else {'<'});
+    if align == '<'{
+        return Ok(text + &fill.repeat(padding));
+    }
+    else if align == '>'{
+        return Ok(fill.repeat(padding) + &text);
+    }
+    return Ok(fill.repeat(padding / 2) + &text + &fill.repeat(padding - padding / 2));
+}
+
+
+/* `{}` Or `{:specifier}` For Every Value, `{{` And `}}` Are Printed As Braces */
+fn call_format_function(arguments: &Vec<BuiltinValue>) -> Result<BuiltinValue, String>{
+    let template = get_string_argument(arguments, 0);
+    let values = &arguments[1..];
+
+    let mut result = String::new();
+    let mut value_index = 0;
+    let mut characters = template.chars().peekable();
+
+    while let Some(character) = characters.next(){
+        if character == '{' && characters.peek() == Some(&'{'){
+            characters.next();
+            result.push('{');
+        }
+        else if character == '}' && characters.peek() == Some(&'}'){
+            characters.next();
+            result.push('}');
+        }
+        else if character == '}'{
+            return Err(String::from("`format` has an unmatched `}`, use `}}` to print it"));
+        }
+        else if character == '{'{
+            let mut placeholder = String::new();
+            loop{
+                let next_character = characters.next();
+                if next_character == None{
+                    return Err(String::from("`format` has an unclosed `{`, use `{{` to print it"));
+                }
+                if next_character == Some('}'){
+                    break;
+                }
+                placeholder.push(next_character.unwrap());
+            }
+
+            if placeholder != "" && !placeholder.starts_with(':'){
+                return Err(format!(
+                    "`format` placeholder `{{{}}}` is invalid, expected `{{}}` or `{{:specifier}}`",
+                    placeholder));
+            }
+            if value_index >= values.len(){
+                return Err(format!(
+                    "`format` has more placeholders than the {} values", values.len()));
+            }
+
+            let specifier = if placeholder == "" {""} else {&placeholder[1..]};
+            result += &format_value(&values[value_index], specifier)?;
+            value_index += 1;
+        }
+        else{
+            result.push(character);
+        }
+    }
+
+    if value_index != values.len(){
+        return Err(format!(
+            "`format` has {} placeholders for {} values", value_index, values.len()));
+    }
+
+    return Ok(BuiltinValue::String(result));
+}
+
+
+fn convert_to_int(name: &str, value: f64) -> Result<BuiltinValue, String>{
+    if !value.is_finite() || value < i64::MIN as f64 || value >= i64::MAX as f64{
+        return Err(format!("`{}` result `{}` does not fit into int", name, value));
+    }
+    return Ok(BuiltinValue::Int(value as i64));
+}
+
+
+fn call_math_function(
+    name: &str, arguments: &Vec<BuiltinValue>, is_int: bool
+) -> Result<BuiltinValue, String>{
+
+    if is_int && (name == "abs" || name == "min" || name == "max" || name == "pow"){
+        let first = get_int_argument(arguments, 0);
+
+        if name == "abs"{
+            let result = first.checked_abs();
+            if result == None{
+                return Err(format!("`abs` result of `{}` does not fit into int", first));
+            }
+            return Ok(BuiltinValue::Int(result.unwrap()));
+        }
+
+        let second = get_int_argument(arguments, 1);
+        if name == "min"{
+            return Ok(BuiltinValue::Int(first.min(second)));
+        }
+        else if name == "max"{
+            return Ok(BuiltinValue::Int(first.max(second)));
+        }
+
+        if second < 0{
+            return Err(format!(
+                "`pow` exponent `{}` can't be negative for int values, use a double base",
+                second));
+        }
+        let result = if second > u32::MAX as i64 {None} else {first.checked_pow(second as u32)};
+        if result == None{
+            return Err(format!("`pow` result of `{}` and `{}` does not fit into int", first, second));
+        }
+        return Ok(BuiltinValue::Int(result.unwrap()));
+    }
+
+    if is_int && (name == "floor" || name == "ceil" || name == "round"){
+        return Ok(BuiltinValue::Int(get_int_argument(arguments, 0)));
+    }
+
+    let first = get_double_argument(arguments, 0);
+
+    if name == "abs"{
+        return Ok(BuiltinValue::Double(first.abs()));
+    }
+    else if name == "min"{
+        return Ok(BuiltinValue::Double(first.min(get_double_argument(arguments, 1))));
+    }
+    else if name == "max"{
+        return Ok(BuiltinValue::Double(first.max(get_double_argument(arguments, 1))));
+    }
+    else if name == "pow"{
+        return Ok(BuiltinValue::Double(first.powf(get_double_argument(arguments, 1))));
+    }
+    else if name == "sqrt"{
+        return Ok(BuiltinValue::Double(first.sqrt()));
+    }
+    else if name == "floor"{
+        return convert_to_int(name, first.floor());
+    }
+    else if name == "ceil"{
+        return convert_to_int(name, first.ceil());
+    }
+    else if name == "round"{
+        return convert_to_int(name, first.round());
+    }
+    else if name == "sin"{
+        return Ok(BuiltinValue::Double(first.sin()));
+    }
+    else if name == "cos"{
+        return Ok(BuiltinValue::Double(first.cos()));
+    }
+    else if name == "tan"{
+        return Ok(BuiltinValue::Double(first.tan()));
+    }
+    else if name == "log"{
+        return Ok(BuiltinValue::Double(first.ln()));
+    }
+    else if name == "exp"{
+        return Ok(BuiltinValue::Double(first.exp()));
+    }
+
+    return Err(format!("Unknown function `{}`", name));
+}
+
+
+/* Run A Call, The Arguments Types Are Already Checked By `get_builtin_return_type` */
+pub fn call_builtin(
+    name: &str, arguments: Vec<BuiltinValue>
+) -> Result<BuiltinValue, String>{
+
+    let arguments_types: Vec<BuiltinType> = arguments.iter().map(
+        |argument| argument.get_type()).collect();
+    get_builtin_return_type(name, &arguments_types)?;
+
+    if get_math_function(name) != None{
+        let is_int = arguments_types.iter().all(|argument_type| argument_type == &BuiltinType::Int);
+        return call_math_function(name, &arguments, is_int);
+    }
+    else if name == FORMAT_FUNCTION{
+        return call_format_function(&arguments);
+    }
+    else if arguments_types.first() == Some(&BuiltinType::List){
+        return call_list_function(name, &arguments);
+    }
+    else if get_file_function(name) != None{
+        return call_file_function(name, &arguments);
+    }
+    else if get_input_function(name) != None{
+        return call_input_function(name);
+    }
+    else if get_program_function(name) != None{
+        return call_program_function(name, &arguments);
+    }
+
+    return call_string_function(name, &arguments);
+}
+
+
+/* Used By The Compiled Programs, Runtime Errors Stop The Program Like The Other Backends */
+pub fn expect_builtin(
+    name: &str, arguments: Vec<BuiltinValue>, line: u64, pos: u64
+) -> BuiltinValue{
+
+    let result = call_builtin(name, arguments);
+    if result.is_err() && get_exit_code() != None{
+        use std::io::Write;
+
+        std::io::stdout().flush().unwrap_or(());
+        std::process::exit(get_exit_code().unwrap());
+    }
+    if result.is_err(){
+        panic!(
+            "Engine Compiler: Builtin Error -> {}, line: {}:{}",
+            result.unwrap_err(), line, pos);
+    }
+
+    return result.unwrap();
+}
@@ -0,0 +1,177 @@
+mod functions;
+
+use crate::constants::Mode;
+use crate::tokens::TokenType;
+use crate::environments::{
+    Value,
+    ValueType
+};
+use crate::syntax_tree::OperationNode;
+
+pub use functions::{
+    BuiltinType,
+    BuiltinValue,
+    is_builtin_function,
+    is_file_function,
+    is_input_function,
+    is_pure_function,
+    get_builtin_constant,
+    set_program_arguments,
+    get_exit_code,
+    get_file_error,
+    set_file_error,
+    get_input_eof,
+    set_input_eof,
+    set_memory_limit,
+    get_builtin_return_type,
+    call_builtin
+};
+
+
+/* The Compiled Programs Embed The Functions Source As A Module */
+pub const BUILTINS_SOURCE: &'static str = include_str!("functions.rs");
+
+
+/* Accepts Both Literal Types (`IntNumber`) And Variable Types (`Int`) */
+pub fn get_builtin_type(token_type: &TokenType) -> Option<BuiltinType>{
+    if token_type == &TokenType::Bool ||
+        token_type == &TokenType::True ||
+        token_type == &TokenType::False
+    {
+        return Some(BuiltinType::Bool);
+    }
+    else if token_type == &TokenType::Int || token_type == &TokenType::IntNumber{
+        return Some(BuiltinType::Int);
+    }
+    else if token_type == &TokenType::Double || token_type == &TokenType::DoubleNumber{
+        return Some(BuiltinType::Double);
+    }
+    else if token_type == &TokenType::Char || token_type == &TokenType::Character{
+        return Some(BuiltinType::Char);
+    }
+    else if token_type == &TokenType::String || token_type == &TokenType::StringSequence{
+        return Some(BuiltinType::String);
+    }
+    else if token_type == &TokenType::List{
+        return Some(BuiltinType::List);
+    }
+
+    return None;
+}
+
+
+/* The Type Of An Expression Result, As Returned By The Analyzers */
+pub fn get_builtin_token_type(builtin_type: &BuiltinType) -> TokenType{
+    return match builtin_type {
+        BuiltinType::Bool => TokenType::Bool,
+        BuiltinType::Int => TokenType::IntNumber,
+        BuiltinType::Double => TokenType::DoubleNumber,
+        BuiltinType::Char => TokenType::Character,
+        BuiltinType::String => TokenType::StringSequence,
+        BuiltinType::List => TokenType::List,
+    };
+}
+
+
+/* The Type Of A Variable Holding The Result */
+pub fn get_builtin_variable_type(builtin_type: &BuiltinType) -> TokenType{
+    return match builtin_type {
+        BuiltinType::Bool => TokenType::Bool,
+        BuiltinType::Int => TokenType::Int,
+        BuiltinType::Double => TokenType::Double,
+        BuiltinType::Char => TokenType::Char,
+        BuiltinType::String => TokenType::String,
+        BuiltinType::List => TokenType::List,
+    };
+}
+
+
+pub fn get_builtin_value(value: &Value) -> BuiltinValue{
+    if value.value_type == Some(ValueType::Boolean){
+        return BuiltinValue::Bool(value.boolean.unwrap());
+    }
+    else if value.value_type == Some(ValueType::Integer){
+        return BuiltinValue::Int(value.int.unwrap());
+    }
+    else if value.value_type == Some(ValueType::Double){
+        return BuiltinValue::Double(value.double.unwrap());
+    }
+    else if value.value_type == Some(ValueType::Character){
+        return BuiltinValue::Char(value.character.unwrap());
+    }
+    else if value.value_type == Some(ValueType::List){
+        return BuiltinValue::List(value.list.clone().unwrap());
+    }
+
+    return BuiltinValue::String(value.string.clone().unwrap_or(String::new()));
+}
+
+
+pub fn construct_builtin_value(builtin_value: BuiltinValue) -> Value{
+    let mut value = Value::new();
+
+    match builtin_value {
+        BuiltinValue::Bool(boolean) => {
+            value.value_type = Some(ValueType::Boolean);
+            value.boolean = Some(boolean);
+        },
+        BuiltinValue::Int(int) => {
+            value.value_type = Some(ValueType::Integer);
+            value.int = Some(int);
+        },
+        BuiltinValue::Double(double) => {
+            value.value_type = Some(ValueType::Double);
+            value.double = Some(double);
+        },
+        BuiltinValue::Char(character) => {
+            value.value_type = Some(ValueType::Character);
+            value.character = Some(character);
+        },
+        BuiltinValue::String(string) => {
+            value.value_type = Some(ValueType::String);
+            value.string = Some(string);
+        },
+        BuiltinValue::List(list) => {
+            value.value_type = Some(ValueType::List);
+            value.list = Some(list);
+        },
+    }
+
+    return value;
+}
+
+
+/* Type Check A Call Node With Its Analyzed Arguments Types */
+pub fn analyze_call(
+    mode: &Mode, operation_node: &OperationNode, arguments_types: Vec<TokenType>
+) -> Result<TokenType, String>{
+
+    let function_token = operation_node.value.as_ref().unwrap();
+
+    let mut builtin_types = vec![];
+    for (index, argument_type) in arguments_types.iter().enumerate(){
+        let builtin_type = get_builtin_type(argument_type);
+        if builtin_type == None{
+            return Err(format!(
+                "{}: Syntax Error -> {}, line {}:{}.",
+                mode,
+                format!(
+                    "Function `{}` argument {} has an invalid type `{:?}`",
+                    function_token.value, index + 1, argument_type),
+                function_token.start_line,
+                function_token.start_pos));
+        }
+        builtin_types.push(builtin_type.unwrap());
+    }
+
+    let return_type = get_builtin_return_type(&function_token.value, &builtin_types);
+    if return_type.is_err(){
+        return Err(format!(
+            "{}: Syntax Error -> {}, line {}:{}.",
+            mode, return_type.unwrap_err(),
+            function_token.start_line,
+            function_token.start_pos));
+    }
+
+    return Ok(get_builtin_token_type(&return_type.unwrap()));
+}
@@ -0,0 +1,812 @@
+use std::collections::{
+    HashMap,
+    HashSet,
+    VecDeque
+};
+
+use crate::tokens::{
+    Token,
+    TokenType
+};
+use crate::constants::{
+    Mode,
+    INT_NUMBER_MAX_LENGTH,
+    DOUBLE_NUMBER_MAX_LENGTH
+};
+use crate::builtins::{
+    analyze_call,
+    get_builtin_constant
+};
+use crate::environments::{
+    Environment,
+    EnvironmentScope,
+    Variable
+};
+use crate::syntax_tree::{
+    StatementsNode,
+    StatementNode,
+    StatementType,
+    DefineVariableNode,
+    DefineVarNode,
+    DefineIfStatementNode,
+    DefineForLoopStatementNode,
+    OperationNode,
+    OperatorType,
+};
+
+
+/*
+* The Type Checker Shared By The Interpreter, The Compiler And The Byte Code Generator,
+* It Rejects The Invalid Programs Before They Run And Annotates The Syntax Tree With
+* The Resolved Types So The Backends Don't Infer Them Again.
+*/
+#[derive(Debug)]
+pub struct Checker{
+    pub mode: Mode,
+    pub environments_stack: VecDeque<Environment>,
+
+    /* Names Of The Variables Defined With `const` */
+    pub constants: HashSet<String>,
+}
+
+impl Checker{
+    pub fn new(mode: Mode) -> Self{
+        let mut environments_stack = VecDeque::new();
+        environments_stack.push_back(Environment {
+            scope: EnvironmentScope::Main,
+            variables: HashMap::new(),
+            internal_variables: HashMap::new(),
+            stop_statements_execution: None,
+        });
+
+        return Checker{
+            mode,
+            environments_stack,
+            constants: HashSet::new(),
+        };
+    }
+}
+
+
+fn is_variable_exists(checker: &Checker, variable_name: &String) -> bool{
+    for environment in &checker.environments_stack{
+        if environment.variables.contains_key(variable_name){
+            return true;
+        }
+    }
+
+    return false;
+}
+
+
+fn insert_variable_into_current_environmment(
+    checker: &mut Checker, name: &String, variable_type: TokenType
+){
+    let mut variable = Variable::new();
+    variable.name = Some(name.clone());
+    variable.variable_type = Some(variable_type);
+
+    checker.environments_stack.back_mut().unwrap().variables.insert(
+        name.clone(), Some(variable));
+}
+
+
+fn get_variable_type(checker: &Checker, variable_name: &String) -> Option<TokenType>{
+    for environment in &checker.environments_stack{
+        if environment.variables.contains_key(variable_name){
+            return environment.variables.get(variable_name).unwrap()
+                .as_ref().unwrap().variable_type.clone();
+        }
+    }
+
+    return None;
+}
+
+
+fn push_environment(checker: &mut Checker, scope: EnvironmentScope){
+    checker.environments_stack.push_back(Environment {
+        scope,
+        variables: HashMap::new(),
+        internal_variables: HashMap::new(),
+        stop_statements_execution: None,
+    });
+}
+
+
+fn syntax_error(checker: &Checker, message: String, token: &Token) -> String{
+    return format!(
+        "{}: Syntax Error -> {}, line {}:{}.",
+        checker.mode, message, token.start_line, token.start_pos);
+}
+
+
+fn analyze_error(checker: &Checker, message: String, token: &Token) -> String{
+    return format!(
+        "{}: Analyze Error -> {}, line {}:{}.",
+        checker.mode, message, token.start_line, token.start_pos);
+}
+
+
+fn is_bool_type(node_type: &TokenType) -> bool{
+    return node_type == &TokenType::Bool ||
+        node_type == &TokenType::True ||
+        node_type == &TokenType::False;
+}
+
+
+/* The Type Of A Variable Holding An Expression Result, A Character Becomes A String */
+pub fn get_variable_type_of(node_type: &TokenType) -> TokenType{
+    if is_bool_type(node_type){
+        return TokenType::Bool;
+    }
+    else if node_type == &TokenType::IntNumber{
+        return TokenType::Int;
+    }
+    else if node_type == &TokenType::DoubleNumber{
+        return TokenType::Double;
+    }
+    else if node_type == &TokenType::Character || node_type == &TokenType::StringSequence{
+        return TokenType::String;
+    }
+
+    return node_type.clone();
+}
+
+
+pub fn check(checker: &mut Checker, syntax_tree: &mut StatementsNode) -> Result<(), String>{
+    let mut checker = checker;
+
+    for statement in &mut syntax_tree.statements{
+        check_statement(&mut checker, statement)?;
+    }
+
+    return Ok(());
+}
+
+
+fn check_statement(checker: &mut Checker, statement: &mut StatementNode) -> Result<(), String>{
+    let mut checker = checker;
+
+    check_constant(&mut checker, statement)?;
+
+    if statement.statement_type == Some(StatementType::DefineBool){
+        let define_bool_node = statement.define_bool_statement.as_mut().unwrap();
+        check_typed_definition(
+            &mut checker, define_bool_node.name.as_ref().unwrap(),
+            define_bool_node.left.as_mut().unwrap(), TokenType::Bool)?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineInt){
+        let define_int_node = statement.define_int_statement.as_mut().unwrap();
+        check_typed_definition(
+            &mut checker, define_int_node.name.as_ref().unwrap(),
+            define_int_node.left.as_mut().unwrap(), TokenType::Int)?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineDouble){
+        let define_double_node = statement.define_double_statement.as_mut().unwrap();
+        check_typed_definition(
+            &mut checker, define_double_node.name.as_ref().unwrap(),
+            define_double_node.left.as_mut().unwrap(), TokenType::Double)?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineChar){
+        let define_char_node = statement.define_char_statement.as_mut().unwrap();
+        check_typed_definition(
+            &mut checker, define_char_node.name.as_ref().unwrap(),
+            define_char_node.left.as_mut().unwrap(), TokenType::Char)?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineString){
+        let define_string_node = statement.define_string_statement.as_mut().unwrap();
+        check_typed_definition(
+            &mut checker, define_string_node.name.as_ref().unwrap(),
+            define_string_node.left.as_mut().unwrap(), TokenType::String)?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineVar){
+        check_define_var(&mut checker, statement.define_var_statement.as_mut().unwrap())?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineVariable){
+        check_define_variable(&mut checker, statement.define_variable_statement.as_mut().unwrap())?;
+    }
+
+    else if statement.statement_type == Some(StatementType::Print){
+        let define_print_node = statement.define_print_statement.as_mut().unwrap();
+        for expression in &mut define_print_node.expressions{
+            check_operation_node(&checker, expression)?;
+        }
+        if define_print_node.separator != None{
+            check_operation_node(&checker, define_print_node.separator.as_mut().unwrap())?;
+        }
+        if define_print_node.end != None{
+            check_operation_node(&checker, define_print_node.end.as_mut().unwrap())?;
+        }
+    }
+    else if statement.statement_type == Some(StatementType::Call){
+        check_operation_node(
+            &checker, statement.define_call_statement.as_mut().unwrap().expression.as_mut().unwrap())?;
+    }
+
+    else if statement.statement_type == Some(StatementType::DefineIf){
+        check_define_if_statement(&mut checker, statement.define_if_statement.as_mut().unwrap())?;
+    }
+
+    else if statement.statement_type == Some(StatementType::DefineForLoop){
+        check_define_for_loop_statement(
+            &mut checker, statement.define_for_loop_statement.as_mut().unwrap())?;
+    }
+    else if statement.statement_type == Some(StatementType::Continue){
+        let continue_token = statement.define_continue_statement.as_ref().unwrap()
+            .meta.get("continue-token").unwrap().as_ref().unwrap();
+        check_inside_loop(&checker, "continue", continue_token)?;
+    }
+    else if statement.statement_type == Some(StatementType::Break){
+        let break_token = statement.define_break_statement.as_ref().unwrap()
+            .meta.get("break-token").unwrap().as_ref().unwrap();
+        check_inside_loop(&checker, "break", break_token)?;
+    }
+
+    check_constant_expressions(&mut checker, statement)?;
+
+    return Ok(());
+}
+
+
+/* Constants Can't Be Reassigned Or Redefined, And Their Values Are Known At Compile Time */
+fn check_constant(checker: &mut Checker, statement: &StatementNode) -> Result<(), String>{
+    let name_token = if statement.statement_type == Some(StatementType::DefineForLoop){
+        statement.define_for_loop_statement.as_ref().unwrap().variable.as_ref()
+    }
+    else {statement.get_assigned_token()};
+
+    if name_token == None{
+        return Ok(());
+    }
+    let name_token = name_token.unwrap();
+
+    if checker.constants.contains(&name_token.value){
+        let message = if statement.statement_type == Some(StatementType::DefineVariable){
+            format!("Constant `{}` can't be reassigned", name_token.value)
+        }
+        else{
+            format!("Constant `{}` can't be redefined", name_token.value)
+        };
+
+        return Err(syntax_error(checker, message, name_token));
+    }
+
+    if !statement.is_constant{
+        return Ok(());
+    }
+
+    if is_variable_exists(checker, &name_token.value){
+        return Err(syntax_error(
+            checker,
+            format!("Variable `{}` is already defined, it can't be a constant", name_token.value),
+            name_token));
+    }
+
+    return Ok(());
+}
+
+
+/* Runs After The Statement Is Checked, When `PI` And `E` Are Already Resolved */
+fn check_constant_expressions(checker: &mut Checker, statement: &StatementNode) -> Result<(), String>{
+    if !statement.is_constant{
+        return Ok(());
+    }
+    let name_token = statement.get_assigned_token().unwrap();
+
+    for expression in statement.get_expressions(){
+        let token = expression.get_non_constant_token(&checker.constants);
+        if token != None{
+            let token = token.unwrap();
+            return Err(syntax_error(
+                checker,
+                format!(
+                    "Constant `{}` must be initialized with a constant expression, `{}` is not constant",
+                    name_token.value, token.value),
+                &token));
+        }
+    }
+
+    checker.constants.insert(name_token.value.clone());
+
+    return Ok(());
+}
+
+
+/* `bool`, `int`, `double`, `char` And `string` Definitions */
+fn check_typed_definition(
+    checker: &mut Checker,
+    name: &Token,
+    left: &mut OperationNode,
+    variable_type: TokenType
+) -> Result<(), String>{
+
+    let mut checker = checker;
+
+    let node_type = check_operation_node(&checker, left)?;
+
+    let (is_valid, type_name) = if variable_type == TokenType::Bool{
+        (is_bool_type(&node_type), "Boolean")
+    }
+    else if variable_type == TokenType::Int{
+        (node_type == TokenType::IntNumber || node_type == TokenType::DoubleNumber, "Int")
+    }
+    else if variable_type == TokenType::Double{
+        (node_type == TokenType::IntNumber || node_type == TokenType::DoubleNumber, "Double")
+    }
+    else if variable_type == TokenType::Char{
+        (node_type == TokenType::Character || node_type == TokenType::StringSequence, "Character")
+    }
+    else{
+        (node_type == TokenType::StringSequence || node_type == TokenType::Character, "String")
+    };
+
+    if !is_valid{
+        return Err(syntax_error(
+            checker, format!("Can't assign `{:?}` to {}", node_type, type_name), name));
+    }
+
+    insert_variable_into_current_environmment(&mut checker, &name.value, variable_type);
+
+    return Ok(());
+}
+
+
+fn check_define_var(checker: &mut Checker, statement: &mut DefineVarNode) -> Result<(), String>{
+    let mut checker = checker;
+
+    let node_type = check_operation_node(&checker, statement.left.as_mut().unwrap())?;
+    let variable_type = get_variable_type_of(&node_type);
+
+    insert_variable_into_current_environmment(
+        &mut checker, &statement.name.as_ref().unwrap().value, variable_type.clone());
+
+    statement.variable_type = Some(variable_type);
+
+    return Ok(());
+}
+
+
+fn check_define_variable(
+    checker: &mut Checker, statement: &mut DefineVariableNode
+) -> Result<(), String>{
+
+    let mut checker = checker;
+
+    let name = statement.name.as_ref().unwrap().clone();
+    let operator_type = statement.operator.as_ref().unwrap().token_type.clone();
+
+    /* Assigning An Undefined Variable Defines It In The Current Scope */
+    if !is_variable_exists(&checker, &name.value){
+        if operator_type != TokenType::Assign{
+            return Err(syntax_error(
+                &checker, format!("Undefined variable `{}`", name.value), &name));
+        }
+
+        let node_type = check_operation_node(&checker, statement.left.as_mut().unwrap())?;
+        let variable_type = get_variable_type_of(&node_type);
+
+        insert_variable_into_current_environmment(&mut checker, &name.value, variable_type.clone());
+
+        statement.variable_type = Some(variable_type);
+        statement.is_definition = true;
+
+        return Ok(());
+    }
+
+    let variable_type = get_variable_type(&checker, &name.value).unwrap();
+
+    /* Validate The Operator With The Variable Type */
+    let is_valid_operator = if variable_type == TokenType::String{
+        operator_type == TokenType::Assign || operator_type == TokenType::PlusEqual
+    }
+    else if
+        variable_type == TokenType::Char ||
+        variable_type == TokenType::Bool ||
+        variable_type == TokenType::List
+    {
+        operator_type == TokenType::Assign
+    }
+    else {true};
+
+    if !is_valid_operator{
+        return Err(syntax_error(
+            &checker,
+            format!(
+                "Invalid operation `{:?}` on `{:?}` which has type `{:?}`",
+                operator_type, name.value, variable_type),
+            &name));
+    }
+
+    /* Validate The Expression Type With The Variable Type */
+    let node_type = check_operation_node(&checker, statement.left.as_mut().unwrap())?;
+
+    let is_valid_type = if node_type == TokenType::List || variable_type == TokenType::List{
+        node_type == variable_type
+    }
+    else if is_bool_type(&node_type){
+        variable_type == TokenType::Bool
+    }
+    else if node_type == TokenType::IntNumber || node_type == TokenType::DoubleNumber{
+        variable_type == TokenType::Int || variable_type == TokenType::Double
+    }
+    else if node_type == TokenType::Character || node_type == TokenType::StringSequence{
+        variable_type == TokenType::String || variable_type == TokenType::Char
+    }
+    else {true};
+
+    if !is_valid_type{
+        return Err(syntax_error(
+            &checker, format!("Can't assign `{:?}` to `{:?}`", node_type, variable_type), &name));
+    }
+
+    statement.variable_type = Some(variable_type);
+    statement.is_definition = false;
+
+    return Ok(());
+}
+
+
+fn check_condition(
+    checker: &Checker, condition: &mut OperationNode, if_token: &Token
+) -> Result<(), String>{
+
+    let node_type = check_operation_node(checker, condition)?;
+
+    if !is_bool_type(&node_type){
+        return Err(analyze_error(
+            checker, String::from("if condition must be `Bool`"), if_token));
+    }
+
+    return Ok(());
+}
+
+
+fn check_define_if_statement(
+    checker: &mut Checker, statement: &mut DefineIfStatementNode
+) -> Result<(), String>{
+
+    let mut checker = checker;
+
+    /* Check If Statement */
+    {
+        let define_if_node = statement.define_if_node.as_mut().unwrap();
+
+        push_environment(&mut checker, EnvironmentScope::If);
+
+        check_condition(
+            &checker, define_if_node.condition.as_mut().unwrap(),
+            define_if_node.token.as_ref().unwrap())?;
+        check(&mut checker, &mut define_if_node.statements)?;
+
+        checker.environments_stack.pop_back();
+    }
+
+    /* Check Else If Statements */
+    for define_if_else_node in &mut statement.define_if_else_nodes{
+        push_environment(&mut checker, EnvironmentScope::If);
+
+        check_condition(
+            &checker, define_if_else_node.condition.as_mut().unwrap(),
+            define_if_else_node.token.as_ref().unwrap())?;
+        check(&mut checker, &mut define_if_else_node.statements)?;
+
+        checker.environments_stack.pop_back();
+    }
+
+    /* Check Else Statement */
+    if statement.define_else_node != None{
+        push_environment(&mut checker, EnvironmentScope::If);
+
+        check(&mut checker, &mut statement.define_else_node.as_mut().unwrap().statements)?;
+
+        checker.environments_stack.pop_back();
+    }
+
+    return Ok(());
+}
+
+
+fn check_loop_expression(
+    checker: &Checker,
+    expression: &mut Option<OperationNode>,
+    token: &Option<Token>,
+    expression_name: &str
+) -> Result<(), String>{
+
+    if expression == &None{
+        return Ok(());
+    }
+
+    let node_type = check_operation_node(checker, expression.as_mut().unwrap())?;
+
+    if node_type != TokenType::IntNumber{
+        let token = token.as_ref().unwrap();
+
+        return Err(analyze_error(
+            checker,
+            format!(
+                "{} expression must be of type `Int` Found `{:?}`",
+                expression_name, token.token_type),
+            token));
+    }
+
+    return Ok(());
+}
+
+
+fn check_define_for_loop_statement(
+    checker: &mut Checker, statement: &mut DefineForLoopStatementNode
+) -> Result<(), String>{
+
+    let mut checker = checker;
+
+    /* Check Loop Conditions */
+    check_loop_expression(
+        &checker, &mut statement.start, statement.meta.get("start-token").unwrap(), "Start")?;
+    check_loop_expression(
+        &checker, &mut statement.stop, statement.meta.get("stop-token").unwrap(), "Stop")?;
+    check_loop_expression(
+        &checker, &mut statement.step, statement.meta.get("step-token").unwrap(), "Step")?;
+
+    /* Check Loop Statements */
+    push_environment(&mut checker, EnvironmentScope::ForLoop);
+
+    if statement.variable != None{
+        insert_variable_into_current_environmment(
+            &mut checker, &statement.variable.as_ref().unwrap().value, TokenType::Int);
+    }
+
+    check(&mut checker, &mut statement.statements)?;
+
+    checker.environments_stack.pop_back();
+
+    return Ok(());
+}
+
+
+fn check_inside_loop(checker: &Checker, keyword: &str, token: &Token) -> Result<(), String>{
+    for environment in &checker.environments_stack{
+        if environment.scope == EnvironmentScope::ForLoop{
+            return Ok(());
+        }
+    }
+
+    return Err(analyze_error(
+        checker,
+        format!("Use of `{}` statement outside of Loop statement is invalid", keyword),
+        token));
+}
+
+
+/* Checks The Expression And Annotates Every Node With Its Result Type */
+fn check_operation_node(
+    checker: &Checker, operation_node: &mut OperationNode
+) -> Result<TokenType, String>{
+
+    let node_type = get_operation_node_type(checker, operation_node)?;
+    operation_node.result_type = Some(node_type.clone());
+
+    return Ok(node_type);
+}
+
+
+fn get_operation_node_type(
+    checker: &Checker, operation_node: &mut OperationNode
+) -> Result<TokenType, String>{
+
+    if operation_node.operator == None{
+        let constant_token = get_builtin_constant_token(checker, operation_node.value.as_ref().unwrap());
+        if constant_token != None{
+            operation_node.value = constant_token;
+        }
+        return get_value_type(checker, operation_node.value.as_ref().unwrap());
+    }
+    let operator = operation_node.operator.clone().unwrap();
+
+    /* Check Built-in Function Call */
+    if operator == OperatorType::Call{
+        let mut arguments_types = vec![];
+        for argument in &mut operation_node.arguments{
+            arguments_types.push(check_operation_node(checker, argument)?);
+        }
+
+        return analyze_call(&checker.mode, operation_node, arguments_types);
+    }
+
+    let mut left_type: TokenType = TokenType::BadToken;
+    let mut right_type: TokenType = TokenType::BadToken;
+
+    if operation_node.left != None{
+        left_type = check_operation_node(checker, operation_node.left.as_mut().unwrap())?;
+    }
+    if operation_node.right != None{
+        right_type = check_operation_node(checker, operation_node.right.as_mut().unwrap())?;
+    }
+
+    if operation_node.left != None && operation_node.right == None{
+        return Ok(left_type);
+    }
+    else if operation_node.right != None && operation_node.left == None{
+        return Ok(right_type);
+    }
+
+    let operator_token = operation_node.value.as_ref();
+
+    /* Check Input Operation, The Value Is The Type To Convert Into */
+    if operator == OperatorType::Convert{
+        let operator_token = operator_token.unwrap();
+        if operator_token.token_type == TokenType::Bool{
+            return Ok(TokenType::Bool);
+        }
+        else if operator_token.token_type == TokenType::Int{
+            return Ok(TokenType::IntNumber);
+        }
+        else if operator_token.token_type == TokenType::Double{
+            return Ok(TokenType::DoubleNumber);
+        }
+        else if operator_token.token_type == TokenType::Char{
+            return Ok(TokenType::Character);
+        }
+        else if operator_token.token_type == TokenType::String{
+            return Ok(TokenType::StringSequence);
+        }
+    }
+
+    /* Lists Are Only Passed To Functions And Printed */
+    if left_type == TokenType::List || right_type == TokenType::List{
+        return Err(syntax_error(
+            checker,
+            format!("Invalid operation {:?} on List types", operator),
+            operator_token.unwrap()));
+    }
+
+    /* Check String And Character Types, Only Concatenation Is Allowed */
+    if
+        left_type == TokenType::StringSequence || right_type == TokenType::StringSequence ||
+        left_type == TokenType::Character || right_type == TokenType::Character
+    {
+        let is_string = left_type == TokenType::StringSequence || right_type == TokenType::StringSequence;
+        let types_name = if is_string {"String"} else {"Character"};
+
+        let other_type = if left_type == TokenType::StringSequence ||
+            (!is_string && left_type == TokenType::Character) {&right_type} else {&left_type};
+
+        let is_valid_other_type = if is_string{
+            other_type == &TokenType::StringSequence || other_type == &TokenType::Character
+        }
+        else{
+            other_type == &TokenType::Character
+        };
+
+        if !is_valid_other_type{
+            return Err(syntax_error(
+                checker,
+                format!("Can't concate `{:?}` with {} types", other_type, types_name),
+                operation_node.right.as_ref().unwrap().value.as_ref().unwrap()));
+        }
+        else if operator != OperatorType::Plus{
+            return Err(syntax_error(
+                checker,
+                format!("Invalid operation {:?} on {} types", operator, types_name),
+                operator_token.unwrap()));
+        }
+        return Ok(TokenType::StringSequence);
+    }
+
+    /* Check Number Types, An Int With A Double Gives A Double Only In Divisions */
+    if left_type == TokenType::IntNumber || right_type == TokenType::IntNumber{
+        let other_type = if left_type == TokenType::IntNumber {&right_type} else {&left_type};
+
+        if other_type != &TokenType::IntNumber && other_type != &TokenType::DoubleNumber{
+            return Err(syntax_error(
+                checker,
+                format!(
+                    "Invalid operation {:?} on {:?} and {:?}",
+                    operator, TokenType::IntNumber, other_type),
+                operator_token.unwrap()));
+        }
+        else if
+            operator == OperatorType::Plus ||
+            operator == OperatorType::Minus ||
+            operator == OperatorType::Mul ||
+            operator == OperatorType::Mod
+        {
+            return Ok(TokenType::IntNumber);
+        }
+        else if operator == OperatorType::Div{
+            return Ok(TokenType::DoubleNumber);
+        }
+        return Ok(TokenType::Bool);
+    }
+
+    /* Check Boolean Types */
+    if is_bool_type(&left_type) || is_bool_type(&right_type){
+        if
+            !is_bool_type(&left_type) || !is_bool_type(&right_type) ||
+            (
+                operator != OperatorType::And &&
+                operator != OperatorType::Or &&
+                operator != OperatorType::Equal &&
+                operator != OperatorType::NotEqual
+            )
+        {
+            return Err(syntax_error(
+                checker,
+                format!("Invalid operation {:?} on Boolean types", operator),
+                operator_token.unwrap()));
+        }
+        return Ok(TokenType::Bool);
+    }
+
+    return Err(syntax_error(
+        checker,
+        format!("Undefined operator {:?} behavior", operator),
+        operator_token.unwrap()));
+}
+
+
+/*
+* `PI` And `E` Are Double Literals When No Variable With That Name Is Defined,
+* Variables Of Imported Modules Are Already Renamed To `prefix__name`.
+*/
+fn get_builtin_constant_token(checker: &Checker, token: &Token) -> Option<Token>{
+    if token.token_type != TokenType::Variable || is_variable_exists(checker, &token.value){
+        return None;
+    }
+
+    let name = token.value.rsplit("__").next().unwrap();
+    let constant = get_builtin_constant(name);
+    if constant == None{
+        return None;
+    }
+
+    return Some(Token{
+        token_type: TokenType::DoubleNumber,
+        start_line: token.start_line,
+        start_pos: token.start_pos,
+        value: format!("{:?}", constant.unwrap())
+    });
+}
+
+
+fn get_value_type(checker: &Checker, token: &Token) -> Result<TokenType, String>{
+    if token.token_type == TokenType::Variable{
+        let variable_type = get_variable_type(checker, &token.value);
+        if variable_type == None{
+            return Err(syntax_error(
+                checker, format!("Undefined variable `{}`", token.value), token));
+        }
+        let variable_type = variable_type.unwrap();
+
+        if is_bool_type(&variable_type){
+            return Ok(TokenType::Bool);
+        }
+        else if variable_type == TokenType::Int{
+            return Ok(TokenType::IntNumber);
+        }
+        else if variable_type == TokenType::Double{
+            return Ok(TokenType::DoubleNumber);
+        }
+        else if variable_type == TokenType::String{
+            return Ok(TokenType::StringSequence);
+        }
+        else if variable_type == TokenType::Char{
+            return Ok(TokenType::Character);
+        }
+        return Ok(variable_type);
+    }
+
+    if token.token_type == TokenType::IntNumber && token.value.len() > INT_NUMBER_MAX_LENGTH as usize{
+        return Err(syntax_error(
+            checker, format!("The litral `{}` does not fit into int type", token.value), token));
+    }
+    else if
+        token.token_type == TokenType::DoubleNumber &&
+        token.value.len() > DOUBLE_NUMBER_MAX_LENGTH as usize
+    {
+        return Err(syntax_error(
+            checker, format!("The litral `{}` does not fit into double type", token.value), token));
+    }
+
+    return Ok(token.token_type.clone());
+}
@@ -0,0 +1,934 @@
+use std::collections::{
+    HashMap,
+    HashSet
+};
+
+use crate::file::File;
+use crate::constants::Mode;
+use crate::environments::{
+    Value,
+    ValueType
+};
+use crate::syntax_tree::{
+    InstructionType,
+    OperatorType
+};
+
+use super::constants_folder::evaluate_operation;
+
+
+#[derive(Debug, Clone)]
+struct ByteCodeInstruction{
+    line: u128,
+    instruction_type: InstructionType,
+    arguments: Vec<String>,
+    is_removed: bool,
+}
+
+impl ByteCodeInstruction{
+    fn to_line(&self, line: u128) -> String{
+        let instruction_name = match self.instruction_type {
+            InstructionType::Assign => "Assign",
+            InstructionType::Convert => "Convert",
+            InstructionType::Operation => "Operation",
+            InstructionType::Print => "Print",
+            InstructionType::Input => "Input",
+            InstructionType::Call => "Call",
+            InstructionType::If => "If",
+            InstructionType::Else => "Else",
+            InstructionType::GoTo => "GoTo",
+            InstructionType::End => "End",
+        };
+
+        if self.arguments.len() == 0{
+            return format!("{line}:{instruction_name}:");
+        }
+        return format!("{line}:{instruction_name}:{}", self.arguments.join(":"));
+    }
+
+    /* Variables That Their Values Are Read By The Instruction */
+    fn read_variables(&self) -> Vec<String>{
+        if self.instruction_type == InstructionType::Convert{
+            return vec![self.arguments[2].clone()];
+        }
+        else if self.instruction_type == InstructionType::Operation{
+            return vec![self.arguments[2].clone(), self.arguments[3].clone()];
+        }
+        else if self.instruction_type == InstructionType::Call{
+            return self.arguments[2..].to_vec();
+        }
+        else if
+            self.instruction_type == InstructionType::Print ||
+            self.instruction_type == InstructionType::If
+        {
+            return vec![self.arguments[0].clone()];
+        }
+
+        return vec![];
+    }
+
+    /* Variable That Its Value Is Set By The Instruction */
+    fn assigned_variable(&self) -> Option<String>{
+        if
+            self.instruction_type == InstructionType::Assign ||
+            self.instruction_type == InstructionType::Convert ||
+            self.instruction_type == InstructionType::Operation ||
+            self.instruction_type == InstructionType::Call
+        {
+            return Some(self.arguments[1].clone());
+        }
+        else if self.instruction_type == InstructionType::Input{
+            return Some(self.arguments[0].clone());
+        }
+
+        return None;
+    }
+
+    fn is_referencing(&self, variable_name: &String) -> bool{
+        return
+            self.read_variables().contains(variable_name) ||
+            self.assigned_variable().as_ref() == Some(variable_name);
+    }
+
+    fn is_jump(&self) -> bool{
+        return
+            self.instruction_type == InstructionType::If ||
+            self.instruction_type == InstructionType::GoTo;
+    }
+
+    fn jump_line(&self) -> Option<u128>{
+        if self.instruction_type == InstructionType::If{
+            return self.arguments[1].trim().parse::<u128>().ok();
+        }
+        else if self.instruction_type == InstructionType::GoTo{
+            return self.arguments[0].trim().parse::<u128>().ok();
+        }
+
+        return None;
+    }
+
+    fn set_jump_line(&mut self, line: u128){
+        if self.instruction_type == InstructionType::If{
+            self.arguments[1] = line.to_string();
+        }
+        else if self.instruction_type == InstructionType::GoTo{
+            self.arguments[0] = line.to_string();
+        }
+    }
+}
+
+
+#[derive(Debug)]
+pub struct ByteCodeOptimizer{
+    file_path: String,
+    header: String,
+    instructions: Vec<ByteCodeInstruction>,
+    variables_types: HashMap<String, Option<String>>,
+}
+
+impl ByteCodeOptimizer{
+    pub fn new(file_path: String) -> Result<Self, String>{
+        let content = std::fs::read_to_string(&file_path);
+        if content.is_err(){
+            return Err(format!(
+                "Engine Compiler: Byte Code Optimization Error -> Can't read the byte code file `{}`.",
+                file_path));
+        }
+        let content = content.unwrap();
+
+        let mut lines = content.lines();
+
+        let header = lines.next();
+        if header == None{
+            return Err(format!(
+                "Engine Compiler: Byte Code Optimization Error -> Empty byte code file `{}`.",
+                file_path));
+        }
+        let header = String::from(header.unwrap());
+
+        let mut instructions = Vec::new();
+        for line in lines{
+            let line = line.trim_end();
+            if line.is_empty(){
+                continue;
+            }
+            instructions.push(construct_byte_code_instruction(line)?);
+        }
+
+        /* Collect Variables Types From Assign Instructions */
+        let mut variables_types: HashMap<String, Option<String>> = HashMap::new();
+        for instruction in &instructions{
+            if instruction.instruction_type != InstructionType::Assign{
+                continue;
+            }
+
+            let variable_name = instruction.arguments[1].clone();
+            let variable_type = instruction.arguments[0].clone();
+
+            if
+                variables_types.contains_key(&variable_name) &&
+                variables_types.get(&variable_name).unwrap() != &Some(variable_type.clone())
+            {
+                variables_types.insert(variable_name, None);
+                continue;
+            }
+            variables_types.insert(variable_name, Some(variable_type));
+        }
+
+        return Ok(ByteCodeOptimizer{
+            file_path,
+            header,
+            instructions,
+            variables_types
+        });
+    }
+
+    fn get_variable_type(&self, variable_name: &String) -> Option<String>{
+        return self.variables_types.get(variable_name).cloned().unwrap_or(None);
+    }
+
+    /* Mark Instructions That Start A New Basic Block */
+    fn get_block_leaders(&self) -> HashSet<usize>{
+        let mut leaders = HashSet::new();
+
+        let mut jump_lines = HashSet::new();
+        for instruction in &self.instructions{
+            if !instruction.is_removed && instruction.is_jump(){
+                jump_lines.insert(instruction.jump_line().unwrap_or(0));
+            }
+        }
+
+        let mut previous_is_jump = true;
+        let mut pending_jump_target = false;
+        for (index, instruction) in self.instructions.iter().enumerate(){
+            if jump_lines.contains(&instruction.line){
+                pending_jump_target = true;
+            }
+
+            if instruction.is_removed{
+                continue;
+            }
+
+            if previous_is_jump || pending_jump_target{
+                leaders.insert(index);
+            }
+
+            previous_is_jump = instruction.is_jump();
+            pending_jump_target = false;
+        }
+
+        return leaders;
+    }
+
+    fn remove_instruction(&mut self, index: usize){
+        self.instructions[index].is_removed = true;
+    }
+
+    fn replace_with_assign(
+        &mut self, index: usize, variable_type: String,
+        variable_name: String, value: String
+    ){
+        let instruction = &mut self.instructions[index];
+        instruction.instruction_type = InstructionType::Assign;
+        instruction.arguments = vec![variable_type, variable_name, value];
+    }
+}
+
+
+fn construct_byte_code_instruction(
+    line: &str
+) -> Result<ByteCodeInstruction, String>{
+
+    let error = format!(
+        "Engine Compiler: Byte Code Optimization Error -> Invalid instruction `{}`.",
+        line);
+
+    let splitted_line = line.split_once(":");
+    if splitted_line == None{
+        return Err(error);
+    }
+    let (line_counter, line) = splitted_line.unwrap();
+
+    let line_counter = line_counter.parse::<u128>();
+    if line_counter.is_err(){
+        return Err(error);
+    }
+    let line_counter = line_counter.unwrap();
+
+    let splitted_line = line.split_once(":");
+    if splitted_line == None{
+        return Err(error);
+    }
+    let (instruction_str, line) = splitted_line.unwrap();
+
+    let (instruction_type, arguments_count) = if instruction_str == "Assign"{
+        (InstructionType::Assign, 3)} else if instruction_str == "Convert"{
+        (InstructionType::Convert, 3)} else if instruction_str == "Operation"{
+        (InstructionType::Operation, 4)} else if instruction_str == "Print"{
+        (InstructionType::Print, 1)} else if instruction_str == "Input"{
+        (InstructionType::Input, 1)} else if instruction_str == "Call"{
+        (InstructionType::Call, 2)} else if instruction_str == "If"{
+        (InstructionType::If, 2)} else if instruction_str == "Else"{
+        (InstructionType::Else, 0)} else if instruction_str == "GoTo"{
+        (InstructionType::GoTo, 1)} else if instruction_str == "End"{
+        (InstructionType::End, 0)} else {
+        return Err(error);
+    };
+
+    /* The Last Argument Takes The Rest Of The Line (String Values May Have `:`) */
+    let arguments: Vec<String> = if arguments_count == 0 {vec![]} else {
+        line.splitn(arguments_count, ":").map(|argument| String::from(argument)).collect()
+    };
+    if arguments.len() != arguments_count{
+        return Err(error);
+    }
+
+    /* Calls Have A Function, A Result And Any Number Of Arguments Variables, Prints May Have A Stream */
+    let arguments = if
+        instruction_type != InstructionType::Call && instruction_type != InstructionType::Print
+    {arguments} else {
+        line.split(":").map(|argument| String::from(argument)).collect()
+    };
+
+    return Ok(ByteCodeInstruction{
+        line: line_counter,
+        instruction_type,
+        arguments,
+        is_removed: false,
+    });
+}
+
+
+pub fn optimize_byte_code(
+    byte_code_optimizer: &mut ByteCodeOptimizer
+) -> Result<(), String>{
+
+    let mut byte_code_optimizer = byte_code_optimizer;
+
+    /* Run Passes Until Nothing Changes */
+    loop{
+        let mut changed = false;
+
+        changed |= propagate_constants_and_copies(&mut byte_code_optimizer);
+        changed |= forward_operation_results(&mut byte_code_optimizer);
+        changed |= remove_overwritten_assigns(&mut byte_code_optimizer);
+        changed |= remove_dead_temp_variables(&mut byte_code_optimizer);
+
+        if !changed{
+            break;
+        }
+    }
+
+    write_byte_code(&mut byte_code_optimizer);
+
+    return Ok(());
+}
+
+
+fn kill_variable(
+    variable_name: &String,
+    constants: &mut HashMap<String, (String, String)>,
+    copies: &mut HashMap<String, String>
+){
+    constants.remove(variable_name);
+    copies.remove(variable_name);
+    copies.retain(|_, copied_from| copied_from != variable_name);
+}
+
+
+/* Replace Reads With Their Copies Sources, Fold Constants Converts And Operations */
+fn propagate_constants_and_copies(
+    byte_code_optimizer: &mut ByteCodeOptimizer
+) -> bool{
+
+    let leaders = byte_code_optimizer.get_block_leaders();
+
+    let mut changed = false;
+    let mut constants: HashMap<String, (String, String)> = HashMap::new();
+    let mut copies: HashMap<String, String> = HashMap::new();
+
+    for index in 0..byte_code_optimizer.instructions.len(){
+        if leaders.contains(&index){
+            constants.clear();
+            copies.clear();
+        }
+
+        if byte_code_optimizer.instructions[index].is_removed{
+            continue;
+        }
+
+        /* Propagate Copies Into Read Variables */
+        {
+            let instruction = &mut byte_code_optimizer.instructions[index];
+            let read_indexes: Vec<usize> = match instruction.instruction_type {
+                InstructionType::Convert => vec![2],
+                InstructionType::Operation => vec![2, 3],
+                InstructionType::Call => (2..instruction.arguments.len()).collect(),
+                InstructionType::Print | InstructionType::If => vec![0],
+                _ => vec![],
+            };
+            for read_index in read_indexes{
+                let copied_from = copies.get(&instruction.arguments[read_index]);
+                if copied_from != None{
+                    instruction.arguments[read_index] = copied_from.unwrap().clone();
+                    changed = true;
+                }
+            }
+        }
+
+        let instruction = byte_code_optimizer.instructions[index].clone();
+
+        if instruction.instruction_type == InstructionType::Assign{
+            kill_variable(&instruction.arguments[1], &mut constants, &mut copies);
+            constants.insert(
+                instruction.arguments[1].clone(),
+                (instruction.arguments[0].clone(), instruction.arguments[2].clone()));
+        }
+        else if instruction.instruction_type == InstructionType::Convert{
+            let convert_type = instruction.arguments[0].clone();
+            let convert_to = instruction.arguments[1].clone();
+            let convert_from = instruction.arguments[2].clone();
+
+            /* Convert Variable Into It Self */
+            if convert_to == convert_from &&
+                byte_code_optimizer.get_variable_type(&convert_to) == Some(convert_type.clone())
+            {
+                byte_code_optimizer.remove_instruction(index);
+                changed = true;
+                continue;
+            }
+
+            kill_variable(&convert_to, &mut constants, &mut copies);
+
+            let folded_value = if constants.contains_key(&convert_from){
+                let (from_type, from_value) = constants.get(&convert_from).unwrap();
+                if from_type == &convert_type{
+                    Some(from_value.clone())
+                }
+                else if from_type == "int" && convert_type == "double"{
+                    Some(from_value.clone())
+                }
+                else{
+                    None
+                }
+            } else {None};
+
+            if folded_value != None{
+                let folded_value = folded_value.unwrap();
+                byte_code_optimizer.replace_with_assign(
+                    index, convert_type.clone(), convert_to.clone(), folded_value.clone());
+                constants.insert(convert_to, (convert_type, folded_value));
+                changed = true;
+            }
+            else if
+                byte_code_optimizer.get_variable_type(&convert_from) == Some(convert_type.clone()) &&
+                byte_code_optimizer.get_variable_type(&convert_to) == Some(convert_type.clone())
+            {
+                copies.insert(convert_to, convert_from);
+            }
+        }
+        else if instruction.instruction_type == InstructionType::Operation{
+            let assign_to = instruction.arguments[1].clone();
+
+            let assign_type = byte_code_optimizer.get_variable_type(&assign_to);
+            let left = constants.get(&instruction.arguments[2]).cloned();
+            let right = constants.get(&instruction.arguments[3]).cloned();
+
+            kill_variable(&assign_to, &mut constants, &mut copies);
+
+            if assign_type == None || left == None || right == None{
+                continue;
+            }
+            let assign_type = assign_type.unwrap();
+
+            let folded_value = fold_operation(
+                &instruction.arguments[0], &assign_type,
+                left.as_ref().unwrap(), right.as_ref().unwrap());
+
+            if folded_value != None{
+                let folded_value = folded_value.unwrap();
+                byte_code_optimizer.replace_with_assign(
+                    index, assign_type.clone(), assign_to.clone(), folded_value.clone());
+                constants.insert(assign_to, (assign_type, folded_value));
+                changed = true;
+            }
+        }
+        else if instruction.instruction_type == InstructionType::Input{
+            kill_variable(&instruction.arguments[0], &mut constants, &mut copies);
+        }
+        else if instruction.instruction_type == InstructionType::Call{
+            kill_variable(&instruction.arguments[1], &mut constants, &mut copies);
+        }
+    }
+
+    return changed;
+}
+
+
+/*
+* Compute Operation Result With The Constants Folder Evaluator, Operations That
+* Overflow Or Divide By Zero Are Left To Run, The VM Wraps Or Reports Them.
+*/
+fn fold_operation(
+    operation: &String,
+    assign_type: &String,
+    left: &(String, String),
+    right: &(String, String)
+) -> Option<String>{
+
+    /* The VM Only Runs Arithmetic On Operands Of The Assigned Variable Type */
+    if assign_type != "bool" && (&left.0 != assign_type || &right.0 != assign_type){
+        return None;
+    }
+
+    let operator = get_operator_type(operation)?;
+    let left_value = get_byte_code_value(left)?;
+    let right_value = get_byte_code_value(right)?;
+
+    let value = evaluate_operation(&operator, &left_value, &right_value).ok()??;
+
+    /* The Result Must Already Have The Type Of The Assigned Variable */
+    if assign_type == "int" && value.value_type == Some(ValueType::Integer){
+        return Some(value.int.unwrap().to_string());
+    }
+    else if assign_type == "double" && value.value_type == Some(ValueType::Double){
+        return Some(format!("{:?}", value.double.unwrap()));
+    }
+    else if assign_type == "bool" && value.value_type == Some(ValueType::Boolean){
+        return Some(String::from(if value.boolean.unwrap() {"True"} else {"False"}));
+    }
+    else if assign_type == "string" && value.value_type == Some(ValueType::String){
+        return Some(format!("\"{}\"", value.string.unwrap()));
+    }
+
+    return None;
+}
+
+
+fn get_operator_type(operation: &String) -> Option<OperatorType>{
+    return Some(match operation.as_str() {
+        "Plus" => OperatorType::Plus,
+        "Minus" => OperatorType::Minus,
+        "Mul" => OperatorType::Mul,
+        "Div" => OperatorType::Div,
+        "Mod" => OperatorType::Mod,
+        "And" => OperatorType::And,
+        "Or" => OperatorType::Or,
+        "GreaterThan" => OperatorType::GreaterThan,
+        "GreaterThanOrEqual" => OperatorType::GreaterThanOrEqual,
+        "LessThan" => OperatorType::LessThan,
+        "LessThanOrEqual" => OperatorType::LessThanOrEqual,
+        "Equal" => OperatorType::Equal,
+        "NotEqual" => OperatorType::NotEqual,
+        _ => return None,
+    });
+}
+
+
+/* Strings With Escaped Characters Are Not Folded, Their Value Is Kept Escaped */
+fn get_byte_code_value(constant: &(String, String)) -> Option<Value>{
+    let (value_type, value) = constant;
+
+    let mut byte_code_value = Value::new();
+
+    if value_type == "int"{
+        byte_code_value.value_type = Some(ValueType::Integer);
+        byte_code_value.int = Some(value.parse::<i64>().ok()?);
+    }
+    else if value_type == "double"{
+        byte_code_value.value_type = Some(ValueType::Double);
+        byte_code_value.double = Some(value.parse::<f64>().ok()?);
+    }
+    else if value_type == "bool"{
+        byte_code_value.value_type = Some(ValueType::Boolean);
+        byte_code_value.boolean = Some(value == "True");
+    }
+    else if
+        value_type == "string" && !value.contains('\\') &&
+        value.len() >= 2 && value.starts_with('"') && value.ends_with('"')
+    {
+        byte_code_value.value_type = Some(ValueType::String);
+        byte_code_value.string = Some(String::from(&value[1..value.len()-1]));
+    }
+    else{
+        return None;
+    }
+
+    return Some(byte_code_value);
+}
+
+
+/* Store Operation Result Directly Into The Variable That Copies It */
+fn forward_operation_results(
+    byte_code_optimizer: &mut ByteCodeOptimizer
+) -> bool{
+
+    let leaders = byte_code_optimizer.get_block_leaders();
+
+    /* Count Reads And Assigns Of Every Variable */
+    let mut reads_count: HashMap<String, u128> = HashMap::new();
+    let mut operations_count: HashMap<String, u128> = HashMap::new();
+    for instruction in &byte_code_optimizer.instructions{
+        if instruction.is_removed{
+            continue;
+        }
+
+        for variable_name in instruction.read_variables(){
+            *reads_count.entry(variable_name).or_insert(0) += 1;
+        }
+
+        if instruction.instruction_type != InstructionType::Assign{
+            let assigned_variable = instruction.assigned_variable();
+            if assigned_variable != None{
+                *operations_count.entry(assigned_variable.unwrap()).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let mut changed = false;
+
+    for index in 0..byte_code_optimizer.instructions.len(){
+        let instruction = byte_code_optimizer.instructions[index].clone();
+        if instruction.is_removed || instruction.instruction_type != InstructionType::Operation{
+            continue;
+        }
+
+        let temp_variable = instruction.arguments[1].clone();
+        if
+            !temp_variable.starts_with("\"temp_") ||
+            reads_count.get(&temp_variable) != Some(&1) ||
+            operations_count.get(&temp_variable) != Some(&1)
+        {
+            continue;
+        }
+
+        let left = instruction.arguments[2].clone();
+        let right = instruction.arguments[3].clone();
+
+        /* Find The Convert That Reads The Temp In The Same Block */
+        let mut convert_index = None;
+        let mut between_indexes = vec![];
+        for next_index in index + 1..byte_code_optimizer.instructions.len(){
+            let next_instruction = &byte_code_optimizer.instructions[next_index];
+            if leaders.contains(&next_index){
+                break;
+            }
+            if next_instruction.is_removed{
+                continue;
+            }
+
+            if next_instruction.is_referencing(&temp_variable){
+                if next_instruction.instruction_type == InstructionType::Convert &&
+                    next_instruction.arguments[2] == temp_variable
+                {
+                    convert_index = Some(next_index);
+                }
+                break;
+            }
+
+            /* Operation Operands Must Not Change */
+            let assigned_variable = next_instruction.assigned_variable();
+            if assigned_variable == Some(left.clone()) || assigned_variable == Some(right.clone()){
+                break;
+            }
+            between_indexes.push(next_index);
+        }
+
+        if convert_index == None{
+            continue;
+        }
+        let convert_index = convert_index.unwrap();
+        let convert_instruction = byte_code_optimizer.instructions[convert_index].clone();
+
+        let convert_type = convert_instruction.arguments[0].clone();
+        let convert_to = convert_instruction.arguments[1].clone();
+
+        if
+            byte_code_optimizer.get_variable_type(&temp_variable) != Some(convert_type.clone()) ||
+            byte_code_optimizer.get_variable_type(&convert_to) != Some(convert_type.clone())
+        {
+            continue;
+        }
+
+        /* The Converted To Variable Must Only Be Declared Between Them */
+        let mut is_safe = true;
+        for between_index in between_indexes{
+            let between_instruction = &byte_code_optimizer.instructions[between_index];
+            if !between_instruction.is_referencing(&convert_to){
+                continue;
+            }
+
+            if
+                between_instruction.instruction_type != InstructionType::Assign ||
+                convert_to == left || convert_to == right
+            {
+                is_safe = false;
+                break;
+            }
+        }
+        if !is_safe{
+            continue;
+        }
+
+        let forwarded_instruction = &mut byte_code_optimizer.instructions[convert_index];
+        forwarded_instruction.instruction_type = InstructionType::Operation;
+        forwarded_instruction.arguments = vec![
+            instruction.arguments[0].clone(), convert_to, left, right];
+
+        byte_code_optimizer.remove_instruction(index);
+        changed = true;
+    }
+
+    return changed;
+}
+
+
+/* Remove Assigns That Are Overwritten By Another Assign Before Any Use */
+fn remove_overwritten_assigns(
+    byte_code_optimizer: &mut ByteCodeOptimizer
+) -> bool{
+
+    let leaders = byte_code_optimizer.get_block_leaders();
+
+    let mut changed = false;
+
+    for index in 0..byte_code_optimizer.instructions.len(){
+        let instruction = byte_code_optimizer.instructions[index].clone();
+        if instruction.is_removed || instruction.instruction_type != InstructionType::Assign{
+            continue;
+        }
+
+        let variable_name = instruction.arguments[1].clone();
+
+        for next_index in index + 1..byte_code_optimizer.instructions.len(){
+            let next_instruction = &byte_code_optimizer.instructions[next_index];
+            if leaders.contains(&next_index){
+                break;
+            }
+            if next_instruction.is_removed{
+                continue;
+            }
+
+            if next_instruction.is_referencing(&variable_name){
+                if
+                    next_instruction.instruction_type == InstructionType::Assign &&
+                    !next_instruction.read_variables().contains(&variable_name)
+                {
+                    byte_code_optimizer.remove_instruction(index);
+                    changed = true;
+                }
+                break;
+            }
+        }
+    }
+
+    return changed;
+}
+
+
+/* Remove Temp Variables That Are Never Read */
+fn remove_dead_temp_variables(
+    byte_code_optimizer: &mut ByteCodeOptimizer
+) -> bool{
+
+    let mut used_variables = HashSet::new();
+    for instruction in &byte_code_optimizer.instructions{
+        if instruction.is_removed{
+            continue;
+        }
+
+        for variable_name in instruction.read_variables(){
+            used_variables.insert(variable_name);
+        }
+
+        /* Input Needs Its Variable To Be Declared */
+        if instruction.instruction_type == InstructionType::Input{
+            used_variables.insert(instruction.arguments[0].clone());
+        }
+
+        /* Calls May Fail, So They Are Kept With Their Declared Result */
+        if instruction.instruction_type == InstructionType::Call{
+            used_variables.insert(instruction.arguments[1].clone());
+        }
+    }
+
+    let mut changed = false;
+
+    for instruction in &mut byte_code_optimizer.instructions{
+        if
+            instruction.is_removed ||
+            instruction.instruction_type == InstructionType::Input ||
+            instruction.instruction_type == InstructionType::Call
+        {
+            continue;
+        }
+
+        let assigned_variable = instruction.assigned_variable();
+        if assigned_variable == None{
+            continue;
+        }
+        let assigned_variable = assigned_variable.unwrap();
+
+        if
+            assigned_variable.starts_with("\"temp_") &&
+            !used_variables.contains(&assigned_variable)
+        {
+            instruction.is_removed = true;
+            changed = true;
+        }
+    }
+
+    return changed;
+}
+
+
+/* Renumber Instructions And Rewrite The Byte Code File */
+fn write_byte_code(byte_code_optimizer: &mut ByteCodeOptimizer){
+
+    /* Map Old Lines Into New Lines, Removed Lines Go To The Next Kept Line */
+    let mut new_lines: HashMap<u128, u128> = HashMap::new();
+    let mut kept_count = byte_code_optimizer.instructions.iter()
+        .filter(|instruction| !instruction.is_removed).count() as u128;
+    let mut next_kept_line = kept_count + 1;
+
+    for instruction in byte_code_optimizer.instructions.iter().rev(){
+        if !instruction.is_removed{
+            next_kept_line = kept_count;
+            kept_count -= 1;
+        }
+        new_lines.insert(instruction.line, next_kept_line);
+    }
+
+    let mut file = File::create_new(
+        byte_code_optimizer.file_path.clone(), Mode::ByteCodeGenerator).expect(format!(
+            "Engine Compiler: Byte Code Optimization Error -> Can't write the byte code file `{}`.",
+            byte_code_optimizer.file_path).as_str());
+
+    file.writeln(byte_code_optimizer.header.clone());
+
+    let mut current_line = 0;
+    for instruction in &mut byte_code_optimizer.instructions{
+        if instruction.is_removed{
+            continue;
+        }
+        current_line += 1;
+
+        let jump_line = instruction.jump_line();
+        if jump_line != None{
+            let jump_line = jump_line.unwrap();
+            instruction.set_jump_line(new_lines.get(&jump_line).cloned().unwrap_or(jump_line));
+        }
+
+        file.writeln(instruction.to_line(current_line));
+    }
+}
+
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    const HEADER: &'static str = "0:EngineByteCode:v0.1.0\n";
+
+    /* Optimizes The Byte Code Lines Written Into A Temp File, Returns The Optimized Lines */
+    fn optimize(name: &str, instructions: &[&str]) -> Vec<String>{
+        let file_path = std::env::temp_dir().join(
+            format!("engine_byte_code_optimizer_{}_{}.en.byte", name, std::process::id()));
+        let file_path = String::from(file_path.to_str().unwrap());
+
+        std::fs::write(&file_path, String::from(HEADER) + &instructions.join("\n") + "\n").unwrap();
+
+        let mut byte_code_optimizer = ByteCodeOptimizer::new(file_path.clone()).unwrap();
+        optimize_byte_code(&mut byte_code_optimizer).unwrap();
+
+        let content = std::fs::read_to_string(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+
+        return content.lines().skip(1).map(|line| String::from(line)).collect();
+    }
+
+    #[test]
+    fn operations_on_constants_are_folded(){
+        let lines = optimize("fold", &[
+            "1:Assign:int:\"x\":6",
+            "2:Assign:int:\"y\":7",
+            "3:Assign:int:\"z\":0",
+            "4:Operation:Mul:\"z\":\"x\":\"y\"",
+            "5:Assign:bool:\"b\":False",
+            "6:Operation:GreaterThan:\"b\":\"z\":\"x\"",
+            "7:Assign:string:\"s\":\"ab\"",
+            "8:Operation:Plus:\"s\":\"s\":\"s\"",
+            "9:Print:\"z\"",
+            "10:Print:\"b\"",
+            "11:Print:\"s\"",
+            "12:End:",
+        ]);
+
+        assert_eq!(lines, [
+            "1:Assign:int:\"x\":6",
+            "2:Assign:int:\"y\":7",
+            "3:Assign:int:\"z\":42",
+            "4:Assign:bool:\"b\":True",
+            "5:Assign:string:\"s\":\"abab\"",
+            "6:Print:\"z\"",
+            "7:Print:\"b\"",
+            "8:Print:\"s\"",
+            "9:End:",
+        ]);
+    }
+
+    #[test]
+    fn failing_operations_are_left_to_the_vm(){
+        let lines = optimize("failing", &[
+            "1:Assign:int:\"x\":9223372036854775807",
+            "2:Assign:int:\"y\":1",
+            "3:Assign:int:\"zero\":0",
+            "4:Assign:int:\"z\":0",
+            "5:Operation:Plus:\"z\":\"x\":\"y\"",
+            "6:Print:\"z\"",
+            "7:Operation:Mod:\"z\":\"y\":\"zero\"",
+            "8:Print:\"z\"",
+            "9:End:",
+        ]);
+
+        assert!(lines.contains(&String::from("5:Operation:Plus:\"z\":\"x\":\"y\"")), "{:?}", lines);
+        assert!(lines.contains(&String::from("7:Operation:Mod:\"z\":\"y\":\"zero\"")), "{:?}", lines);
+    }
+
+    #[test]
+    fn copies_are_propagated_into_reads(){
+        let lines = optimize("copies", &[
+            "1:Assign:string:\"line\":\"\"",
+            "2:Assign:int:\"a\":0",
+            "3:Assign:int:\"b\":0",
+            "4:Input:\"line\"",
+            "5:Convert:int:\"a\":\"line\"",
+            "6:Convert:int:\"b\":\"a\"",
+            "7:Print:\"b\"",
+            "8:End:",
+        ]);
+
+        assert!(lines.contains(&String::from("6:Convert:int:\"b\":\"a\"")), "{:?}", lines);
+        assert!(lines.contains(&String::from("7:Print:\"a\"")), "{:?}", lines);
+    }
+
+    /* The Jump Target Is Reached Without The Copy Too */
+    #[test]
+    fn copies_are_not_propagated_across_blocks(){
+        let lines = optimize("blocks", &[
+            "1:Assign:string:\"line\":\"\"",
+            "2:Assign:int:\"a\":0",
+            "3:Assign:int:\"b\":0",
+            "4:Assign:bool:\"c\":False",
+            "5:Input:\"line\"",
+            "6:Convert:int:\"a\":\"line\"",
+            "7:Convert:bool:\"c\":\"line\"",
+            "8:If:\"c\":10",
+            "9:Convert:int:\"b\":\"a\"",
+            "10:Print:\"b\"",
+            "11:End:",
+        ]);
+
+        assert!(lines.contains(&String::from("10:Print:\"b\"")), "{:?}", lines);
+    }
+}
@@ -0,0 +1,450 @@
+use std::process::Command;
+
+use crate::tokens::TokenType;
+use crate::constants::Mode;
+use crate::syntax_tree::OperatorType;
+use crate::ir::{
+    IrProgram,
+    IrInstruction,
+    IrInstructionKind,
+    IrTerminator,
+    get_operator_symbol
+};
+
+use crate::file::File;
+use super::get_output_path;
+
+
+/* Strings, Printing, Input And Conversions Used By The Generated Code */
+const C_RUNTIME_SOURCE: &'static str = include_str!("c_runtime.h");
+const C_RUNTIME_FILE_NAME: &'static str = "engine_runtime.h";
+
+
+/* The Built-in Functions Of The Runtime, The Others Are Rejected Before The Files Are Written */
+pub const C_FUNCTIONS: [&'static str; 25] = [
+    "len", "substring", "find", "replace", "split", "trim", "to_upper", "to_lower",
+    "starts_with", "ends_with", "contains", "repeat",
+    "abs", "min", "max", "sqrt", "pow", "floor", "ceil", "round", "sin", "cos", "tan", "log", "exp",
+];
+
+
+/* The String Functions Stopping The Program On Invalid Arguments, They Are Given The Line */
+const C_CHECKED_FUNCTIONS: [&'static str; 6] = [
+    "substring", "replace", "split", "to_upper", "to_lower", "repeat",
+];
+
+
+#[derive(Debug)]
+pub struct CCodeGenerator{
+    file: File,
+    c_file_path: String,
+    executable_path: String,
+    program: IrProgram,
+}
+
+impl CCodeGenerator{
+    /* The Runtime Header Is Written Next To The C File */
+    pub fn new(
+        program: IrProgram,
+        parent_folder_path: String,
+        file_name: String,
+    ) -> Result<Self, std::io::Error>{
+
+        let mut runtime_file = File::create_new(
+            get_output_path(&parent_folder_path, C_RUNTIME_FILE_NAME), Mode::Compiler)?;
+        runtime_file.write(String::from(C_RUNTIME_SOURCE));
+
+        let executable_path = get_output_path(&parent_folder_path, &file_name);
+        let c_file_path = executable_path.clone() + &String::from(".c");
+
+        let mut _file = File::create_new(
+            c_file_path.clone(), Mode::Compiler)?;
+
+        return Ok(CCodeGenerator{
+            file: _file,
+            c_file_path,
+            executable_path,
+            program,
+        });
+    }
+
+    /* Compiles The C File With The System C Compiler, The Sources Are Kept */
+    pub fn execute(&mut self) -> Result<(), String>{
+        let output = Command::new("cc")
+            .arg("-std=c99")
+            .arg("-o")
+            .arg(self.executable_path.clone())
+            .arg(self.c_file_path.clone())
+            .arg("-lm")
+            .output();
+
+        if output.is_err(){
+            return Err(format!(
+                "Engine Compiler: Link Error -> Failed to run `cc`: {}.", output.unwrap_err()));
+        }
+        let output = output.unwrap();
+
+        if !output.status.success(){
+            return Err(format!(
+                "Engine Compiler: Link Error -> `cc` failed to compile `{}`:\n{}",
+                self.c_file_path, String::from_utf8_lossy(&output.stderr).trim_end()));
+        }
+
+        return Ok(());
+    }
+}
+
+
+/*
+* Every Variable Is Declared At The Start Of `main`, The Blocks Are Labels
+* Reached With `goto`.
+*/
+pub fn generate_c_code(
+    c_code_generator: &mut CCodeGenerator
+) -> Result<(), String>{
+
+    let mut lines = vec![
+        format!("#include \"{}\"", C_RUNTIME_FILE_NAME),
+        String::new(),
+        String::from("int main(void){"),
+    ];
+
+    for variable in &c_code_generator.program.variables{
+        lines.push(format!(
+            "{} v_{} = {};",
+            get_c_type(&variable.variable_type), variable.name,
+            if variable.variable_type == TokenType::String {"{NULL, 0}"} else {"0"}));
+    }
+
+    for (index, block) in c_code_generator.program.blocks.iter().enumerate(){
+        lines.push(format!("block_{}:", index));
+
+        for instruction in &block.instructions{
+            lines.extend(generate_instruction(c_code_generator, instruction)?);
+        }
+
+        lines.push(match &block.terminator {
+            IrTerminator::Jump{target} => format!("goto block_{};", target),
+            IrTerminator::Branch{condition, then_block, else_block} => format!(
+                "if(v_{}){{goto block_{};}} else{{goto block_{};}}", condition, then_block, else_block),
+            IrTerminator::End => String::from("goto end;"),
+        });
+    }
+
+    lines.push(String::from("end:"));
+    lines.push(String::from("return 0;"));
+    lines.push(String::from("}"));
+
+    for line in lines{
+        c_code_generator.file.writeln(line);
+    }
+
+    return Ok(());
+}
+
+
+fn get_c_type(variable_type: &TokenType) -> &'static str{
+    if variable_type == &TokenType::Bool{
+        return "int";
+    }
+    else if variable_type == &TokenType::Int{
+        return "int64_t";
+    }
+    else if variable_type == &TokenType::Double{
+        return "double";
+    }
+    else if variable_type == &TokenType::Char{
+        return "uint32_t";
+    }
+    return "EngineString";
+}
+
+
+/* A C String Literal, Octal Escapes Keep Every Byte And `\?` Avoids The Trigraphs */
+fn get_c_string(value: &String) -> String{
+    let mut text = String::from("\"");
+    for byte in value.bytes(){
+        if byte == b'"' || byte == b'\\' || byte == b'?'{
+            text.push('\\');
+            text.push(byte as char);
+        }
+        else if byte >= 0x20 && byte < 0x7F{
+            text.push(byte as char);
+        }
+        else{
+            text.push_str(&format!("\\{:03o}", byte));
+        }
+    }
+    text.push('"');
+
+    return text;
+}
+
+
+fn get_c_literal(variable_type: &TokenType, value: &String) -> String{
+    if variable_type == &TokenType::Bool{
+        return String::from(if value == "True" {"1"} else {"0"});
+    }
+    else if variable_type == &TokenType::Int{
+        let number = value.parse::<i64>().unwrap_or(0);
+        if number == i64::MIN{
+            return String::from("INT64_MIN");
+        }
+        return format!("INT64_C({})", number);
+    }
+    else if variable_type == &TokenType::Double{
+        let number = value.parse::<f64>().unwrap_or(0.0);
+        if number.is_nan(){
+            return String::from("NAN");
+        }
+        else if number.is_infinite(){
+            return String::from(if number > 0.0 {"INFINITY"} else {"-INFINITY"});
+        }
+        return format!("{:e}", number);
+    }
+    return format!("{}", value.chars().nth(0).unwrap_or('\0') as u32);
+}
+
+
+fn generate_instruction(
+    c_code_generator: &CCodeGenerator, instruction: &IrInstruction
+) -> Result<Vec<String>, String>{
+
+    let program = &c_code_generator.program;
+    let get_type = |name: &String| program.get_variable_type(name).unwrap();
+
+    let line = instruction.start_line;
+    let pos = instruction.start_pos;
+
+    return match &instruction.kind {
+        IrInstructionKind::Constant{target, value} => {
+            let target_type = get_type(target);
+            if target_type == TokenType::String{
+                return Ok(vec![format!(
+                    "engine_string_assign(&v_{}, {}, {});", target, get_c_string(value), value.len())]);
+            }
+
+            Ok(vec![format!("v_{} = {};", target, get_c_literal(&target_type, value))])
+        },
+
+        IrInstructionKind::Convert{target, source} => {
+            let target_type = get_type(target);
+            let source_type = get_type(source);
+
+            if target_type == source_type{
+                if target_type == TokenType::String{
+                    return Ok(vec![format!(
+                        "engine_string_assign(&v_{}, v_{}.data, v_{}.length);", target, source, source)]);
+                }
+                return Ok(vec![format!("v_{} = v_{};", target, source)]);
+            }
+            else if target_type == TokenType::Int && source_type == TokenType::Double{
+                return Ok(vec![format!("v_{} = engine_double_to_int(v_{});", target, source)]);
+            }
+            else if target_type == TokenType::Double && source_type == TokenType::Int{
+                return Ok(vec![format!("v_{} = (double) v_{};", target, source)]);
+            }
+            else if target_type == TokenType::String && source_type == TokenType::Char{
+                return Ok(vec![format!("engine_char_to_string(&v_{}, v_{});", target, source)]);
+            }
+            else if source_type == TokenType::String{
+                let function = if target_type == TokenType::Bool{
+                    "engine_string_to_bool"
+                }
+                else if target_type == TokenType::Int{
+                    "engine_string_to_int"
+                }
+                else if target_type == TokenType::Double{
+                    "engine_string_to_double"
+                }
+                else{
+                    "engine_string_to_char"
+                };
+
+                return Ok(vec![format!(
+                    "v_{} = {}(&v_{}, {});", target, function, source, get_c_string(&format!(
+                        "Engine Compiler: Convert Error -> can't convert from `String` to `{:?}`, line: {}:{}",
+                        target_type, line, pos)))]);
+            }
+
+            Err(format!(
+                "Engine Compiler: Code Generation Error -> Can't convert `{:?}` to `{:?}`, line: {}:{}",
+                source_type, target_type, line, pos))
+        },
+
+        IrInstructionKind::Operation{operator, target, left, right} => {
+            let operands_type = get_type(left);
+
+            if operands_type == TokenType::String{
+                return Ok(vec![format!("engine_string_concat(&v_{}, &v_{}, &v_{});", target, left, right)]);
+            }
+            else if operands_type == TokenType::Int && operator == &OperatorType::Mod{
+                return Ok(vec![format!(
+                    "v_{} = engine_int_mod(v_{}, v_{}, {});", target, left, right, get_c_string(&format!(
+                        "Engine Compiler: Compiler Error -> Division by zero, line: {}:{}", line, pos)))]);
+            }
+            else if
+                operands_type == TokenType::Int &&
+                (operator == &OperatorType::Plus || operator == &OperatorType::Minus || operator == &OperatorType::Mul)
+            {
+                let function = if operator == &OperatorType::Plus{
+                    "engine_int_plus"
+                }
+                else if operator == &OperatorType::Minus{
+                    "engine_int_minus"
+                }
+                else{
+                    "engine_int_mul"
+                };
+
+                return Ok(vec![format!("v_{} = {}(v_{}, v_{});", target, function, left, right)]);
+            }
+            else if operands_type == TokenType::Double && operator == &OperatorType::Mod{
+                return Ok(vec![format!("v_{} = fmod(v_{}, v_{});", target, left, right)]);
+            }
+
+            Ok(vec![format!(
+                "v_{} = v_{} {} v_{};", target, left, get_operator_symbol(operator), right)])
+        },
+
+        IrInstructionKind::Call{function, target, arguments} => generate_call(
+            c_code_generator, function, target, arguments, line, pos),
+
+        IrInstructionKind::Input{target} => Ok(vec![format!(
+            "engine_input(&v_{}, {});", target, get_c_string(&format!(
+                "Engine Compiler: Compiler Error -> Failed to read input, line: {}:{}", line, pos)))]),
+
+        IrInstructionKind::Print{source, is_stderr} => {
+            let stream = if *is_stderr {"stderr"} else {"stdout"};
+            let source_type = get_type(source);
+
+            let function = if source_type == TokenType::Bool{
+                "engine_print_bool"
+            }
+            else if source_type == TokenType::Int{
+                "engine_print_int"
+            }
+            else if source_type == TokenType::Double{
+                "engine_print_double"
+            }
+            else if source_type == TokenType::Char{
+                "engine_print_char"
+            }
+            else{
+                return Ok(vec![format!("engine_print_string({}, &v_{});", stream, source)]);
+            };
+
+            Ok(vec![format!("{}({}, v_{});", function, stream, source)])
+        },
+    };
+}
+
+
+/*
+* A `char` Argument Of A String Function Is Converted Into A String Of The Call
+* Block, Freed After The Call. The String Results Are Written To The Target.
+*/
+fn generate_call(
+    c_code_generator: &CCodeGenerator, function: &String, target: &String, arguments: &Vec<String>,
+    line: u64, pos: u64
+) -> Result<Vec<String>, String>{
+
+    let program = &c_code_generator.program;
+    let get_type = |name: &String| program.get_variable_type(name).unwrap();
+
+    if !C_FUNCTIONS.contains(&function.as_str()){
+        return Err(format!(
+            "Engine Compiler: Code Generation Error -> Function `{}` is not supported by the C backend, line: {}:{}",
+            function, line, pos));
+    }
+
+    let location = get_c_string(&format!(", line: {}:{}", line, pos));
+
+    /* The String Functions Take A `string` Or A `char` First, The Math Functions A Number */
+    let first_type = get_type(&arguments[0]);
+    if first_type == TokenType::String || first_type == TokenType::Char{
+        let mut lines = vec![String::from("{")];
+        let mut call_arguments = vec![];
+        let mut temporaries = vec![];
+
+        if get_type(target) == TokenType::String{
+            call_arguments.push(format!("&v_{}", target));
+        }
+        for argument in arguments{
+            let argument_type = get_type(argument);
+
+            if argument_type == TokenType::Char{
+                let temporary = format!("t_{}", temporaries.len());
+                lines.push(format!("EngineString {} = {{NULL, 0}};", temporary));
+                lines.push(format!("engine_char_to_string(&{}, v_{});", temporary, argument));
+                call_arguments.push(format!("&{}", temporary));
+                temporaries.push(temporary);
+            }
+            else if argument_type == TokenType::String{
+                call_arguments.push(format!("&v_{}", argument));
+            }
+            else{
+                call_arguments.push(format!("v_{}", argument));
+            }
+        }
+        if C_CHECKED_FUNCTIONS.contains(&function.as_str()){
+            call_arguments.push(location);
+        }
+
+        let call = format!("engine_string_{}({})", function, call_arguments.join(", "));
+        if get_type(target) == TokenType::String{
+            lines.push(format!("{};", call));
+        }
+        else{
+            lines.push(format!("v_{} = {};", target, call));
+        }
+
+        for temporary in temporaries{
+            lines.push(format!("free({}.data);", temporary));
+        }
+        lines.push(String::from("}"));
+
+        return Ok(lines);
+    }
+
+    /* The Math Functions, `int` Arguments Are Promoted To `double` Unless All Of Them Are `int` */
+    let is_int = arguments.iter().all(|argument| get_type(argument) == TokenType::Int);
+
+    if is_int && function == "abs"{
+        return Ok(vec![format!("v_{} = engine_int_abs(v_{}, {});", target, arguments[0], location)]);
+    }
+    else if is_int && function == "pow"{
+        return Ok(vec![format!(
+            "v_{} = engine_int_pow(v_{}, v_{}, {});", target, arguments[0], arguments[1], location)]);
+    }
+    else if is_int && (function == "min" || function == "max"){
+        return Ok(vec![format!(
+            "v_{} = v_{} {} v_{} ? v_{} : v_{};",
+            target, arguments[0], if function == "min" {"<"} else {">"}, arguments[1],
+            arguments[0], arguments[1])]);
+    }
+    else if is_int && (function == "floor" || function == "ceil" || function == "round"){
+        return Ok(vec![format!("v_{} = v_{};", target, arguments[0])]);
+    }
+
+    let call_arguments: Vec<String> = arguments.iter().map(|argument| {
+        if get_type(argument) == TokenType::Int{
+            return format!("(double) v_{}", argument);
+        }
+        return format!("v_{}", argument);
+    }).collect();
+
+    let library_function = match function.as_str() {
+        "abs" => "fabs",
+        "min" => "fmin",
+        "max" => "fmax",
+        name => name,
+    };
+    let call = format!("{}({})", library_function, call_arguments.join(", "));
+
+    if function == "floor" || function == "ceil" || function == "round"{
+        return Ok(vec![format!(
+            "v_{} = engine_double_result_to_int(\"{}\", {}, {});", target, function, call, location)]);
+    }
+    return Ok(vec![format!("v_{} = {};", target, call)]);
+}
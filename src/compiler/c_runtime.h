@@ -0,0 +1,909 @@
+/*
+* The Runtime Of The Programs Compiled Into C99, Written Next To The Generated File.
+*
+* A `string` Owns Its Heap Buffer, A `char` Is Its Unicode Code Point, And The
+* Integers Wrap On Overflow Like In The VM. The Runtime Errors Are Printed To The
+* Standard Output And Exit With The Code 101 Like The Rust Executables.
+*/
+#ifndef ENGINE_RUNTIME_H
+#define ENGINE_RUNTIME_H
+
+#include <inttypes.h>
+#include <math.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+
+typedef struct{
+    char *data;
+    size_t length;
+} EngineString;
+
+
+static inline void engine_error(const char *message){
+    fputs(message, stdout);
+    fputs("\n", stdout);
+    fflush(stdout);
+    exit(101);
+}
+
+
+/* Every Print Is Flushed Like In The Rust Executables */
+static inline void engine_write(FILE *stream, const char *data, size_t length){
+    if(fwrite(data, 1, length, stream) != length || fflush(stream) != 0){
+        engine_error("Engine Compiler: Compiler Error -> Error in printing to console");
+    }
+}
+
+
+/* Strings */
+static inline void engine_string_assign(EngineString *target, const char *data, size_t length){
+    char *buffer = NULL;
+    if(length > 0){
+        buffer = malloc(length);
+        if(buffer == NULL){
+            engine_error("Engine Compiler: Compiler Error -> Out of memory");
+        }
+        memcpy(buffer, data, length);
+    }
+
+    /* The Data May Be The Old Buffer, It Is Copied First */
+    free(target->data);
+    target->data = buffer;
+    target->length = length;
+}
+
+
+static inline void engine_string_concat(EngineString *target, const EngineString *left, const EngineString *right){
+    size_t length = left->length + right->length;
+    char *buffer = NULL;
+    if(length > 0){
+        buffer = malloc(length);
+        if(buffer == NULL){
+            engine_error("Engine Compiler: Compiler Error -> Out of memory");
+        }
+        if(left->length > 0){
+            memcpy(buffer, left->data, left->length);
+        }
+        if(right->length > 0){
+            memcpy(buffer + left->length, right->data, right->length);
+        }
+    }
+
+    free(target->data);
+    target->data = buffer;
+    target->length = length;
+}
+
+
+static inline size_t engine_encode_char(uint32_t code_point, char *buffer){
+    if(code_point < 0x80){
+        buffer[0] = (char) code_point;
+        return 1;
+    }
+    else if(code_point < 0x800){
+        buffer[0] = (char) (0xC0 | (code_point >> 6));
+        buffer[1] = (char) (0x80 | (code_point & 0x3F));
+        return 2;
+    }
+    else if(code_point < 0x10000){
+        buffer[0] = (char) (0xE0 | (code_point >> 12));
+        buffer[1] = (char) (0x80 | ((code_point >> 6) & 0x3F));
+        buffer[2] = (char) (0x80 | (code_point & 0x3F));
+        return 3;
+    }
+    buffer[0] = (char) (0xF0 | (code_point >> 18));
+    buffer[1] = (char) (0x80 | ((code_point >> 12) & 0x3F));
+    buffer[2] = (char) (0x80 | ((code_point >> 6) & 0x3F));
+    buffer[3] = (char) (0x80 | (code_point & 0x3F));
+    return 4;
+}
+
+
+static inline void engine_char_to_string(EngineString *target, uint32_t code_point){
+    char buffer[4];
+    size_t length = engine_encode_char(code_point, buffer);
+    engine_string_assign(target, buffer, length);
+}
+
+
+/* Integers, The Operations Are Done Unsigned To Wrap Without Undefined Behavior */
+static inline int64_t engine_int_plus(int64_t left, int64_t right){
+    return (int64_t) ((uint64_t) left + (uint64_t) right);
+}
+
+
+static inline int64_t engine_int_minus(int64_t left, int64_t right){
+    return (int64_t) ((uint64_t) left - (uint64_t) right);
+}
+
+
+static inline int64_t engine_int_mul(int64_t left, int64_t right){
+    return (int64_t) ((uint64_t) left * (uint64_t) right);
+}
+
+
+static inline int64_t engine_int_mod(int64_t left, int64_t right, const char *error){
+    if(right == 0){
+        engine_error(error);
+    }
+    else if(right == -1){
+        return 0;
+    }
+    return left % right;
+}
+
+
+/* Saturated Like Rust `as`, `NaN` Gives 0 */
+static inline int64_t engine_double_to_int(double value){
+    if(isnan(value)){
+        return 0;
+    }
+    else if(value >= 9223372036854775808.0){
+        return INT64_MAX;
+    }
+    else if(value < -9223372036854775808.0){
+        return INT64_MIN;
+    }
+    return (int64_t) value;
+}
+
+
+/* Printing */
+static inline void engine_print_string(FILE *stream, const EngineString *value){
+    engine_write(stream, value->data, value->length);
+}
+
+
+static inline void engine_print_bool(FILE *stream, int value){
+    if(value){
+        engine_write(stream, "True", 4);
+    }
+    else{
+        engine_write(stream, "False", 5);
+    }
+}
+
+
+static inline void engine_print_int(FILE *stream, int64_t value){
+    char buffer[32];
+    int length = snprintf(buffer, sizeof(buffer), "%" PRId64, value);
+    engine_write(stream, buffer, (size_t) length);
+}
+
+
+static inline void engine_print_char(FILE *stream, uint32_t code_point){
+    char buffer[4];
+    size_t length = engine_encode_char(code_point, buffer);
+    engine_write(stream, buffer, length);
+}
+
+
+/*
+* Written Like Rust, With The Shortest Digits Reading Back The Same Value And No
+* Exponent. `%e` Rounds A Tie To Even While Rust Rounds It Up, A Tie Is A `5`
+* Followed Only By Zeros In The Exact Digits. The Longest Text Is The Smallest
+* Subnormal Value, Under 400 Bytes.
+*/
+static inline size_t engine_format_double(double value, char *text){
+    char digits[32];
+    char exact[64];
+    size_t length = 0;
+    int precision;
+
+    if(isnan(value)){
+        memcpy(text, "NaN", 3);
+        return 3;
+    }
+    if(signbit(value)){
+        text[length++] = '-';
+        value = -value;
+    }
+    if(isinf(value)){
+        memcpy(text + length, "inf", 3);
+        return length + 3;
+    }
+    if(value == 0){
+        text[length++] = '0';
+        return length;
+    }
+
+    for(precision = 0; precision < 16; precision++){
+        snprintf(digits, sizeof(digits), "%.*e", precision, value);
+        if(strtod(digits, NULL) == value){
+            break;
+        }
+    }
+    snprintf(digits, sizeof(digits), "%.*e", precision, value);
+
+    /* `%e` Only Rounds An Even Digit Down On A Tie, Adding One Never Carries */
+    snprintf(exact, sizeof(exact), "%.40e", value);
+    {
+        int last = precision == 0 ? 0 : precision + 1;
+        int index = precision + 2;
+        int is_tie = digits[last] == exact[last] && exact[index] == '5';
+        for(index = index + 1; is_tie && exact[index] != 'e'; index++){
+            is_tie = exact[index] == '0';
+        }
+        if(is_tie){
+            digits[last] += 1;
+            if(strtod(digits, NULL) != value){
+                digits[last] -= 1;
+            }
+        }
+    }
+
+    {
+        /* The Text Is `d.ddde+XX`, Or `de+XX` Without Decimals */
+        char *exponent_text = strchr(digits, 'e');
+        long exponent = strtol(exponent_text + 1, NULL, 10);
+        long count = 0;
+        long index;
+
+        for(index = 0; digits + index < exponent_text; index++){
+            if(digits[index] != '.'){
+                digits[count++] = digits[index];
+            }
+        }
+        while(count > 1 && digits[count - 1] == '0'){
+            count--;
+        }
+
+        if(exponent >= 0){
+            for(index = 0; index <= exponent; index++){
+                text[length++] = index < count ? digits[index] : '0';
+            }
+            if(count > exponent + 1){
+                text[length++] = '.';
+                for(; index < count; index++){
+                    text[length++] = digits[index];
+                }
+            }
+        }
+        else{
+            text[length++] = '0';
+            text[length++] = '.';
+            for(index = 0; index < -exponent - 1; index++){
+                text[length++] = '0';
+            }
+            for(index = 0; index < count; index++){
+                text[length++] = digits[index];
+            }
+        }
+    }
+
+    return length;
+}
+
+
+static inline void engine_print_double(FILE *stream, double value){
+    char text[400];
+    size_t length = engine_format_double(value, text);
+    engine_write(stream, text, length);
+}
+
+
+/* Input, The Line Without Its Trailing Spaces, Empty At The End Of The Input */
+static inline void engine_input(EngineString *target, const char *error){
+    size_t capacity = 64;
+    size_t length = 0;
+    char *buffer = malloc(capacity);
+    int character;
+
+    if(buffer == NULL){
+        engine_error("Engine Compiler: Compiler Error -> Out of memory");
+    }
+
+    while((character = getchar()) != EOF){
+        if(length == capacity){
+            char *new_buffer = realloc(buffer, capacity * 2);
+            if(new_buffer == NULL){
+                engine_error("Engine Compiler: Compiler Error -> Out of memory");
+            }
+            buffer = new_buffer;
+            capacity *= 2;
+        }
+        buffer[length++] = (char) character;
+        if(character == '\n'){
+            break;
+        }
+    }
+    if(ferror(stdin)){
+        engine_error(error);
+    }
+
+    while(
+        length > 0 &&
+        (buffer[length - 1] == ' ' || (buffer[length - 1] >= '\t' && buffer[length - 1] <= '\r'))
+    ){
+        length--;
+    }
+
+    engine_string_assign(target, buffer, length);
+    free(buffer);
+}
+
+
+/* Conversions From Strings, Accepting The Same Text As Rust `parse` */
+static inline int64_t engine_string_to_int(const EngineString *source, const char *error){
+    size_t index = 0;
+    int is_negative = 0;
+    uint64_t value = 0;
+    uint64_t limit;
+
+    if(source->length > 0 && (source->data[0] == '-' || source->data[0] == '+')){
+        is_negative = source->data[0] == '-';
+        index = 1;
+    }
+    if(index == source->length){
+        engine_error(error);
+    }
+
+    limit = is_negative ? (uint64_t) INT64_MAX + 1 : (uint64_t) INT64_MAX;
+    for(; index < source->length; index++){
+        unsigned digit = (unsigned char) source->data[index] - '0';
+        if(digit > 9 || value > (limit - digit) / 10){
+            engine_error(error);
+        }
+        value = value * 10 + digit;
+    }
+
+    return is_negative ? (int64_t) (0 - value) : (int64_t) value;
+}
+
+
+/* A Sign Then `inf`, `infinity`, `nan` Or Digits With An Optional Point And Exponent */
+static inline int engine_is_float_text(const char *data, size_t length){
+    size_t index = 0;
+    size_t digits_count = 0;
+
+    if(length > 0 && (data[0] == '-' || data[0] == '+')){
+        index = 1;
+    }
+
+    {
+        const char *words[3] = {"inf", "infinity", "nan"};
+        int word;
+        for(word = 0; word < 3; word++){
+            size_t word_length = strlen(words[word]);
+            size_t letter;
+            if(length - index != word_length){
+                continue;
+            }
+            for(letter = 0; letter < word_length; letter++){
+                if((data[index + letter] | 0x20) != words[word][letter]){
+                    break;
+                }
+            }
+            if(letter == word_length){
+                return 1;
+            }
+        }
+    }
+
+    for(; index < length && data[index] >= '0' && data[index] <= '9'; index++){
+        digits_count++;
+    }
+    if(index < length && data[index] == '.'){
+        for(index++; index < length && data[index] >= '0' && data[index] <= '9'; index++){
+            digits_count++;
+        }
+    }
+    if(digits_count == 0){
+        return 0;
+    }
+    if(index < length && (data[index] == 'e' || data[index] == 'E')){
+        size_t exponent_digits = 0;
+        index++;
+        if(index < length && (data[index] == '-' || data[index] == '+')){
+            index++;
+        }
+        for(; index < length && data[index] >= '0' && data[index] <= '9'; index++){
+            exponent_digits++;
+        }
+        if(exponent_digits == 0){
+            return 0;
+        }
+    }
+
+    return index == length;
+}
+
+
+static inline double engine_string_to_double(const EngineString *source, const char *error){
+    char *text;
+    double value;
+
+    if(!engine_is_float_text(source->data, source->length)){
+        engine_error(error);
+    }
+
+    text = malloc(source->length + 1);
+    if(text == NULL){
+        engine_error("Engine Compiler: Compiler Error -> Out of memory");
+    }
+    memcpy(text, source->data, source->length);
+    text[source->length] = '\0';
+
+    value = strtod(text, NULL);
+    free(text);
+
+    return value;
+}
+
+
+static inline int engine_string_to_bool(const EngineString *source, const char *error){
+    if(source->length == 4 && memcmp(source->data, "true", 4) == 0){
+        return 1;
+    }
+    else if(source->length == 5 && memcmp(source->data, "false", 5) == 0){
+        return 0;
+    }
+    engine_error(error);
+    return 0;
+}
+
+
+/* The First Character */
+static inline uint32_t engine_string_to_char(const EngineString *source, const char *error){
+    const unsigned char *data = (const unsigned char *) source->data;
+    uint32_t code_point;
+    size_t continuation_count;
+    size_t index;
+
+    if(source->length == 0){
+        engine_error(error);
+    }
+
+    if(data[0] < 0x80){
+        return data[0];
+    }
+    else if(data[0] < 0xE0){
+        code_point = data[0] & 0x1F;
+        continuation_count = 1;
+    }
+    else if(data[0] < 0xF0){
+        code_point = data[0] & 0x0F;
+        continuation_count = 2;
+    }
+    else{
+        code_point = data[0] & 0x07;
+        continuation_count = 3;
+    }
+
+    if(source->length <= continuation_count){
+        engine_error(error);
+    }
+    for(index = 1; index <= continuation_count; index++){
+        code_point = (code_point << 6) | (data[index] & 0x3F);
+    }
+
+    return code_point;
+}
+
+
+/* Built-in Functions, Their Errors Are Written Like In The Rust Executables */
+static inline void engine_builtin_error(const char *message, const char *location){
+    fputs("Engine Compiler: Builtin Error -> ", stdout);
+    fputs(message, stdout);
+    engine_error(location);
+}
+
+
+/* The Buffer Of A New String, None For An Empty One */
+static inline char *engine_allocate(size_t length){
+    char *buffer = NULL;
+    if(length > 0){
+        buffer = malloc(length);
+        if(buffer == NULL){
+            engine_error("Engine Compiler: Compiler Error -> Out of memory");
+        }
+    }
+    return buffer;
+}
+
+
+/* The Target Takes The Buffer, Which Is Built Before The Old One Is Freed */
+static inline void engine_string_take(EngineString *target, char *buffer, size_t length){
+    free(target->data);
+    target->data = buffer;
+    target->length = length;
+}
+
+
+/* Indexes Count Characters, A Character Starts With Any Byte But A Continuation Byte */
+static inline int64_t engine_char_count(const char *data, size_t length){
+    int64_t count = 0;
+    size_t index;
+    for(index = 0; index < length; index++){
+        if(((unsigned char) data[index] & 0xC0) != 0x80){
+            count++;
+        }
+    }
+    return count;
+}
+
+
+static inline size_t engine_char_offset(const EngineString *text, int64_t char_index){
+    size_t index = 0;
+    int64_t count = 0;
+    while(index < text->length){
+        if(((unsigned char) text->data[index] & 0xC0) != 0x80){
+            if(count == char_index){
+                break;
+            }
+            count++;
+        }
+        index++;
+    }
+    return index;
+}
+
+
+/* The Byte Offset Of The First Match From `start`, The Length Of The Text When There Is None */
+static inline size_t engine_string_search(const EngineString *text, const EngineString *pattern, size_t start){
+    size_t index;
+    if(pattern->length > text->length){
+        return text->length;
+    }
+    for(index = start; index + pattern->length <= text->length; index++){
+        if(pattern->length == 0 || memcmp(text->data + index, pattern->data, pattern->length) == 0){
+            return index;
+        }
+    }
+    return text->length;
+}
+
+
+static inline int engine_string_is_at(const EngineString *text, const EngineString *pattern, size_t index){
+    return index + pattern->length <= text->length &&
+        (pattern->length == 0 || memcmp(text->data + index, pattern->data, pattern->length) == 0);
+}
+
+
+static inline int64_t engine_string_len(const EngineString *text){
+    return engine_char_count(text->data, text->length);
+}
+
+
+static inline void engine_string_substring(
+    EngineString *target, const EngineString *text, int64_t start, int64_t end, const char *location
+){
+    int64_t length = engine_string_len(text);
+    size_t start_offset;
+    size_t end_offset;
+
+    if(start < 0 || end < start || end > length){
+        char message[160];
+        snprintf(
+            message, sizeof(message),
+            "`substring` range %" PRId64 "..%" PRId64 " is out of bounds for a string of length %" PRId64,
+            start, end, length);
+        engine_builtin_error(message, location);
+    }
+
+    start_offset = engine_char_offset(text, start);
+    end_offset = engine_char_offset(text, end);
+    engine_string_assign(target, text->data + start_offset, end_offset - start_offset);
+}
+
+
+static inline int64_t engine_string_find(const EngineString *text, const EngineString *pattern){
+    size_t index = engine_string_search(text, pattern, 0);
+    if(!engine_string_is_at(text, pattern, index)){
+        return -1;
+    }
+    return engine_char_count(text->data, index);
+}
+
+
+/* The Matches Don't Overlap, They Are Found From The Start Like In Rust */
+static inline void engine_string_replace(
+    EngineString *target, const EngineString *text, const EngineString *from, const EngineString *to,
+    const char *location
+){
+    size_t matches_count = 0;
+    size_t index = 0;
+    size_t length;
+    size_t result_index = 0;
+    char *buffer;
+
+    if(from->length == 0){
+        engine_builtin_error("`replace` pattern can't be empty", location);
+    }
+
+    while((index = engine_string_search(text, from, index)) < text->length){
+        matches_count++;
+        index += from->length;
+    }
+
+    length = text->length - matches_count * from->length;
+    if(to->length > 0 && matches_count > (SIZE_MAX - length) / to->length){
+        engine_error("Engine Compiler: Compiler Error -> Out of memory");
+    }
+    length += matches_count * to->length;
+
+    buffer = engine_allocate(length);
+    index = 0;
+    while(index < text->length){
+        size_t match = engine_string_search(text, from, index);
+        if(match > index){
+            memcpy(buffer + result_index, text->data + index, match - index);
+            result_index += match - index;
+        }
+        if(match == text->length){
+            break;
+        }
+        if(to->length > 0){
+            memcpy(buffer + result_index, to->data, to->length);
+            result_index += to->length;
+        }
+        index = match + from->length;
+    }
+
+    engine_string_take(target, buffer, length);
+}
+
+
+static inline void engine_string_split(
+    EngineString *target, const EngineString *text, const EngineString *separator, int64_t index,
+    const char *location
+){
+    size_t parts_count = 1;
+    size_t start = 0;
+    size_t end;
+    int64_t part;
+
+    if(separator->length == 0){
+        engine_builtin_error("`split` separator can't be empty", location);
+    }
+
+    while((end = engine_string_search(text, separator, start)) < text->length){
+        parts_count++;
+        start = end + separator->length;
+    }
+
+    if(index < 0 || (uint64_t) index >= parts_count){
+        char message[160];
+        snprintf(
+            message, sizeof(message), "`split` index %" PRId64 " is out of bounds for %zu parts",
+            index, parts_count);
+        engine_builtin_error(message, location);
+    }
+
+    start = 0;
+    for(part = 0; part < index; part++){
+        start = engine_string_search(text, separator, start) + separator->length;
+    }
+    end = engine_string_search(text, separator, start);
+
+    engine_string_assign(target, text->data + start, end - start);
+}
+
+
+/* The Unicode White Space Characters, Like Rust `char::is_whitespace` */
+static inline int engine_is_whitespace(uint32_t code_point){
+    return code_point == ' ' || (code_point >= '\t' && code_point <= '\r') ||
+        code_point == 0x85 || code_point == 0xA0 || code_point == 0x1680 ||
+        (code_point >= 0x2000 && code_point <= 0x200A) ||
+        code_point == 0x2028 || code_point == 0x2029 || code_point == 0x202F ||
+        code_point == 0x205F || code_point == 0x3000;
+}
+
+
+/* The Code Point Of The Character Starting At The Index */
+static inline uint32_t engine_decode_char(const EngineString *text, size_t index){
+    const unsigned char *data = (const unsigned char *) text->data + index;
+    uint32_t code_point;
+    size_t continuation_count;
+    size_t continuation;
+
+    if(data[0] < 0x80){
+        return data[0];
+    }
+    else if(data[0] < 0xE0){
+        code_point = data[0] & 0x1F;
+        continuation_count = 1;
+    }
+    else if(data[0] < 0xF0){
+        code_point = data[0] & 0x0F;
+        continuation_count = 2;
+    }
+    else{
+        code_point = data[0] & 0x07;
+        continuation_count = 3;
+    }
+
+    for(continuation = 1; continuation <= continuation_count && index + continuation < text->length; continuation++){
+        code_point = (code_point << 6) | (data[continuation] & 0x3F);
+    }
+    return code_point;
+}
+
+
+static inline void engine_string_trim(EngineString *target, const EngineString *text){
+    size_t start = 0;
+    size_t end = text->length;
+
+    while(start < end && engine_is_whitespace(engine_decode_char(text, start))){
+        start++;
+        while(start < end && ((unsigned char) text->data[start] & 0xC0) == 0x80){
+            start++;
+        }
+    }
+    while(end > start){
+        size_t last = end - 1;
+        while(last > start && ((unsigned char) text->data[last] & 0xC0) == 0x80){
+            last--;
+        }
+        if(!engine_is_whitespace(engine_decode_char(text, last))){
+            break;
+        }
+        end = last;
+    }
+
+    engine_string_assign(target, text->data + start, end - start);
+}
+
+
+/* Only ASCII Letters Are Mapped, Other Text Needs The Unicode Case Tables Of The Rust Executables */
+static inline void engine_string_change_case(
+    EngineString *target, const EngineString *text, int is_upper, const char *location
+){
+    char *buffer = engine_allocate(text->length);
+    size_t index;
+
+    for(index = 0; index < text->length; index++){
+        char character = text->data[index];
+        if((unsigned char) character >= 0x80){
+            free(buffer);
+            engine_builtin_error(
+                is_upper ?
+                    "`to_upper` only supports ASCII text in the C backend" :
+                    "`to_lower` only supports ASCII text in the C backend",
+                location);
+        }
+        if(is_upper && character >= 'a' && character <= 'z'){
+            character = (char) (character - 'a' + 'A');
+        }
+        else if(!is_upper && character >= 'A' && character <= 'Z'){
+            character = (char) (character - 'A' + 'a');
+        }
+        buffer[index] = character;
+    }
+
+    engine_string_take(target, buffer, text->length);
+}
+
+
+static inline void engine_string_to_upper(EngineString *target, const EngineString *text, const char *location){
+    engine_string_change_case(target, text, 1, location);
+}
+
+
+static inline void engine_string_to_lower(EngineString *target, const EngineString *text, const char *location){
+    engine_string_change_case(target, text, 0, location);
+}
+
+
+static inline int engine_string_starts_with(const EngineString *text, const EngineString *pattern){
+    return engine_string_is_at(text, pattern, 0);
+}
+
+
+static inline int engine_string_ends_with(const EngineString *text, const EngineString *pattern){
+    return pattern->length <= text->length &&
+        engine_string_is_at(text, pattern, text->length - pattern->length);
+}
+
+
+static inline int engine_string_contains(const EngineString *text, const EngineString *pattern){
+    return engine_string_is_at(text, pattern, engine_string_search(text, pattern, 0));
+}
+
+
+static inline void engine_string_repeat(
+    EngineString *target, const EngineString *text, int64_t count, const char *location
+){
+    char *buffer;
+    int64_t index;
+
+    if(count < 0){
+        char message[160];
+        snprintf(message, sizeof(message), "`repeat` count %" PRId64 " can't be negative", count);
+        engine_builtin_error(message, location);
+    }
+    if(text->length > 0 && (uint64_t) count > SIZE_MAX / text->length){
+        engine_error("Engine Compiler: Compiler Error -> Out of memory");
+    }
+
+    buffer = engine_allocate(text->length * (size_t) count);
+    for(index = 0; index < count && text->length > 0; index++){
+        memcpy(buffer + (size_t) index * text->length, text->data, text->length);
+    }
+
+    engine_string_take(target, buffer, text->length * (size_t) count);
+}
+
+
+/* The `int` Math Functions Stop On Overflow, The `double` Ones Are The C Library Functions */
+static inline int64_t engine_int_abs(int64_t value, const char *location){
+    if(value == INT64_MIN){
+        engine_builtin_error("`abs` result of `-9223372036854775808` does not fit into int", location);
+    }
+    return value < 0 ? -value : value;
+}
+
+
+/* 0 When The Product Does Not Fit */
+static inline int engine_checked_mul(int64_t left, int64_t right, int64_t *result){
+    if(left > 0){
+        if(right > 0 ? left > INT64_MAX / right : right < INT64_MIN / left){
+            return 0;
+        }
+    }
+    else if(right > 0){
+        if(left < INT64_MIN / right){
+            return 0;
+        }
+    }
+    else if(left != 0 && right < INT64_MAX / left){
+        return 0;
+    }
+    *result = left * right;
+    return 1;
+}
+
+
+/* Squared Like Rust `checked_pow`, The Last Bit Returns Before Squaring The Base Again */
+static inline int64_t engine_int_pow(int64_t base, int64_t exponent, const char *location){
+    int64_t result = 1;
+    int64_t square = base;
+    int64_t bits = exponent;
+    int fits = exponent <= (int64_t) UINT32_MAX;
+    char message[160];
+
+    if(exponent < 0){
+        snprintf(
+            message, sizeof(message),
+            "`pow` exponent `%" PRId64 "` can't be negative for int values, use a double base", exponent);
+        engine_builtin_error(message, location);
+    }
+
+    while(fits && bits > 0){
+        if(bits & 1){
+            fits = engine_checked_mul(result, square, &result);
+            if(bits == 1){
+                break;
+            }
+        }
+        bits /= 2;
+        fits = fits && engine_checked_mul(square, square, &square);
+    }
+
+    if(!fits){
+        snprintf(
+            message, sizeof(message),
+            "`pow` result of `%" PRId64 "` and `%" PRId64 "` does not fit into int", base, exponent);
+        engine_builtin_error(message, location);
+    }
+    return result;
+}
+
+
+/* The Results Of `floor`, `ceil` And `round` */
+static inline int64_t engine_double_result_to_int(const char *name, double value, const char *location){
+    if(isnan(value) || value < -9223372036854775808.0 || value >= 9223372036854775808.0){
+        char text[400];
+        char message[480];
+        size_t length = engine_format_double(value, text);
+        snprintf(
+            message, sizeof(message), "`%s` result `%.*s` does not fit into int", name, (int) length, text);
+        engine_builtin_error(message, location);
+    }
+    return (int64_t) value;
+}
+
+#endif
@@ -1,36 +1,22 @@
-use std::collections::{
-    HashMap,
-    VecDeque
-};
-use std::ops::Deref;
 use std::process::Command;
 
-use crate::environments::{
-    Environment,
-    EnvironmentScope,
-    Variable
-};
-use crate::syntax_tree::{
-    StatementsNode,
-    StatementNode,
-    StatementType,
-    DefineBoolNode,
-    OperationNode,
-    DefineIntNode,
-    DefineDoubleNode,
-    DefineCharNode,
-    DefineStringNode,
-    OperatorType,
-    DefinePrintNode,
-    DefineVarNode,
-    DefineVariableNode,
-    DefineIfStatementNode,
-    DefineForLoopStatementNode
-};
 use crate::tokens::TokenType;
+use crate::builtins::BUILTINS_SOURCE;
 use crate::constants::Mode;
+use crate::syntax_tree::OperatorType;
+use crate::ir::{
+    IrProgram,
+    IrInstruction,
+    IrInstructionKind,
+    IrTerminator,
+    get_operator_symbol
+};
 
 use crate::file::File;
+use super::{
+    get_output_folder,
+    get_output_path
+};
 
 
 #[derive(Debug)]
@@ -38,132 +24,102 @@ pub struct CodeGenerator{
     file: File,
     parent_folder: String,
     new_file_path: String,
-    syntax_tree: StatementsNode,
-    currrent_counter: u128,
-    environments_stack: VecDeque<Environment>,
+    program: IrProgram,
+
+    /* The Engine Line And Position Of Every Generated Line, Used To Report `rustc` Errors */
+    source_lines: Vec<Option<(u64, u64)>>,
 }
 
 impl CodeGenerator{
     pub fn new(
-        syntax_tree: StatementsNode,
+        program: IrProgram,
         parent_folder_path: String,
         file_name: String,
     ) -> Result<Self, std::io::Error>{
 
-        let mut _parent_folder_path = parent_folder_path.clone();
-        _parent_folder_path.push_str("/");
-
-        let new_file_path = _parent_folder_path + &file_name + &String::from(".rs");
+        let new_file_path = get_output_path(&parent_folder_path, &(file_name + ".rs"));
 
         let mut _file = File::create_new(
             new_file_path.clone(), Mode::Compiler)?;
 
-        let mut environments_stack = VecDeque::new();
-        environments_stack.push_back(Environment {
-            scope: EnvironmentScope::Main,
-            variables: HashMap::new(),
-            internal_variables: HashMap::new(),
-            stop_statements_execution: None,
-        });
-
         return Ok(CodeGenerator{
             file: _file,
-            parent_folder: parent_folder_path.clone(),
+            parent_folder: get_output_folder(&parent_folder_path),
             new_file_path,
-            syntax_tree,
-            currrent_counter: 0,
-            environments_stack
+            program,
+            source_lines: vec![],
         });
     }
 
-    fn generate_variable_name(&mut self) -> String{
-        self.currrent_counter += 1;
-        return format!("_{}", self.currrent_counter);
+    fn get_type(&self, name: &String) -> TokenType{
+        return self.program.get_variable_type(name).unwrap();
     }
 
-    fn insert_variable_into_environments_stack(
-        &mut self, variable_name: String, variable: Variable,
-    ){
-
-        for environment in &mut self.environments_stack{
-            if environment.variables.contains_key(&variable_name){
-                environment.variables.insert(variable_name, Some(variable));
-                return ;
-            }
+    fn write(&mut self, data: String, source: Option<(u64, u64)>){
+        for _ in data.matches("\n"){
+            self.source_lines.push(source);
         }
-
-        self.environments_stack.back_mut().as_mut().unwrap().variables.insert(
-            variable_name, Some(variable));
+        self.file.write(data);
     }
 
-    fn insert_internal_variable_into_environment_stack(
-        &mut self, variable_name: String, variable: Variable
-    ){
-        for environment in &mut self.environments_stack{
-            if environment.internal_variables.contains_key(&variable_name){
-                environment.internal_variables.entry(variable_name).and_modify(|e|{
-                    e.push_back(variable)
-                });
-                return ;
-            }
-        }
-
-        self.environments_stack.back_mut().as_mut().unwrap().internal_variables.insert(
-            variable_name, VecDeque::from([variable]));
+    fn writeln(&mut self, data: String){
+        self.write(data + "\n", None);
     }
 
-    fn is_variable_exists(&mut self, variable_name: &String) -> bool{
-        for environment in &self.environments_stack{
-            if environment.variables.contains_key(variable_name){
-                return true;
-            }
-        }
+    pub fn execute(&mut self) -> Result<(), String>{
+        let output = Command::new("rustc")
+            .arg(self.file.file_path.clone())
+            .arg("--out-dir")
+            .arg(self.parent_folder.clone())
+            .arg("--error-format=short")
+            .output();
 
-        return false;
-    }
+        if output.is_err(){
+            return Err(format!(
+                "Engine Compiler: Compiler Error -> Failed to run `rustc`: {}.", output.unwrap_err()));
+        }
+        let output = output.unwrap();
 
-    fn get_variable_type(
-        &self, variable_name: &String
-    ) -> Result<TokenType, String>{
+        if !output.status.success(){
+            let diagnostics = String::from_utf8_lossy(&output.stderr);
+            let diagnostics: Vec<String> = diagnostics.lines().map(
+                |diagnostic| self.map_diagnostic(diagnostic)).collect();
 
-        for environment in &self.environments_stack{
-            if environment.variables.contains_key(variable_name){
-                return Ok(
-                    environment.variables.get(variable_name)
-                        .as_ref().unwrap().as_ref().unwrap()
-                        .variable_type.as_ref().unwrap().clone());
-            }
+            return Err(format!(
+                "Engine Compiler: Compiler Error -> `rustc` failed to compile `{}`:\n{}",
+                self.new_file_path, diagnostics.join("\n").trim_end()));
         }
 
-        return Err(format!(
-            "Engine Compiler: Code Generation Error -> Variable `{}` not found.",
-            variable_name));
+        return Ok(());
     }
 
-    fn get_internal_variable(
-        &mut self, variable_name: &String
-    ) -> Option<VecDeque<Variable>>{
+    /*
+    * A Short `rustc` Diagnostic Starts With `file.rs:line:column: `, It Is Given
+    * The Engine Line Instead When The Generated Line Comes From The Program.
+    */
+    fn map_diagnostic(&self, diagnostic: &str) -> String{
+        let prefix = format!("{}:", self.file.file_path);
+        if !diagnostic.starts_with(&prefix){
+            return String::from(diagnostic);
+        }
 
-        for environment in &self.environments_stack{
-            if environment.internal_variables.contains_key(variable_name){
-                return Some(
-                    environment.internal_variables.get(variable_name)
-                        .as_ref().unwrap().deref().clone());
-            }
+        let location: Vec<&str> = diagnostic[prefix.len()..].splitn(3, ":").collect();
+        if location.len() < 3{
+            return String::from(diagnostic);
         }
 
-        return None;
-    }
+        let line = location[0].parse::<usize>();
+        if line.is_err() || line.as_ref().unwrap() == &0{
+            return String::from(diagnostic);
+        }
 
-    pub fn execute(&mut self) -> Result<(), String>{
-        Command::new("rustc")
-            .arg(self.file.file_path.clone())
-            .arg("--out-dir")
-            .arg(self.parent_folder.clone())
-            .output()
-            .expect("failed to execute process");
+        let source = self.source_lines.get(line.unwrap() - 1);
+        if source == None || source.unwrap() == &None{
+            return String::from(diagnostic);
+        }
+        let (source_line, source_pos) = source.unwrap().unwrap();
 
-        return Ok(());
+        return format!("{}, line: {}:{}", location[2].trim(), source_line, source_pos);
     }
 
     pub fn clean(&mut self) -> Result<(), String>{
@@ -174,1131 +130,314 @@ impl CodeGenerator{
 }
 
 
+/*
+* Every Variable Is Declared At The Start Of `main`, The Blocks Are The Arms
+* Of A `match` On The Current Block Inside A Loop.
+*/
 pub fn generate(
     code_generator: &mut CodeGenerator
 ) -> Result<(), String>{
-    let mut code_generator = code_generator;
-
-    code_generator.file.writeln(String::from("#![allow(arithmetic_overflow)]"));
-    code_generator.file.writeln(String::from("use std::io;"));
-    code_generator.file.writeln(String::from("use std::panic;"));
-    code_generator.file.writeln(String::from("fn main(){"));
-    code_generator.file.writeln(String::from("use std::io::Write;"));
-    code_generator.file.writeln(String::from("panic::set_hook(Box::new(|panic_info| {"));
-    code_generator.file.writeln(String::from("if let Some(panic_message) = panic_info.payload().downcast_ref::<String>() {"));
-    code_generator.file.writeln(String::from("println!(\"{}\", panic_message);"));
-    code_generator.file.writeln(String::from("} else if let Some(panic_message) = panic_info.payload().downcast_ref::<&str>() {"));
-    code_generator.file.writeln(String::from("println!(\"{}\", panic_message);"));
-    code_generator.file.writeln(String::from("} else {"));
-    code_generator.file.writeln(String::from("println!(\"Engine Compiler -> Interperter Error {}\", panic_info);"));
-    code_generator.file.writeln(String::from("}"));
-    code_generator.file.writeln(String::from("}));"));
-
-    let mut tree = code_generator.syntax_tree.clone();
-
-    generate_statements_node(
-        &mut code_generator, &mut tree)?;
-
-    code_generator.file.writeln(String::from("}"));
 
-    return Ok(());
-}
-
-fn generate_statements_node(
-    code_generator: &mut CodeGenerator,
-    statements_node: &mut StatementsNode
-) -> Result<(), String>{
-    let mut code_generator = code_generator;
-
-    for statement in &mut statements_node.statements{
-        generate_statement_node(&mut code_generator, statement)?;
-    }
-
-    return Ok(());
-}
-
-fn generate_statement_node(
-    code_generator: &mut CodeGenerator,
-    statement: &mut StatementNode
-) -> Result<(), String>{
+    code_generator.writeln(String::from("#![allow(arithmetic_overflow, unused)]"));
+    code_generator.writeln(String::from("use std::io;"));
+    code_generator.writeln(String::from("use std::panic;"));
+
+    /* Built-in Functions Module */
+    code_generator.writeln(String::from("mod engine_builtins{"));
+    code_generator.write(String::from(BUILTINS_SOURCE), None);
+    code_generator.writeln(String::from("}"));
+
+    code_generator.writeln(String::from("fn main(){"));
+    code_generator.writeln(String::from("use std::io::Write;"));
+    code_generator.writeln(String::from("panic::set_hook(Box::new(|panic_info| {"));
+    code_generator.writeln(String::from("if let Some(panic_message) = panic_info.payload().downcast_ref::<String>() {"));
+    code_generator.writeln(String::from("println!(\"{}\", panic_message);"));
+    code_generator.writeln(String::from("} else if let Some(panic_message) = panic_info.payload().downcast_ref::<&str>() {"));
+    code_generator.writeln(String::from("println!(\"{}\", panic_message);"));
+    code_generator.writeln(String::from("} else {"));
+    code_generator.writeln(String::from("println!(\"Engine Compiler -> Interperter Error {}\", panic_info);"));
+    code_generator.writeln(String::from("}"));
+    code_generator.writeln(String::from("}));"));
+
+    /* Declare Variables */
+    let mut source_lines = vec![];
+    for variable in &code_generator.program.variables{
+        let (rust_type, default_value) = get_rust_type(&variable.variable_type);
+        source_lines.push((format!("let mut {}: {} = {};", variable.name, rust_type, default_value), None));
+    }
+
+    source_lines.push((String::from("let mut block: usize = 0;"), None));
+    source_lines.push((String::from("loop{"), None));
+    source_lines.push((String::from("match block{"), None));
+
+    let mut injected_error_line = get_injected_error_line();
+    for (index, block) in code_generator.program.blocks.iter().enumerate(){
+        source_lines.push((format!("{} => {{", index), None));
+
+        for instruction in &block.instructions{
+            for line in generate_instruction(code_generator, instruction)?{
+                source_lines.push((line, Some((instruction.start_line, instruction.start_pos))));
+            }
 
-    let mut code_generator = code_generator;
+            if injected_error_line == Some(instruction.start_line){
+                source_lines.push((
+                    String::from("let engine_injected_error: i64 = \"\";"),
+                    Some((instruction.start_line, instruction.start_pos))));
+                injected_error_line = None;
+            }
+        }
 
-    if statement.statement_type == Some(StatementType::DefineBool){
-        generate_define_bool_variable(
-            &mut code_generator,
-            &statement.define_bool_statement.as_ref().unwrap())?;
-    }
-    else if statement.statement_type == Some(StatementType::DefineInt){
-        generate_define_int_variable(
-            &mut code_generator,
-            &statement.define_int_statement.as_ref().unwrap())?;
-    }
-    else if statement.statement_type == Some(StatementType::DefineDouble){
-        generate_define_double_variable(
-            &mut code_generator,
-            &statement.define_double_statement.as_ref().unwrap())?;
-    }
-    else if statement.statement_type == Some(StatementType::DefineChar){
-        generate_define_char_variable(
-            &mut code_generator,
-            &statement.define_char_statement.as_ref().unwrap())?;
-    }
-    else if statement.statement_type == Some(StatementType::DefineString){
-        generate_define_string_variable(
-            &mut code_generator,
-            &statement.define_string_statement.as_ref().unwrap())?;
-    }
-    else if statement.statement_type == Some(StatementType::DefineVar){
-        generate_define_var_variable(
-            &mut code_generator,
-            &statement.define_var_statement.as_ref().unwrap())?;
-    }
+        source_lines.push((match &block.terminator {
+            IrTerminator::Jump{target} => format!("block = {};", target),
+            IrTerminator::Branch{condition, then_block, else_block} => format!(
+                "block = if {} {{{}}} else {{{}}};", condition, then_block, else_block),
+            IrTerminator::End => String::from("break;"),
+        }, None));
 
-    else if statement.statement_type == Some(StatementType::DefineVariable){
-        generate_define_variable(
-            &mut code_generator,
-            &statement.define_variable_statement.as_ref().unwrap())?;
+        source_lines.push((String::from("},"), None));
     }
 
-    else if statement.statement_type == Some(StatementType::Print){
-        generate_define_print_variable(
-            &mut code_generator,
-            &statement.define_print_statement.as_ref().unwrap())?;
-    }
+    source_lines.push((String::from("_ => break,"), None));
+    source_lines.push((String::from("}"), None));
+    source_lines.push((String::from("}"), None));
 
-    else if statement.statement_type == Some(StatementType::DefineIf){
-        generate_define_if_statement(
-            &mut code_generator,
-            &mut statement.define_if_statement.as_mut().unwrap())?;
+    for (line, source) in source_lines{
+        code_generator.write(line + "\n", source);
     }
 
-    else if statement.statement_type == Some(StatementType::DefineForLoop){
-        generate_define_for_loop_statement(
-            &mut code_generator,
-            &mut statement.define_for_loop_statement.as_mut().unwrap())?;
-    }
-    else if statement.statement_type == Some(StatementType::Continue){
-        generate_continue_statement(&mut code_generator)?;
-    }
-    else if statement.statement_type == Some(StatementType::Break){
-        generate_break_statement(&mut code_generator)?;
-    }
+    code_generator.writeln(String::from("}"));
 
     return Ok(());
 }
 
-fn generate_define_bool_variable(
-    code_generator: &mut CodeGenerator,
-    statement: &DefineBoolNode
-) -> Result<(), String>{
-
-    let mut code_generator = code_generator;
-
-    let result = define_operation_node_variables(
-        &mut code_generator,
-        statement.left.as_ref().unwrap())?;
-
-    code_generator.file.writeln(format!(
-        "let mut variable_{}: bool = {};",
-        statement.name.as_ref().unwrap().value,
-        result.0,
-    ));
-
-    code_generator.insert_variable_into_environments_stack(
-        statement.name.as_ref().unwrap().value.clone(),
-        Variable {
-            variable_type: Some(TokenType::Bool),
-            name: Some(statement.name.as_ref().unwrap().value.clone()),
-            value: None,
-            is_reasigned: false
-        });
 
-    return Ok(());
+/* Tests Hook, Debug Builds Add Invalid Rust After The First Instruction Of This Engine Line */
+#[cfg(debug_assertions)]
+fn get_injected_error_line() -> Option<u64>{
+    return std::env::var("ENGINE_INJECT_RUST_ERROR").ok().and_then(|line| line.parse::<u64>().ok());
 }
 
-fn generate_define_int_variable(
-    code_generator: &mut CodeGenerator,
-    statement: &DefineIntNode
-) -> Result<(), String>{
-
-    let mut code_generator = code_generator;
-
-    let result = define_operation_node_variables(
-        &mut code_generator,
-        statement.left.as_ref().unwrap())?;
-
-    code_generator.file.writeln(format!(
-        "let mut variable_{}: i64 = {} as i64;",
-        statement.name.as_ref().unwrap().value,
-        result.0
-    ));
-
-    code_generator.insert_variable_into_environments_stack(
-        statement.name.as_ref().unwrap().value.clone(),
-        Variable {
-            variable_type: Some(TokenType::Int),
-            name: Some(statement.name.as_ref().unwrap().value.clone()),
-            value: None,
-            is_reasigned: false
-        });
-
-    return Ok(());
+#[cfg(not(debug_assertions))]
+fn get_injected_error_line() -> Option<u64>{
+    return None;
 }
 
-fn generate_define_double_variable(
-    code_generator: &mut CodeGenerator,
-    statement: &DefineDoubleNode
-) -> Result<(), String>{
 
-    let mut code_generator = code_generator;
-
-    let result = define_operation_node_variables(
-        &mut code_generator,
-        statement.left.as_ref().unwrap())?;
-
-    code_generator.file.writeln(format!(
-        "let mut variable_{}: f64 = {} as f64;",
-        statement.name.as_ref().unwrap().value,
-        result.0
-    ));
-
-    code_generator.insert_variable_into_environments_stack(
-        statement.name.as_ref().unwrap().value.clone(),
-        Variable {
-            variable_type: Some(TokenType::Double),
-            name: Some(statement.name.as_ref().unwrap().value.clone()),
-            value: None,
-            is_reasigned: false
-        });
-
-    return Ok(());
-}
-
-fn generate_define_char_variable(
-    code_generator: &mut CodeGenerator,
-    statement: &DefineCharNode
-) -> Result<(), String>{
-
-    let mut code_generator = code_generator;
-
-    let result = define_operation_node_variables(
-        &mut code_generator,
-        statement.left.as_ref().unwrap())?;
-
-    if result.1 == TokenType::Character{
-        code_generator.file.writeln(format!(
-            "let mut variable_{}: char = {};",
-            statement.name.as_ref().unwrap().value,
-            result.0
-        ));
+fn get_rust_type(variable_type: &TokenType) -> (&'static str, &'static str){
+    if variable_type == &TokenType::Bool{
+        return ("bool", "false");
     }
-    else{
-        code_generator.file.writeln(format!(
-            "let mut variable_{}: char = {}.chars().nth(0).unwrap();",
-            statement.name.as_ref().unwrap().value,
-            result.0
-        ));
+    else if variable_type == &TokenType::Int{
+        return ("i64", "0");
     }
-
-    code_generator.insert_variable_into_environments_stack(
-        statement.name.as_ref().unwrap().value.clone(),
-        Variable {
-            variable_type: Some(TokenType::Char),
-            name: Some(statement.name.as_ref().unwrap().value.clone()),
-            value: None,
-            is_reasigned: false
-        });
-
-    return Ok(());
+    else if variable_type == &TokenType::Double{
+        return ("f64", "0.0");
+    }
+    else if variable_type == &TokenType::Char{
+        return ("char", "'\\0'");
+    }
+    else if variable_type == &TokenType::List{
+        return ("Vec<String>", "vec![]");
+    }
+    return ("String", "String::new()");
 }
 
-fn generate_define_string_variable(
-    code_generator: &mut CodeGenerator,
-    statement: &DefineStringNode
-) -> Result<(), String>{
-
-    let mut code_generator = code_generator;
-
-    let result = define_operation_node_variables(
-        &mut code_generator,
-        statement.left.as_ref().unwrap())?;
-
-    code_generator.file.writeln(format!(
-        "let mut variable_{}: String = {};",
-        statement.name.as_ref().unwrap().value,
-        result.0
-    ));
-
-    code_generator.insert_variable_into_environments_stack(
-        statement.name.as_ref().unwrap().value.clone(),
-        Variable {
-            variable_type: Some(TokenType::String),
-            name: Some(statement.name.as_ref().unwrap().value.clone()),
-            value: None,
-            is_reasigned: false
-        });
 
-    return Ok(());
-}
-
-fn generate_define_var_variable(
-    code_generator: &mut CodeGenerator,
-    statement: &DefineVarNode
-) -> Result<(), String>{
+fn generate_instruction(
+    code_generator: &CodeGenerator, instruction: &IrInstruction
+) -> Result<Vec<String>, String>{
 
-    let mut code_generator = code_generator;
+    let line = instruction.start_line;
+    let pos = instruction.start_pos;
 
-    let result = define_operation_node_variables(
-        &mut code_generator,
-        statement.left.as_ref().unwrap())?;
+    return match &instruction.kind {
+        IrInstructionKind::Constant{target, value} => Ok(vec![format!(
+            "{} = {};", target, get_rust_literal(&code_generator.get_type(target), value))]),
 
-    code_generator.file.writeln(format!(
-        "let mut variable_{} = {};",
-        statement.name.as_ref().unwrap().value,
-        result.0
-    ));
+        IrInstructionKind::Convert{target, source} => {
+            let target_type = code_generator.get_type(target);
+            let source_type = code_generator.get_type(source);
 
-    code_generator.insert_variable_into_environments_stack(
-        statement.name.as_ref().unwrap().value.clone(),
-        Variable {
-            variable_type: Some({
-                if result.1 == TokenType::Bool{
-                    TokenType::Bool
-                }
-                else if result.1 == TokenType::IntNumber{
-                    TokenType::Int
-                }
-                else if result.1 == TokenType::DoubleNumber{
-                    TokenType::Double
-                }
-                else if result.1 == TokenType::Character{
-                    TokenType::Char
+            let value = if target_type == source_type{
+                if target_type == TokenType::String || target_type == TokenType::List{
+                    format!("{}.clone()", source)
                 }
                 else{
-                    TokenType::String
+                    source.clone()
                 }
-            }),
-            name: Some(statement.name.as_ref().unwrap().value.clone()),
-            value: None,
-            is_reasigned: false
-        });
-
-    return Ok(());
-}
-
-fn generate_define_variable(
-    code_generator: &mut CodeGenerator,
-    statement: &DefineVariableNode
-) -> Result<(), String>{
-
-    let mut code_generator = code_generator;
-
-    let result = define_operation_node_variables(
-        &mut code_generator,
-        statement.left.as_ref().unwrap())?;
-
-    if code_generator.is_variable_exists(&statement.name.as_ref().unwrap().value){
-        let variable_type = code_generator.get_variable_type(
-            &statement.name.as_ref().unwrap().value)?;
-
-        if variable_type == TokenType::Double{
-            code_generator.file.writeln(format!(
-                "variable_{} {} {} as f64;",
-                statement.name.as_ref().unwrap().value,
-                statement.operator.as_ref().unwrap().value,
-                result.0
-            ));
-        }
-        else if variable_type == TokenType::Int{
-            code_generator.file.writeln(format!(
-                "variable_{} {} {} as i64;",
-                statement.name.as_ref().unwrap().value,
-                statement.operator.as_ref().unwrap().value,
-                result.0
-            ));
-        }
-        else if variable_type == TokenType::Char{
-            if result.1 == TokenType::Character{
-                code_generator.file.writeln(format!(
-                    "variable_{} = {};",
-                    statement.name.as_ref().unwrap().value,
-                    result.0
-                ));
             }
-            else{
-                code_generator.file.writeln(format!(
-                    "variable_{} = {}.chars().nth(0).unwrap();",
-                    statement.name.as_ref().unwrap().value,
-                    result.0
-                ));
+            else if target_type == TokenType::Int && source_type == TokenType::Double{
+                format!("{} as i64", source)
             }
-        }
-        else if variable_type == TokenType::String{
-            if result.1 == TokenType::Character{
-                code_generator.file.writeln(format!(
-                    "variable_{}.push({});",
-                    statement.name.as_ref().unwrap().value,
-                    result.0
-                ));
+            else if target_type == TokenType::Double && source_type == TokenType::Int{
+                format!("{} as f64", source)
             }
-            else{
-                if statement.operator.as_ref().unwrap().token_type == TokenType::Assign{
-                    code_generator.file.writeln(format!(
-                        "variable_{} {} {}.clone();",
-                        statement.name.as_ref().unwrap().value,
-                        statement.operator.as_ref().unwrap().value,
-                        result.0
-                    ));
-                }
-                else{
-                    code_generator.file.writeln(format!(
-                        "variable_{} {} &{}.clone();",
-                        statement.name.as_ref().unwrap().value,
-                        statement.operator.as_ref().unwrap().value,
-                        result.0
-                    ));
-                }
+            else if target_type == TokenType::String && source_type == TokenType::Char{
+                format!("String::from({})", source)
             }
-        }
-        else{
-            code_generator.file.writeln(format!(
-                "variable_{} = {};",
-                statement.name.as_ref().unwrap().value,
-                result.0
-            ));
-        }
-    }
-    else{
-        code_generator.file.writeln(format!(
-            "let mut variable_{} = {};",
-            statement.name.as_ref().unwrap().value,
-            result.0
-        ));
-
-        code_generator.insert_variable_into_environments_stack(
-            statement.name.as_ref().unwrap().value.clone(),
-            Variable {
-                variable_type: Some({
-                    if result.1 == TokenType::Bool{
-                        TokenType::Bool
-                    }
-                    else if result.1 == TokenType::IntNumber{
-                        TokenType::Int
-                    }
-                    else if result.1 == TokenType::DoubleNumber{
-                        TokenType::Double
-                    }
-                    else if result.1 == TokenType::Character{
-                        TokenType::Char
-                    }
-                    else{
-                        TokenType::String
-                    }
-                }),
-                name: Some(statement.name.as_ref().unwrap().value.clone()),
-                value: None,
-                is_reasigned: false
-            });
-    }
-
-    return Ok(());
-}
-
-fn generate_define_print_variable(
-    code_generator: &mut CodeGenerator,
-    statement: &DefinePrintNode
-) -> Result<(), String>{
-
-    let mut code_generator = code_generator;
-
-    let result = define_operation_node_variables(
-        &mut code_generator,
-        statement.expression.as_ref().unwrap())?;
-
-    /* Print Variable */
-    code_generator.file.writeln(format!("print!(\"{{}}\", {});", result.0));
-
-    /* Flush Variable */
-    code_generator.file.writeln(format!("if io::stdout().flush().is_err(){{"));
-    code_generator.file.writeln(format!(
-        "panic!(\"Engine Compiler: Compiler Error -> Error in printing to console\");"));
-    code_generator.file.writeln(format!("}}"));
-
-    return Ok(());
-}
-
-fn generate_define_if_statement(
-    code_generator: &mut CodeGenerator,
-    statement: &mut DefineIfStatementNode
-) -> Result<(), String>{
-
-    let mut code_generator = code_generator;
-
-    let if_condition_result;
-    let mut else_if_condition_results: Vec<(String, TokenType)> = Vec::new();
-
-    /* Define Conditions */
-    {
-        /* Define If Condition */
-        {
-            let define_if_node = statement.define_if_node.as_mut().unwrap();
-
-            if_condition_result = define_operation_node_variables(
-                &mut code_generator,
-                define_if_node.condition.as_ref().unwrap())?;
-        }
-
-        /* Define Else if Conditions */
-        {
-            for define_if_else_node in &mut statement.define_if_else_nodes{
-
-                else_if_condition_results.push(define_operation_node_variables(
-                    &mut code_generator,
-                    define_if_else_node.condition.as_ref().unwrap())?);
-            }
-        }
-    }
-
-    /* Generate If Statement */
-    {
-        code_generator.environments_stack.push_back(Environment {
-            scope: EnvironmentScope::If,
-            variables: HashMap::new(),
-            internal_variables: HashMap::new(),
-            stop_statements_execution: None,
-        });
-
-        let define_if_node = statement.define_if_node.as_mut().unwrap();
-
-        code_generator.file.writeln(format!("if {}{{", if_condition_result.0));
-
-        generate_statements_node(
-            &mut code_generator, &mut define_if_node.statements)?;
-
-        code_generator.file.writeln(String::from("}"));
-
-        code_generator.environments_stack.pop_back();
-    }
-
-    /* Generate Else if Statements */
-    {
-        let mut index = 0;
-        for define_if_else_node in &mut statement.define_if_else_nodes{
-            code_generator.environments_stack.push_back(Environment {
-                scope: EnvironmentScope::If,
-                variables: HashMap::new(),
-                internal_variables: HashMap::new(),
-                stop_statements_execution: None,
-            });
-
-            let result = &else_if_condition_results[index];
-
-            code_generator.file.writeln(format!("else if {}{{", result.0));
-
-            generate_statements_node(
-                &mut code_generator, &mut define_if_else_node.statements)?;
-
-            code_generator.file.writeln(String::from("}"));
-
-            code_generator.environments_stack.pop_back();
-
-            index += 1;
-        }
-    }
-
-    /* Generate Else Statement */
-    {
-        if statement.define_else_node != None{
-            code_generator.environments_stack.push_back(Environment {
-                scope: EnvironmentScope::If,
-                variables: HashMap::new(),
-                internal_variables: HashMap::new(),
-                stop_statements_execution: None,
-            });
-
-            let define_else_node = statement.define_else_node.as_mut().unwrap();
-
-            code_generator.file.writeln(String::from("else {"));
-
-            generate_statements_node(
-                &mut code_generator, &mut define_else_node.statements)?;
-
-            code_generator.file.writeln(String::from("}"));
-
-            code_generator.environments_stack.pop_back();
-        }
-    }
-
-    return Ok(());
-}
-
-fn generate_define_for_loop_statement(
-    code_generator: &mut CodeGenerator,
-    statement: &mut DefineForLoopStatementNode
-) -> Result<(), String>{
-
-    let mut code_generator = code_generator;
-
-    code_generator.file.writeln(String::from("{"));
-
-    let start_loop_variable_name: String;
-    let mut stop_loop_variable_name: String = String::from("");
-    let mut step_loop_variable_name: String = String::from("");
-
-    /* Generate Loop Conditions */
-    {
-        if statement.start != None{
-            let result = define_operation_node_variables(
-                &mut code_generator,
-                statement.start.as_ref().unwrap())?;
-
-            start_loop_variable_name = format!(
-                "temp{}", code_generator.generate_variable_name().clone());
-
-            code_generator.insert_internal_variable_into_environment_stack(
-                String::from("start_loop_variable"),
-                Variable {
-                    variable_type: Some(TokenType::Int),
-                    name: Some(start_loop_variable_name.clone()),
-                    value: None,
-                    is_reasigned: false
-                });
-
-            code_generator.file.writeln(format!(
-                "let mut {}: i64 = {} as i64;",
-                start_loop_variable_name, result.0));
-        }
-        else{
-            start_loop_variable_name = format!(
-                "temp{}", code_generator.generate_variable_name().clone());
-
-            code_generator.insert_internal_variable_into_environment_stack(
-                String::from("start_loop_variable"),
-                Variable {
-                    variable_type: Some(TokenType::Int),
-                    name: Some(start_loop_variable_name.clone()),
-                    value: None,
-                    is_reasigned: false
-                });
-
-            code_generator.file.writeln(format!(
-                "let mut {}: i64 = 0;", start_loop_variable_name));
-        }
-
-        if statement.stop != None{
-            let result = define_operation_node_variables(
-                &mut code_generator,
-                statement.stop.as_ref().unwrap())?;
-
-            stop_loop_variable_name = format!(
-                "temp{}", code_generator.generate_variable_name().clone());
-
-            code_generator.file.writeln(format!(
-                "let mut {}: i64 = {} as i64;",
-                stop_loop_variable_name, result.0));
-        }
-
-        if statement.step != None{
-            let result = define_operation_node_variables(
-                &mut code_generator,
-                statement.step.as_ref().unwrap())?;
-
-            step_loop_variable_name = format!(
-                "temp{}", code_generator.generate_variable_name().clone());
-
-            code_generator.insert_internal_variable_into_environment_stack(
-                String::from("step_loop_variable"),
-                Variable {
-                    variable_type: Some(TokenType::Int),
-                    name: Some(step_loop_variable_name.clone()),
-                    value: None,
-                    is_reasigned: false
-                });
-
-            code_generator.file.writeln(format!(
-                "let mut {}: i64 = {} as i64;",
-                step_loop_variable_name, result.0));
-        }
-    }
-
-    /* Generate Loop */
-    {
-        code_generator.file.writeln(String::from("loop{"));
-
-        /* Before Execute Statements */
-        {
-            if statement.stop != None{
-                code_generator.file.writeln(format!(
-                    "if {} >= {} {{ break; }}",
-                    start_loop_variable_name, stop_loop_variable_name));
-            }
-        }
-
-        /* Start Execute Loop Statements */
-        {
-            code_generator.environments_stack.push_back(Environment {
-                scope: EnvironmentScope::ForLoop,
-                variables: HashMap::new(),
-                internal_variables: HashMap::new(),
-                stop_statements_execution: None,
-            });
-
-            if statement.variable != None{
-                code_generator.file.writeln(format!(
-                    "let mut variable_{}: i64 = {} as i64;",
-                    statement.variable.as_ref().unwrap().value,
-                    start_loop_variable_name));
+            else if target_type == TokenType::Char && source_type == TokenType::String{
+                format!(
+                    "match {}.chars().nth(0) {{Some(value) => value, None => panic!(\"{}\")}}",
+                    source,
+                    format!(
+                        "Engine Compiler: Convert Error -> can't convert from `String` to `Char`, line: {}:{}",
+                        line, pos))
             }
-
-            code_generator.insert_variable_into_environments_stack(
-                statement.variable.as_ref().unwrap().value.clone(),
-                Variable {
-                    variable_type: Some(TokenType::Int),
-                    name: Some(statement.variable.as_ref().unwrap().value.clone()),
-                    value: None,
-                    is_reasigned: false
-                });
-
-            generate_statements_node(
-                &mut code_generator, &mut statement.statements)?;
-
-            code_generator.environments_stack.pop_back();
-        }
-
-        /* Before Closing Loop */
-        {
-            if statement.step != None{
-                code_generator.file.writeln(format!(
-                    "{} += {};", start_loop_variable_name,
-                    step_loop_variable_name));
+            else if source_type == TokenType::String{
+                format!(
+                    "match {}.parse::<{}>() {{Ok(value) => value, Err(_) => panic!(\"{}\")}}",
+                    source, get_rust_type(&target_type).0,
+                    format!(
+                        "Engine Compiler: Convert Error -> can't convert from `String` to `{:?}`, line: {}:{}",
+                        target_type, line, pos))
             }
             else{
-                code_generator.file.writeln(format!(
-                    "{} += 1;", start_loop_variable_name));
-            }
-        }
-
-        code_generator.file.writeln(String::from("}"));
-    }
-
-    code_generator.file.writeln(String::from("}"));
-
-    return Ok(());
-}
-
-
-fn generate_continue_statement(
-    code_generator: &mut CodeGenerator
-) -> Result<(), String>{
-
-    let start_variable = code_generator.get_internal_variable(
-        &String::from("start_loop_variable"));
-
-    let step_variable = code_generator.get_internal_variable(
-        &String::from("step_loop_variable"));
-
-    if start_variable != None{
-
-        if step_variable == None{
-            code_generator.file.writeln(format!(
-                "{} += 1;",
-                start_variable.as_ref().unwrap()
-                    .back().as_ref().unwrap().name.as_ref().unwrap()));
-        }
-        else{
-            code_generator.file.writeln(format!(
-                "{} += {};",
-                start_variable.as_ref().unwrap()
-                    .back().as_ref().unwrap().name.as_ref().unwrap(),
-                step_variable.as_ref().unwrap()
-                    .back().as_ref().unwrap().name.as_ref().unwrap()));
-        }
-    }
-
-    code_generator.file.writeln(String::from("continue;"));
-
-    return Ok(());
-}
-
-
-fn generate_break_statement(
-    code_generator: &mut CodeGenerator
-) -> Result<(), String>{
-
-    code_generator.file.writeln(String::from("break;"));
-
-    return Ok(());
-}
-
-
-fn define_operation_node_variables(
-    code_generator: &mut CodeGenerator,
-    operation_node: &OperationNode
-) -> Result<(String, TokenType), String>{
-
-    let mut code_generator = code_generator;
+                return Err(format!(
+                    "Engine Compiler: Code Generation Error -> Can't convert `{:?}` to `{:?}`, line: {}:{}",
+                    source_type, target_type, line, pos));
+            };
 
-    match &operation_node.operator{
-        Some(_operator) => {
-            let mut left_variable_name = String::from("");
-            let mut left_token_type = TokenType::BadToken;
-
-            let mut right_variable_name = String::from("");
-            let mut right_token_type = TokenType::BadToken;
+            Ok(vec![format!("{} = {};", target, value)])
+        },
 
-            if operation_node.left != None{
-                let result = define_operation_node_variables(
-                    &mut code_generator,
-                    operation_node.left.as_ref().unwrap())?;
-                left_variable_name = result.0;
-                left_token_type = result.1;
-            }
-            if operation_node.right != None{
-                let result = define_operation_node_variables(
-                    &mut code_generator,
-                    operation_node.right.as_ref().unwrap())?;
-                right_variable_name = result.0;
-                right_token_type = result.1;
+        IrInstructionKind::Operation{operator, target, left, right} => {
+            if code_generator.get_type(target) == TokenType::String{
+                return Ok(vec![format!("{} = {}.clone() + &{};", target, left, right)]);
             }
 
-            if operation_node.left != None && operation_node.right == None{
-                return Ok((left_variable_name, left_token_type));
-            }
-            else if operation_node.right != None && operation_node.left == None{
-                return Ok((right_variable_name, right_token_type));
-            }
-
-            /* Define Input */
-            if _operator == &OperatorType::Convert{
-                /* Read Input */
-                let variable_name = format!(
-                    "temp{}", code_generator.generate_variable_name().clone());
-
-                code_generator.file.writeln(format!(
-                    "let mut {} = String::new();",
-                    variable_name));
-
-                code_generator.file.writeln(format!(
-                    "if io::stdin().read_line(&mut {}).is_err(){{",
-                    variable_name));
-                code_generator.file.writeln(format!(
-                    "panic!(\"{}\")",
-                    format!(
-                        "Engine Compiler: Compiler Error -> Failed to read input, line: {}:{}",
-                        operation_node.value.as_ref().unwrap().start_line,
-                        operation_node.value.as_ref().unwrap().start_pos)));
-                code_generator.file.writeln(format!("}}"));
-
-                code_generator.file.writeln(format!(
-                    "let mut {} = String::from({}.trim_end());",
-                    variable_name, variable_name));
-
-                /* Convert Type */
-                let convert_to_variable = format!(
-                    "temp{}", code_generator.generate_variable_name().clone());
-
-                code_generator.file.writeln(format!(
-                    "let mut {} = {}.parse::<{}>();",
-                    convert_to_variable, variable_name,
-                    if operation_node.value.as_ref().unwrap().token_type == TokenType::Bool{
-                        "bool"
-                    }
-                    else if operation_node.value.as_ref().unwrap().token_type == TokenType::Int{
-                        "i64"
-                    }
-                    else if operation_node.value.as_ref().unwrap().token_type == TokenType::Double{
-                        "f64"
+            /* Integers Wrap Around And Dividing By Zero Stops The Program Like In The VM */
+            if code_generator.get_type(left) == TokenType::Int{
+                if operator == &OperatorType::Div || operator == &OperatorType::Mod{
+                    return Ok(vec![
+                        format!("if {} == 0{{", right),
+                        format!(
+                            "panic!(\"Engine Compiler: Compiler Error -> Division by zero, line: {}:{}\");",
+                            line, pos),
+                        String::from("}"),
+                        format!(
+                            "{} = {}.{}({});", target, left,
+                            if operator == &OperatorType::Div {"wrapping_div"} else {"wrapping_rem"}, right),
+                    ]);
+                }
+                else if operator == &OperatorType::Plus || operator == &OperatorType::Minus || operator == &OperatorType::Mul{
+                    let function = if operator == &OperatorType::Plus{
+                        "wrapping_add"
                     }
-                    else if operation_node.value.as_ref().unwrap().token_type == TokenType::Char{
-                        "char"
+                    else if operator == &OperatorType::Minus{
+                        "wrapping_sub"
                     }
                     else{
-                        "String"
-                    }));
+                        "wrapping_mul"
+                    };
 
-                code_generator.file.writeln(format!("if {}.is_err(){{", convert_to_variable));
-                code_generator.file.writeln(format!(
-                    "panic!(\"{}\");",
-                    format!(
-                        "Engine Compiler: Convert Error -> {}, line: {}:{}",
-                        format!(
-                            "can't convert from `String` to `{:?}`",
-                            operation_node.value.as_ref().unwrap().token_type),
-                        operation_node.value.as_ref().unwrap().start_line,
-                        operation_node.value.as_ref().unwrap().start_pos)));
-                code_generator.file.writeln(format!("}}"));
-                code_generator.file.writeln(format!(
-                    "let mut {} = {}.unwrap();",
-                    convert_to_variable, convert_to_variable));
-
-                return Ok((
-                    convert_to_variable,
-                    operation_node.value.as_ref().unwrap().token_type.clone()));
-            }
-
-            if left_token_type == TokenType::DoubleNumber ||
-                right_token_type == TokenType::DoubleNumber
-            {
-                let variable_name = format!(
-                    "temp{}", code_generator.generate_variable_name().clone());
-
-                let operator_type = if
-                    _operator == &OperatorType::Plus || _operator == &OperatorType::Minus ||
-                    _operator == &OperatorType::Mul || _operator == &OperatorType::Div ||
-                    _operator == &OperatorType::Mod {"f64"} else {"bool"};
-
-                if left_token_type == TokenType::IntNumber{
-                    code_generator.file.writeln(format!(
-                        "let mut {}: {} = {} as f64 {} {} as f64;",
-                        variable_name,
-                        operator_type,
-                        left_variable_name,
-                        operation_node.value.as_ref().unwrap().value.clone(),
-                        right_variable_name,
-                    ));
-                }
-                else if right_token_type == TokenType::IntNumber{
-                    code_generator.file.writeln(format!(
-                        "let mut {}: {} = {} as f64 {} {} as f64;",
-                        variable_name,
-                        operator_type,
-                        left_variable_name,
-                        operation_node.value.as_ref().unwrap().value.clone(),
-                        right_variable_name,
-                    ));
-                }
-                else{
-                    code_generator.file.writeln(format!(
-                        "let mut {}: {} = {} as f64 {} {} as f64;",
-                        variable_name,
-                        operator_type,
-                        left_variable_name,
-                        operation_node.value.as_ref().unwrap().value.clone(),
-                        right_variable_name,
-                    ));
+                    return Ok(vec![format!("{} = {}.{}({});", target, left, function, right)]);
                 }
+            }
 
-                if _operator == &OperatorType::Plus || _operator == &OperatorType::Minus ||
-                    _operator == &OperatorType::Mul || _operator == &OperatorType::Div ||
-                    _operator == &OperatorType::Mod{
+            Ok(vec![format!(
+                "{} = {} {} {};", target, left, get_operator_symbol(operator), right)])
+        },
 
-                    return Ok((variable_name, TokenType::DoubleNumber));
+        IrInstructionKind::Call{function, target, arguments} => {
+            let mut arguments_values = vec![];
+            for argument in arguments{
+                let argument_type = code_generator.get_type(argument);
+                arguments_values.push(if argument_type == TokenType::Bool{
+                    format!("engine_builtins::BuiltinValue::Bool({})", argument)
                 }
-                return Ok((variable_name, TokenType::Bool));
-            }
-            else if left_token_type == TokenType::IntNumber || right_token_type == TokenType::IntNumber{
-                let variable_name = format!(
-                    "temp{}", code_generator.generate_variable_name().clone());
-
-                if _operator == &OperatorType::Plus || _operator == &OperatorType::Minus ||
-                    _operator == &OperatorType::Mul || _operator == &OperatorType::Mod{
-
-                    code_generator.file.writeln(format!(
-                        "let mut {}: i64 = {} as i64 {} {} as i64;",
-                        variable_name,
-                        left_variable_name,
-                        operation_node.value.as_ref().unwrap().value.clone(),
-                        right_variable_name,
-                    ));
-                    return Ok((variable_name, TokenType::IntNumber))
+                else if argument_type == TokenType::Int{
+                    format!("engine_builtins::BuiltinValue::Int({})", argument)
                 }
-                else if _operator == &OperatorType::Div{
-                    code_generator.file.writeln(format!(
-                        "let mut {}: f64 = {} as f64 {} {} as f64;",
-                        variable_name,
-                        left_variable_name,
-                        operation_node.value.as_ref().unwrap().value.clone(),
-                        right_variable_name,
-                    ));
-                    return Ok((variable_name, TokenType::DoubleNumber));
+                else if argument_type == TokenType::Double{
+                    format!("engine_builtins::BuiltinValue::Double({})", argument)
                 }
-                else{
-                    code_generator.file.writeln(format!(
-                        "let mut {}: bool = {} {} {};",
-                        variable_name,
-                        left_variable_name,
-                        operation_node.value.as_ref().unwrap().value.clone(),
-                        right_variable_name,
-                    ));
-                    return Ok((variable_name, TokenType::Bool));
+                else if argument_type == TokenType::Char{
+                    format!("engine_builtins::BuiltinValue::Char({})", argument)
                 }
-            }
-            else if left_token_type == TokenType::StringSequence || right_token_type == TokenType::StringSequence{
-                let variable_name = format!(
-                    "temp{}", code_generator.generate_variable_name().clone());
-
-                if left_token_type == TokenType::Character{
-                    code_generator.file.writeln(format!(
-                        "let mut {}: String = String::from({}) + &{}.clone();",
-                        variable_name,
-                        left_variable_name,
-                        right_variable_name,
-                    ));
-                }
-                else if right_token_type == TokenType::Character{
-                    code_generator.file.writeln(format!(
-                        "let mut {}: String = {}.clone() + &String::from({});",
-                        variable_name,
-                        left_variable_name,
-                        right_variable_name,
-                    ));
+                else if argument_type == TokenType::List{
+                    format!("engine_builtins::BuiltinValue::List({}.clone())", argument)
                 }
                 else{
-                    code_generator.file.write(String::from(format!(
-                        "\tlet mut {}: String = {}.clone() + &{}.clone();\n",
-                        variable_name,
-                        left_variable_name,
-                        right_variable_name,
-                    )));
-                }
-                return Ok((variable_name, TokenType::StringSequence));
+                    format!("engine_builtins::BuiltinValue::String({}.clone())", argument)
+                });
             }
-            else if left_token_type == TokenType::Character || right_token_type == TokenType::Character{
-                let variable_name = format!(
-                    "temp{}", code_generator.generate_variable_name().clone());
-
-                code_generator.file.writeln(format!(
-                    "let mut {}: String = String::from({}) + &String::from({});",
-                    variable_name,
-                    left_variable_name,
-                    right_variable_name,
-                ));
-
-                return Ok((variable_name, TokenType::StringSequence));
+
+            let target_type = code_generator.get_type(target);
+            let into_function = if target_type == TokenType::Bool{
+                "into_bool"
             }
-            else if left_token_type == TokenType::Bool || left_token_type == TokenType::True ||
-                left_token_type == TokenType::False || right_token_type == TokenType::Bool ||
-                right_token_type == TokenType::True || right_token_type == TokenType::False{
-
-                let variable_name = format!(
-                    "temp{}", code_generator.generate_variable_name().clone());
-
-                code_generator.file.writeln(format!(
-                    "let mut {}: bool = {} {} {};",
-                    variable_name,
-                    left_variable_name,
-                    operation_node.value.as_ref().unwrap().value.clone(),
-                    right_variable_name,
-                ));
-
-                return Ok((variable_name, TokenType::Bool));
+            else if target_type == TokenType::Int{
+                "into_int"
             }
-            return Err(format!(
-                "Engine Compiler: Code Generation Error -> {}, line: {}:{}",
-                format!(
-                    "Unknown Operation {} for types {:?} and {:?}",
-                    operation_node.value.as_ref().unwrap().value,
-                    left_token_type,
-                    right_token_type),
-                operation_node.value.as_ref().unwrap().start_line,
-                operation_node.value.as_ref().unwrap().start_pos));
+            else if target_type == TokenType::Double{
+                "into_double"
+            }
+            else if target_type == TokenType::Char{
+                "into_char"
+            }
+            else if target_type == TokenType::List{
+                "into_list"
+            }
+            else{
+                "into_string"
+            };
+
+            Ok(vec![format!(
+                "{} = engine_builtins::expect_builtin(\"{}\", vec![{}], {}, {}).{}();",
+                target, function, arguments_values.join(", "), line, pos, into_function)])
         },
-        None => {
-            let token = operation_node.value.as_ref().unwrap();
-            let variable_name = format!(
-                "temp{}", code_generator.generate_variable_name().clone());
-
-            match token.token_type{
-                TokenType::IntNumber => {
-                    code_generator.file.writeln(format!(
-                        "let mut {}: i64 = {};", variable_name, token.value));
-                },
-                TokenType::DoubleNumber => {
-                    code_generator.file.writeln(format!(
-                        "let mut {}: f64 = {};", variable_name, token.value));
-                },
-                TokenType::True => {
-                    code_generator.file.writeln(format!(
-                        "let mut {}: bool = true;", variable_name));
-                },
-                TokenType::False => {
-                    code_generator.file.writeln(format!(
-                        "let mut {}: bool = false;", variable_name));
-                },
-                TokenType::Character => {
-                    let mut value = String::from("");
-                    for ch in token.value.chars(){
-                        if String::from(ch) == "\n"{
-                            value += &String::from("\\n");
-                        }
-                        else if String::from(ch) == "'"{
-                            value += &String::from("\\'");
-                        }
-                        else if String::from(ch) == "\""{
-                            value += &String::from("\"");
-                        }
-                        else if String::from(ch) == "\t"{
-                            value += &String::from("\\t");
-                        }
-                        else if String::from(ch) == "\\"{
-                            value += &String::from("\\\\");
-                        }
-                        else{
-                            value += &String::from(ch);
-                        }
-                    }
-                    code_generator.file.writeln(format!(
-                        "let mut {}: char = '{}';",
-                        variable_name, value));
-                },
-                TokenType::StringSequence => {
-                    let mut value = String::from("");
-                    for ch in token.value.chars(){
-                        if String::from(ch) == "'"{
-                            value += &String::from("'");
-                        }
-                        else if String::from(ch) == "\""{
-                            value += &String::from("\\\"");
-                        }
-                        else if String::from(ch) == "\t"{
-                            value += &String::from("\\t");
-                        }
-                        else if String::from(ch) == "\\"{
-                            value += &String::from("\\\\");
-                        }
-                        else{
-                            value += &String::from(ch);
-                        }
-                    }
-                    code_generator.file.writeln(format!(
-                        "let mut {}: String = String::from(\"{}\");",
-                        variable_name, value));
-                },
-                TokenType::Variable => {
-                    let variable_type = code_generator.get_variable_type(&token.value)?;
-
-                    if variable_type == TokenType::Int{
-                        return Ok((
-                            format!("variable_{}", token.value.clone()),
-                            TokenType::IntNumber));
-                    }
-                    else if variable_type == TokenType::Double{
-                        return Ok((
-                            format!("variable_{}", token.value.clone()),
-                            TokenType::DoubleNumber));
-                    }
-                    else if variable_type == TokenType::String{
-                        return Ok((
-                            format!("variable_{}", token.value.clone()),
-                            TokenType::StringSequence));
-                    }
-                    else if variable_type == TokenType::Char{
-                        return Ok((
-                            format!("variable_{}", token.value.clone()),
-                            TokenType::Character));
-                    }
-                    return Ok((
-                        format!("variable_{}", token.value.clone()),
-                        TokenType::Bool));
-                }
-                _ => return Err(format!(
-                    "Engine Compiler: File Error -> {}, line {}:{}",
-                    "Can't Generate File",
-                    token.start_line, token.start_pos))
+
+        IrInstructionKind::Input{target} => Ok(vec![
+            format!("{} = String::new();", target),
+            format!("if io::stdin().read_line(&mut {}).is_err(){{", target),
+            format!(
+                "panic!(\"Engine Compiler: Compiler Error -> Failed to read input, line: {}:{}\");",
+                line, pos),
+            String::from("}"),
+            format!("{} = String::from({}.trim_end());", target, target),
+        ]),
+
+        /* Booleans And Lists Are Printed Like In The Interpreter And The VM */
+        IrInstructionKind::Print{source, is_stderr} => {
+            let print_macro = if *is_stderr {"eprint"} else {"print"};
+
+            let value = if code_generator.get_type(source) == TokenType::Bool{
+                format!("if {} {{\"True\"}} else {{\"False\"}}", source)
+            }
+            else if code_generator.get_type(source) == TokenType::List{
+                format!("format!(\"{{:?}}\", {})", source)
             }
+            else{
+                source.clone()
+            };
+
+            Ok(vec![
+                format!("{}!(\"{{}}\", {});", print_macro, value),
+                String::from("if io::stdout().flush().is_err(){"),
+                String::from("panic!(\"Engine Compiler: Compiler Error -> Error in printing to console\");"),
+                String::from("}"),
+            ])
+        },
+    };
+}
 
-            return Ok((variable_name, token.token_type.clone()));
+
+fn get_rust_literal(variable_type: &TokenType, value: &String) -> String{
+    if variable_type == &TokenType::Bool{
+        return String::from(if value == "True" {"true"} else {"false"});
+    }
+    else if variable_type == &TokenType::Double{
+        let number = value.parse::<f64>().unwrap_or(0.0);
+        if number.is_infinite(){
+            return String::from("f64::INFINITY");
         }
+        return format!("{:?}", number);
+    }
+    else if variable_type == &TokenType::Char{
+        return format!("{:?}", value.chars().nth(0).unwrap_or('\0'));
+    }
+    else if variable_type == &TokenType::String{
+        return format!("String::from({:?})", value);
     }
+    return value.clone();
 }
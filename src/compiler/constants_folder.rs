@@ -0,0 +1,627 @@
+use crate::constants::Mode;
+use crate::builtins::{
+    is_pure_function,
+    call_builtin,
+    get_builtin_value,
+    construct_builtin_value
+};
+use crate::tokens::{
+    Token,
+    TokenType
+};
+use crate::environments::{
+    Value,
+    ValueType
+};
+use crate::syntax_tree::{
+    StatementsNode,
+    StatementNode,
+    StatementType,
+    OperationNode,
+    OperatorType
+};
+
+use super::constants_inliner::get_value_token;
+
+
+/*
+* Operations Made Of Literals Are Computed At Compile Time And Replaced By
+* Their Result, Then The Literals Assigned To Variables Are Converted Into The
+* Variable Type. Runs After The Constants Are Inlined, So Their Uses Are Folded Too.
+*/
+pub fn fold_constants(
+    mode: &Mode, syntax_tree: StatementsNode
+) -> Result<StatementsNode, String>{
+
+    let mut syntax_tree = syntax_tree;
+
+    fold_statements(mode, &mut syntax_tree)?;
+
+    return Ok(syntax_tree);
+}
+
+
+fn fold_statements(mode: &Mode, statements: &mut StatementsNode) -> Result<(), String>{
+    for statement in &mut statements.statements{
+        fold_statement(mode, statement)?;
+    }
+
+    return Ok(());
+}
+
+
+fn fold_statement(mode: &Mode, statement: &mut StatementNode) -> Result<(), String>{
+    if statement.statement_type == Some(StatementType::DefineBool){
+        fold_assigned_expression(
+            mode, &mut statement.define_bool_statement.as_mut().unwrap().left, &TokenType::Bool)?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineInt){
+        fold_assigned_expression(
+            mode, &mut statement.define_int_statement.as_mut().unwrap().left, &TokenType::Int)?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineDouble){
+        fold_assigned_expression(
+            mode, &mut statement.define_double_statement.as_mut().unwrap().left, &TokenType::Double)?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineChar){
+        fold_assigned_expression(
+            mode, &mut statement.define_char_statement.as_mut().unwrap().left, &TokenType::Char)?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineString){
+        fold_assigned_expression(
+            mode, &mut statement.define_string_statement.as_mut().unwrap().left, &TokenType::String)?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineVariable){
+        let node = statement.define_variable_statement.as_mut().unwrap();
+        fold_assigned_expression(mode, &mut node.left, &node.variable_type.clone().unwrap())?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineVar){
+        let node = statement.define_var_statement.as_mut().unwrap();
+        fold_assigned_expression(mode, &mut node.left, &node.variable_type.clone().unwrap())?;
+    }
+
+    else if statement.statement_type == Some(StatementType::Print){
+        let define_print_node = statement.define_print_statement.as_mut().unwrap();
+        for expression in &mut define_print_node.expressions{
+            fold_operation_node(mode, expression)?;
+        }
+        fold_expression(mode, &mut define_print_node.separator)?;
+        fold_expression(mode, &mut define_print_node.end)?;
+    }
+    else if statement.statement_type == Some(StatementType::Call){
+        fold_expression(mode, &mut statement.define_call_statement.as_mut().unwrap().expression)?;
+    }
+
+    else if statement.statement_type == Some(StatementType::DefineIf){
+        let define_if_statement = statement.define_if_statement.as_mut().unwrap();
+
+        if define_if_statement.define_if_node != None{
+            let define_if_node = define_if_statement.define_if_node.as_mut().unwrap();
+            fold_expression(mode, &mut define_if_node.condition)?;
+            fold_statements(mode, &mut define_if_node.statements)?;
+        }
+
+        for define_if_else_node in &mut define_if_statement.define_if_else_nodes{
+            fold_expression(mode, &mut define_if_else_node.condition)?;
+            fold_statements(mode, &mut define_if_else_node.statements)?;
+        }
+
+        if define_if_statement.define_else_node != None{
+            fold_statements(
+                mode, &mut define_if_statement.define_else_node.as_mut().unwrap().statements)?;
+        }
+    }
+
+    else if statement.statement_type == Some(StatementType::DefineForLoop){
+        let for_loop = statement.define_for_loop_statement.as_mut().unwrap();
+        fold_assigned_expression(mode, &mut for_loop.start, &TokenType::Int)?;
+        fold_assigned_expression(mode, &mut for_loop.stop, &TokenType::Int)?;
+        fold_assigned_expression(mode, &mut for_loop.step, &TokenType::Int)?;
+        fold_statements(mode, &mut for_loop.statements)?;
+    }
+
+    return Ok(());
+}
+
+
+fn fold_expression(mode: &Mode, expression: &mut Option<OperationNode>) -> Result<(), String>{
+    if expression != &None{
+        fold_operation_node(mode, expression.as_mut().unwrap())?;
+    }
+
+    return Ok(());
+}
+
+
+/* A Literal Assigned To A Variable Is Converted Like When It Is Assigned */
+fn fold_assigned_expression(
+    mode: &Mode, expression: &mut Option<OperationNode>, variable_type: &TokenType
+) -> Result<(), String>{
+
+    if expression == &None{
+        return Ok(());
+    }
+    let operation_node = expression.as_mut().unwrap();
+
+    fold_operation_node(mode, operation_node)?;
+
+    let value = get_literal_value(operation_node);
+    if value == None{
+        return Ok(());
+    }
+    let token = operation_node.value.clone().unwrap();
+
+    let converted_value = convert_assigned_value(mode, &value.unwrap(), variable_type, &token)?;
+    if converted_value == None{
+        return Ok(());
+    }
+
+    operation_node.value = Some(get_value_token(mode, &token, &converted_value.unwrap())?);
+
+    return Ok(());
+}
+
+
+/* None When The Value Already Has The Variable Type, Or Its Conversion Is Left To Run */
+fn convert_assigned_value(
+    mode: &Mode, value: &Value, variable_type: &TokenType, token: &Token
+) -> Result<Option<Value>, String>{
+
+    let mut converted_value = Value::new();
+
+    if variable_type == &TokenType::Int && value.value_type == Some(ValueType::Double){
+        if !value.double.unwrap().is_finite(){
+            return Ok(None);
+        }
+        converted_value.value_type = Some(ValueType::Integer);
+        converted_value.int = Some(value.double.unwrap() as i64);
+    }
+    else if variable_type == &TokenType::Double && value.value_type == Some(ValueType::Integer){
+        converted_value.value_type = Some(ValueType::Double);
+        converted_value.double = Some(value.int.unwrap() as f64);
+    }
+    else if variable_type == &TokenType::String && value.value_type == Some(ValueType::Character){
+        converted_value.value_type = Some(ValueType::String);
+        converted_value.string = Some(String::from(value.character.unwrap()));
+    }
+    else if variable_type == &TokenType::Char && value.value_type == Some(ValueType::String){
+        let character = value.string.as_ref().unwrap().chars().nth(0);
+        if character == None{
+            return Err(analyze_error(
+                mode, String::from("Can't convert an empty `String` to `Char`"), token));
+        }
+        converted_value.value_type = Some(ValueType::Character);
+        converted_value.character = character;
+    }
+    else{
+        return Ok(None);
+    }
+
+    return Ok(Some(converted_value));
+}
+
+
+/* The Value Of A `const` Definition, Converted Into The Constant Type */
+pub fn evaluate_constant(mode: &Mode, statement: &StatementNode) -> Result<Value, String>{
+    let (expression, variable_type) = if statement.statement_type == Some(StatementType::DefineBool){
+        (&statement.define_bool_statement.as_ref().unwrap().left, TokenType::Bool)
+    }
+    else if statement.statement_type == Some(StatementType::DefineInt){
+        (&statement.define_int_statement.as_ref().unwrap().left, TokenType::Int)
+    }
+    else if statement.statement_type == Some(StatementType::DefineDouble){
+        (&statement.define_double_statement.as_ref().unwrap().left, TokenType::Double)
+    }
+    else if statement.statement_type == Some(StatementType::DefineChar){
+        (&statement.define_char_statement.as_ref().unwrap().left, TokenType::Char)
+    }
+    else if statement.statement_type == Some(StatementType::DefineString){
+        (&statement.define_string_statement.as_ref().unwrap().left, TokenType::String)
+    }
+    else if statement.statement_type == Some(StatementType::DefineVar){
+        let node = statement.define_var_statement.as_ref().unwrap();
+        (&node.left, node.variable_type.clone().unwrap())
+    }
+    else{
+        let node = statement.define_variable_statement.as_ref().unwrap();
+        (&node.left, node.variable_type.clone().unwrap())
+    };
+
+    let name_token = statement.get_assigned_token().unwrap();
+
+    let value = if expression == &None {None} else {
+        evaluate_expression(mode, expression.as_ref().unwrap())?
+    };
+    if value == None{
+        return Err(format!(
+            "{}: Syntax Error -> Constant `{}` can't be evaluated, line {}:{}.",
+            mode, name_token.value, name_token.start_line, name_token.start_pos));
+    }
+    let value = value.unwrap();
+
+    let converted_value = convert_assigned_value(mode, &value, &variable_type, name_token)?;
+
+    return Ok(converted_value.unwrap_or(value));
+}
+
+
+/*
+* Evaluates An Expression Made Of Literals And Pure Function Calls, Like A
+* Constant Initializer Or A Condition. None When A Part Of It Is Only Known
+* When The Program Runs.
+*/
+pub fn evaluate_expression(
+    mode: &Mode, operation_node: &OperationNode
+) -> Result<Option<Value>, String>{
+
+    if operation_node.operator == None{
+        return Ok(get_literal_value(operation_node));
+    }
+
+    let operator = operation_node.operator.as_ref().unwrap();
+
+    if operator == &OperatorType::Convert{
+        return Ok(None);
+    }
+    else if operator == &OperatorType::Call{
+        let function_token = operation_node.value.as_ref().unwrap();
+        if !is_pure_function(&function_token.value){
+            return Ok(None);
+        }
+
+        let mut arguments = vec![];
+        for argument in &operation_node.arguments{
+            let value = evaluate_expression(mode, argument)?;
+            if value == None{
+                return Ok(None);
+            }
+            arguments.push(get_builtin_value(&value.unwrap()));
+        }
+
+        let result = call_builtin(&function_token.value, arguments);
+        if result.is_err(){
+            return Err(analyze_error(mode, result.unwrap_err(), function_token));
+        }
+
+        return Ok(Some(construct_builtin_value(result.unwrap())));
+    }
+
+    /* A Grouped Expression Is Its Only Operand */
+    if operation_node.left == None || operation_node.right == None{
+        let operand = if operation_node.left != None {&operation_node.left} else {&operation_node.right};
+        if operand == &None{
+            return Ok(None);
+        }
+        return evaluate_expression(mode, operand.as_ref().unwrap());
+    }
+
+    let left = evaluate_expression(mode, operation_node.left.as_ref().unwrap())?;
+    let right = evaluate_expression(mode, operation_node.right.as_ref().unwrap())?;
+    if left == None || right == None{
+        return Ok(None);
+    }
+
+    let value = evaluate_operation(operator, &left.unwrap(), &right.unwrap());
+    if value.is_err(){
+        return Err(analyze_error(
+            mode, format!("{} in constant expression", value.unwrap_err()),
+            operation_node.value.as_ref().unwrap()));
+    }
+
+    return Ok(value.unwrap());
+}
+
+
+/*
+* Folds The Operands First, Then The Operation When Both Of Them Are Literals.
+* Calls And Inputs Are Kept, Their Arguments Are Folded.
+*/
+fn fold_operation_node(mode: &Mode, operation_node: &mut OperationNode) -> Result<(), String>{
+    if operation_node.left != None{
+        fold_operation_node(mode, operation_node.left.as_mut().unwrap())?;
+    }
+    if operation_node.right != None{
+        fold_operation_node(mode, operation_node.right.as_mut().unwrap())?;
+    }
+    for argument in &mut operation_node.arguments{
+        fold_operation_node(mode, argument)?;
+    }
+
+    if
+        operation_node.operator == None ||
+        operation_node.operator == Some(OperatorType::Call) ||
+        operation_node.operator == Some(OperatorType::Convert)
+    {
+        return Ok(());
+    }
+
+    /* A Grouped Expression Is Its Only Operand */
+    if operation_node.left != None && operation_node.right == None{
+        let left = *operation_node.left.take().unwrap();
+        *operation_node = left;
+        return Ok(());
+    }
+    else if operation_node.right != None && operation_node.left == None{
+        let right = *operation_node.right.take().unwrap();
+        *operation_node = right;
+        return Ok(());
+    }
+
+    if operation_node.left == None{
+        return Ok(());
+    }
+
+    let left = get_literal_value(operation_node.left.as_ref().unwrap());
+    let right = get_literal_value(operation_node.right.as_ref().unwrap());
+    if left == None || right == None{
+        return Ok(());
+    }
+
+    let value = evaluate_operation(
+        operation_node.operator.as_ref().unwrap(), &left.unwrap(), &right.unwrap());
+    if value.is_err(){
+        return Err(analyze_error(
+            mode, format!("{} in constant expression", value.unwrap_err()),
+            operation_node.value.as_ref().unwrap()));
+    }
+    let value = value.unwrap();
+
+    /* Operations Without A Value Known By Every Backend Are Left To Run */
+    if value == None{
+        return Ok(());
+    }
+    let value = value.unwrap();
+    if value.value_type == Some(ValueType::Double) && !value.double.unwrap().is_finite(){
+        return Ok(());
+    }
+
+    let first_token = operation_node.get_first_token().unwrap().clone();
+
+    let mut literal = OperationNode::new();
+    literal.value = Some(get_value_token(mode, &first_token, &value)?);
+    literal.result_type = operation_node.result_type.clone();
+
+    *operation_node = literal;
+
+    return Ok(());
+}
+
+
+fn get_literal_value(operation_node: &OperationNode) -> Option<Value>{
+    if operation_node.operator != None || operation_node.value == None{
+        return None;
+    }
+    let token = operation_node.value.as_ref().unwrap();
+
+    let mut value = Value::new();
+
+    if token.token_type == TokenType::True || token.token_type == TokenType::False{
+        value.value_type = Some(ValueType::Boolean);
+        value.boolean = Some(token.token_type == TokenType::True);
+    }
+    else if token.token_type == TokenType::IntNumber{
+        let int_value = token.value.parse::<i64>();
+        if int_value.is_err(){
+            return None;
+        }
+        value.value_type = Some(ValueType::Integer);
+        value.int = Some(int_value.unwrap());
+    }
+    else if token.token_type == TokenType::DoubleNumber{
+        let double_value = token.value.parse::<f64>();
+        if double_value.is_err(){
+            return None;
+        }
+        value.value_type = Some(ValueType::Double);
+        value.double = Some(double_value.unwrap());
+    }
+    else if token.token_type == TokenType::Character{
+        value.value_type = Some(ValueType::Character);
+        value.character = token.value.chars().nth(0);
+    }
+    else if token.token_type == TokenType::StringSequence{
+        value.value_type = Some(ValueType::String);
+        value.string = Some(token.value.clone());
+    }
+    else{
+        return None;
+    }
+
+    return Some(value);
+}
+
+
+fn get_text_value(value: &Value) -> Option<String>{
+    if value.value_type == Some(ValueType::Character){
+        return Some(String::from(value.character.unwrap()));
+    }
+    else if value.value_type == Some(ValueType::String){
+        return value.string.clone();
+    }
+
+    return None;
+}
+
+
+/*
+* The Same Operations As The Backends, Shared By Every Constant Folding. Integers
+* Wrapping Around And An Int Remainder By Zero Are Errors, Doubles Give `inf` And
+* `NaN` Like At Runtime. None When The Operation Has No Value Known By Every Backend.
+* Also Used By The Debugger Expressions.
+*/
+pub fn evaluate_operation(
+    operator: &OperatorType, left: &Value, right: &Value
+) -> Result<Option<Value>, String>{
+
+    let mut value = Value::new();
+
+    /* Lists Have No Operations, They Only Reach Here From The Debugger Expressions */
+    if left.value_type == Some(ValueType::List) || right.value_type == Some(ValueType::List){
+        return Ok(None);
+    }
+
+    /* String Concatenation */
+    let left_text = get_text_value(left);
+    let right_text = get_text_value(right);
+    if left_text != None || right_text != None{
+        if operator != &OperatorType::Plus || left_text == None || right_text == None{
+            return Ok(None);
+        }
+
+        value.value_type = Some(ValueType::String);
+        value.string = Some(left_text.unwrap() + &right_text.unwrap());
+
+        return Ok(Some(value));
+    }
+
+    if left.value_type == Some(ValueType::Boolean) || right.value_type == Some(ValueType::Boolean){
+        if left.value_type != right.value_type{
+            return Ok(None);
+        }
+
+        let left = left.boolean.unwrap();
+        let right = right.boolean.unwrap();
+
+        value.value_type = Some(ValueType::Boolean);
+        value.boolean = Some(if operator == &OperatorType::Equal{
+            left == right
+        }
+        else if operator == &OperatorType::NotEqual{
+            left != right
+        }
+        else if operator == &OperatorType::And{
+            left && right
+        }
+        else if operator == &OperatorType::Or{
+            left || right
+        }
+        else{
+            return Ok(None);
+        });
+
+        return Ok(Some(value));
+    }
+
+    let is_arithmetic =
+        operator == &OperatorType::Plus || operator == &OperatorType::Minus ||
+        operator == &OperatorType::Mul || operator == &OperatorType::Div ||
+        operator == &OperatorType::Mod;
+
+    /* An Int Division Gives A Double */
+    if
+        left.value_type == Some(ValueType::Double) || right.value_type == Some(ValueType::Double) ||
+        operator == &OperatorType::Div
+    {
+        let left = if left.double != None {left.double.unwrap()} else {left.int.unwrap() as f64};
+        let right = if right.double != None {right.double.unwrap()} else {right.int.unwrap() as f64};
+
+        if is_arithmetic{
+            value.value_type = Some(ValueType::Double);
+            value.double = Some(if operator == &OperatorType::Plus{
+                left + right
+            }
+            else if operator == &OperatorType::Minus{
+                left - right
+            }
+            else if operator == &OperatorType::Mul{
+                left * right
+            }
+            else if operator == &OperatorType::Div{
+                left / right
+            }
+            else{
+                left % right
+            });
+        }
+        else{
+            value.value_type = Some(ValueType::Boolean);
+            value.boolean = compare(operator, left.partial_cmp(&right));
+            if value.boolean == None{
+                return Ok(None);
+            }
+        }
+
+        return Ok(Some(value));
+    }
+
+    let left = left.int.unwrap();
+    let right = right.int.unwrap();
+
+    if operator == &OperatorType::Mod && right == 0{
+        return Err(String::from("Division by zero"));
+    }
+
+    if is_arithmetic{
+        let result = if operator == &OperatorType::Plus{
+            left.checked_add(right)
+        }
+        else if operator == &OperatorType::Minus{
+            left.checked_sub(right)
+        }
+        else if operator == &OperatorType::Mul{
+            left.checked_mul(right)
+        }
+        else{
+            left.checked_rem(right)
+        };
+
+        if result == None{
+            return Err(String::from("Integer overflow"));
+        }
+
+        value.value_type = Some(ValueType::Integer);
+        value.int = result;
+    }
+    else{
+        value.value_type = Some(ValueType::Boolean);
+        value.boolean = compare(operator, Some(left.cmp(&right)));
+        if value.boolean == None{
+            return Ok(None);
+        }
+    }
+
+    return Ok(Some(value));
+}
+
+
+/* None When The Operator Is Not A Comparison, An Unordered NaN Is Only Not Equal */
+fn compare(operator: &OperatorType, ordering: Option<std::cmp::Ordering>) -> Option<bool>{
+    let is_comparison =
+        operator == &OperatorType::Equal || operator == &OperatorType::NotEqual ||
+        operator == &OperatorType::GreaterThan || operator == &OperatorType::GreaterThanOrEqual ||
+        operator == &OperatorType::LessThan || operator == &OperatorType::LessThanOrEqual;
+    if !is_comparison{
+        return None;
+    }
+
+    if ordering == None{
+        return Some(operator == &OperatorType::NotEqual);
+    }
+    let ordering = ordering.unwrap();
+
+    return Some(if operator == &OperatorType::Equal{
+        ordering.is_eq()
+    }
+    else if operator == &OperatorType::NotEqual{
+        ordering.is_ne()
+    }
+    else if operator == &OperatorType::GreaterThan{
+        ordering.is_gt()
+    }
+    else if operator == &OperatorType::GreaterThanOrEqual{
+        ordering.is_ge()
+    }
+    else if operator == &OperatorType::LessThan{
+        ordering.is_lt()
+    }
+    else{
+        ordering.is_le()
+    });
+}
+
+
+fn analyze_error(mode: &Mode, message: String, token: &Token) -> String{
+    return format!(
+        "{}: Analyze Error -> {}, line {}:{}.",
+        mode, message, token.start_line, token.start_pos);
+}
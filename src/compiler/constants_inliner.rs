@@ -0,0 +1,251 @@
+use std::collections::{
+    HashMap,
+    HashSet
+};
+
+use crate::constants::Mode;
+use crate::tokens::{
+    Token,
+    TokenType
+};
+use crate::environments::{
+    Value,
+    ValueType
+};
+use crate::syntax_tree::{
+    StatementsNode,
+    StatementNode,
+    StatementType,
+    OperationNode,
+};
+
+use super::constants_folder::{
+    evaluate_constant,
+    evaluate_expression
+};
+
+
+/*
+* Constants Are Evaluated At Compile Time, Their Uses Are Replaced By Literals
+* And Their Definitions Are Removed, Conditions Made Of Literals Are Evaluated
+* Too So The Generators Can Drop The Branches That Never Run.
+*/
+pub fn inline_constants(
+    mode: &Mode, syntax_tree: StatementsNode
+) -> Result<StatementsNode, String>{
+
+    let mut constants: HashMap<String, Token> = HashMap::new();
+    let mut inlined_syntax_tree = StatementsNode::new();
+
+    for statement in syntax_tree.statements{
+        let mut statement = statement;
+        inline_statement(mode, &mut statement, &constants);
+
+        if !statement.is_constant{
+            inlined_syntax_tree.statements.push_back(statement);
+            continue;
+        }
+
+        let name_token = statement.get_assigned_token().unwrap().clone();
+
+        let value = evaluate_constant(mode, &statement)?;
+
+        let token = get_value_token(mode, &name_token, &value)?;
+        constants.insert(name_token.value.clone(), token);
+    }
+
+    return Ok(inlined_syntax_tree);
+}
+
+
+fn inline_statements(
+    mode: &Mode, statements: &mut StatementsNode, constants: &HashMap<String, Token>
+){
+    for statement in &mut statements.statements{
+        inline_statement(mode, statement, constants);
+    }
+}
+
+
+fn inline_statement(
+    mode: &Mode, statement: &mut StatementNode, constants: &HashMap<String, Token>
+){
+    if statement.statement_type == Some(StatementType::DefineBool){
+        inline_expression(&mut statement.define_bool_statement.as_mut().unwrap().left, constants);
+    }
+    else if statement.statement_type == Some(StatementType::DefineInt){
+        inline_expression(&mut statement.define_int_statement.as_mut().unwrap().left, constants);
+    }
+    else if statement.statement_type == Some(StatementType::DefineDouble){
+        inline_expression(&mut statement.define_double_statement.as_mut().unwrap().left, constants);
+    }
+    else if statement.statement_type == Some(StatementType::DefineChar){
+        inline_expression(&mut statement.define_char_statement.as_mut().unwrap().left, constants);
+    }
+    else if statement.statement_type == Some(StatementType::DefineString){
+        inline_expression(&mut statement.define_string_statement.as_mut().unwrap().left, constants);
+    }
+    else if statement.statement_type == Some(StatementType::DefineVariable){
+        inline_expression(&mut statement.define_variable_statement.as_mut().unwrap().left, constants);
+    }
+    else if statement.statement_type == Some(StatementType::DefineVar){
+        inline_expression(&mut statement.define_var_statement.as_mut().unwrap().left, constants);
+    }
+
+    else if statement.statement_type == Some(StatementType::Print){
+        let define_print_node = statement.define_print_statement.as_mut().unwrap();
+        for expression in &mut define_print_node.expressions{
+            inline_operation_node(expression, constants);
+        }
+        inline_expression(&mut define_print_node.separator, constants);
+        inline_expression(&mut define_print_node.end, constants);
+    }
+    else if statement.statement_type == Some(StatementType::Call){
+        inline_expression(&mut statement.define_call_statement.as_mut().unwrap().expression, constants);
+    }
+
+    else if statement.statement_type == Some(StatementType::DefineIf){
+        let define_if_statement = statement.define_if_statement.as_mut().unwrap();
+
+        if define_if_statement.define_if_node != None{
+            let define_if_node = define_if_statement.define_if_node.as_mut().unwrap();
+            inline_expression(&mut define_if_node.condition, constants);
+            evaluate_condition(mode, &mut define_if_node.condition);
+            inline_statements(mode, &mut define_if_node.statements, constants);
+        }
+
+        for define_if_else_node in &mut define_if_statement.define_if_else_nodes{
+            inline_expression(&mut define_if_else_node.condition, constants);
+            evaluate_condition(mode, &mut define_if_else_node.condition);
+            inline_statements(mode, &mut define_if_else_node.statements, constants);
+        }
+
+        if define_if_statement.define_else_node != None{
+            inline_statements(
+                mode, &mut define_if_statement.define_else_node.as_mut().unwrap().statements, constants);
+        }
+    }
+
+    else if statement.statement_type == Some(StatementType::DefineForLoop){
+        let for_loop = statement.define_for_loop_statement.as_mut().unwrap();
+        inline_expression(&mut for_loop.start, constants);
+        inline_expression(&mut for_loop.stop, constants);
+        inline_expression(&mut for_loop.step, constants);
+        inline_statements(mode, &mut for_loop.statements, constants);
+    }
+}
+
+
+fn inline_expression(expression: &mut Option<OperationNode>, constants: &HashMap<String, Token>){
+    if expression != &None{
+        inline_operation_node(expression.as_mut().unwrap(), constants);
+    }
+}
+
+
+fn inline_operation_node(operation_node: &mut OperationNode, constants: &HashMap<String, Token>){
+    if operation_node.left != None{
+        inline_operation_node(operation_node.left.as_mut().unwrap(), constants);
+    }
+
+    if operation_node.value != None{
+        let token = operation_node.value.as_ref().unwrap();
+        if token.token_type == TokenType::Variable && constants.contains_key(&token.value){
+
+            /* The Literal Keeps The Position Of The Replaced Variable For The Errors */
+            let mut literal = constants.get(&token.value).unwrap().clone();
+            literal.start_line = token.start_line;
+            literal.start_pos = token.start_pos;
+
+            operation_node.value = Some(literal);
+        }
+    }
+
+    if operation_node.right != None{
+        inline_operation_node(operation_node.right.as_mut().unwrap(), constants);
+    }
+
+    for argument in &mut operation_node.arguments{
+        inline_operation_node(argument, constants);
+    }
+}
+
+
+/* Conditions Failing At Compile Time Are Kept, They Report The Error When They Run */
+fn evaluate_condition(mode: &Mode, condition: &mut Option<OperationNode>){
+    if condition == &None{
+        return;
+    }
+
+    let operation_node = condition.as_ref().unwrap();
+    if
+        operation_node.get_constant_condition() != None ||
+        operation_node.get_non_constant_token(&HashSet::new()) != None
+    {
+        return;
+    }
+
+    let value = evaluate_expression(mode, operation_node);
+    if value.is_err() || value.as_ref().unwrap() == &None{
+        return;
+    }
+    let value = value.unwrap().unwrap();
+    if value.value_type != Some(ValueType::Boolean){
+        return;
+    }
+
+    let first_token = operation_node.get_first_token().unwrap().clone();
+
+    let mut literal = OperationNode::new();
+    literal.value = Some(Token{
+        token_type: if value.boolean.unwrap() {TokenType::True} else {TokenType::False},
+        start_line: first_token.start_line,
+        start_pos: first_token.start_pos,
+        value: String::from(if value.boolean.unwrap() {"True"} else {"False"})
+    });
+
+    *condition = Some(literal);
+}
+
+
+pub fn get_value_token(mode: &Mode, name_token: &Token, value: &Value) -> Result<Token, String>{
+    let mut token = Token{
+        token_type: TokenType::StringSequence,
+        start_line: name_token.start_line,
+        start_pos: name_token.start_pos,
+        value: String::new()
+    };
+
+    if value.value_type == Some(ValueType::Boolean){
+        token.token_type = if value.boolean.unwrap() {TokenType::True} else {TokenType::False};
+        token.value = String::from(if value.boolean.unwrap() {"True"} else {"False"});
+    }
+    else if value.value_type == Some(ValueType::Integer){
+        token.token_type = TokenType::IntNumber;
+        token.value = value.int.unwrap().to_string();
+    }
+    else if value.value_type == Some(ValueType::Double){
+        if !value.double.unwrap().is_finite(){
+            return Err(format!(
+                "{}: Syntax Error -> {}, line {}:{}.",
+                mode,
+                format!(
+                    "Constant `{}` value `{}` can't be inlined",
+                    name_token.value, value.double.unwrap()),
+                name_token.start_line,
+                name_token.start_pos));
+        }
+
+        token.token_type = TokenType::DoubleNumber;
+        token.value = format!("{:?}", value.double.unwrap());
+    }
+    else if value.value_type == Some(ValueType::Character){
+        token.token_type = TokenType::Character;
+        token.value = value.character.unwrap().to_string();
+    }
+    else{
+        token.value = value.string.clone().unwrap_or(String::new());
+    }
+
+    return Ok(token);
+}
@@ -1,12 +1,19 @@
-mod symantic_analyzer;
+mod constants_inliner;
+mod constants_folder;
 mod code_generator;
 mod byte_code_generator;
+mod byte_code_optimizer;
+mod native_code_generator;
+mod c_code_generator;
+mod wat_code_generator;
 
 
 use std::panic;
 use std::env;
 
 use crate::constants::Mode;
+use crate::options::CompileOptions;
+use crate::tokens::TokenType;
 
 use crate::file::File;
 use crate::lexer::Lexer;
@@ -14,9 +21,22 @@ use crate::parser::{
     Parser,
     parse
 };
-use symantic_analyzer::{
-    Analyzer,
-    analyze
+use crate::checker::{
+    Checker,
+    check
+};
+use crate::ir::{
+    IrProgram,
+    IrInstructionKind,
+    lower,
+    eliminate_dead_code,
+    analyze,
+    get_ir_text
+};
+pub use constants_inliner::inline_constants;
+pub use constants_folder::{
+    fold_constants,
+    evaluate_operation
 };
 use code_generator::{
     CodeGenerator,
@@ -26,12 +46,72 @@ use byte_code_generator::{
     ByteCodeGenerator,
     generate_byte_code as start_generate_byte_code
 };
+use byte_code_optimizer::{
+    ByteCodeOptimizer,
+    optimize_byte_code
+};
+use native_code_generator::{
+    NATIVE_FUNCTIONS,
+    NativeCodeGenerator,
+    generate_native_code,
+    get_assembly_text
+};
+use c_code_generator::{
+    C_FUNCTIONS,
+    CCodeGenerator,
+    generate_c_code
+};
+use wat_code_generator::{
+    WAT_FUNCTIONS,
+    get_wat_text
+};
 
 
-pub fn compile(
-    generate_byte_code: bool, analyze_code: bool
+/* A File Given Without A Folder Is In The Current Folder */
+fn get_output_folder(parent_folder_path: &String) -> String{
+    if parent_folder_path.is_empty(){
+        return String::from(".");
+    }
+    return parent_folder_path.clone();
+}
+
+
+/* The Path Of A File Written Next To The Compiled Engine File */
+fn get_output_path(parent_folder_path: &String, file_name: &str) -> String{
+    return format!("{}/{}", get_output_folder(parent_folder_path), file_name);
+}
+
+
+/*
+* The Backends With Their Own Runtime Only Have Some Of The Built-in Functions, A
+* Call To Another One Is Rejected Before Any File Is Written. The List Functions
+* Share Their Names With The String Functions And Are Never Supported.
+*/
+fn check_functions_support(
+    program: &IrProgram, backend_name: &str, functions: &[&str]
 ) -> Result<(), String>{
 
+    for block in &program.blocks{
+        for instruction in &block.instructions{
+            if let IrInstructionKind::Call{function, target: _, arguments} = &instruction.kind{
+                let is_list_call = arguments.first().and_then(
+                    |argument| program.get_variable_type(argument)) == Some(TokenType::List);
+
+                if is_list_call || !functions.contains(&function.as_str()){
+                    return Err(format!(
+                        "Engine Compiler: Code Generation Error -> Function `{}` is not supported by the {} backend, line: {}:{}",
+                        function, backend_name, instruction.start_line, instruction.start_pos));
+                }
+            }
+        }
+    }
+
+    return Ok(());
+}
+
+
+pub fn compile(options: &CompileOptions) -> Result<(), String>{
+
     panic::set_hook(Box::new(|panic_info| {
         if let Some(panic_message) = panic_info.payload().downcast_ref::<String>() {
             println!("{}", panic_message);
@@ -42,6 +122,13 @@ pub fn compile(
         }
     }));
 
+    /* The Native Backend Generates x86-64 Linux Assembly Only */
+    let is_native_target = cfg!(all(target_arch = "x86_64", target_os = "linux"));
+    if (options.native_code || options.emit_format == Some(String::from("asm"))) && !is_native_target{
+        return Err(String::from(
+            "Engine Compiler: Compiler Error -> `--native` and `--emit=asm` generate x86-64 Linux assembly, they are not supported on this platform."));
+    }
+
     let args: Vec<String> = env::args().collect();
     if args.len() < 2{
         panic!("Engine Compiler -> Compiler Error: Must provide file path.");
@@ -59,7 +146,7 @@ pub fn compile(
 
     let file_name_without_ext = ext_arr.join(".");
 
-    let current_mode = if generate_byte_code {Mode::ByteCodeGenerator} else {Mode::Compiler};
+    let current_mode = if options.generate_byte_code {Mode::ByteCodeGenerator} else {Mode::Compiler};
 
     let file = File::new(
         &args[1],
@@ -70,20 +157,74 @@ pub fn compile(
         panic!("{}", lexer.unwrap_err());
     }
 
-    let mut parser = Parser::new(lexer.unwrap(), current_mode)?;
+    let mut parser = Parser::new(lexer.unwrap(), current_mode.clone())?;
+
+    let mut syntax_tree = parse(&mut parser)?;
+
+    let mut checker = Checker::new(current_mode.clone());
+    check(&mut checker, &mut syntax_tree)?;
 
-    let syntax_tree = parse(&mut parser)?;
+    let syntax_tree = fold_constants(
+        &current_mode, inline_constants(&current_mode, syntax_tree)?)?;
 
-    let mut analyzer = Analyzer::new();
-    analyze(&mut analyzer, syntax_tree.clone())?;
+    /* Both Backends Are Generated From The Same Lowered Program */
+    let program = lower(&current_mode, &syntax_tree)?;
 
-    if analyze_code{
+    for warning in analyze(&current_mode, &program)?{
+        eprintln!("{}", warning);
+    }
+
+    let (program, warnings) = eliminate_dead_code(&current_mode, program);
+    for warning in warnings{
+        eprintln!("{}", warning);
+    }
+
+    if options.analyze_code{
+        return Ok(());
+    }
+
+    let is_native_code = options.native_code && !options.generate_byte_code && options.emit_format == None;
+    if is_native_code || options.emit_format == Some(String::from("asm")){
+        check_functions_support(&program, "native", &NATIVE_FUNCTIONS)?;
+    }
+    else if options.emit_format == Some(String::from("c")){
+        check_functions_support(&program, "C", &C_FUNCTIONS)?;
+    }
+    else if options.emit_format == Some(String::from("wat")){
+        check_functions_support(&program, "WebAssembly", &WAT_FUNCTIONS)?;
+    }
+
+    if options.emit_format == Some(String::from("ir")){
+        print!("{}", get_ir_text(&program));
+        return Ok(());
+    }
+    else if options.emit_format == Some(String::from("asm")){
+        print!("{}", get_assembly_text(&program)?);
+        return Ok(());
+    }
+    else if options.emit_format == Some(String::from("wat")){
+        print!("{}", get_wat_text(&program)?);
+        return Ok(());
+    }
+    else if options.emit_format == Some(String::from("c")){
+        let mut c_code_generator = CCodeGenerator::new(
+            program,
+            parent_path,
+            file_name_without_ext);
+        if c_code_generator.is_err(){
+            panic!("{}", c_code_generator.unwrap_err());
+        }
+
+        generate_c_code(c_code_generator.as_mut().unwrap())?;
+        if options.compile_c_code{
+            c_code_generator.as_mut().unwrap().execute()?;
+        }
         return Ok(());
     }
 
-    if generate_byte_code{
+    if options.generate_byte_code{
         let mut byte_code_generator = ByteCodeGenerator::new(
-            syntax_tree.clone(),
+            program,
             parent_path,
             file_name_without_ext);
         if byte_code_generator.is_err(){
@@ -91,19 +232,45 @@ pub fn compile(
         }
 
         start_generate_byte_code(byte_code_generator.as_mut().unwrap())?;
+
+        if options.optimization_level > 0{
+            let mut byte_code_optimizer = ByteCodeOptimizer::new(
+                byte_code_generator.as_ref().unwrap().get_file_path())?;
+
+            optimize_byte_code(&mut byte_code_optimizer)?;
+        }
+    }
+    else if options.native_code{
+        let mut native_code_generator = NativeCodeGenerator::new(
+            program,
+            parent_path,
+            file_name_without_ext);
+        if native_code_generator.is_err(){
+            panic!("{}", native_code_generator.unwrap_err());
+        }
+
+        /* The Assembly File Is Removed Even When Linking Fails */
+        let result = generate_native_code(native_code_generator.as_mut().unwrap());
+        let result = if result.is_ok() {native_code_generator.as_mut().unwrap().execute()} else {result};
+        native_code_generator.as_mut().unwrap().clean()?;
+        result?;
     }
     else{
         let mut code_generator = CodeGenerator::new(
-            syntax_tree.clone(),
+            program,
             parent_path,
             file_name_without_ext);
         if code_generator.is_err(){
             panic!("{}", code_generator.unwrap_err());
         }
 
-        generate(code_generator.as_mut().unwrap())?;
-        code_generator.as_mut().unwrap().execute()?;
-        code_generator.as_mut().unwrap().clean()?;
+        /* The Rust File Is Removed Even When `rustc` Fails, Unless It Is Kept */
+        let result = generate(code_generator.as_mut().unwrap());
+        let result = if result.is_ok() {code_generator.as_mut().unwrap().execute()} else {result};
+        if !options.keep_generated_code{
+            code_generator.as_mut().unwrap().clean()?;
+        }
+        result?;
     }
 
     return Ok(());
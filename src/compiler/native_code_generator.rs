@@ -0,0 +1,624 @@
+use std::process::Command;
+
+use crate::tokens::TokenType;
+use crate::constants::Mode;
+use crate::syntax_tree::OperatorType;
+use crate::ir::{
+    IrProgram,
+    IrInstruction,
+    IrInstructionKind,
+    IrTerminator
+};
+
+use crate::file::File;
+use super::get_output_path;
+
+
+/* Strings, Printing, Input And Conversions Used By The Generated Code */
+const NATIVE_RUNTIME_SOURCE: &'static str = include_str!("native_runtime.s");
+
+
+/* The Built-in Functions Of The Runtime, The Others Are Rejected Before The Assembly Is Written */
+pub const NATIVE_FUNCTIONS: [&'static str; 13] = [
+    "abs", "min", "max", "sqrt", "pow", "floor", "ceil", "round", "sin", "cos", "tan", "log", "exp",
+];
+
+
+#[derive(Debug)]
+pub struct NativeCodeGenerator{
+    file: File,
+    assembly_file_path: String,
+    executable_path: String,
+    program: IrProgram,
+}
+
+impl NativeCodeGenerator{
+    pub fn new(
+        program: IrProgram,
+        parent_folder_path: String,
+        file_name: String,
+    ) -> Result<Self, std::io::Error>{
+
+        let executable_path = get_output_path(&parent_folder_path, &file_name);
+        let assembly_file_path = executable_path.clone() + &String::from(".s");
+
+        let mut _file = File::create_new(
+            assembly_file_path.clone(), Mode::Compiler)?;
+
+        return Ok(NativeCodeGenerator{
+            file: _file,
+            assembly_file_path,
+            executable_path,
+            program,
+        });
+    }
+
+    /* Assembles And Links With The C Library Through The System C Compiler Driver */
+    pub fn execute(&mut self) -> Result<(), String>{
+        let output = Command::new("cc")
+            .arg("-o")
+            .arg(self.executable_path.clone())
+            .arg(self.assembly_file_path.clone())
+            .arg("-lm")
+            .output();
+
+        if output.is_err(){
+            return Err(format!(
+                "Engine Compiler: Link Error -> Failed to run `cc`: {}.", output.unwrap_err()));
+        }
+        let output = output.unwrap();
+
+        if !output.status.success(){
+            return Err(format!(
+                "Engine Compiler: Link Error -> `cc` failed to link `{}`:\n{}",
+                self.executable_path, String::from_utf8_lossy(&output.stderr).trim_end()));
+        }
+
+        return Ok(());
+    }
+
+    pub fn clean(&mut self) -> Result<(), String>{
+        File::delete_file(self.assembly_file_path.clone(), Mode::Compiler);
+
+        return Ok(());
+    }
+}
+
+
+pub fn generate_native_code(
+    native_code_generator: &mut NativeCodeGenerator
+) -> Result<(), String>{
+
+    let assembly = get_assembly_text(&native_code_generator.program)?;
+    native_code_generator.file.write(assembly);
+
+    return Ok(());
+}
+
+
+/* The Code Of `main`, With The Data It Reads Kept Apart Until The End */
+#[derive(Debug)]
+struct Assembly<'a>{
+    program: &'a IrProgram,
+    lines: Vec<String>,
+    data_lines: Vec<String>,
+    labels_count: usize,
+}
+
+impl<'a> Assembly<'a>{
+    fn get_type(&self, name: &String) -> TokenType{
+        return self.program.get_variable_type(name).unwrap();
+    }
+
+    fn new_label(&mut self) -> String{
+        self.labels_count += 1;
+        return format!(".Llabel_{}", self.labels_count);
+    }
+
+    /* A Zero Terminated Message Read By The Runtime Errors */
+    fn add_message(&mut self, message: String) -> String{
+        let label = self.new_label();
+        self.data_lines.push(format!("{}:", label));
+        self.data_lines.push(format!(
+            "    .asciz \"{}\"", message.replace("\\", "\\\\").replace("\"", "\\\"")));
+        return label;
+    }
+
+    fn add_bytes(&mut self, value: &String) -> String{
+        let label = self.new_label();
+        self.data_lines.push(format!("{}:", label));
+        if !value.is_empty(){
+            let bytes: Vec<String> = value.bytes().map(|byte| byte.to_string()).collect();
+            self.data_lines.push(format!("    .byte {}", bytes.join(", ")));
+        }
+        return label;
+    }
+
+    fn emit(&mut self, line: String){
+        self.lines.push(format!("    {}", line));
+    }
+}
+
+
+fn get_address(name: &String) -> String{
+    return format!("[rip + ev_{}]", name);
+}
+
+
+/* A `string` Is A Pointer And A Length, The Other Types Fit In 8 Bytes */
+fn get_size(variable_type: &TokenType) -> u8{
+    return if variable_type == &TokenType::String {16} else {8};
+}
+
+
+/*
+* Every Variable Is A Global In `.bss`, Zeroed Like Their Default Values, And The
+* Blocks Are Labels Of A Single `main` Function. The Runtime Is Appended After It.
+*/
+pub fn get_assembly_text(program: &IrProgram) -> Result<String, String>{
+    let mut assembly = Assembly{
+        program,
+        lines: vec![],
+        data_lines: vec![],
+        labels_count: 0,
+    };
+
+    assembly.lines.push(String::from("    .intel_syntax noprefix"));
+
+    for variable in &program.variables{
+        assembly.lines.push(format!("    .local ev_{}", variable.name));
+        assembly.lines.push(format!(
+            "    .comm ev_{}, {}, 8", variable.name, get_size(&variable.variable_type)));
+    }
+
+    assembly.lines.push(String::from("    .text"));
+    assembly.lines.push(String::from("    .globl main"));
+    assembly.lines.push(String::from("main:"));
+    assembly.emit(String::from("push rbp"));
+    assembly.emit(String::from("mov rbp, rsp"));
+
+    for (index, block) in program.blocks.iter().enumerate(){
+        assembly.lines.push(format!(".Lblock_{}:", index));
+
+        for instruction in &block.instructions{
+            generate_instruction(&mut assembly, instruction)?;
+        }
+
+        match &block.terminator {
+            IrTerminator::Jump{target} => assembly.emit(format!("jmp .Lblock_{}", target)),
+            IrTerminator::Branch{condition, then_block, else_block} => {
+                assembly.emit(format!("cmp BYTE PTR {}, 0", get_address(condition)));
+                assembly.emit(format!("jne .Lblock_{}", then_block));
+                assembly.emit(format!("jmp .Lblock_{}", else_block));
+            },
+            IrTerminator::End => assembly.emit(String::from("jmp .Lend")),
+        };
+    }
+
+    assembly.lines.push(String::from(".Lend:"));
+    assembly.emit(String::from("xor eax, eax"));
+    assembly.emit(String::from("pop rbp"));
+    assembly.emit(String::from("ret"));
+
+    assembly.lines.push(String::from("    .section .rodata"));
+    let data_lines = assembly.data_lines.clone();
+    assembly.lines.extend(data_lines);
+
+    let mut text = assembly.lines.join("\n");
+    text.push_str("\n\n");
+    text.push_str(NATIVE_RUNTIME_SOURCE);
+
+    return Ok(text);
+}
+
+
+fn generate_instruction(
+    assembly: &mut Assembly, instruction: &IrInstruction
+) -> Result<(), String>{
+
+    let line = instruction.start_line;
+    let pos = instruction.start_pos;
+
+    match &instruction.kind {
+        IrInstructionKind::Constant{target, value} => {
+            let target_type = assembly.get_type(target);
+
+            if target_type == TokenType::Bool{
+                assembly.emit(format!(
+                    "mov BYTE PTR {}, {}", get_address(target), if value == "True" {1} else {0}));
+            }
+            else if target_type == TokenType::Int{
+                let number = value.parse::<i64>();
+                if number.is_err(){
+                    return Err(format!(
+                        "Engine Compiler: Code Generation Error -> Invalid `Int` constant `{}`, line: {}:{}",
+                        value, line, pos));
+                }
+
+                assembly.emit(format!("movabs rax, {}", number.unwrap()));
+                assembly.emit(format!("mov QWORD PTR {}, rax", get_address(target)));
+            }
+            else if target_type == TokenType::Double{
+                let number = value.parse::<f64>().unwrap_or(0.0);
+
+                assembly.emit(format!("movabs rax, {:#x}", number.to_bits()));
+                assembly.emit(format!("mov QWORD PTR {}, rax", get_address(target)));
+            }
+            else if target_type == TokenType::Char{
+                assembly.emit(format!(
+                    "mov DWORD PTR {}, {}",
+                    get_address(target), value.chars().nth(0).unwrap_or('\0') as u32));
+            }
+            else{
+                let label = assembly.add_bytes(value);
+
+                assembly.emit(format!("lea rdi, {}", get_address(target)));
+                assembly.emit(format!("lea rsi, [rip + {}]", label));
+                assembly.emit(format!("mov rdx, {}", value.len()));
+                assembly.emit(String::from("call engine_string_assign"));
+            }
+        },
+
+        IrInstructionKind::Convert{target, source} => {
+            generate_convert(assembly, target, source, line, pos)?;
+        },
+
+        IrInstructionKind::Operation{operator, target, left, right} => {
+            generate_operation(assembly, operator, target, left, right, line, pos)?;
+        },
+
+        IrInstructionKind::Call{function, target, arguments} => {
+            generate_call(assembly, function, target, arguments, line, pos)?;
+        },
+
+        IrInstructionKind::Input{target} => {
+            let message = assembly.add_message(format!(
+                "Engine Compiler: Compiler Error -> Failed to read input, line: {}:{}", line, pos));
+
+            assembly.emit(format!("lea rdi, {}", get_address(target)));
+            assembly.emit(format!("lea rsi, [rip + {}]", message));
+            assembly.emit(String::from("call engine_input"));
+        },
+
+        IrInstructionKind::Print{source, is_stderr} => {
+            let source_type = assembly.get_type(source);
+
+            assembly.emit(format!("mov edi, {}", if *is_stderr {2} else {1}));
+            if source_type == TokenType::Bool{
+                assembly.emit(format!("movzx esi, BYTE PTR {}", get_address(source)));
+                assembly.emit(String::from("call engine_print_bool"));
+            }
+            else if source_type == TokenType::Int{
+                assembly.emit(format!("mov rsi, QWORD PTR {}", get_address(source)));
+                assembly.emit(String::from("call engine_print_int"));
+            }
+            else if source_type == TokenType::Double{
+                assembly.emit(format!("movsd xmm0, QWORD PTR {}", get_address(source)));
+                assembly.emit(String::from("call engine_print_double"));
+            }
+            else if source_type == TokenType::Char{
+                assembly.emit(format!("mov esi, DWORD PTR {}", get_address(source)));
+                assembly.emit(String::from("call engine_print_char"));
+            }
+            else{
+                assembly.emit(format!("mov rsi, QWORD PTR [rip + ev_{}]", source));
+                assembly.emit(format!("mov rdx, QWORD PTR [rip + ev_{} + 8]", source));
+                assembly.emit(String::from("call engine_write"));
+            }
+        },
+    };
+
+    return Ok(());
+}
+
+
+/* The Same Conversions As The Rust Executables, Strings Are Parsed When The Program Runs */
+fn generate_convert(
+    assembly: &mut Assembly, target: &String, source: &String, line: u64, pos: u64
+) -> Result<(), String>{
+
+    let target_type = assembly.get_type(target);
+    let source_type = assembly.get_type(source);
+
+    if target_type == source_type{
+        if target_type == TokenType::String{
+            assembly.emit(format!("lea rdi, {}", get_address(target)));
+            assembly.emit(format!("mov rsi, QWORD PTR [rip + ev_{}]", source));
+            assembly.emit(format!("mov rdx, QWORD PTR [rip + ev_{} + 8]", source));
+            assembly.emit(String::from("call engine_string_assign"));
+        }
+        else{
+            assembly.emit(format!("mov rax, QWORD PTR {}", get_address(source)));
+            assembly.emit(format!("mov QWORD PTR {}, rax", get_address(target)));
+        }
+    }
+    else if target_type == TokenType::Int && source_type == TokenType::Double{
+        assembly.emit(format!("movsd xmm0, QWORD PTR {}", get_address(source)));
+        assembly.emit(String::from("call engine_double_to_int"));
+        assembly.emit(format!("mov QWORD PTR {}, rax", get_address(target)));
+    }
+    else if target_type == TokenType::Double && source_type == TokenType::Int{
+        assembly.emit(format!("cvtsi2sd xmm0, QWORD PTR {}", get_address(source)));
+        assembly.emit(format!("movsd QWORD PTR {}, xmm0", get_address(target)));
+    }
+    else if target_type == TokenType::String && source_type == TokenType::Char{
+        assembly.emit(format!("lea rdi, {}", get_address(target)));
+        assembly.emit(format!("mov esi, DWORD PTR {}", get_address(source)));
+        assembly.emit(String::from("call engine_char_to_string"));
+    }
+    else if source_type == TokenType::String{
+        let (function, result) = if target_type == TokenType::Bool{
+            ("engine_string_to_bool", "mov BYTE PTR {}, al")
+        }
+        else if target_type == TokenType::Int{
+            ("engine_string_to_int", "mov QWORD PTR {}, rax")
+        }
+        else if target_type == TokenType::Double{
+            ("engine_string_to_double", "movsd QWORD PTR {}, xmm0")
+        }
+        else{
+            ("engine_string_to_char", "mov DWORD PTR {}, eax")
+        };
+
+        let message = assembly.add_message(format!(
+            "Engine Compiler: Convert Error -> can't convert from `String` to `{:?}`, line: {}:{}",
+            target_type, line, pos));
+
+        assembly.emit(format!("lea rdi, {}", get_address(source)));
+        assembly.emit(format!("lea rsi, [rip + {}]", message));
+        assembly.emit(format!("call {}", function));
+        assembly.emit(result.replace("{}", &get_address(target)));
+    }
+    else{
+        return Err(format!(
+            "Engine Compiler: Code Generation Error -> Can't convert `{:?}` to `{:?}`, line: {}:{}",
+            source_type, target_type, line, pos));
+    }
+
+    return Ok(());
+}
+
+
+/*
+* The Math Functions, `int` Arguments Are Promoted To `double` Unless All Of Them
+* Are `int`. The Errors Are Written By The Runtime With The Values, Then The Line.
+*/
+fn generate_call(
+    assembly: &mut Assembly, function: &String, target: &String, arguments: &Vec<String>,
+    line: u64, pos: u64
+) -> Result<(), String>{
+
+    if !NATIVE_FUNCTIONS.contains(&function.as_str()){
+        return Err(format!(
+            "Engine Compiler: Code Generation Error -> Function `{}` is not supported by the native backend, line: {}:{}",
+            function, line, pos));
+    }
+
+    let location = format!(", line: {}:{}", line, pos);
+    let is_int = arguments.iter().all(|argument| assembly.get_type(argument) == TokenType::Int);
+
+    if is_int && (function == "abs" || function == "min" || function == "max" || function == "pow"){
+        assembly.emit(format!("mov rdi, QWORD PTR {}", get_address(&arguments[0])));
+
+        if function == "abs"{
+            let message = assembly.add_message(format!(
+                "Engine Compiler: Builtin Error -> `abs` result of `{}` does not fit into int{}",
+                i64::MIN, location));
+
+            assembly.emit(format!("lea rsi, [rip + {}]", message));
+            assembly.emit(String::from("call engine_int_abs"));
+        }
+        else if function == "pow"{
+            let message = assembly.add_message(location);
+
+            assembly.emit(format!("mov rsi, QWORD PTR {}", get_address(&arguments[1])));
+            assembly.emit(format!("lea rdx, [rip + {}]", message));
+            assembly.emit(String::from("call engine_int_pow"));
+        }
+        else{
+            assembly.emit(String::from("mov rax, rdi"));
+            assembly.emit(format!("mov rcx, QWORD PTR {}", get_address(&arguments[1])));
+            assembly.emit(String::from("cmp rax, rcx"));
+            assembly.emit(String::from(if function == "min" {"cmovg rax, rcx"} else {"cmovl rax, rcx"}));
+        }
+
+        assembly.emit(format!("mov QWORD PTR {}, rax", get_address(target)));
+        return Ok(());
+    }
+
+    if is_int && (function == "floor" || function == "ceil" || function == "round"){
+        assembly.emit(format!("mov rax, QWORD PTR {}", get_address(&arguments[0])));
+        assembly.emit(format!("mov QWORD PTR {}, rax", get_address(target)));
+        return Ok(());
+    }
+
+    for (index, argument) in arguments.iter().enumerate(){
+        if assembly.get_type(argument) == TokenType::Int{
+            assembly.emit(format!("cvtsi2sd xmm{}, QWORD PTR {}", index, get_address(argument)));
+        }
+        else{
+            assembly.emit(format!("movsd xmm{}, QWORD PTR {}", index, get_address(argument)));
+        }
+    }
+
+    let library_function = match function.as_str() {
+        "abs" => "fabs",
+        "min" => "fmin",
+        "max" => "fmax",
+        name => name,
+    };
+    assembly.emit(format!("call {}@PLT", library_function));
+
+    if function == "floor" || function == "ceil" || function == "round"{
+        let prefix = assembly.add_message(format!(
+            "Engine Compiler: Builtin Error -> `{}` result `", function));
+        let suffix = assembly.add_message(format!("` does not fit into int{}", location));
+
+        assembly.emit(format!("lea rdi, [rip + {}]", prefix));
+        assembly.emit(format!("lea rsi, [rip + {}]", suffix));
+        assembly.emit(String::from("call engine_checked_double_to_int"));
+        assembly.emit(format!("mov QWORD PTR {}, rax", get_address(target)));
+    }
+    else{
+        assembly.emit(format!("movsd QWORD PTR {}, xmm0", get_address(target)));
+    }
+
+    return Ok(());
+}
+
+
+/* Integers Wrap Like In The VM, The Comparisons Set A Bool Byte */
+fn generate_operation(
+    assembly: &mut Assembly, operator: &OperatorType, target: &String, left: &String, right: &String,
+    line: u64, pos: u64
+) -> Result<(), String>{
+
+    let operands_type = assembly.get_type(left);
+
+    if operands_type == TokenType::String && operator == &OperatorType::Plus{
+        assembly.emit(format!("lea rdi, {}", get_address(target)));
+        assembly.emit(format!("lea rsi, {}", get_address(left)));
+        assembly.emit(format!("lea rdx, {}", get_address(right)));
+        assembly.emit(String::from("call engine_string_concat"));
+    }
+    else if operands_type == TokenType::Int{
+        assembly.emit(format!("mov rax, QWORD PTR {}", get_address(left)));
+        assembly.emit(format!("mov rcx, QWORD PTR {}", get_address(right)));
+
+        let condition = get_condition_code(operator);
+        if condition != None{
+            assembly.emit(String::from("cmp rax, rcx"));
+            assembly.emit(format!("set{} al", condition.unwrap()));
+            assembly.emit(format!("mov BYTE PTR {}, al", get_address(target)));
+            return Ok(());
+        }
+
+        if operator == &OperatorType::Plus{
+            assembly.emit(String::from("add rax, rcx"));
+        }
+        else if operator == &OperatorType::Minus{
+            assembly.emit(String::from("sub rax, rcx"));
+        }
+        else if operator == &OperatorType::Mul{
+            assembly.emit(String::from("imul rax, rcx"));
+        }
+        else if operator == &OperatorType::Mod{
+            /* `idiv` Faults On The Minimum Modulo -1, Whose Remainder Is 0 */
+            let checked_label = assembly.new_label();
+            let divide_label = assembly.new_label();
+            let done_label = assembly.new_label();
+            let message = assembly.add_message(format!(
+                "Engine Compiler: Compiler Error -> Division by zero, line: {}:{}", line, pos));
+
+            assembly.emit(String::from("test rcx, rcx"));
+            assembly.emit(format!("jnz {}", checked_label));
+            assembly.emit(format!("lea rdi, [rip + {}]", message));
+            assembly.emit(String::from("call engine_error"));
+            assembly.lines.push(format!("{}:", checked_label));
+            assembly.emit(String::from("cmp rcx, -1"));
+            assembly.emit(format!("jne {}", divide_label));
+            assembly.emit(String::from("xor eax, eax"));
+            assembly.emit(format!("jmp {}", done_label));
+            assembly.lines.push(format!("{}:", divide_label));
+            assembly.emit(String::from("cqo"));
+            assembly.emit(String::from("idiv rcx"));
+            assembly.emit(String::from("mov rax, rdx"));
+            assembly.lines.push(format!("{}:", done_label));
+        }
+        else{
+            return Err(get_operation_error(operator, &operands_type, line, pos));
+        }
+
+        assembly.emit(format!("mov QWORD PTR {}, rax", get_address(target)));
+    }
+    else if operands_type == TokenType::Double{
+        assembly.emit(format!("movsd xmm0, QWORD PTR {}", get_address(left)));
+        assembly.emit(format!("movsd xmm1, QWORD PTR {}", get_address(right)));
+
+        /* `cmpsd` Only Has Less Than Predicates, Greater Than Swaps The Operands */
+        let comparison = match operator {
+            OperatorType::Equal => Some("cmpeqsd xmm0, xmm1"),
+            OperatorType::NotEqual => Some("cmpneqsd xmm0, xmm1"),
+            OperatorType::LessThan => Some("cmpltsd xmm0, xmm1"),
+            OperatorType::LessThanOrEqual => Some("cmplesd xmm0, xmm1"),
+            OperatorType::GreaterThan => Some("cmpltsd xmm1, xmm0"),
+            OperatorType::GreaterThanOrEqual => Some("cmplesd xmm1, xmm0"),
+            _ => None,
+        };
+        if comparison != None{
+            let comparison = comparison.unwrap();
+            assembly.emit(String::from(comparison));
+            assembly.emit(format!("movq rax, {}", &comparison[comparison.len() - 4..]));
+            assembly.emit(String::from("and eax, 1"));
+            assembly.emit(format!("mov BYTE PTR {}, al", get_address(target)));
+            return Ok(());
+        }
+
+        if operator == &OperatorType::Plus{
+            assembly.emit(String::from("addsd xmm0, xmm1"));
+        }
+        else if operator == &OperatorType::Minus{
+            assembly.emit(String::from("subsd xmm0, xmm1"));
+        }
+        else if operator == &OperatorType::Mul{
+            assembly.emit(String::from("mulsd xmm0, xmm1"));
+        }
+        else if operator == &OperatorType::Div{
+            assembly.emit(String::from("divsd xmm0, xmm1"));
+        }
+        else if operator == &OperatorType::Mod{
+            assembly.emit(String::from("call fmod@PLT"));
+        }
+        else{
+            return Err(get_operation_error(operator, &operands_type, line, pos));
+        }
+
+        assembly.emit(format!("movsd QWORD PTR {}, xmm0", get_address(target)));
+    }
+    else if operands_type == TokenType::Bool{
+        assembly.emit(format!("movzx eax, BYTE PTR {}", get_address(left)));
+        assembly.emit(format!("movzx ecx, BYTE PTR {}", get_address(right)));
+
+        if operator == &OperatorType::And{
+            assembly.emit(String::from("and eax, ecx"));
+        }
+        else if operator == &OperatorType::Or{
+            assembly.emit(String::from("or eax, ecx"));
+        }
+        else if operator == &OperatorType::Equal || operator == &OperatorType::NotEqual{
+            assembly.emit(String::from("cmp eax, ecx"));
+            assembly.emit(format!("set{} al", get_condition_code(operator).unwrap()));
+        }
+        else{
+            return Err(get_operation_error(operator, &operands_type, line, pos));
+        }
+
+        assembly.emit(format!("mov BYTE PTR {}, al", get_address(target)));
+    }
+    else{
+        return Err(get_operation_error(operator, &operands_type, line, pos));
+    }
+
+    return Ok(());
+}
+
+
+/* The `set` Suffix Of A Signed Comparison */
+fn get_condition_code(operator: &OperatorType) -> Option<&'static str>{
+    return match operator {
+        OperatorType::Equal => Some("e"),
+        OperatorType::NotEqual => Some("ne"),
+        OperatorType::LessThan => Some("l"),
+        OperatorType::LessThanOrEqual => Some("le"),
+        OperatorType::GreaterThan => Some("g"),
+        OperatorType::GreaterThanOrEqual => Some("ge"),
+        _ => None,
+    };
+}
+
+
+fn get_operation_error(operator: &OperatorType, operands_type: &TokenType, line: u64, pos: u64) -> String{
+    return format!(
+        "Engine Compiler: Code Generation Error -> Invalid operation `{:?}` on `{:?}`, line: {}:{}",
+        operator, operands_type, line, pos);
+}
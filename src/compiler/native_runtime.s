@@ -0,0 +1,1020 @@
+# The Runtime Of The Programs Compiled Into x86-64 Assembly, Appended To The
+# Generated File And Linked With The C Library.
+#
+# A `string` Variable Takes 16 Bytes, The Pointer To Its Own Heap Buffer Then Its
+# Length, A `char` Is Its Unicode Code Point. The Runtime Errors Are Printed To
+# The Standard Output And Exit With The Code 101 Like The Rust Executables.
+
+    .section .rodata
+.Lnewline:
+    .ascii "\n"
+.Ltrue_text:
+    .ascii "True"
+.Lfalse_text:
+    .ascii "False"
+.Lnan_text:
+    .ascii "NaN"
+.Linf_text:
+    .ascii "inf"
+.Lexponent_format:
+    .asciz "%.*e"
+.Lprint_error:
+    .asciz "Engine Compiler: Compiler Error -> Error in printing to console"
+.Lmemory_error:
+    .asciz "Engine Compiler: Compiler Error -> Out of memory"
+.Lpow_exponent_text:
+    .asciz "Engine Compiler: Builtin Error -> `pow` exponent `"
+.Lpow_negative_text:
+    .asciz "` can't be negative for int values, use a double base"
+.Lpow_result_text:
+    .asciz "Engine Compiler: Builtin Error -> `pow` result of `"
+.Lpow_and_text:
+    .asciz "` and `"
+.Lpow_overflow_text:
+    .asciz "` does not fit into int"
+
+    .text
+
+# engine_error(message: *const c_char) -> !
+engine_error:
+    push rbx
+    mov rbx, rdi
+    call strlen@PLT
+    mov rdx, rax
+    mov rsi, rbx
+    mov edi, 1
+    call engine_write_raw
+    mov edi, 1
+    lea rsi, [rip + .Lnewline]
+    mov edx, 1
+    call engine_write_raw
+    mov edi, 101
+    call exit@PLT
+
+
+# engine_write_raw(fd: i32, data: *const u8, length: u64) -> i32, 0 Or -1 On Errors
+engine_write_raw:
+    push rbx
+    push r12
+    push r13
+    mov ebx, edi
+    mov r12, rsi
+    mov r13, rdx
+.Lwrite_loop:
+    test r13, r13
+    jz .Lwrite_done
+    mov edi, ebx
+    mov rsi, r12
+    mov rdx, r13
+    call write@PLT
+    test rax, rax
+    jle .Lwrite_check
+    add r12, rax
+    sub r13, rax
+    jmp .Lwrite_loop
+.Lwrite_check:
+    call __errno_location@PLT
+    cmp DWORD PTR [rax], 4
+    je .Lwrite_loop
+    mov eax, -1
+    jmp .Lwrite_end
+.Lwrite_done:
+    xor eax, eax
+.Lwrite_end:
+    pop r13
+    pop r12
+    pop rbx
+    ret
+
+
+# engine_write(fd: i32, data: *const u8, length: u64)
+engine_write:
+    sub rsp, 8
+    call engine_write_raw
+    add rsp, 8
+    test eax, eax
+    jnz .Lwrite_failed
+    ret
+.Lwrite_failed:
+    sub rsp, 8
+    lea rdi, [rip + .Lprint_error]
+    call engine_error
+
+
+engine_out_of_memory:
+    sub rsp, 8
+    lea rdi, [rip + .Lmemory_error]
+    call engine_error
+
+
+# engine_print_bool(fd: i32, value: bool)
+engine_print_bool:
+    lea rax, [rip + .Ltrue_text]
+    mov edx, 4
+    lea rcx, [rip + .Lfalse_text]
+    mov r8d, 5
+    test sil, sil
+    cmovz rax, rcx
+    cmovz edx, r8d
+    mov rsi, rax
+    jmp engine_write
+
+
+# engine_print_int(fd: i32, value: i64), The Digits Are Written Backward
+engine_print_int:
+    sub rsp, 40
+    mov rax, rsi
+    lea r8, [rsp + 32]
+    mov r9, r8
+    mov rcx, 10
+    test rax, rax
+    jns .Lprint_int_positive
+.Lprint_int_negative:
+    cqo
+    idiv rcx
+    neg rdx
+    add dl, '0'
+    dec r9
+    mov BYTE PTR [r9], dl
+    test rax, rax
+    jnz .Lprint_int_negative
+    dec r9
+    mov BYTE PTR [r9], '-'
+    jmp .Lprint_int_write
+.Lprint_int_positive:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec r9
+    mov BYTE PTR [r9], dl
+    test rax, rax
+    jnz .Lprint_int_positive
+.Lprint_int_write:
+    mov rsi, r9
+    mov rdx, r8
+    sub rdx, r9
+    call engine_write
+    add rsp, 40
+    ret
+
+
+# engine_encode_char(code_point: u32, buffer: *mut u8) -> u64, The UTF-8 Length
+engine_encode_char:
+    cmp edi, 0x80
+    jae .Lencode_two
+    mov BYTE PTR [rsi], dil
+    mov eax, 1
+    ret
+.Lencode_two:
+    cmp edi, 0x800
+    jae .Lencode_three
+    mov eax, edi
+    shr eax, 6
+    or al, 0xC0
+    mov BYTE PTR [rsi], al
+    mov eax, edi
+    and al, 0x3F
+    or al, 0x80
+    mov BYTE PTR [rsi + 1], al
+    mov eax, 2
+    ret
+.Lencode_three:
+    cmp edi, 0x10000
+    jae .Lencode_four
+    mov eax, edi
+    shr eax, 12
+    or al, 0xE0
+    mov BYTE PTR [rsi], al
+    mov eax, edi
+    shr eax, 6
+    and al, 0x3F
+    or al, 0x80
+    mov BYTE PTR [rsi + 1], al
+    mov eax, edi
+    and al, 0x3F
+    or al, 0x80
+    mov BYTE PTR [rsi + 2], al
+    mov eax, 3
+    ret
+.Lencode_four:
+    mov eax, edi
+    shr eax, 18
+    or al, 0xF0
+    mov BYTE PTR [rsi], al
+    mov eax, edi
+    shr eax, 12
+    and al, 0x3F
+    or al, 0x80
+    mov BYTE PTR [rsi + 1], al
+    mov eax, edi
+    shr eax, 6
+    and al, 0x3F
+    or al, 0x80
+    mov BYTE PTR [rsi + 2], al
+    mov eax, edi
+    and al, 0x3F
+    or al, 0x80
+    mov BYTE PTR [rsi + 3], al
+    mov eax, 4
+    ret
+
+
+# engine_print_char(fd: i32, code_point: u32)
+engine_print_char:
+    push rbx
+    sub rsp, 16
+    mov ebx, edi
+    mov edi, esi
+    mov rsi, rsp
+    call engine_encode_char
+    mov edi, ebx
+    mov rsi, rsp
+    mov rdx, rax
+    call engine_write
+    add rsp, 16
+    pop rbx
+    ret
+
+
+# engine_print_double(fd: i32, value: f64), Printed Like Rust With The Shortest
+# Digits Reading Back The Same Value, Without An Exponent
+engine_print_double:
+    push rbx
+    push r12
+    push r13
+    push r14
+    push r15
+    sub rsp, 512
+    mov ebx, edi
+    movsd QWORD PTR [rsp + 32], xmm0
+    lea r12, [rsp + 64]
+    mov r13, r12
+
+    ucomisd xmm0, xmm0
+    jp .Lprint_double_nan
+
+    movq rax, xmm0
+    test rax, rax
+    jns .Lprint_double_positive
+    mov BYTE PTR [r13], '-'
+    inc r13
+    btr rax, 63
+    movq xmm0, rax
+    movsd QWORD PTR [rsp + 32], xmm0
+.Lprint_double_positive:
+    movabs rcx, 0x7FF0000000000000
+    cmp rax, rcx
+    je .Lprint_double_inf
+    test rax, rax
+    jz .Lprint_double_zero
+
+    xor r14d, r14d
+.Lprint_double_precision:
+    mov rdi, rsp
+    mov esi, 32
+    lea rdx, [rip + .Lexponent_format]
+    mov ecx, r14d
+    movsd xmm0, QWORD PTR [rsp + 32]
+    mov eax, 1
+    call snprintf@PLT
+    mov rdi, rsp
+    xor esi, esi
+    call strtod@PLT
+    ucomisd xmm0, QWORD PTR [rsp + 32]
+    je .Lprint_double_found
+    inc r14d
+    cmp r14d, 17
+    jb .Lprint_double_precision
+    dec r14d
+
+    # `%e` Rounds A Tie To Even While Rust Rounds It Up, A Tie Is A `5` Followed
+    # Only By Zeros In The Exact Digits. Only An Even Digit Is Rounded Down By
+    # `%e`, So Adding One Never Carries, And Is Kept When It Reads Back The Value.
+.Lprint_double_found:
+    lea rdi, [rsp + 448]
+    mov esi, 64
+    lea rdx, [rip + .Lexponent_format]
+    mov ecx, 40
+    movsd xmm0, QWORD PTR [rsp + 32]
+    mov eax, 1
+    call snprintf@PLT
+    lea rcx, [r14 + 1]
+    test r14d, r14d
+    jnz .Lprint_double_tie_digit
+    xor ecx, ecx
+.Lprint_double_tie_digit:
+    mov dl, BYTE PTR [rsp + rcx]
+    cmp dl, BYTE PTR [rsp + rcx + 448]
+    jne .Lprint_double_text
+    lea rax, [r14 + 2]
+    cmp BYTE PTR [rsp + rax + 448], '5'
+    jne .Lprint_double_text
+.Lprint_double_tie_zeros:
+    inc rax
+    movzx edx, BYTE PTR [rsp + rax + 448]
+    cmp dl, 'e'
+    je .Lprint_double_tie
+    cmp dl, '0'
+    jne .Lprint_double_text
+    jmp .Lprint_double_tie_zeros
+.Lprint_double_tie:
+    mov r15, rcx
+    inc BYTE PTR [rsp + r15]
+    mov rdi, rsp
+    xor esi, esi
+    call strtod@PLT
+    ucomisd xmm0, QWORD PTR [rsp + 32]
+    je .Lprint_double_text
+    dec BYTE PTR [rsp + r15]
+
+    # The Text Is `d.ddde+XX`, Or `de+XX` Without Decimals
+.Lprint_double_text:
+    lea rcx, [r14 + 2]
+    test r14d, r14d
+    jnz .Lprint_double_exponent
+    mov ecx, 1
+.Lprint_double_exponent:
+    movzx r8d, BYTE PTR [rsp + rcx + 1]
+    add rcx, 2
+    xor r15, r15
+.Lprint_double_exponent_digits:
+    movzx edx, BYTE PTR [rsp + rcx]
+    test dl, dl
+    jz .Lprint_double_exponent_sign
+    sub edx, '0'
+    imul r15, r15, 10
+    add r15, rdx
+    inc rcx
+    jmp .Lprint_double_exponent_digits
+.Lprint_double_exponent_sign:
+    cmp r8b, '-'
+    jne .Lprint_double_compact
+    neg r15
+
+    # Move The Decimals Next To The First Digit, Then Drop The Trailing Zeros
+.Lprint_double_compact:
+    xor ecx, ecx
+.Lprint_double_compact_loop:
+    cmp ecx, r14d
+    jae .Lprint_double_trim
+    movzx edx, BYTE PTR [rsp + rcx + 2]
+    mov BYTE PTR [rsp + rcx + 1], dl
+    inc ecx
+    jmp .Lprint_double_compact_loop
+.Lprint_double_trim:
+    lea r9, [r14 + 1]
+.Lprint_double_trim_loop:
+    cmp r9, 1
+    jbe .Lprint_double_layout
+    cmp BYTE PTR [rsp + r9 - 1], '0'
+    jne .Lprint_double_layout
+    dec r9
+    jmp .Lprint_double_trim_loop
+
+.Lprint_double_layout:
+    test r15, r15
+    js .Lprint_double_fraction
+
+    # The Integer Part Is Padded With Zeros After The Digits
+    xor ecx, ecx
+.Lprint_double_integer:
+    cmp rcx, r15
+    jg .Lprint_double_decimals
+    mov dl, '0'
+    cmp rcx, r9
+    jae .Lprint_double_integer_digit
+    mov dl, BYTE PTR [rsp + rcx]
+.Lprint_double_integer_digit:
+    mov BYTE PTR [r13], dl
+    inc r13
+    inc rcx
+    jmp .Lprint_double_integer
+.Lprint_double_decimals:
+    cmp rcx, r9
+    jae .Lprint_double_write
+    mov BYTE PTR [r13], '.'
+    inc r13
+.Lprint_double_decimals_loop:
+    cmp rcx, r9
+    jae .Lprint_double_write
+    mov dl, BYTE PTR [rsp + rcx]
+    mov BYTE PTR [r13], dl
+    inc r13
+    inc rcx
+    jmp .Lprint_double_decimals_loop
+
+    # `0.` Then The Zeros Before The First Digit
+.Lprint_double_fraction:
+    mov BYTE PTR [r13], '0'
+    mov BYTE PTR [r13 + 1], '.'
+    add r13, 2
+    mov rcx, r15
+    neg rcx
+    dec rcx
+.Lprint_double_fraction_zeros:
+    test rcx, rcx
+    jz .Lprint_double_fraction_digits
+    mov BYTE PTR [r13], '0'
+    inc r13
+    dec rcx
+    jmp .Lprint_double_fraction_zeros
+.Lprint_double_fraction_digits:
+    xor ecx, ecx
+.Lprint_double_fraction_loop:
+    cmp rcx, r9
+    jae .Lprint_double_write
+    mov dl, BYTE PTR [rsp + rcx]
+    mov BYTE PTR [r13], dl
+    inc r13
+    inc rcx
+    jmp .Lprint_double_fraction_loop
+
+.Lprint_double_nan:
+    lea rsi, [rip + .Lnan_text]
+    mov edx, 3
+    jmp .Lprint_double_copy
+.Lprint_double_inf:
+    lea rsi, [rip + .Linf_text]
+    mov edx, 3
+    jmp .Lprint_double_copy
+.Lprint_double_zero:
+    mov BYTE PTR [r13], '0'
+    inc r13
+    jmp .Lprint_double_write
+.Lprint_double_copy:
+    mov cl, BYTE PTR [rsi]
+    mov BYTE PTR [r13], cl
+    inc rsi
+    inc r13
+    dec edx
+    jnz .Lprint_double_copy
+
+.Lprint_double_write:
+    mov edi, ebx
+    mov rsi, r12
+    mov rdx, r13
+    sub rdx, r12
+    call engine_write
+    add rsp, 512
+    pop r15
+    pop r14
+    pop r13
+    pop r12
+    pop rbx
+    ret
+
+
+# engine_string_assign(target: *mut String, data: *const u8, length: u64), The
+# Data Is Copied Before The Old Buffer Is Freed Since It May Be The Same
+engine_string_assign:
+    push rbx
+    push r12
+    push r13
+    mov rbx, rdi
+    mov r12, rsi
+    mov r13, rdx
+    xor eax, eax
+    test r13, r13
+    jz .Lstring_assign_store
+    mov rdi, r13
+    call malloc@PLT
+    test rax, rax
+    jz engine_out_of_memory
+    mov rdi, rax
+    mov rsi, r12
+    mov rdx, r13
+    call memcpy@PLT
+.Lstring_assign_store:
+    mov r12, rax
+    mov rdi, QWORD PTR [rbx]
+    call free@PLT
+    mov QWORD PTR [rbx], r12
+    mov QWORD PTR [rbx + 8], r13
+    pop r13
+    pop r12
+    pop rbx
+    ret
+
+
+# engine_string_concat(target: *mut String, left: *const String, right: *const String)
+engine_string_concat:
+    push rbx
+    push r12
+    push r13
+    push r14
+    push r15
+    mov rbx, rdi
+    mov r12, rsi
+    mov r13, rdx
+    mov r14, QWORD PTR [r12 + 8]
+    add r14, QWORD PTR [r13 + 8]
+    xor r15d, r15d
+    test r14, r14
+    jz .Lstring_concat_store
+    mov rdi, r14
+    call malloc@PLT
+    test rax, rax
+    jz engine_out_of_memory
+    mov r15, rax
+    mov rdx, QWORD PTR [r12 + 8]
+    test rdx, rdx
+    jz .Lstring_concat_right
+    mov rdi, r15
+    mov rsi, QWORD PTR [r12]
+    call memcpy@PLT
+.Lstring_concat_right:
+    mov rdx, QWORD PTR [r13 + 8]
+    test rdx, rdx
+    jz .Lstring_concat_store
+    mov rdi, r15
+    add rdi, QWORD PTR [r12 + 8]
+    mov rsi, QWORD PTR [r13]
+    call memcpy@PLT
+.Lstring_concat_store:
+    mov rdi, QWORD PTR [rbx]
+    call free@PLT
+    mov QWORD PTR [rbx], r15
+    mov QWORD PTR [rbx + 8], r14
+    pop r15
+    pop r14
+    pop r13
+    pop r12
+    pop rbx
+    ret
+
+
+# engine_char_to_string(target: *mut String, code_point: u32)
+engine_char_to_string:
+    push rbx
+    sub rsp, 16
+    mov rbx, rdi
+    mov edi, esi
+    mov rsi, rsp
+    call engine_encode_char
+    mov rdi, rbx
+    mov rsi, rsp
+    mov rdx, rax
+    call engine_string_assign
+    add rsp, 16
+    pop rbx
+    ret
+
+
+# engine_input(target: *mut String, error: *const c_char), The Line Without Its
+# Trailing Spaces, Empty At The End Of The Input
+engine_input:
+    push rbx
+    push r12
+    sub rsp, 24
+    mov rbx, rdi
+    mov r12, rsi
+    mov QWORD PTR [rsp], 0
+    mov QWORD PTR [rsp + 8], 0
+    mov rdi, rsp
+    lea rsi, [rsp + 8]
+    mov rax, QWORD PTR [rip + stdin@GOTPCREL]
+    mov rdx, QWORD PTR [rax]
+    call getline@PLT
+    test rax, rax
+    js .Linput_end_of_file
+.Linput_trim:
+    test rax, rax
+    jz .Linput_store
+    mov rcx, QWORD PTR [rsp]
+    movzx edx, BYTE PTR [rcx + rax - 1]
+    cmp dl, ' '
+    je .Linput_trim_character
+    cmp dl, 9
+    jb .Linput_store
+    cmp dl, 13
+    ja .Linput_store
+.Linput_trim_character:
+    dec rax
+    jmp .Linput_trim
+.Linput_store:
+    mov r12, rax
+    mov rdi, QWORD PTR [rbx]
+    call free@PLT
+    mov rax, QWORD PTR [rsp]
+    mov QWORD PTR [rbx], rax
+    mov QWORD PTR [rbx + 8], r12
+    jmp .Linput_end
+.Linput_end_of_file:
+    mov rax, QWORD PTR [rip + stdin@GOTPCREL]
+    mov rdi, QWORD PTR [rax]
+    call ferror@PLT
+    test eax, eax
+    jnz .Linput_error
+    xor eax, eax
+    jmp .Linput_store
+.Linput_error:
+    mov rdi, r12
+    call engine_error
+.Linput_end:
+    add rsp, 24
+    pop r12
+    pop rbx
+    ret
+
+
+# engine_string_to_int(source: *const String, error: *const c_char) -> i64, A
+# Sign Then Only Digits, The Value Is Built Negative To Reach The Minimum
+engine_string_to_int:
+    mov rcx, QWORD PTR [rdi]
+    mov r8, QWORD PTR [rdi + 8]
+    xor eax, eax
+    xor r9d, r9d
+    test r8, r8
+    jz .Lstring_to_int_error
+    movzx edx, BYTE PTR [rcx]
+    cmp dl, '-'
+    jne .Lstring_to_int_plus
+    mov r9d, 1
+    inc rcx
+    dec r8
+    jmp .Lstring_to_int_digits
+.Lstring_to_int_plus:
+    cmp dl, '+'
+    jne .Lstring_to_int_digits
+    inc rcx
+    dec r8
+.Lstring_to_int_digits:
+    test r8, r8
+    jz .Lstring_to_int_error
+.Lstring_to_int_loop:
+    movzx edx, BYTE PTR [rcx]
+    sub edx, '0'
+    cmp edx, 9
+    ja .Lstring_to_int_error
+    imul rax, rax, 10
+    jo .Lstring_to_int_error
+    sub rax, rdx
+    jo .Lstring_to_int_error
+    inc rcx
+    dec r8
+    jnz .Lstring_to_int_loop
+    test r9d, r9d
+    jnz .Lstring_to_int_end
+    neg rax
+    jo .Lstring_to_int_error
+.Lstring_to_int_end:
+    ret
+.Lstring_to_int_error:
+    sub rsp, 8
+    mov rdi, rsi
+    call engine_error
+
+
+# engine_is_float_text(data: *const u8, length: u64) -> bool, The Rust Grammar: A
+# Sign Then `inf`, `infinity`, `nan` Or Digits With An Optional Point And Exponent
+engine_is_float_text:
+    test rsi, rsi
+    jz .Lfloat_text_invalid
+    movzx edx, BYTE PTR [rdi]
+    cmp dl, '+'
+    je .Lfloat_text_sign
+    cmp dl, '-'
+    jne .Lfloat_text_words
+.Lfloat_text_sign:
+    inc rdi
+    dec rsi
+.Lfloat_text_words:
+    cmp rsi, 3
+    je .Lfloat_text_three_letters
+    cmp rsi, 8
+    jne .Lfloat_text_number
+    mov rcx, QWORD PTR [rdi]
+    movabs rdx, 0x2020202020202020
+    or rcx, rdx
+    movabs rdx, 0x7974696E69666E69
+    cmp rcx, rdx
+    je .Lfloat_text_valid
+    jmp .Lfloat_text_number
+.Lfloat_text_three_letters:
+    movzx ecx, BYTE PTR [rdi]
+    movzx edx, BYTE PTR [rdi + 1]
+    shl edx, 8
+    or ecx, edx
+    movzx edx, BYTE PTR [rdi + 2]
+    shl edx, 16
+    or ecx, edx
+    or ecx, 0x202020
+    cmp ecx, 0x666E69
+    je .Lfloat_text_valid
+    cmp ecx, 0x6E616E
+    je .Lfloat_text_valid
+
+.Lfloat_text_number:
+    xor ecx, ecx
+.Lfloat_text_integer:
+    test rsi, rsi
+    jz .Lfloat_text_end
+    movzx edx, BYTE PTR [rdi]
+    sub edx, '0'
+    cmp edx, 9
+    ja .Lfloat_text_point
+    inc ecx
+    inc rdi
+    dec rsi
+    jmp .Lfloat_text_integer
+.Lfloat_text_point:
+    cmp BYTE PTR [rdi], '.'
+    jne .Lfloat_text_exponent
+    inc rdi
+    dec rsi
+.Lfloat_text_decimals:
+    test rsi, rsi
+    jz .Lfloat_text_end
+    movzx edx, BYTE PTR [rdi]
+    sub edx, '0'
+    cmp edx, 9
+    ja .Lfloat_text_exponent
+    inc ecx
+    inc rdi
+    dec rsi
+    jmp .Lfloat_text_decimals
+.Lfloat_text_exponent:
+    test ecx, ecx
+    jz .Lfloat_text_invalid
+    movzx edx, BYTE PTR [rdi]
+    or edx, 0x20
+    cmp edx, 'e'
+    jne .Lfloat_text_invalid
+    inc rdi
+    dec rsi
+    jz .Lfloat_text_invalid
+    movzx edx, BYTE PTR [rdi]
+    cmp dl, '+'
+    je .Lfloat_text_exponent_sign
+    cmp dl, '-'
+    jne .Lfloat_text_exponent_digits
+.Lfloat_text_exponent_sign:
+    inc rdi
+    dec rsi
+    jz .Lfloat_text_invalid
+.Lfloat_text_exponent_digits:
+    movzx edx, BYTE PTR [rdi]
+    sub edx, '0'
+    cmp edx, 9
+    ja .Lfloat_text_invalid
+    inc rdi
+    dec rsi
+    jnz .Lfloat_text_exponent_digits
+    jmp .Lfloat_text_valid
+.Lfloat_text_end:
+    test ecx, ecx
+    jz .Lfloat_text_invalid
+.Lfloat_text_valid:
+    mov eax, 1
+    ret
+.Lfloat_text_invalid:
+    xor eax, eax
+    ret
+
+
+# engine_string_to_double(source: *const String, error: *const c_char) -> f64,
+# Checked Then Read By `strtod` From A Copy Ending With A Zero
+engine_string_to_double:
+    push rbx
+    push r12
+    push r13
+    mov rbx, QWORD PTR [rdi]
+    mov r12, QWORD PTR [rdi + 8]
+    mov r13, rsi
+    mov rdi, rbx
+    mov rsi, r12
+    call engine_is_float_text
+    test eax, eax
+    jz .Lstring_to_double_error
+    lea rdi, [r12 + 1]
+    call malloc@PLT
+    test rax, rax
+    jz engine_out_of_memory
+    mov rdi, rax
+    mov rsi, rbx
+    mov rdx, r12
+    call memcpy@PLT
+    mov BYTE PTR [rax + r12], 0
+    mov rbx, rax
+    mov rdi, rax
+    xor esi, esi
+    call strtod@PLT
+    sub rsp, 16
+    movsd QWORD PTR [rsp], xmm0
+    mov rdi, rbx
+    call free@PLT
+    movsd xmm0, QWORD PTR [rsp]
+    add rsp, 16
+    pop r13
+    pop r12
+    pop rbx
+    ret
+.Lstring_to_double_error:
+    mov rdi, r13
+    call engine_error
+
+
+# engine_string_to_bool(source: *const String, error: *const c_char) -> bool, Only `true` Or `false`
+engine_string_to_bool:
+    mov rcx, QWORD PTR [rdi]
+    mov r8, QWORD PTR [rdi + 8]
+    cmp r8, 4
+    jne .Lstring_to_bool_false
+    cmp DWORD PTR [rcx], 0x65757274
+    jne .Lstring_to_bool_error
+    mov eax, 1
+    ret
+.Lstring_to_bool_false:
+    cmp r8, 5
+    jne .Lstring_to_bool_error
+    cmp DWORD PTR [rcx], 0x736C6166
+    jne .Lstring_to_bool_error
+    cmp BYTE PTR [rcx + 4], 'e'
+    jne .Lstring_to_bool_error
+    xor eax, eax
+    ret
+.Lstring_to_bool_error:
+    sub rsp, 8
+    mov rdi, rsi
+    call engine_error
+
+
+# engine_string_to_char(source: *const String, error: *const c_char) -> u32, The First Character
+engine_string_to_char:
+    mov rcx, QWORD PTR [rdi]
+    mov r8, QWORD PTR [rdi + 8]
+    test r8, r8
+    jz .Lstring_to_char_error
+    movzx eax, BYTE PTR [rcx]
+    cmp eax, 0x80
+    jb .Lstring_to_char_end
+    cmp eax, 0xE0
+    jb .Lstring_to_char_two
+    cmp eax, 0xF0
+    jb .Lstring_to_char_three
+    and eax, 0x07
+    mov r9d, 3
+    jmp .Lstring_to_char_continuation
+.Lstring_to_char_two:
+    and eax, 0x1F
+    mov r9d, 1
+    jmp .Lstring_to_char_continuation
+.Lstring_to_char_three:
+    and eax, 0x0F
+    mov r9d, 2
+.Lstring_to_char_continuation:
+    cmp r8, r9
+    jbe .Lstring_to_char_error
+.Lstring_to_char_loop:
+    inc rcx
+    movzx edx, BYTE PTR [rcx]
+    and edx, 0x3F
+    shl eax, 6
+    or eax, edx
+    dec r9d
+    jnz .Lstring_to_char_loop
+.Lstring_to_char_end:
+    ret
+.Lstring_to_char_error:
+    sub rsp, 8
+    mov rdi, rsi
+    call engine_error
+
+
+# engine_double_to_int(value: f64) -> i64, Saturated Like Rust `as`, `NaN` Gives 0
+engine_double_to_int:
+    ucomisd xmm0, xmm0
+    jp .Ldouble_to_int_nan
+    movabs rax, 0x43E0000000000000
+    movq xmm1, rax
+    ucomisd xmm0, xmm1
+    jae .Ldouble_to_int_maximum
+    cvttsd2si rax, xmm0
+    ret
+.Ldouble_to_int_maximum:
+    movabs rax, 0x7FFFFFFFFFFFFFFF
+    ret
+.Ldouble_to_int_nan:
+    xor eax, eax
+    ret
+
+
+# engine_write_text(text: *const c_char), Written To The Standard Output Like The Errors
+engine_write_text:
+    push rbx
+    mov rbx, rdi
+    call strlen@PLT
+    mov rdx, rax
+    mov rsi, rbx
+    mov edi, 1
+    pop rbx
+    jmp engine_write
+
+
+# engine_int_abs(value: i64, error: *const c_char) -> i64, Only The Minimum Overflows
+engine_int_abs:
+    mov rax, rdi
+    neg rax
+    jo .Lint_abs_error
+    cmovs rax, rdi
+    ret
+.Lint_abs_error:
+    sub rsp, 8
+    mov rdi, rsi
+    call engine_error
+
+
+# engine_int_pow(base: i64, exponent: i64, location: *const c_char) -> i64, Squared
+# Like Rust `checked_pow`, The Last Bit Returns Before Squaring The Base Again
+engine_int_pow:
+    test rsi, rsi
+    js .Lint_pow_negative
+    mov eax, 0xFFFFFFFF
+    cmp rsi, rax
+    ja .Lint_pow_overflow
+    mov eax, 1
+    mov rcx, rdi
+    mov r8, rsi
+    test r8, r8
+    jz .Lint_pow_end
+.Lint_pow_loop:
+    test r8, 1
+    jz .Lint_pow_square
+    imul rax, rcx
+    jo .Lint_pow_overflow
+    cmp r8, 1
+    je .Lint_pow_end
+.Lint_pow_square:
+    shr r8, 1
+    imul rcx, rcx
+    jo .Lint_pow_overflow
+    jmp .Lint_pow_loop
+.Lint_pow_end:
+    ret
+.Lint_pow_negative:
+    push rbx
+    push r12
+    push r13
+    mov r12, rsi
+    mov r13, rdx
+    lea rdi, [rip + .Lpow_exponent_text]
+    call engine_write_text
+    mov edi, 1
+    mov rsi, r12
+    call engine_print_int
+    lea rdi, [rip + .Lpow_negative_text]
+    call engine_write_text
+    mov rdi, r13
+    call engine_error
+.Lint_pow_overflow:
+    push rbx
+    push r12
+    push r13
+    mov rbx, rdi
+    mov r12, rsi
+    mov r13, rdx
+    lea rdi, [rip + .Lpow_result_text]
+    call engine_write_text
+    mov edi, 1
+    mov rsi, rbx
+    call engine_print_int
+    lea rdi, [rip + .Lpow_and_text]
+    call engine_write_text
+    mov edi, 1
+    mov rsi, r12
+    call engine_print_int
+    lea rdi, [rip + .Lpow_overflow_text]
+    call engine_write_text
+    mov rdi, r13
+    call engine_error
+
+
+# engine_checked_double_to_int(value: f64, prefix: *const c_char, suffix: *const c_char) -> i64,
+# The Results Of `floor`, `ceil` And `round`, An Error Prints The Value Between The Texts
+engine_checked_double_to_int:
+    ucomisd xmm0, xmm0
+    jp .Lchecked_double_to_int_error
+    movabs rax, 0x43E0000000000000
+    movq xmm1, rax
+    ucomisd xmm0, xmm1
+    jae .Lchecked_double_to_int_error
+    movabs rax, 0xC3E0000000000000
+    movq xmm1, rax
+    ucomisd xmm0, xmm1
+    jb .Lchecked_double_to_int_error
+    cvttsd2si rax, xmm0
+    ret
+.Lchecked_double_to_int_error:
+    push rbx
+    push r12
+    sub rsp, 24
+    mov r12, rsi
+    movsd QWORD PTR [rsp], xmm0
+    call engine_write_text
+    mov edi, 1
+    movsd xmm0, QWORD PTR [rsp]
+    call engine_print_double
+    mov rdi, r12
+    call engine_error
+
+    .section .note.GNU-stack,"",@progbits
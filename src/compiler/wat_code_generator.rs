@@ -0,0 +1,390 @@
+use crate::tokens::TokenType;
+use crate::syntax_tree::OperatorType;
+use crate::ir::{
+    IrProgram,
+    IrInstruction,
+    IrInstructionKind,
+    IrTerminator
+};
+
+
+/* Imports, Strings, Printing, Input And Conversions Used By The Generated Code */
+const WAT_RUNTIME_SOURCE: &'static str = include_str!("wat_runtime.wat");
+
+/* The Runtime Has No Built-in Functions Yet, A Call Is Rejected Before The Module Is Printed */
+pub const WAT_FUNCTIONS: [&'static str; 0] = [];
+
+/* The Runtime Keeps The Memory Below This Address For Itself */
+const DATA_ADDRESS: usize = 12288;
+
+
+/* The Body Of `_start`, With The Strings It Reads Kept Apart Until The End */
+#[derive(Debug)]
+struct Module<'a>{
+    program: &'a IrProgram,
+    lines: Vec<String>,
+    data: Vec<u8>,
+}
+
+impl<'a> Module<'a>{
+    fn get_type(&self, name: &String) -> TokenType{
+        return self.program.get_variable_type(name).unwrap();
+    }
+
+    /* A Runtime String, Its Length Followed By Its Bytes, The Empty String Is At 0 */
+    fn add_string(&mut self, value: &String) -> usize{
+        if value.is_empty(){
+            return 0;
+        }
+
+        while self.data.len() % 4 != 0{
+            self.data.push(0);
+        }
+
+        let address = DATA_ADDRESS + self.data.len();
+        self.data.extend((value.len() as u32).to_le_bytes());
+        self.data.extend(value.bytes());
+        return address;
+    }
+
+    fn emit(&mut self, line: String){
+        self.lines.push(format!("    {}", line));
+    }
+}
+
+
+fn get_local(name: &String) -> String{
+    return format!("$v_{}", name);
+}
+
+
+fn get_wat_type(variable_type: &TokenType) -> &'static str{
+    if variable_type == &TokenType::Int{
+        return "i64";
+    }
+    else if variable_type == &TokenType::Double{
+        return "f64";
+    }
+    return "i32";
+}
+
+
+/* A WebAssembly Text String, Every Byte Out Of Printable ASCII Is Escaped */
+fn get_wat_string(data: &Vec<u8>) -> String{
+    let mut text = String::from("\"");
+    for byte in data{
+        if *byte == b'"' || *byte == b'\\' || *byte < 0x20 || *byte >= 0x7F{
+            text.push_str(&format!("\\{:02x}", byte));
+        }
+        else{
+            text.push(*byte as char);
+        }
+    }
+    text.push('"');
+
+    return text;
+}
+
+
+/*
+* Every Variable Is A Local Of `_start`, Zeroed Like Their Default Values. The Blocks
+* Are Nested `block`s Inside A Loop, Entered Through A `br_table` On The Block Number.
+* The Runtime Comes First, The Program Strings And The Heap Follow Its Memory.
+*/
+pub fn get_wat_text(program: &IrProgram) -> Result<String, String>{
+    let mut module = Module{
+        program,
+        lines: vec![],
+        data: vec![],
+    };
+
+    module.lines.push(String::from("  (func $start (export \"_start\")"));
+    module.emit(String::from("(local $block i32)"));
+    for variable in &program.variables{
+        module.emit(format!(
+            "(local {} {})", get_local(&variable.name), get_wat_type(&variable.variable_type)));
+    }
+
+    module.emit(String::from("loop $dispatch"));
+    for index in (0..program.blocks.len()).rev(){
+        module.emit(format!("block $block_{}", index));
+    }
+    module.emit(String::from("local.get $block"));
+    let targets: Vec<String> = (0..program.blocks.len()).map(|index| format!("$block_{}", index)).collect();
+    module.emit(format!("br_table {}", targets.join(" ")));
+
+    for (index, block) in program.blocks.iter().enumerate(){
+        module.emit(format!("end ;; block_{}", index));
+
+        for instruction in &block.instructions{
+            generate_instruction(&mut module, instruction)?;
+        }
+
+        match &block.terminator {
+            IrTerminator::Jump{target} => {
+                module.emit(format!("i32.const {}", target));
+                module.emit(String::from("local.set $block"));
+                module.emit(String::from("br $dispatch"));
+            },
+            IrTerminator::Branch{condition, then_block, else_block} => {
+                module.emit(format!("i32.const {}", then_block));
+                module.emit(format!("i32.const {}", else_block));
+                module.emit(format!("local.get {}", get_local(condition)));
+                module.emit(String::from("select"));
+                module.emit(String::from("local.set $block"));
+                module.emit(String::from("br $dispatch"));
+            },
+            IrTerminator::End => module.emit(String::from("return")),
+        };
+    }
+    module.emit(String::from("end)"));
+
+    let heap_address = (DATA_ADDRESS + module.data.len() + 7) / 8 * 8;
+
+    let mut text = String::from("(module\n");
+    text.push_str(WAT_RUNTIME_SOURCE);
+    text.push_str(&format!("\n  (global $heap (mut i32) (i32.const {}))\n", heap_address));
+    if !module.data.is_empty(){
+        text.push_str(&format!(
+            "  (data (i32.const {}) {})\n", DATA_ADDRESS, get_wat_string(&module.data)));
+    }
+    text.push('\n');
+    text.push_str(&module.lines.join("\n"));
+    text.push_str(")\n");
+
+    return Ok(text);
+}
+
+
+fn generate_instruction(
+    module: &mut Module, instruction: &IrInstruction
+) -> Result<(), String>{
+
+    let line = instruction.start_line;
+    let pos = instruction.start_pos;
+
+    match &instruction.kind {
+        IrInstructionKind::Constant{target, value} => {
+            let target_type = module.get_type(target);
+
+            if target_type == TokenType::Bool{
+                module.emit(format!("i32.const {}", if value == "True" {1} else {0}));
+            }
+            else if target_type == TokenType::Int{
+                let number = value.parse::<i64>();
+                if number.is_err(){
+                    return Err(format!(
+                        "Engine Compiler: Code Generation Error -> Invalid `Int` constant `{}`, line: {}:{}",
+                        value, line, pos));
+                }
+
+                module.emit(format!("i64.const {}", number.unwrap()));
+            }
+            else if target_type == TokenType::Double{
+                let number = value.parse::<f64>().unwrap_or(0.0);
+
+                if number.is_nan(){
+                    module.emit(String::from("f64.const nan"));
+                }
+                else if number.is_infinite(){
+                    module.emit(String::from(if number > 0.0 {"f64.const inf"} else {"f64.const -inf"}));
+                }
+                else{
+                    module.emit(format!("f64.const {:e}", number));
+                }
+            }
+            else if target_type == TokenType::Char{
+                module.emit(format!("i32.const {}", value.chars().nth(0).unwrap_or('\0') as u32));
+            }
+            else{
+                let address = module.add_string(value);
+                module.emit(format!("i32.const {}", address));
+            }
+
+            module.emit(format!("local.set {}", get_local(target)));
+        },
+
+        IrInstructionKind::Convert{target, source} => {
+            generate_convert(module, target, source, line, pos)?;
+        },
+
+        IrInstructionKind::Operation{operator, target, left, right} => {
+            generate_operation(module, operator, target, left, right, line, pos)?;
+        },
+
+        IrInstructionKind::Call{function, target: _, arguments: _} => {
+            return Err(format!(
+                "Engine Compiler: Code Generation Error -> Function `{}` is not supported by the WebAssembly backend, line: {}:{}",
+                function, line, pos));
+        },
+
+        IrInstructionKind::Input{target} => {
+            let message = module.add_string(&format!(
+                "Engine Compiler: Compiler Error -> Failed to read input, line: {}:{}", line, pos));
+
+            module.emit(format!("i32.const {}", message));
+            module.emit(String::from("call $input"));
+            module.emit(format!("local.set {}", get_local(target)));
+        },
+
+        IrInstructionKind::Print{source, is_stderr} => {
+            let source_type = module.get_type(source);
+
+            let function = if source_type == TokenType::Bool{
+                "$print_bool"
+            }
+            else if source_type == TokenType::Int{
+                "$print_int"
+            }
+            else if source_type == TokenType::Double{
+                "$print_double"
+            }
+            else if source_type == TokenType::Char{
+                "$print_char"
+            }
+            else{
+                "$print_string"
+            };
+
+            module.emit(format!("i32.const {}", if *is_stderr {2} else {1}));
+            module.emit(format!("local.get {}", get_local(source)));
+            module.emit(format!("call {}", function));
+        },
+    };
+
+    return Ok(());
+}
+
+
+/* The Same Conversions As The Rust Executables, Strings Are Parsed When The Program Runs */
+fn generate_convert(
+    module: &mut Module, target: &String, source: &String, line: u64, pos: u64
+) -> Result<(), String>{
+
+    let target_type = module.get_type(target);
+    let source_type = module.get_type(source);
+
+    if target_type == source_type{
+        module.emit(format!("local.get {}", get_local(source)));
+    }
+    else if target_type == TokenType::Int && source_type == TokenType::Double{
+        module.emit(format!("local.get {}", get_local(source)));
+        module.emit(String::from("i64.trunc_sat_f64_s"));
+    }
+    else if target_type == TokenType::Double && source_type == TokenType::Int{
+        module.emit(format!("local.get {}", get_local(source)));
+        module.emit(String::from("f64.convert_i64_s"));
+    }
+    else if target_type == TokenType::String && source_type == TokenType::Char{
+        module.emit(format!("local.get {}", get_local(source)));
+        module.emit(String::from("call $char_to_string"));
+    }
+    else if source_type == TokenType::String{
+        let function = if target_type == TokenType::Bool{
+            "$string_to_bool"
+        }
+        else if target_type == TokenType::Int{
+            "$string_to_int"
+        }
+        else if target_type == TokenType::Double{
+            "$string_to_double"
+        }
+        else{
+            "$string_to_char"
+        };
+
+        let message = module.add_string(&format!(
+            "Engine Compiler: Convert Error -> can't convert from `String` to `{:?}`, line: {}:{}",
+            target_type, line, pos));
+
+        module.emit(format!("local.get {}", get_local(source)));
+        module.emit(format!("i32.const {}", message));
+        module.emit(format!("call {}", function));
+    }
+    else{
+        return Err(format!(
+            "Engine Compiler: Code Generation Error -> Can't convert `{:?}` to `{:?}`, line: {}:{}",
+            source_type, target_type, line, pos));
+    }
+
+    module.emit(format!("local.set {}", get_local(target)));
+
+    return Ok(());
+}
+
+
+/* Integers Wrap Like In The VM, `i64.rem_s` Gives 0 For The Minimum Modulo -1 */
+fn generate_operation(
+    module: &mut Module, operator: &OperatorType, target: &String, left: &String, right: &String,
+    line: u64, pos: u64
+) -> Result<(), String>{
+
+    let operands_type = module.get_type(left);
+
+    module.emit(format!("local.get {}", get_local(left)));
+    module.emit(format!("local.get {}", get_local(right)));
+
+    let instruction = if operands_type == TokenType::String && operator == &OperatorType::Plus{
+        Some(String::from("call $string_concat"))
+    }
+    else if operands_type == TokenType::Int && operator == &OperatorType::Mod{
+        let message = module.add_string(&format!(
+            "Engine Compiler: Compiler Error -> Division by zero, line: {}:{}", line, pos));
+
+        module.emit(format!("i32.const {}", message));
+        Some(String::from("call $int_mod"))
+    }
+    else if operands_type == TokenType::Int{
+        match operator {
+            OperatorType::Plus => Some(String::from("i64.add")),
+            OperatorType::Minus => Some(String::from("i64.sub")),
+            OperatorType::Mul => Some(String::from("i64.mul")),
+            OperatorType::Equal => Some(String::from("i64.eq")),
+            OperatorType::NotEqual => Some(String::from("i64.ne")),
+            OperatorType::LessThan => Some(String::from("i64.lt_s")),
+            OperatorType::LessThanOrEqual => Some(String::from("i64.le_s")),
+            OperatorType::GreaterThan => Some(String::from("i64.gt_s")),
+            OperatorType::GreaterThanOrEqual => Some(String::from("i64.ge_s")),
+            _ => None,
+        }
+    }
+    else if operands_type == TokenType::Double{
+        match operator {
+            OperatorType::Plus => Some(String::from("f64.add")),
+            OperatorType::Minus => Some(String::from("f64.sub")),
+            OperatorType::Mul => Some(String::from("f64.mul")),
+            OperatorType::Div => Some(String::from("f64.div")),
+            OperatorType::Mod => Some(String::from("call $double_mod")),
+            OperatorType::Equal => Some(String::from("f64.eq")),
+            OperatorType::NotEqual => Some(String::from("f64.ne")),
+            OperatorType::LessThan => Some(String::from("f64.lt")),
+            OperatorType::LessThanOrEqual => Some(String::from("f64.le")),
+            OperatorType::GreaterThan => Some(String::from("f64.gt")),
+            OperatorType::GreaterThanOrEqual => Some(String::from("f64.ge")),
+            _ => None,
+        }
+    }
+    else if operands_type == TokenType::Bool{
+        match operator {
+            OperatorType::And => Some(String::from("i32.and")),
+            OperatorType::Or => Some(String::from("i32.or")),
+            OperatorType::Equal => Some(String::from("i32.eq")),
+            OperatorType::NotEqual => Some(String::from("i32.ne")),
+            _ => None,
+        }
+    }
+    else{
+        None
+    };
+
+    if instruction == None{
+        return Err(format!(
+            "Engine Compiler: Code Generation Error -> Invalid operation `{:?}` on `{:?}`, line: {}:{}",
+            operator, operands_type, line, pos));
+    }
+
+    module.emit(instruction.unwrap());
+    module.emit(format!("local.set {}", get_local(target)));
+
+    return Ok(());
+}
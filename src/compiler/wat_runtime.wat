@@ -0,0 +1,2446 @@
+  ;; Engine WebAssembly Runtime, Included Once In Every Module Emitted With `--emit=wat`.
+  ;;
+  ;; A `string` is the address of its length as an `i32`, followed by its bytes. Strings
+  ;; are never changed once made, the address 0 is the empty string.
+  ;;
+  ;; Memory layout:
+  ;;     0        the empty string
+  ;;     8        `iovec` given to `fd_write` and `fd_read`, 16 holds the bytes count
+  ;;     32       digits of a printed `int`
+  ;;     96       bytes of a printed `char`
+  ;;     256      runtime texts and messages
+  ;;     1024     eight big integers of 128 limbs, used to print and read a `double`
+  ;;     6144     text of a printed `double`, 6656 holds its digits
+  ;;     8192     input buffer
+  ;;     12288    program strings, followed by the heap
+
+  (import "wasi_snapshot_preview1" "fd_write" (func $fd_write (param i32 i32 i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "fd_read" (func $fd_read (param i32 i32 i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "proc_exit" (func $proc_exit (param i32)))
+
+  (memory (export "memory") 1)
+
+  (global $input_position (mut i32) (i32.const 0))
+  (global $input_length (mut i32) (i32.const 0))
+
+  (data (i32.const 256) "True")
+  (data (i32.const 264) "False")
+  (data (i32.const 272) "NaN")
+  (data (i32.const 280) "inf")
+  (data (i32.const 284) "nan")
+  (data (i32.const 288) "\0a")
+  (data (i32.const 296) "infinity")
+  (data (i32.const 304) "\3f\00\00\00Engine Compiler: Compiler Error -> Error in printing to console")
+  (data (i32.const 376) "\30\00\00\00Engine Compiler: Compiler Error -> Out of memory")
+  (data (i32.const 432) "true\00\00\00\00false")
+
+
+  ;; Memory
+
+  (func $reserve (param $end i32)
+    block $reserved
+      loop $grow
+        local.get $end
+        memory.size
+        i32.const 16
+        i32.shl
+        i32.le_u
+        br_if $reserved
+        i32.const 1
+        memory.grow
+        i32.const -1
+        i32.eq
+        if
+          i32.const 376
+          call $error
+        end
+        br $grow
+      end
+    end)
+
+  (func $alloc (param $size i32) (result i32)
+    (local $address i32)
+    global.get $heap
+    local.set $address
+    local.get $address
+    local.get $size
+    i32.add
+    i32.const 7
+    i32.add
+    i32.const -8
+    i32.and
+    global.set $heap
+    global.get $heap
+    call $reserve
+    local.get $address)
+
+
+  ;; Output
+
+  (func $write_all (param $fd i32) (param $data i32) (param $length i32) (result i32)
+    (local $errno i32)
+    block $written
+      loop $write
+        local.get $length
+        i32.eqz
+        br_if $written
+        i32.const 8
+        local.get $data
+        i32.store
+        i32.const 12
+        local.get $length
+        i32.store
+        local.get $fd
+        i32.const 8
+        i32.const 1
+        i32.const 16
+        call $fd_write
+        local.tee $errno
+        if
+          local.get $errno
+          return
+        end
+        i32.const 16
+        i32.load
+        i32.eqz
+        if
+          i32.const 1
+          return
+        end
+        local.get $data
+        i32.const 16
+        i32.load
+        i32.add
+        local.set $data
+        local.get $length
+        i32.const 16
+        i32.load
+        i32.sub
+        local.set $length
+        br $write
+      end
+    end
+    i32.const 0)
+
+  ;; Prints The Message On The Standard Output And Exits Like A Failed Engine Program
+  (func $error (param $message i32)
+    i32.const 1
+    local.get $message
+    i32.const 4
+    i32.add
+    local.get $message
+    i32.load
+    call $write_all
+    drop
+    i32.const 1
+    i32.const 288
+    i32.const 1
+    call $write_all
+    drop
+    i32.const 101
+    call $proc_exit
+    unreachable)
+
+  (func $write (param $fd i32) (param $data i32) (param $length i32)
+    local.get $fd
+    local.get $data
+    local.get $length
+    call $write_all
+    if
+      i32.const 304
+      call $error
+    end)
+
+  (func $print_string (param $fd i32) (param $value i32)
+    local.get $fd
+    local.get $value
+    i32.const 4
+    i32.add
+    local.get $value
+    i32.load
+    call $write)
+
+  (func $print_bool (param $fd i32) (param $value i32)
+    local.get $value
+    if
+      local.get $fd
+      i32.const 256
+      i32.const 4
+      call $write
+    else
+      local.get $fd
+      i32.const 264
+      i32.const 5
+      call $write
+    end)
+
+  (func $print_int (param $fd i32) (param $value i64)
+    (local $position i32)
+    (local $magnitude i64)
+    i32.const 64
+    local.set $position
+    local.get $value
+    local.set $magnitude
+    local.get $value
+    i64.const 0
+    i64.lt_s
+    if
+      i64.const 0
+      local.get $value
+      i64.sub
+      local.set $magnitude
+    end
+    loop $digits
+      local.get $position
+      i32.const 1
+      i32.sub
+      local.tee $position
+      local.get $magnitude
+      i64.const 10
+      i64.rem_u
+      i32.wrap_i64
+      i32.const 48
+      i32.add
+      i32.store8
+      local.get $magnitude
+      i64.const 10
+      i64.div_u
+      local.tee $magnitude
+      i64.const 0
+      i64.ne
+      br_if $digits
+    end
+    local.get $value
+    i64.const 0
+    i64.lt_s
+    if
+      local.get $position
+      i32.const 1
+      i32.sub
+      local.tee $position
+      i32.const 45
+      i32.store8
+    end
+    local.get $fd
+    local.get $position
+    i32.const 64
+    local.get $position
+    i32.sub
+    call $write)
+
+  (func $encode_char (param $code_point i32) (param $buffer i32) (result i32)
+    local.get $code_point
+    i32.const 0x80
+    i32.lt_u
+    if
+      local.get $buffer
+      local.get $code_point
+      i32.store8
+      i32.const 1
+      return
+    end
+    local.get $code_point
+    i32.const 0x800
+    i32.lt_u
+    if
+      local.get $buffer
+      local.get $code_point
+      i32.const 6
+      i32.shr_u
+      i32.const 0xC0
+      i32.or
+      i32.store8
+      local.get $buffer
+      local.get $code_point
+      i32.const 0x3F
+      i32.and
+      i32.const 0x80
+      i32.or
+      i32.store8 offset=1
+      i32.const 2
+      return
+    end
+    local.get $code_point
+    i32.const 0x10000
+    i32.lt_u
+    if
+      local.get $buffer
+      local.get $code_point
+      i32.const 12
+      i32.shr_u
+      i32.const 0xE0
+      i32.or
+      i32.store8
+      local.get $buffer
+      local.get $code_point
+      i32.const 6
+      i32.shr_u
+      i32.const 0x3F
+      i32.and
+      i32.const 0x80
+      i32.or
+      i32.store8 offset=1
+      local.get $buffer
+      local.get $code_point
+      i32.const 0x3F
+      i32.and
+      i32.const 0x80
+      i32.or
+      i32.store8 offset=2
+      i32.const 3
+      return
+    end
+    local.get $buffer
+    local.get $code_point
+    i32.const 18
+    i32.shr_u
+    i32.const 0xF0
+    i32.or
+    i32.store8
+    local.get $buffer
+    local.get $code_point
+    i32.const 12
+    i32.shr_u
+    i32.const 0x3F
+    i32.and
+    i32.const 0x80
+    i32.or
+    i32.store8 offset=1
+    local.get $buffer
+    local.get $code_point
+    i32.const 6
+    i32.shr_u
+    i32.const 0x3F
+    i32.and
+    i32.const 0x80
+    i32.or
+    i32.store8 offset=2
+    local.get $buffer
+    local.get $code_point
+    i32.const 0x3F
+    i32.and
+    i32.const 0x80
+    i32.or
+    i32.store8 offset=3
+    i32.const 4)
+
+  (func $print_char (param $fd i32) (param $code_point i32)
+    local.get $fd
+    i32.const 96
+    local.get $code_point
+    i32.const 96
+    call $encode_char
+    call $write)
+
+  ;; Prints Like Rust, Without An Exponent And With The Fewest Digits Read Back As The Same Value
+  (func $print_double (param $fd i32) (param $value f64)
+    (local $position i32)
+    local.get $value
+    local.get $value
+    f64.ne
+    if
+      local.get $fd
+      i32.const 272
+      i32.const 3
+      call $write
+      return
+    end
+    i32.const 6144
+    local.set $position
+    local.get $value
+    i64.reinterpret_f64
+    i64.const 0
+    i64.lt_s
+    if
+      local.get $position
+      i32.const 45
+      i32.store8
+      local.get $position
+      i32.const 1
+      i32.add
+      local.set $position
+    end
+    local.get $value
+    f64.abs
+    local.tee $value
+    f64.const inf
+    f64.eq
+    if
+      local.get $position
+      i32.const 280
+      i32.const 3
+      memory.copy
+      local.get $position
+      i32.const 3
+      i32.add
+      local.set $position
+    else
+      local.get $value
+      f64.const 0
+      f64.eq
+      if
+        local.get $position
+        i32.const 48
+        i32.store8
+        local.get $position
+        i32.const 1
+        i32.add
+        local.set $position
+      else
+        local.get $value
+        local.get $position
+        call $format_shortest
+        local.set $position
+      end
+    end
+    local.get $fd
+    i32.const 6144
+    local.get $position
+    i32.const 6144
+    i32.sub
+    call $write)
+
+
+  ;; Strings
+
+  (func $string_concat (param $left i32) (param $right i32) (result i32)
+    (local $length i32)
+    (local $result i32)
+    local.get $left
+    i32.load
+    local.get $right
+    i32.load
+    i32.add
+    local.tee $length
+    i32.eqz
+    if
+      i32.const 0
+      return
+    end
+    local.get $length
+    i32.const 4
+    i32.add
+    call $alloc
+    local.tee $result
+    local.get $length
+    i32.store
+    local.get $result
+    i32.const 4
+    i32.add
+    local.get $left
+    i32.const 4
+    i32.add
+    local.get $left
+    i32.load
+    memory.copy
+    local.get $result
+    i32.const 4
+    i32.add
+    local.get $left
+    i32.load
+    i32.add
+    local.get $right
+    i32.const 4
+    i32.add
+    local.get $right
+    i32.load
+    memory.copy
+    local.get $result)
+
+  (func $char_to_string (param $code_point i32) (result i32)
+    (local $result i32)
+    i32.const 8
+    call $alloc
+    local.set $result
+    local.get $result
+    local.get $code_point
+    local.get $result
+    i32.const 4
+    i32.add
+    call $encode_char
+    i32.store
+    local.get $result)
+
+  (func $match_word (param $data i32) (param $length i32) (param $word i32) (param $word_length i32) (param $ignore_case i32) (result i32)
+    (local $index i32)
+    local.get $length
+    local.get $word_length
+    i32.ne
+    if
+      i32.const 0
+      return
+    end
+    block $matched
+      loop $letters
+        local.get $index
+        local.get $length
+        i32.ge_u
+        br_if $matched
+        local.get $data
+        local.get $index
+        i32.add
+        i32.load8_u
+        local.get $ignore_case
+        if (result i32)
+          i32.const 0x20
+        else
+          i32.const 0
+        end
+        i32.or
+        local.get $word
+        local.get $index
+        i32.add
+        i32.load8_u
+        i32.ne
+        if
+          i32.const 0
+          return
+        end
+        local.get $index
+        i32.const 1
+        i32.add
+        local.set $index
+        br $letters
+      end
+    end
+    i32.const 1)
+
+
+  ;; Input
+
+  (func $read_byte (param $error i32) (result i32)
+    global.get $input_position
+    global.get $input_length
+    i32.ge_u
+    if
+      i32.const 8
+      i32.const 8192
+      i32.store
+      i32.const 12
+      i32.const 4096
+      i32.store
+      i32.const 0
+      i32.const 8
+      i32.const 1
+      i32.const 16
+      call $fd_read
+      if
+        local.get $error
+        call $error
+      end
+      i32.const 16
+      i32.load
+      global.set $input_length
+      i32.const 0
+      global.set $input_position
+      global.get $input_length
+      i32.eqz
+      if
+        i32.const -1
+        return
+      end
+    end
+    global.get $input_position
+    i32.load8_u offset=8192
+    global.get $input_position
+    i32.const 1
+    i32.add
+    global.set $input_position)
+
+  ;; Reads A Line, Its Bytes Are Written Right Where The Heap Ends And Then Allocated There
+  (func $input (param $error i32) (result i32)
+    (local $result i32)
+    (local $length i32)
+    (local $byte i32)
+    global.get $heap
+    local.set $result
+    block $read
+      loop $bytes
+        local.get $error
+        call $read_byte
+        local.tee $byte
+        i32.const -1
+        i32.eq
+        br_if $read
+        local.get $result
+        local.get $length
+        i32.add
+        i32.const 5
+        i32.add
+        call $reserve
+        local.get $result
+        local.get $length
+        i32.add
+        local.get $byte
+        i32.store8 offset=4
+        local.get $length
+        i32.const 1
+        i32.add
+        local.set $length
+        local.get $byte
+        i32.const 10
+        i32.ne
+        br_if $bytes
+      end
+    end
+    block $trimmed
+      loop $trim
+        local.get $length
+        i32.eqz
+        br_if $trimmed
+        local.get $result
+        local.get $length
+        i32.add
+        i32.load8_u offset=3
+        local.tee $byte
+        i32.const 32
+        i32.eq
+        local.get $byte
+        i32.const 9
+        i32.sub
+        i32.const 4
+        i32.le_u
+        i32.or
+        i32.eqz
+        br_if $trimmed
+        local.get $length
+        i32.const 1
+        i32.sub
+        local.set $length
+        br $trim
+      end
+    end
+    local.get $length
+    i32.eqz
+    if
+      i32.const 0
+      return
+    end
+    local.get $length
+    i32.const 4
+    i32.add
+    call $alloc
+    local.get $length
+    i32.store
+    local.get $result)
+
+
+  ;; Numbers
+
+  (func $int_mod (param $left i64) (param $right i64) (param $error i32) (result i64)
+    local.get $right
+    i64.eqz
+    if
+      local.get $error
+      call $error
+    end
+    local.get $left
+    local.get $right
+    i64.rem_s)
+
+  ;; The `fmod` Of The C Library, Exact By Dividing The Mantissas As Integers
+  (func $double_mod (param $x f64) (param $y f64) (result f64)
+    (local $ux i64)
+    (local $uy i64)
+    (local $ex i32)
+    (local $ey i32)
+    (local $sign i64)
+    (local $i i64)
+    local.get $x
+    i64.reinterpret_f64
+    local.set $ux
+    local.get $y
+    i64.reinterpret_f64
+    local.set $uy
+    local.get $ux
+    i64.const 52
+    i64.shr_u
+    i32.wrap_i64
+    i32.const 0x7FF
+    i32.and
+    local.set $ex
+    local.get $uy
+    i64.const 52
+    i64.shr_u
+    i32.wrap_i64
+    i32.const 0x7FF
+    i32.and
+    local.set $ey
+    local.get $ux
+    i64.const 0x8000000000000000
+    i64.and
+    local.set $sign
+    local.get $uy
+    i64.const 1
+    i64.shl
+    i64.eqz
+    local.get $y
+    local.get $y
+    f64.ne
+    i32.or
+    local.get $ex
+    i32.const 0x7FF
+    i32.eq
+    i32.or
+    if
+      local.get $x
+      local.get $y
+      f64.mul
+      local.get $x
+      local.get $y
+      f64.mul
+      f64.div
+      return
+    end
+    local.get $ux
+    i64.const 1
+    i64.shl
+    local.get $uy
+    i64.const 1
+    i64.shl
+    i64.le_u
+    if
+      local.get $ux
+      i64.const 1
+      i64.shl
+      local.get $uy
+      i64.const 1
+      i64.shl
+      i64.eq
+      if
+        f64.const 0
+        local.get $x
+        f64.mul
+        return
+      end
+      local.get $x
+      return
+    end
+    local.get $ex
+    i32.eqz
+    if
+      local.get $ux
+      i64.const 12
+      i64.shl
+      local.set $i
+      block $normalized
+        loop $normalize
+          local.get $i
+          i64.const 0
+          i64.lt_s
+          br_if $normalized
+          local.get $ex
+          i32.const 1
+          i32.sub
+          local.set $ex
+          local.get $i
+          i64.const 1
+          i64.shl
+          local.set $i
+          br $normalize
+        end
+      end
+      local.get $ux
+      i32.const 1
+      local.get $ex
+      i32.sub
+      i64.extend_i32_u
+      i64.shl
+      local.set $ux
+    else
+      local.get $ux
+      i64.const 0xFFFFFFFFFFFFF
+      i64.and
+      i64.const 0x10000000000000
+      i64.or
+      local.set $ux
+    end
+    local.get $ey
+    i32.eqz
+    if
+      local.get $uy
+      i64.const 12
+      i64.shl
+      local.set $i
+      block $normalized
+        loop $normalize
+          local.get $i
+          i64.const 0
+          i64.lt_s
+          br_if $normalized
+          local.get $ey
+          i32.const 1
+          i32.sub
+          local.set $ey
+          local.get $i
+          i64.const 1
+          i64.shl
+          local.set $i
+          br $normalize
+        end
+      end
+      local.get $uy
+      i32.const 1
+      local.get $ey
+      i32.sub
+      i64.extend_i32_u
+      i64.shl
+      local.set $uy
+    else
+      local.get $uy
+      i64.const 0xFFFFFFFFFFFFF
+      i64.and
+      i64.const 0x10000000000000
+      i64.or
+      local.set $uy
+    end
+    block $divided
+      loop $divide
+        local.get $ex
+        local.get $ey
+        i32.le_s
+        br_if $divided
+        local.get $ux
+        local.get $uy
+        i64.sub
+        local.tee $i
+        i64.const 0
+        i64.ge_s
+        if
+          local.get $i
+          i64.eqz
+          if
+            f64.const 0
+            local.get $x
+            f64.mul
+            return
+          end
+          local.get $i
+          local.set $ux
+        end
+        local.get $ux
+        i64.const 1
+        i64.shl
+        local.set $ux
+        local.get $ex
+        i32.const 1
+        i32.sub
+        local.set $ex
+        br $divide
+      end
+    end
+    local.get $ux
+    local.get $uy
+    i64.sub
+    local.tee $i
+    i64.const 0
+    i64.ge_s
+    if
+      local.get $i
+      i64.eqz
+      if
+        f64.const 0
+        local.get $x
+        f64.mul
+        return
+      end
+      local.get $i
+      local.set $ux
+    end
+    block $scaled
+      loop $scale
+        local.get $ux
+        i64.const 52
+        i64.shr_u
+        i64.const 0
+        i64.ne
+        br_if $scaled
+        local.get $ux
+        i64.const 1
+        i64.shl
+        local.set $ux
+        local.get $ex
+        i32.const 1
+        i32.sub
+        local.set $ex
+        br $scale
+      end
+    end
+    local.get $ex
+    i32.const 0
+    i32.gt_s
+    if
+      local.get $ux
+      i64.const 0x10000000000000
+      i64.sub
+      local.get $ex
+      i64.extend_i32_u
+      i64.const 52
+      i64.shl
+      i64.or
+      local.set $ux
+    else
+      local.get $ux
+      i32.const 1
+      local.get $ex
+      i32.sub
+      i64.extend_i32_u
+      i64.shr_u
+      local.set $ux
+    end
+    local.get $ux
+    local.get $sign
+    i64.or
+    f64.reinterpret_i64)
+
+  (func $string_to_int (param $value i32) (param $error i32) (result i64)
+    (local $index i32)
+    (local $length i32)
+    (local $is_negative i32)
+    (local $digit i64)
+    (local $result i64)
+    (local $limit i64)
+    local.get $value
+    i32.load
+    local.set $length
+    local.get $length
+    if
+      local.get $value
+      i32.load8_u offset=4
+      i32.const 45
+      i32.eq
+      local.set $is_negative
+      local.get $is_negative
+      local.get $value
+      i32.load8_u offset=4
+      i32.const 43
+      i32.eq
+      i32.or
+      local.set $index
+    end
+    local.get $index
+    local.get $length
+    i32.eq
+    if
+      local.get $error
+      call $error
+    end
+    i64.const 0x7FFFFFFFFFFFFFFF
+    local.get $is_negative
+    i64.extend_i32_u
+    i64.add
+    local.set $limit
+    loop $digits
+      local.get $value
+      local.get $index
+      i32.add
+      i64.load8_u offset=4
+      i64.const 48
+      i64.sub
+      local.tee $digit
+      i64.const 9
+      i64.gt_u
+      local.get $result
+      local.get $limit
+      local.get $digit
+      i64.sub
+      i64.const 10
+      i64.div_u
+      i64.gt_u
+      i32.or
+      if
+        local.get $error
+        call $error
+      end
+      local.get $result
+      i64.const 10
+      i64.mul
+      local.get $digit
+      i64.add
+      local.set $result
+      local.get $index
+      i32.const 1
+      i32.add
+      local.tee $index
+      local.get $length
+      i32.lt_u
+      br_if $digits
+    end
+    local.get $is_negative
+    if
+      i64.const 0
+      local.get $result
+      i64.sub
+      return
+    end
+    local.get $result)
+
+  ;; Rounds `value * 2^exponent` To The Nearest `double`, `sticky` Tells Bits Below `value` Were Not Zero
+  (func $make_double (param $value i64) (param $exponent i32) (param $sticky i32) (result f64)
+    (local $top i32)
+    (local $lowest i32)
+    (local $dropped i32)
+    (local $mantissa i64)
+    (local $remainder i64)
+    (local $half i64)
+    (local $bits i64)
+    local.get $exponent
+    i32.const 63
+    i32.add
+    local.get $value
+    i64.clz
+    i32.wrap_i64
+    i32.sub
+    local.tee $top
+    i32.const 1023
+    i32.gt_s
+    if
+      f64.const inf
+      return
+    end
+    local.get $top
+    i32.const 52
+    i32.sub
+    local.tee $lowest
+    i32.const -1074
+    i32.lt_s
+    if
+      i32.const -1074
+      local.set $lowest
+    end
+    local.get $lowest
+    local.get $exponent
+    i32.sub
+    local.tee $dropped
+    i32.const 0
+    i32.le_s
+    if
+      local.get $value
+      i32.const 0
+      local.get $dropped
+      i32.sub
+      i64.extend_i32_u
+      i64.shl
+      local.set $mantissa
+    else
+      local.get $dropped
+      i32.const 64
+      i32.gt_s
+      if
+        i64.const 1
+        local.set $half
+      else
+        local.get $dropped
+        i32.const 64
+        i32.eq
+        if
+          local.get $value
+          local.set $remainder
+          i64.const 0x8000000000000000
+          local.set $half
+        else
+          local.get $value
+          local.get $dropped
+          i64.extend_i32_u
+          i64.shr_u
+          local.set $mantissa
+          local.get $value
+          i64.const 1
+          local.get $dropped
+          i64.extend_i32_u
+          i64.shl
+          i64.const 1
+          i64.sub
+          i64.and
+          local.set $remainder
+          i64.const 1
+          local.get $dropped
+          i32.const 1
+          i32.sub
+          i64.extend_i32_u
+          i64.shl
+          local.set $half
+        end
+      end
+      local.get $remainder
+      local.get $half
+      i64.gt_u
+      local.get $remainder
+      local.get $half
+      i64.eq
+      local.get $sticky
+      local.get $mantissa
+      i32.wrap_i64
+      i32.const 1
+      i32.and
+      i32.or
+      i32.and
+      i32.or
+      if
+        local.get $mantissa
+        i64.const 1
+        i64.add
+        local.set $mantissa
+      end
+    end
+    local.get $lowest
+    i32.const 1074
+    i32.add
+    i64.extend_i32_u
+    i64.const 52
+    i64.shl
+    local.get $mantissa
+    i64.add
+    local.tee $bits
+    i64.const 0x7FF0000000000000
+    i64.ge_u
+    if
+      f64.const inf
+      return
+    end
+    local.get $bits
+    f64.reinterpret_i64)
+
+  ;; Accepts What Rust Parses, The Value Is Rounded Exactly With Big Integers
+  (func $string_to_double (param $value i32) (param $error i32) (result f64)
+    (local $position i32)
+    (local $end i32)
+    (local $is_negative i32)
+    (local $character i32)
+    (local $digit i32)
+    (local $has_point i32)
+    (local $has_digits i32)
+    (local $digits_count i32)
+    (local $exponent i32)
+    (local $exponent_value i32)
+    (local $exponent_is_negative i32)
+    (local $exponent_start i32)
+    (local $sticky i32)
+    (local $magnitude i32)
+    (local $shift i32)
+    (local $index i32)
+    (local $quotient i64)
+    (local $result f64)
+    local.get $value
+    i32.const 4
+    i32.add
+    local.tee $position
+    local.get $value
+    i32.load
+    i32.add
+    local.set $end
+    block $parsed
+      local.get $position
+      local.get $end
+      i32.lt_u
+      if
+        local.get $position
+        i32.load8_u
+        local.tee $character
+        i32.const 45
+        i32.eq
+        local.set $is_negative
+        local.get $is_negative
+        local.get $character
+        i32.const 43
+        i32.eq
+        i32.or
+        local.get $position
+        i32.add
+        local.set $position
+      end
+      local.get $position
+      local.get $end
+      local.get $position
+      i32.sub
+      i32.const 280
+      i32.const 3
+      i32.const 1
+      call $match_word
+      local.get $position
+      local.get $end
+      local.get $position
+      i32.sub
+      i32.const 296
+      i32.const 8
+      i32.const 1
+      call $match_word
+      i32.or
+      if
+        f64.const inf
+        local.set $result
+        br $parsed
+      end
+      local.get $position
+      local.get $end
+      local.get $position
+      i32.sub
+      i32.const 284
+      i32.const 3
+      i32.const 1
+      call $match_word
+      if
+        f64.const nan
+        local.set $result
+        br $parsed
+      end
+
+      ;; Up To 768 Significant Digits Are Kept, The Others Only Tell If They Were Zero
+      i32.const 1024
+      i64.const 0
+      call $bn_set
+      block $digits_read
+        loop $digits
+          local.get $position
+          local.get $end
+          i32.ge_u
+          br_if $digits_read
+          local.get $position
+          i32.load8_u
+          local.tee $character
+          i32.const 46
+          i32.eq
+          local.get $has_point
+          i32.eqz
+          i32.and
+          if
+            i32.const 1
+            local.set $has_point
+            local.get $position
+            i32.const 1
+            i32.add
+            local.set $position
+            br $digits
+          end
+          local.get $character
+          i32.const 48
+          i32.sub
+          local.tee $digit
+          i32.const 9
+          i32.gt_u
+          br_if $digits_read
+          i32.const 1
+          local.set $has_digits
+          local.get $digits_count
+          i32.eqz
+          local.get $digit
+          i32.eqz
+          i32.and
+          if
+            local.get $exponent
+            local.get $has_point
+            i32.sub
+            local.set $exponent
+          else
+            local.get $digits_count
+            i32.const 768
+            i32.lt_u
+            if
+              i32.const 1024
+              i64.const 10
+              call $bn_mul_small
+              i32.const 1024
+              local.get $digit
+              i64.extend_i32_u
+              call $bn_add_small
+              local.get $digits_count
+              i32.const 1
+              i32.add
+              local.set $digits_count
+              local.get $exponent
+              local.get $has_point
+              i32.sub
+              local.set $exponent
+            else
+              local.get $exponent
+              local.get $has_point
+              i32.eqz
+              i32.add
+              local.set $exponent
+              local.get $sticky
+              local.get $digit
+              i32.const 0
+              i32.ne
+              i32.or
+              local.set $sticky
+            end
+          end
+          local.get $position
+          i32.const 1
+          i32.add
+          local.set $position
+          br $digits
+        end
+      end
+      local.get $has_digits
+      i32.eqz
+      if
+        local.get $error
+        call $error
+      end
+
+      local.get $position
+      local.get $end
+      i32.lt_u
+      if
+        local.get $position
+        i32.load8_u
+        i32.const 0x20
+        i32.or
+        i32.const 101
+        i32.eq
+        if
+          local.get $position
+          i32.const 1
+          i32.add
+          local.set $position
+          local.get $position
+          local.get $end
+          i32.lt_u
+          if
+            local.get $position
+            i32.load8_u
+            local.tee $character
+            i32.const 45
+            i32.eq
+            local.set $exponent_is_negative
+            local.get $exponent_is_negative
+            local.get $character
+            i32.const 43
+            i32.eq
+            i32.or
+            local.get $position
+            i32.add
+            local.set $position
+          end
+          local.get $position
+          local.set $exponent_start
+          block $exponent_read
+            loop $exponent_digits
+              local.get $position
+              local.get $end
+              i32.ge_u
+              br_if $exponent_read
+              local.get $position
+              i32.load8_u
+              i32.const 48
+              i32.sub
+              local.tee $digit
+              i32.const 9
+              i32.gt_u
+              br_if $exponent_read
+              local.get $exponent_value
+              i32.const 10
+              i32.mul
+              local.get $digit
+              i32.add
+              local.tee $exponent_value
+              i32.const 100000
+              i32.gt_u
+              if
+                i32.const 100000
+                local.set $exponent_value
+              end
+              local.get $position
+              i32.const 1
+              i32.add
+              local.set $position
+              br $exponent_digits
+            end
+          end
+          local.get $position
+          local.get $exponent_start
+          i32.eq
+          if
+            local.get $error
+            call $error
+          end
+          local.get $exponent
+          i32.const 0
+          local.get $exponent_value
+          i32.sub
+          local.get $exponent_value
+          local.get $exponent_is_negative
+          select
+          i32.add
+          local.set $exponent
+        end
+      end
+      local.get $position
+      local.get $end
+      i32.ne
+      if
+        local.get $error
+        call $error
+      end
+
+      local.get $digits_count
+      i32.eqz
+      br_if $parsed
+      local.get $digits_count
+      local.get $exponent
+      i32.add
+      local.tee $magnitude
+      i32.const 310
+      i32.gt_s
+      if
+        f64.const inf
+        local.set $result
+        br $parsed
+      end
+      local.get $magnitude
+      i32.const -330
+      i32.lt_s
+      br_if $parsed
+
+      local.get $exponent
+      i32.const 0
+      i32.ge_s
+      if
+        i32.const 1024
+        local.get $exponent
+        call $bn_mul_pow10
+        i32.const 1024
+        call $bn_bit_length
+        i32.const 64
+        i32.sub
+        local.tee $shift
+        i32.const 0
+        i32.lt_s
+        if
+          i32.const 0
+          local.set $shift
+        end
+        i32.const 1024
+        local.get $shift
+        call $bn_bits64
+        local.get $shift
+        local.get $sticky
+        i32.const 1024
+        local.get $shift
+        call $bn_low_nonzero
+        i32.or
+        call $make_double
+        local.set $result
+        br $parsed
+      end
+
+      ;; The Quotient Of The Digits By The Power Of Ten Is Made 64 Bits Long
+      i32.const 1536
+      i64.const 1
+      call $bn_set
+      i32.const 1536
+      i32.const 0
+      local.get $exponent
+      i32.sub
+      call $bn_mul_pow10
+      i32.const 1536
+      call $bn_bit_length
+      i32.const 1024
+      call $bn_bit_length
+      i32.sub
+      i32.const 63
+      i32.add
+      local.tee $shift
+      i32.const 0
+      i32.ge_s
+      if
+        i32.const 1024
+        local.get $shift
+        call $bn_shift_left
+      else
+        i32.const 1536
+        i32.const 0
+        local.get $shift
+        i32.sub
+        call $bn_shift_left
+      end
+      i32.const 64
+      local.set $index
+      loop $quotient_bits
+        local.get $index
+        i32.const 1
+        i32.sub
+        local.set $index
+        i32.const 2048
+        i32.const 1536
+        call $bn_copy
+        i32.const 2048
+        local.get $index
+        call $bn_shift_left
+        i32.const 1024
+        i32.const 2048
+        call $bn_compare
+        i32.const 0
+        i32.ge_s
+        if
+          i32.const 1024
+          i32.const 2048
+          call $bn_sub
+          local.get $quotient
+          i64.const 1
+          local.get $index
+          i64.extend_i32_u
+          i64.shl
+          i64.or
+          local.set $quotient
+        end
+        local.get $index
+        br_if $quotient_bits
+      end
+      local.get $quotient
+      i32.const 0
+      local.get $shift
+      i32.sub
+      local.get $sticky
+      i32.const 1024
+      call $bn_bit_length
+      i32.const 0
+      i32.ne
+      i32.or
+      call $make_double
+      local.set $result
+    end
+    local.get $result
+    f64.neg
+    local.get $result
+    local.get $is_negative
+    select)
+
+  (func $string_to_bool (param $value i32) (param $error i32) (result i32)
+    local.get $value
+    i32.const 4
+    i32.add
+    local.get $value
+    i32.load
+    i32.const 432
+    i32.const 4
+    i32.const 0
+    call $match_word
+    if
+      i32.const 1
+      return
+    end
+    local.get $value
+    i32.const 4
+    i32.add
+    local.get $value
+    i32.load
+    i32.const 440
+    i32.const 5
+    i32.const 0
+    call $match_word
+    i32.eqz
+    if
+      local.get $error
+      call $error
+    end
+    i32.const 0)
+
+  (func $string_to_char (param $value i32) (param $error i32) (result i32)
+    (local $first i32)
+    (local $code_point i32)
+    (local $continuation_count i32)
+    (local $index i32)
+    local.get $value
+    i32.load
+    i32.eqz
+    if
+      local.get $error
+      call $error
+    end
+    local.get $value
+    i32.load8_u offset=4
+    local.tee $first
+    i32.const 0x80
+    i32.lt_u
+    if
+      local.get $first
+      return
+    end
+    local.get $first
+    i32.const 0xE0
+    i32.lt_u
+    if
+      local.get $first
+      i32.const 0x1F
+      i32.and
+      local.set $code_point
+      i32.const 1
+      local.set $continuation_count
+    else
+      local.get $first
+      i32.const 0xF0
+      i32.lt_u
+      if
+        local.get $first
+        i32.const 0x0F
+        i32.and
+        local.set $code_point
+        i32.const 2
+        local.set $continuation_count
+      else
+        local.get $first
+        i32.const 0x07
+        i32.and
+        local.set $code_point
+        i32.const 3
+        local.set $continuation_count
+      end
+    end
+    local.get $value
+    i32.load
+    local.get $continuation_count
+    i32.le_u
+    if
+      local.get $error
+      call $error
+    end
+    loop $continuations
+      local.get $index
+      i32.const 1
+      i32.add
+      local.set $index
+      local.get $code_point
+      i32.const 6
+      i32.shl
+      local.get $value
+      local.get $index
+      i32.add
+      i32.load8_u offset=4
+      i32.const 0x3F
+      i32.and
+      i32.or
+      local.set $code_point
+      local.get $index
+      local.get $continuation_count
+      i32.lt_u
+      br_if $continuations
+    end
+    local.get $code_point)
+
+
+  ;; Big Integers Of 128 Little Endian 32 Bit Limbs
+
+  (func $bn_set (param $target i32) (param $value i64)
+    local.get $target
+    i32.const 0
+    i32.const 512
+    memory.fill
+    local.get $target
+    local.get $value
+    i64.store)
+
+  (func $bn_copy (param $target i32) (param $source i32)
+    local.get $target
+    local.get $source
+    i32.const 512
+    memory.copy)
+
+  (func $bn_limb (param $number i32) (param $index i32) (result i64)
+    local.get $index
+    i32.const 128
+    i32.ge_u
+    if
+      i64.const 0
+      return
+    end
+    local.get $number
+    local.get $index
+    i32.const 2
+    i32.shl
+    i32.add
+    i64.load32_u)
+
+  (func $bn_mul_small (param $target i32) (param $factor i64)
+    (local $offset i32)
+    (local $product i64)
+    loop $limbs
+      local.get $target
+      local.get $offset
+      i32.add
+      local.get $target
+      local.get $offset
+      i32.add
+      i64.load32_u
+      local.get $factor
+      i64.mul
+      local.get $product
+      i64.const 32
+      i64.shr_u
+      i64.add
+      local.tee $product
+      i64.store32
+      local.get $offset
+      i32.const 4
+      i32.add
+      local.tee $offset
+      i32.const 512
+      i32.lt_u
+      br_if $limbs
+    end)
+
+  (func $bn_add_small (param $target i32) (param $value i64)
+    (local $offset i32)
+    (local $sum i64)
+    local.get $value
+    i64.const 32
+    i64.shl
+    local.set $sum
+    loop $limbs
+      local.get $target
+      local.get $offset
+      i32.add
+      local.get $target
+      local.get $offset
+      i32.add
+      i64.load32_u
+      local.get $sum
+      i64.const 32
+      i64.shr_u
+      i64.add
+      local.tee $sum
+      i64.store32
+      local.get $offset
+      i32.const 4
+      i32.add
+      local.tee $offset
+      i32.const 512
+      i32.lt_u
+      br_if $limbs
+    end)
+
+  (func $bn_add (param $target i32) (param $left i32) (param $right i32)
+    (local $offset i32)
+    (local $sum i64)
+    loop $limbs
+      local.get $target
+      local.get $offset
+      i32.add
+      local.get $left
+      local.get $offset
+      i32.add
+      i64.load32_u
+      local.get $right
+      local.get $offset
+      i32.add
+      i64.load32_u
+      i64.add
+      local.get $sum
+      i64.const 32
+      i64.shr_u
+      i64.add
+      local.tee $sum
+      i64.store32
+      local.get $offset
+      i32.const 4
+      i32.add
+      local.tee $offset
+      i32.const 512
+      i32.lt_u
+      br_if $limbs
+    end)
+
+  ;; `target` Must Not Be Smaller Than `value`
+  (func $bn_sub (param $target i32) (param $value i32)
+    (local $offset i32)
+    (local $difference i64)
+    loop $limbs
+      local.get $target
+      local.get $offset
+      i32.add
+      local.get $target
+      local.get $offset
+      i32.add
+      i64.load32_u
+      local.get $value
+      local.get $offset
+      i32.add
+      i64.load32_u
+      i64.sub
+      local.get $difference
+      i64.const 63
+      i64.shr_u
+      i64.sub
+      local.tee $difference
+      i64.store32
+      local.get $offset
+      i32.const 4
+      i32.add
+      local.tee $offset
+      i32.const 512
+      i32.lt_u
+      br_if $limbs
+    end)
+
+  (func $bn_compare (param $left i32) (param $right i32) (result i32)
+    (local $offset i32)
+    (local $left_limb i64)
+    (local $right_limb i64)
+    i32.const 512
+    local.set $offset
+    loop $limbs
+      local.get $offset
+      i32.const 4
+      i32.sub
+      local.set $offset
+      local.get $left
+      local.get $offset
+      i32.add
+      i64.load32_u
+      local.tee $left_limb
+      local.get $right
+      local.get $offset
+      i32.add
+      i64.load32_u
+      local.tee $right_limb
+      i64.ne
+      if
+        i32.const 1
+        i32.const -1
+        local.get $left_limb
+        local.get $right_limb
+        i64.gt_u
+        select
+        return
+      end
+      local.get $offset
+      br_if $limbs
+    end
+    i32.const 0)
+
+  (func $bn_shift_left (param $target i32) (param $count i32)
+    (local $index i32)
+    (local $source i32)
+    (local $bits i64)
+    local.get $count
+    i32.const 31
+    i32.and
+    i64.extend_i32_u
+    local.set $bits
+    i32.const 128
+    local.set $index
+    loop $limbs
+      local.get $index
+      i32.const 1
+      i32.sub
+      local.tee $index
+      local.get $count
+      i32.const 5
+      i32.shr_u
+      i32.sub
+      local.set $source
+      local.get $target
+      local.get $index
+      i32.const 2
+      i32.shl
+      i32.add
+      local.get $target
+      local.get $source
+      call $bn_limb
+      i64.const 32
+      i64.shl
+      local.get $target
+      local.get $source
+      i32.const 1
+      i32.sub
+      call $bn_limb
+      i64.or
+      local.get $bits
+      i64.shl
+      i64.const 32
+      i64.shr_u
+      i64.store32
+      local.get $index
+      br_if $limbs
+    end)
+
+  (func $bn_bit_length (param $number i32) (result i32)
+    (local $index i32)
+    (local $limb i32)
+    i32.const 128
+    local.set $index
+    loop $limbs
+      local.get $number
+      local.get $index
+      i32.const 1
+      i32.sub
+      local.tee $index
+      i32.const 2
+      i32.shl
+      i32.add
+      i32.load
+      local.tee $limb
+      if
+        local.get $index
+        i32.const 5
+        i32.shl
+        i32.const 32
+        i32.add
+        local.get $limb
+        i32.clz
+        i32.sub
+        return
+      end
+      local.get $index
+      br_if $limbs
+    end
+    i32.const 0)
+
+  (func $bn_mul_pow10 (param $target i32) (param $count i32)
+    block $multiplied
+      loop $billions
+        local.get $count
+        i32.const 9
+        i32.lt_u
+        br_if $multiplied
+        local.get $target
+        i64.const 1000000000
+        call $bn_mul_small
+        local.get $count
+        i32.const 9
+        i32.sub
+        local.set $count
+        br $billions
+      end
+    end
+    block $done
+      loop $tens
+        local.get $count
+        i32.eqz
+        br_if $done
+        local.get $target
+        i64.const 10
+        call $bn_mul_small
+        local.get $count
+        i32.const 1
+        i32.sub
+        local.set $count
+        br $tens
+      end
+    end)
+
+  ;; The 64 Bits Starting At Bit `start`
+  (func $bn_bits64 (param $number i32) (param $start i32) (result i64)
+    (local $index i32)
+    (local $bits i64)
+    local.get $start
+    i32.const 5
+    i32.shr_u
+    local.set $index
+    local.get $start
+    i32.const 31
+    i32.and
+    i64.extend_i32_u
+    local.tee $bits
+    i64.eqz
+    if
+      local.get $number
+      local.get $index
+      call $bn_limb
+      local.get $number
+      local.get $index
+      i32.const 1
+      i32.add
+      call $bn_limb
+      i64.const 32
+      i64.shl
+      i64.or
+      return
+    end
+    local.get $number
+    local.get $index
+    call $bn_limb
+    local.get $bits
+    i64.shr_u
+    local.get $number
+    local.get $index
+    i32.const 1
+    i32.add
+    call $bn_limb
+    i64.const 32
+    local.get $bits
+    i64.sub
+    i64.shl
+    i64.or
+    local.get $number
+    local.get $index
+    i32.const 2
+    i32.add
+    call $bn_limb
+    i64.const 64
+    local.get $bits
+    i64.sub
+    i64.shl
+    i64.or)
+
+  ;; Tells If Any Bit Below Bit `start` Is Set
+  (func $bn_low_nonzero (param $number i32) (param $start i32) (result i32)
+    (local $index i32)
+    block $checked
+      loop $limbs
+        local.get $index
+        local.get $start
+        i32.const 5
+        i32.shr_u
+        i32.ge_u
+        br_if $checked
+        local.get $number
+        local.get $index
+        call $bn_limb
+        i64.const 0
+        i64.ne
+        if
+          i32.const 1
+          return
+        end
+        local.get $index
+        i32.const 1
+        i32.add
+        local.set $index
+        br $limbs
+      end
+    end
+    local.get $number
+    local.get $index
+    call $bn_limb
+    i64.const 1
+    local.get $start
+    i32.const 31
+    i32.and
+    i64.extend_i32_u
+    i64.shl
+    i64.const 1
+    i64.sub
+    i64.and
+    i64.const 0
+    i64.ne)
+
+  ;; Multiplies The Dragon4 Numbers Kept Between Two Digits By Ten
+  (func $dragon_next
+    i32.const 1024
+    i64.const 10
+    call $bn_mul_small
+    i32.const 1536
+    i64.const 10
+    call $bn_mul_small
+    i32.const 2048
+    i64.const 10
+    call $bn_mul_small)
+
+  ;; Compares `left` And `right`, Equal Counts As Smaller When The Bounds Are Inclusive
+  (func $dragon_less (param $left i32) (param $right i32) (param $inclusive i32) (result i32)
+    (local $compare i32)
+    local.get $left
+    local.get $right
+    call $bn_compare
+    local.tee $compare
+    i32.const 0
+    i32.lt_s
+    local.get $inclusive
+    local.get $compare
+    i32.eqz
+    i32.and
+    i32.or)
+
+  ;; Subtracts `scale` From The Remaining Mantissa When It Fits, Giving `weight` To The Digit
+  (func $dragon_subtract (param $scale i32) (param $weight i32) (result i32)
+    i32.const 1024
+    local.get $scale
+    call $bn_compare
+    i32.const 0
+    i32.lt_s
+    if
+      i32.const 0
+      return
+    end
+    i32.const 1024
+    local.get $scale
+    call $bn_sub
+    local.get $weight)
+
+  ;; The Dragon4 Shortest Digits Rust Prints, Written From `position` For A Positive Finite Value
+  (func $format_shortest (param $value f64) (param $position i32) (result i32)
+    (local $bits i64)
+    (local $mantissa i64)
+    (local $exponent i32)
+    (local $plus i64)
+    (local $inclusive i32)
+    (local $k i32)
+    (local $count i32)
+    (local $digit i32)
+    (local $down i32)
+    (local $up i32)
+    (local $index i32)
+    ;; mantissa 1024, minus 1536, plus 2048, scale 2560, mantissa + plus 3072, scale * 2, 4, 8 from 3584
+    local.get $value
+    i64.reinterpret_f64
+    local.tee $bits
+    i64.const 52
+    i64.shr_u
+    i32.wrap_i64
+    local.set $exponent
+    local.get $bits
+    i64.const 0xFFFFFFFFFFFFF
+    i64.and
+    local.set $mantissa
+    i64.const 1
+    local.set $plus
+    local.get $exponent
+    i32.eqz
+    if
+      local.get $mantissa
+      i64.const 1
+      i64.shl
+      local.set $mantissa
+      i32.const -1075
+      local.set $exponent
+      i32.const 1
+      local.set $inclusive
+    else
+      local.get $mantissa
+      i32.wrap_i64
+      i32.const 1
+      i32.and
+      i32.eqz
+      local.set $inclusive
+      local.get $mantissa
+      i64.const 0x10000000000000
+      i64.or
+      local.set $mantissa
+      local.get $exponent
+      i32.const 1075
+      i32.sub
+      local.set $exponent
+      local.get $mantissa
+      i64.const 0x10000000000000
+      i64.eq
+      if
+        local.get $mantissa
+        i64.const 2
+        i64.shl
+        local.set $mantissa
+        i64.const 2
+        local.set $plus
+        local.get $exponent
+        i32.const 2
+        i32.sub
+        local.set $exponent
+      else
+        local.get $mantissa
+        i64.const 1
+        i64.shl
+        local.set $mantissa
+        local.get $exponent
+        i32.const 1
+        i32.sub
+        local.set $exponent
+      end
+    end
+
+    i32.const 1024
+    local.get $mantissa
+    call $bn_set
+    i32.const 1536
+    i64.const 1
+    call $bn_set
+    i32.const 2048
+    local.get $plus
+    call $bn_set
+    i32.const 2560
+    i64.const 1
+    call $bn_set
+    local.get $exponent
+    i32.const 0
+    i32.lt_s
+    if
+      i32.const 2560
+      i32.const 0
+      local.get $exponent
+      i32.sub
+      call $bn_shift_left
+    else
+      i32.const 1024
+      local.get $exponent
+      call $bn_shift_left
+      i32.const 1536
+      local.get $exponent
+      call $bn_shift_left
+      i32.const 2048
+      local.get $exponent
+      call $bn_shift_left
+    end
+
+    ;; An Estimate Of The Decimal Exponent, Corrected Below When One Too Small
+    i64.const 64
+    local.get $mantissa
+    local.get $plus
+    i64.add
+    i64.const 1
+    i64.sub
+    i64.clz
+    i64.sub
+    local.get $exponent
+    i64.extend_i32_s
+    i64.add
+    i64.const 1292913986
+    i64.mul
+    i64.const 32
+    i64.shr_s
+    i32.wrap_i64
+    local.tee $k
+    i32.const 0
+    i32.ge_s
+    if
+      i32.const 2560
+      local.get $k
+      call $bn_mul_pow10
+    else
+      i32.const 1024
+      i32.const 0
+      local.get $k
+      i32.sub
+      call $bn_mul_pow10
+      i32.const 1536
+      i32.const 0
+      local.get $k
+      i32.sub
+      call $bn_mul_pow10
+      i32.const 2048
+      i32.const 0
+      local.get $k
+      i32.sub
+      call $bn_mul_pow10
+    end
+    i32.const 3072
+    i32.const 1024
+    i32.const 2048
+    call $bn_add
+    i32.const 2560
+    i32.const 3072
+    local.get $inclusive
+    call $dragon_less
+    if
+      local.get $k
+      i32.const 1
+      i32.add
+      local.set $k
+    else
+      call $dragon_next
+    end
+
+    i32.const 3584
+    i32.const 2560
+    call $bn_copy
+    i32.const 3584
+    i32.const 1
+    call $bn_shift_left
+    i32.const 4096
+    i32.const 2560
+    call $bn_copy
+    i32.const 4096
+    i32.const 2
+    call $bn_shift_left
+    i32.const 4608
+    i32.const 2560
+    call $bn_copy
+    i32.const 4608
+    i32.const 3
+    call $bn_shift_left
+
+    loop $digits
+      i32.const 4608
+      i32.const 8
+      call $dragon_subtract
+      i32.const 4096
+      i32.const 4
+      call $dragon_subtract
+      i32.add
+      i32.const 3584
+      i32.const 2
+      call $dragon_subtract
+      i32.add
+      i32.const 2560
+      i32.const 1
+      call $dragon_subtract
+      i32.add
+      local.set $digit
+      local.get $count
+      local.get $digit
+      i32.const 48
+      i32.add
+      i32.store8 offset=6656
+      local.get $count
+      i32.const 1
+      i32.add
+      local.set $count
+      i32.const 1024
+      i32.const 1536
+      local.get $inclusive
+      call $dragon_less
+      local.set $down
+      i32.const 3072
+      i32.const 1024
+      i32.const 2048
+      call $bn_add
+      i32.const 2560
+      i32.const 3072
+      local.get $inclusive
+      call $dragon_less
+      local.set $up
+      local.get $down
+      local.get $up
+      i32.or
+      i32.eqz
+      if
+        call $dragon_next
+        br $digits
+      end
+    end
+
+    ;; Rounds Up When Past The Middle, Or At The Middle Like Rust
+    local.get $up
+    if
+      local.get $down
+      if (result i32)
+        i32.const 1024
+        i32.const 1
+        call $bn_shift_left
+        i32.const 1024
+        i32.const 2560
+        call $bn_compare
+        i32.const 0
+        i32.ge_s
+      else
+        i32.const 1
+      end
+      if
+        local.get $count
+        local.set $index
+        block $rounded
+          loop $carry
+            local.get $index
+            i32.eqz
+            if
+              i32.const 6656
+              i32.const 49
+              i32.store8
+              i32.const 6657
+              i32.const 48
+              local.get $count
+              memory.fill
+              local.get $count
+              i32.const 1
+              i32.add
+              local.set $count
+              local.get $k
+              i32.const 1
+              i32.add
+              local.set $k
+              br $rounded
+            end
+            local.get $index
+            i32.const 1
+            i32.sub
+            local.tee $index
+            i32.load8_u offset=6656
+            i32.const 57
+            i32.eq
+            if
+              local.get $index
+              i32.const 48
+              i32.store8 offset=6656
+              br $carry
+            end
+          end
+          local.get $index
+          local.get $index
+          i32.load8_u offset=6656
+          i32.const 1
+          i32.add
+          i32.store8 offset=6656
+        end
+      end
+    end
+
+    ;; The Digits Are `0.d1d2...` Times `10^k`
+    local.get $k
+    i32.const 0
+    i32.le_s
+    if
+      local.get $position
+      i32.const 48
+      i32.store8
+      local.get $position
+      i32.const 46
+      i32.store8 offset=1
+      local.get $position
+      i32.const 2
+      i32.add
+      local.tee $position
+      i32.const 48
+      i32.const 0
+      local.get $k
+      i32.sub
+      memory.fill
+      local.get $position
+      i32.const 0
+      local.get $k
+      i32.sub
+      i32.add
+      local.tee $position
+      i32.const 6656
+      local.get $count
+      memory.copy
+      local.get $position
+      local.get $count
+      i32.add
+      return
+    end
+    local.get $k
+    local.get $count
+    i32.lt_s
+    if
+      local.get $position
+      i32.const 6656
+      local.get $k
+      memory.copy
+      local.get $position
+      local.get $k
+      i32.add
+      local.tee $position
+      i32.const 46
+      i32.store8
+      local.get $position
+      i32.const 1
+      i32.add
+      local.tee $position
+      i32.const 6656
+      local.get $k
+      i32.add
+      local.get $count
+      local.get $k
+      i32.sub
+      memory.copy
+      local.get $position
+      local.get $count
+      i32.add
+      local.get $k
+      i32.sub
+      return
+    end
+    local.get $position
+    i32.const 6656
+    local.get $count
+    memory.copy
+    local.get $position
+    local.get $count
+    i32.add
+    i32.const 48
+    local.get $k
+    local.get $count
+    i32.sub
+    memory.fill
+    local.get $position
+    local.get $k
+    i32.add)
@@ -2,7 +2,6 @@ pub const VERSION: &'static str = "v0.1.0";
 pub const VARIABLE_MAX_LENGTH: i8 = 100;
 pub const INT_NUMBER_MAX_LENGTH: i8 = 18;
 pub const DOUBLE_NUMBER_MAX_LENGTH: i8 = 18;
-pub const BYTECODE_SPACE_STRING_LENGTH: i8 = 80;
 
 
 #[derive(Clone)]
@@ -11,6 +10,7 @@ pub enum Mode {
     ByteCodeGenerator,
     Interpreter,
     VirtualMachine,
+    Linter,
 }
 
 impl std::fmt::Debug for Mode{
@@ -20,6 +20,7 @@ impl std::fmt::Debug for Mode{
             Self::ByteCodeGenerator => write!(f, "Engine ByteCodeGenerator"),
             Self::Interpreter => write!(f, "Engine Interpreter"),
             Self::VirtualMachine => write!(f, "Engine VM"),
+            Self::Linter => write!(f, "Engine Linter"),
         }
     }
 }
@@ -31,6 +32,7 @@ impl std::fmt::Display for Mode{
             Self::ByteCodeGenerator => write!(f, "Engine ByteCodeGenerator"),
             Self::Interpreter => write!(f, "Engine Interpreter"),
             Self::VirtualMachine => write!(f, "Engine VM"),
+            Self::Linter => write!(f, "Engine Linter"),
         }
     }
 }
@@ -0,0 +1,429 @@
+use std::collections::VecDeque;
+use std::io::Write;
+
+use crate::tokens::{
+    Token,
+    TokenType
+};
+use crate::environments::{
+    Value,
+    ValueType
+};
+use crate::syntax_tree::{
+    OperationNode,
+    construct_expression_node
+};
+use crate::constants::Mode;
+use crate::file::File;
+use crate::lexer::{
+    Lexer,
+    next_token
+};
+use crate::builtins::get_builtin_constant;
+use crate::compiler::evaluate_operation;
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DebugCommand{
+    Step,
+    Next,
+    Continue,
+    Break(u128),
+    Delete(u128),
+    Breakpoints,
+    Print(String),
+    Variables,
+    Watch(String),
+    Unwatch(String),
+    List,
+    Help,
+    Quit,
+}
+
+
+pub const DEBUG_HELP: &'static str = "Engine Debugger Commands:
+    step, s              Execute one statement or instruction, stepping into blocks
+    next, n              Execute until the next statement or instruction at the same level
+    continue, c          Run until a breakpoint is hit or the program ends
+    break, b <line>      Set a breakpoint on a line
+    delete, d <line>     Remove a breakpoint
+    breakpoints          List breakpoints
+    print, p <expression>
+                         Print a variable or an expression, like `total + 1`
+    variables, v         Print all variables
+    watch, w <expression>
+                         Add a variable or an expression to the watch list
+    unwatch <expression> Remove a variable or an expression from the watch list
+    list, l              Print the current statement or instruction
+    help, h              Print this help
+    quit, q              Stop debugging";
+
+
+fn parse_line_argument(argument: Option<&str>) -> Option<u128>{
+    if argument == None{
+        return None;
+    }
+    return argument.unwrap().parse::<u128>().ok();
+}
+
+
+/* Commands Without Arguments Or With A Line Take The Whole Line, Expressions Take The Rest Of It */
+fn parse_debug_command(line: &str) -> Result<DebugCommand, String>{
+    let unknown_command_error = format!("Unknown command `{}`, type `help` for commands.", line);
+
+    let mut parts = line.splitn(2, char::is_whitespace);
+
+    let command = parts.next().unwrap();
+    let rest = parts.next().unwrap_or("").trim();
+
+    let mut arguments = rest.split_whitespace();
+    let argument = arguments.next();
+    let is_single_argument = argument != None && arguments.next() == None;
+
+    if command == "print" || command == "p" ||
+        command == "watch" || command == "w" ||
+        command == "unwatch"
+    {
+        if rest.is_empty(){
+            return Err(unknown_command_error);
+        }
+
+        let expression = parse_debug_expression(rest);
+        if expression.is_err(){
+            return Err(format!("Invalid expression `{}`: {}.", rest, expression.unwrap_err()));
+        }
+
+        return Ok(if command == "print" || command == "p"{
+            DebugCommand::Print(String::from(rest))
+        }
+        else if command == "unwatch"{
+            DebugCommand::Unwatch(String::from(rest))
+        }
+        else{
+            DebugCommand::Watch(String::from(rest))
+        });
+    }
+
+    if command == "break" || command == "b" || command == "delete" || command == "d"{
+        let line = parse_line_argument(argument);
+        if line == None || !is_single_argument{
+            return Err(unknown_command_error);
+        }
+
+        return Ok(if command == "break" || command == "b"{
+            DebugCommand::Break(line.unwrap())
+        } else {DebugCommand::Delete(line.unwrap())});
+    }
+
+    if !rest.is_empty(){
+        return Err(unknown_command_error);
+    }
+
+    if command == "step" || command == "s"{
+        return Ok(DebugCommand::Step);
+    }
+    else if command == "next" || command == "n"{
+        return Ok(DebugCommand::Next);
+    }
+    else if command == "continue" || command == "c"{
+        return Ok(DebugCommand::Continue);
+    }
+    else if command == "breakpoints"{
+        return Ok(DebugCommand::Breakpoints);
+    }
+    else if command == "variables" || command == "v"{
+        return Ok(DebugCommand::Variables);
+    }
+    else if command == "list" || command == "l"{
+        return Ok(DebugCommand::List);
+    }
+    else if command == "help" || command == "h"{
+        return Ok(DebugCommand::Help);
+    }
+    else if command == "quit" || command == "q"{
+        return Ok(DebugCommand::Quit);
+    }
+
+    return Err(unknown_command_error);
+}
+
+
+fn is_operand_token(token: &Token) -> bool{
+    return token.token_type == TokenType::IntNumber ||
+        token.token_type == TokenType::DoubleNumber ||
+        token.token_type == TokenType::Character ||
+        token.token_type == TokenType::StringSequence ||
+        token.token_type == TokenType::True ||
+        token.token_type == TokenType::False ||
+        token.token_type == TokenType::Variable;
+}
+
+
+fn is_operator_token(token: &Token) -> bool{
+    return [
+        TokenType::Or,
+        TokenType::And,
+        TokenType::Equal,
+        TokenType::NotEqual,
+        TokenType::GreaterThanOrEqual,
+        TokenType::LessThanOrEqual,
+        TokenType::GreaterThan,
+        TokenType::LessThan,
+        TokenType::Plus,
+        TokenType::Minus,
+        TokenType::Mul,
+        TokenType::Div,
+        TokenType::Mod,
+        TokenType::OpenParenthes,
+        TokenType::CloseParenthes,
+    ].contains(&token.token_type);
+}
+
+
+/* `Engine Interpreter: Syntax Error -> message, line 1:5.` Is Printed As `message` */
+fn get_lexer_error_message(error: String) -> String{
+    let message = error.splitn(2, "-> ").nth(1).unwrap_or(&error);
+    let end = message.rfind(", line ").unwrap_or(message.len());
+
+    return String::from(&message[..end]);
+}
+
+
+/* Splits An Expression Into Tokens With The Lexer Of The Programs */
+fn get_expression_tokens(text: &str) -> Result<VecDeque<Token>, String>{
+    let lexer = Lexer::new(File::from_text(text, Mode::Interpreter), Mode::Interpreter);
+    if lexer.is_err(){
+        return Err(String::from("can't read the expression"));
+    }
+    let mut lexer = lexer.unwrap();
+
+    let mut tokens: VecDeque<Token> = VecDeque::new();
+    loop{
+        let token = next_token(&mut lexer);
+        if token.is_err(){
+            return Err(get_lexer_error_message(token.unwrap_err()));
+        }
+        let token = token.unwrap();
+
+        if token.token_type == TokenType::Eof{
+            break;
+        }
+        else if token.token_type == TokenType::Space{
+            continue;
+        }
+        else if !is_operand_token(&token) && !is_operator_token(&token){
+            return Err(format!("unexpected `{}`", token.value));
+        }
+
+        /* The Lexer Reads `-1` As A Number, After A Value It Is A Subtraction */
+        let is_subtraction =
+            (token.token_type == TokenType::IntNumber || token.token_type == TokenType::DoubleNumber) &&
+            token.value.starts_with("-") && tokens.back() != None && (
+                is_operand_token(tokens.back().unwrap()) ||
+                tokens.back().unwrap().token_type == TokenType::CloseParenthes);
+
+        if is_subtraction{
+            tokens.push_back(Token{
+                token_type: TokenType::Minus,
+                start_line: token.start_line,
+                start_pos: token.start_pos,
+                value: String::from("-")
+            });
+            tokens.push_back(Token{
+                token_type: token.token_type,
+                start_line: token.start_line,
+                start_pos: token.start_pos + 1,
+                value: String::from(&token.value[1..])
+            });
+            continue;
+        }
+
+        tokens.push_back(token);
+    }
+
+    return Ok(tokens);
+}
+
+
+/* Values And Operators Alternate, With Balanced Parentheses Around Values */
+pub fn parse_debug_expression(text: &str) -> Result<OperationNode, String>{
+    let mut tokens = get_expression_tokens(text)?;
+
+    let mut is_operand_expected = true;
+    let mut depth = 0;
+    for token in &tokens{
+        if is_operand_expected && token.token_type == TokenType::OpenParenthes{
+            depth += 1;
+        }
+        else if is_operand_expected{
+            if !is_operand_token(token){
+                return Err(format!("expected a value, found `{}`", token.value));
+            }
+            is_operand_expected = false;
+        }
+        else if token.token_type == TokenType::CloseParenthes{
+            if depth == 0{
+                return Err(String::from("unexpected `)`"));
+            }
+            depth -= 1;
+        }
+        else if is_operand_token(token) || token.token_type == TokenType::OpenParenthes{
+            return Err(format!("expected an operator, found `{}`", token.value));
+        }
+        else{
+            is_operand_expected = true;
+        }
+    }
+
+    if is_operand_expected{
+        return Err(String::from("expected a value at the end"));
+    }
+    if depth != 0{
+        return Err(String::from("missing `)`"));
+    }
+
+    return Ok(construct_expression_node(&mut tokens));
+}
+
+
+fn get_literal_value(token: &Token) -> Value{
+    let mut value = Value::new();
+
+    if token.token_type == TokenType::IntNumber{
+        value.value_type = Some(ValueType::Integer);
+        value.int = token.value.parse::<i64>().ok();
+    }
+    else if token.token_type == TokenType::DoubleNumber{
+        value.value_type = Some(ValueType::Double);
+        value.double = token.value.parse::<f64>().ok();
+    }
+    else if token.token_type == TokenType::True || token.token_type == TokenType::False{
+        value.value_type = Some(ValueType::Boolean);
+        value.boolean = Some(token.token_type == TokenType::True);
+    }
+    else if token.token_type == TokenType::Character{
+        value.value_type = Some(ValueType::Character);
+        value.character = token.value.chars().nth(0);
+    }
+    else{
+        value.value_type = Some(ValueType::String);
+        value.string = Some(token.value.clone());
+    }
+
+    return value;
+}
+
+
+/* `PI` And `E` Are Used When No Variable With That Name Is Defined, Like In The Programs */
+fn get_constant_value(token: &Token) -> Result<Value, String>{
+    let constant = get_builtin_constant(&token.value);
+    if constant == None{
+        return Err(format!("`{}` not found", token.value));
+    }
+
+    let mut value = Value::new();
+    value.value_type = Some(ValueType::Double);
+    value.double = constant;
+
+    return Ok(value);
+}
+
+
+/* Operations Are Computed Like The Constant Expressions Of The Compiler */
+fn evaluate_operation_node(
+    operation_node: &OperationNode, get_variable_value: &dyn Fn(&String) -> Option<Value>
+) -> Result<Value, String>{
+
+    let token = operation_node.value.as_ref().unwrap();
+
+    if operation_node.operator == None{
+        if token.token_type != TokenType::Variable{
+            let value = get_literal_value(token);
+            if value.int == None && value.double == None && value.value_type != Some(ValueType::Boolean) &&
+                value.character == None && value.string == None
+            {
+                return Err(format!("`{}` is out of range", token.value));
+            }
+            return Ok(value);
+        }
+
+        let value = get_variable_value(&token.value);
+        if value == None{
+            return get_constant_value(token);
+        }
+        return Ok(value.unwrap());
+    }
+
+    let left = evaluate_operation_node(operation_node.left.as_ref().unwrap(), get_variable_value)?;
+    let right = evaluate_operation_node(operation_node.right.as_ref().unwrap(), get_variable_value)?;
+
+    let value = evaluate_operation(operation_node.operator.as_ref().unwrap(), &left, &right)?;
+    if value == None{
+        return Err(format!(
+            "can't do `{}` on `{:?}` and `{:?}`",
+            token.value, left.value_type.unwrap(), right.value_type.unwrap()));
+    }
+
+    return Ok(value.unwrap());
+}
+
+
+/* Prints `expression = value`, Variables Are Looked Up By The Debugger Of The Running Mode */
+pub fn print_debug_expression(text: &String, get_variable_value: &dyn Fn(&String) -> Option<Value>){
+    let expression = parse_debug_expression(text);
+    if expression.is_err(){
+        println!("    {} = <{}>", text, expression.unwrap_err());
+        return;
+    }
+
+    let value = evaluate_operation_node(&expression.unwrap(), get_variable_value);
+    if value.is_err(){
+        println!("    {} = <{}>", text, value.unwrap_err());
+        return;
+    }
+
+    println!("    {} = {}", text, value.unwrap());
+}
+
+
+/* A Single Variable Is Printed With Its Type, Like The `variables` Command */
+pub fn is_variable_name(text: &String) -> bool{
+    let tokens = get_expression_tokens(text);
+    return tokens.is_ok() && tokens.as_ref().unwrap().len() == 1 &&
+        tokens.unwrap()[0].token_type == TokenType::Variable;
+}
+
+
+/* Read Commands Until A Valid One Is Entered, End Of Input Quits */
+pub fn read_debug_command() -> Result<DebugCommand, String>{
+    loop{
+        print!("(engine-debug) ");
+        if std::io::stdout().flush().is_err(){
+            return Err(String::from(
+                "Engine Debugger: Error -> Failed to print to console."));
+        }
+
+        let mut line = String::new();
+        let length = std::io::stdin().read_line(&mut line);
+        if length.is_err(){
+            return Err(String::from(
+                "Engine Debugger: Error -> Failed to read line."));
+        }
+        if length.unwrap() == 0{
+            println!();
+            return Ok(DebugCommand::Quit);
+        }
+
+        if line.trim().is_empty(){
+            continue;
+        }
+
+        let command = parse_debug_command(line.trim());
+        if command.is_err(){
+            println!("{}", command.unwrap_err());
+            continue;
+        }
+
+        return Ok(command.unwrap());
+    }
+}
@@ -21,6 +21,7 @@ pub enum ValueType {
     Double,
     Character,
     String,
+    List,
 }
 
 
@@ -69,6 +70,7 @@ pub struct Value{
     pub double: Option<f64>,
     pub character: Option<char>,
     pub string: Option<String>,
+    pub list: Option<Vec<String>>,
 }
 
 impl Value{
@@ -80,7 +82,33 @@ impl Value{
             int: None,
             double: None,
             character: None,
-            string: None
+            string: None,
+            list: None
         };
     }
 }
+
+impl std::fmt::Display for Value{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.value_type == Some(ValueType::Boolean){
+            return write!(f, "{}", if self.boolean == Some(true) {"True"} else {"False"});
+        }
+        else if self.value_type == Some(ValueType::Integer){
+            return write!(f, "{}", self.int.unwrap());
+        }
+        else if self.value_type == Some(ValueType::Double){
+            return write!(f, "{}", self.double.unwrap());
+        }
+        else if self.value_type == Some(ValueType::Character){
+            return write!(f, "{}", self.character.unwrap());
+        }
+        else if self.value_type == Some(ValueType::String){
+            return write!(f, "{}", self.string.as_ref().unwrap());
+        }
+        else if self.value_type == Some(ValueType::List){
+            return write!(f, "{:?}", self.list.as_ref().unwrap());
+        }
+
+        return write!(f, "");
+    }
+}
@@ -1,19 +1,28 @@
 use std::io::{
+    Read,
+    Write,
     Seek,
     SeekFrom,
     BufRead,
-    BufReader
+    BufReader,
+    Cursor
 };
 
 use crate::character::Character;
 use crate::constants::Mode;
 
 
+/* The Characters Are Read From A File On Disk Or From A Text In Memory */
+trait Source: Read + Write + Seek + std::fmt::Debug{}
+
+impl<T: Read + Write + Seek + std::fmt::Debug> Source for T{}
+
+
 #[derive(Debug)]
 pub struct File{
     position: u64,
     file_length: u64,
-    file: std::fs::File,
+    file: Box<dyn Source>,
     current_character: [u8; 4],
     pub file_path: String,
     mode: Mode,
@@ -22,9 +31,7 @@ pub struct File{
 
 impl File{
     pub fn new(file_path: &String, mode: Mode) -> Self{
-        use std::io::Read;
-
-        let mut file = std::fs::File::open(file_path).expect(format!(
+        let file = std::fs::File::open(file_path).expect(format!(
             "{mode}: File Error -> Can't open the file `{}`.",
             file_path).as_str());
 
@@ -32,6 +39,19 @@ impl File{
             "{mode}: File Error -> Failed to get file metadata `{}`.",
             file_path).as_str()).len();
 
+        return File::from_source(Box::new(file), file_length, file_path, mode);
+    }
+
+    /* A Source Without A File, Like The Expressions Of The Debugger */
+    pub fn from_text(text: &str, mode: Mode) -> Self{
+        let file = Cursor::new(text.as_bytes().to_vec());
+
+        return File::from_source(Box::new(file), text.len() as u64, &String::new(), mode);
+    }
+
+    fn from_source(file: Box<dyn Source>, file_length: u64, file_path: &String, mode: Mode) -> Self{
+        let mut file = file;
+
         /* Read First Character */
         let mut first_char = [0; 1];
         file.read(&mut first_char).expect(format!(
@@ -90,7 +110,7 @@ impl File{
         return Ok(File{
             position: 0,
             file_length: 0,
-            file,
+            file: Box::new(file),
             current_character: [0; 4],
             file_path,
             mode,
@@ -109,7 +129,7 @@ impl File{
             reader: Some(BufReader::new(file.try_clone().expect(format!(
                 "{}: File Error -> Failed Construct file reader `{}`.",
                 Mode::VirtualMachine, file_path).as_str()))),
-            file,
+            file: Box::new(file),
             current_character: [0; 4],
             file_path: file_path.clone(),
             mode: Mode::VirtualMachine,
@@ -120,8 +140,6 @@ impl File{
 impl File{
     /* Implement The Peek Functionality With 0 and 1 Only */
     pub fn peek(&mut self, index: u64) -> Character{
-        use std::io::Read;
-
         if index == 0{
             return Character::new(self.current_character, self.mode.clone());
         }
@@ -181,8 +199,6 @@ impl File{
 
     /* Read UTF-8 Characters Which takes 4 bytes array */
     pub fn read(&mut self) -> Character{
-        use std::io::Read;
-
         if self.position >= self.file_length{
             if self.current_character[0] != 0{
                 let current = Character::new(
@@ -251,15 +267,11 @@ impl File{
     }
 
     pub fn write(&mut self, data: String){
-        use std::io::Write;
-
         self.file.write_all(data.as_bytes()).expect(format!(
             "{}: File Error -> Error in Writing Data Into File.", self.mode).as_str());
     }
 
     pub fn writeln(&mut self, data: String){
-        use std::io::Write;
-
         let mut data = data;
         data.push_str("\n");
 
@@ -273,12 +285,6 @@ impl File{
             "{}: File Error -> Error in deleting file.", mode).as_str());
     }
 
-    pub fn get_stream_position(&mut self) -> u64{
-        return self.file.stream_position().expect(format!(
-            "{}: File Error -> Failed to get stream position.",
-            self.mode).as_str());
-    }
-
     pub fn get_reader_stream_position(&mut self) -> u64{
         return self.reader.as_mut().unwrap().stream_position().expect(format!(
             "{}: File Error -> Failed to get stream position.",
@@ -291,18 +297,6 @@ impl File{
             self.mode).as_str());
     }
 
-    pub fn rewrite_line(&mut self, stream_position: u64, data: String){
-        self.file.seek(SeekFrom::Start(stream_position)).expect(format!(
-            "{}: File Error -> Failed to set stream position.",
-            self.mode).as_str());
-
-        self.writeln(data);
-
-        self.file.seek(SeekFrom::End(0)).expect(format!(
-            "{}: File Error -> Failed to set stream position.",
-            self.mode).as_str());
-    }
-
     pub fn read_byte_code_line(&mut self) -> Result<(String, usize), std::io::Error>{
         let mut line = String::new();
 
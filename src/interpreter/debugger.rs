@@ -0,0 +1,235 @@
+use std::collections::BTreeSet;
+use std::io::Write;
+
+use crate::debugger::{
+    DebugCommand,
+    DEBUG_HELP,
+    read_debug_command,
+    is_variable_name,
+    print_debug_expression
+};
+use crate::environments::Variable;
+use crate::syntax_tree::StatementNode;
+use crate::interpreter::symantic_analyzer::Analyzer;
+
+
+#[derive(Debug, Clone, PartialEq)]
+enum DebugMode{
+    Step,
+    Next(usize),
+    Continue,
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Debugger{
+    breakpoints: BTreeSet<u64>,
+    watch_list: Vec<String>,
+    mode: DebugMode,
+    source_lines: Vec<String>,
+}
+
+impl Debugger{
+    pub fn new(file_path: &String) -> Result<Self, String>{
+        let source = std::fs::read_to_string(file_path);
+        if source.is_err(){
+            return Err(format!(
+                "Engine Debugger: File Error -> Can't open the file `{}`.",
+                file_path));
+        }
+
+        return Ok(Debugger{
+            breakpoints: BTreeSet::new(),
+            watch_list: vec![],
+            mode: DebugMode::Step,
+            source_lines: source.unwrap().lines().map(|line| String::from(line)).collect(),
+        });
+    }
+}
+
+
+fn print_variable(variable: &Variable){
+    let value = variable.value.as_ref();
+
+    if value == None{
+        println!("    {} = <undefined>", variable.name.as_ref().unwrap());
+    }
+    else{
+        println!(
+            "    {}: {:?} = {}",
+            variable.name.as_ref().unwrap(),
+            variable.variable_type.as_ref().unwrap(),
+            value.unwrap());
+    }
+}
+
+
+/* Search From The Innermost Environment */
+fn find_variable(analyzer: &Analyzer, name: &String) -> Option<Variable>{
+    for environment in &analyzer.environments_stack{
+        let variable = environment.variables.get(name);
+        if variable != None && variable.unwrap() != &None{
+            return variable.unwrap().clone();
+        }
+    }
+
+    return None;
+}
+
+
+fn print_named_variable(analyzer: &Analyzer, name: &String){
+    if !is_variable_name(name){
+        print_debug_expression(name, &|variable_name| {
+            let variable = find_variable(analyzer, variable_name);
+            if variable == None{
+                return None;
+            }
+            return variable.unwrap().value;
+        });
+        return;
+    }
+
+    let variable = find_variable(analyzer, name);
+    if variable == None{
+        println!("    {} = <not found>", name);
+        return;
+    }
+
+    print_variable(variable.as_ref().unwrap());
+}
+
+
+fn print_current_statement(debugger: &Debugger, line: Option<u64>){
+    if line == None{
+        println!("=> <unknown line>");
+        return;
+    }
+    let line = line.unwrap();
+
+    let source_line = debugger.source_lines.get((line as usize).wrapping_sub(1));
+    println!("=> {}: {}", line, source_line.map(|line| line.trim()).unwrap_or(""));
+}
+
+
+fn print_state(analyzer: &Analyzer, debugger: &Debugger, line: Option<u64>){
+    print_current_statement(debugger, line);
+
+    if debugger.watch_list.len() > 0{
+        println!("Watch:");
+        for name in &debugger.watch_list{
+            print_named_variable(analyzer, name);
+        }
+    }
+}
+
+
+/* Called Before Every Statement Is Executed */
+pub fn debug_statement(
+    analyzer: &mut Analyzer, statement: &StatementNode
+) -> Result<(), String>{
+
+    let mut debugger = analyzer.debugger.take().unwrap();
+
+    let line = statement.get_line();
+    let depth = analyzer.environments_stack.len();
+
+    let is_breakpoint = line != None && debugger.breakpoints.contains(line.as_ref().unwrap());
+    let should_stop = is_breakpoint || match debugger.mode {
+        DebugMode::Step => true,
+        DebugMode::Next(next_depth) => depth <= next_depth,
+        DebugMode::Continue => false,
+    };
+
+    if !should_stop{
+        analyzer.debugger = Some(debugger);
+        return Ok(());
+    }
+
+    if std::io::stdout().flush().is_err(){
+        return Err(String::from(
+            "Engine Debugger: Error -> Failed to print to console."));
+    }
+    println!();
+
+    if is_breakpoint && debugger.mode == DebugMode::Continue{
+        println!("Breakpoint at line {}.", line.unwrap());
+    }
+    print_state(analyzer, &debugger, line);
+
+    loop{
+        let command = read_debug_command()?;
+
+        if command == DebugCommand::Step{
+            debugger.mode = DebugMode::Step;
+            break;
+        }
+        else if command == DebugCommand::Next{
+            debugger.mode = DebugMode::Next(depth);
+            break;
+        }
+        else if command == DebugCommand::Continue{
+            debugger.mode = DebugMode::Continue;
+            break;
+        }
+        else if let DebugCommand::Break(breakpoint_line) = command{
+            if breakpoint_line == 0 || breakpoint_line as usize > debugger.source_lines.len(){
+                println!("There is no line {}.", breakpoint_line);
+            }
+            else{
+                debugger.breakpoints.insert(breakpoint_line as u64);
+                println!("Breakpoint set at line {}.", breakpoint_line);
+            }
+        }
+        else if let DebugCommand::Delete(breakpoint_line) = command{
+            if debugger.breakpoints.remove(&(breakpoint_line as u64)){
+                println!("Breakpoint at line {} removed.", breakpoint_line);
+            }
+            else{
+                println!("There is no breakpoint at line {}.", breakpoint_line);
+            }
+        }
+        else if command == DebugCommand::Breakpoints{
+            for breakpoint_line in &debugger.breakpoints{
+                println!("    {}", breakpoint_line);
+            }
+        }
+        else if let DebugCommand::Print(name) = command{
+            print_named_variable(analyzer, &name);
+        }
+        else if command == DebugCommand::Variables{
+            for environment in &analyzer.environments_stack{
+                println!("{:?}:", environment.scope);
+
+                let mut names: Vec<&String> = environment.variables.keys().collect();
+                names.sort();
+                for name in names{
+                    let variable = environment.variables.get(name).unwrap();
+                    if variable != &None{
+                        print_variable(variable.as_ref().unwrap());
+                    }
+                }
+            }
+        }
+        else if let DebugCommand::Watch(name) = command{
+            if !debugger.watch_list.contains(&name){
+                debugger.watch_list.push(name);
+            }
+        }
+        else if let DebugCommand::Unwatch(name) = command{
+            debugger.watch_list.retain(|watched_name| watched_name != &name);
+        }
+        else if command == DebugCommand::List{
+            print_current_statement(&debugger, line);
+        }
+        else if command == DebugCommand::Help{
+            println!("{}", DEBUG_HELP);
+        }
+        else if command == DebugCommand::Quit{
+            std::process::exit(0);
+        }
+    }
+
+    analyzer.debugger = Some(debugger);
+
+    return Ok(());
+}
@@ -1,7 +1,24 @@
 use std::collections::HashMap;
+use std::time::Instant;
 
 use crate::interpreter::symantic_analyzer::Analyzer;
+use crate::interpreter::debugger::debug_statement;
 use crate::tokens::TokenType;
+use crate::limiter::{
+    check_step,
+    check_memory,
+    check_input,
+    check_call
+};
+use crate::tracer::{
+    format_trace_value,
+    format_trace_line
+};
+use crate::builtins::{
+    call_builtin,
+    get_builtin_value,
+    construct_builtin_value
+};
 use crate::environments::{
     Variable,
     Value,
@@ -19,6 +36,7 @@ use crate::syntax_tree::{
     DefineStringNode,
     DefineVarNode,
     DefinePrintNode,
+    DefineCallNode,
     OperationNode,
     OperatorType,
     StatementNode,
@@ -30,17 +48,6 @@ use crate::syntax_tree::{
     DefineBreakStatementNode,
 };
 use crate::interpreter::symantic_analyzer::{
-    analyze_define_bool,
-    analyze_define_int,
-    analyze_define_double,
-    analyze_define_char,
-    analyze_define_string,
-    analyze_define_var,
-    analyze_define_variable,
-    analyze_define_print,
-    analyze_if_condition,
-    analyze_for_loop_conditions,
-    is_variable_exists,
     get_variable,
     insert_variable_into_current_environmment
 };
@@ -52,63 +59,198 @@ pub fn execute_statement(
 
     let mut analyzer = analyzer;
 
-    if node.statement_type.as_ref().unwrap() == &StatementType::DefineBool{
-        analyze_define_bool(
-            &mut analyzer, &(node.define_bool_statement.as_ref().unwrap()))?;
+    if analyzer.debugger != None{
+        debug_statement(&mut analyzer, node)?;
+    }
+
+    if node.statement_type == Some(StatementType::Discarded){
+        return Ok(());
+    }
+
+    let line = node.get_line().unwrap_or(0) as u128;
+    if analyzer.limiter != None{
+        check_step(analyzer.limiter.as_mut().unwrap(), "statements", line)?;
+    }
+
+    if analyzer.profiler != None{
+        profile_statement(&mut analyzer, node)?;
+    }
+    else{
+        run_statement(&mut analyzer, node)?;
+    }
+
+    if analyzer.limiter != None && analyzer.limiter.as_ref().unwrap().has_memory_limit(){
+        check_memory(
+            analyzer.limiter.as_ref().unwrap(), get_environments_memory(analyzer), line)?;
+    }
+
+    return Ok(());
+}
+
+
+/* Bytes Of The Strings And The Lists Items Held In All Environments */
+fn get_environments_memory(analyzer: &Analyzer) -> usize{
+    let mut memory = 0;
+
+    let get_variable_memory = |variable: &Variable| -> usize{
+        if variable.value == None{
+            return 0;
+        }
+        let value = variable.value.as_ref().unwrap();
+
+        if value.list != None{
+            return value.list.as_ref().unwrap().iter().map(|item| item.len()).sum();
+        }
+        else if value.string == None{
+            return 0;
+        }
+        return value.string.as_ref().unwrap().len();
+    };
+
+    for environment in &analyzer.environments_stack{
+        for variable in environment.variables.values(){
+            if variable != &None{
+                memory += get_variable_memory(variable.as_ref().unwrap());
+            }
+        }
+
+        for variables in environment.internal_variables.values(){
+            for variable in variables{
+                memory += get_variable_memory(variable);
+            }
+        }
+    }
+
+    return memory;
+}
+
+
+fn run_statement(
+    analyzer: &mut Analyzer, node: &StatementNode
+) -> Result<(), String>{
+
+    let mut analyzer = analyzer;
+
+    if analyzer.tracer.is_some(){
+        return trace_statement(&mut analyzer, node);
+    }
+
+    return dispatch_statement(&mut analyzer, node);
+}
+
 
+fn profile_statement(
+    analyzer: &mut Analyzer, node: &StatementNode
+) -> Result<(), String>{
+
+    let mut analyzer = analyzer;
+
+    let line = node.get_line().unwrap_or(0) as u128;
+    let kind = format!("{:?}", node.statement_type.as_ref().unwrap());
+
+    analyzer.profiler.as_mut().unwrap().enter(&kind, line);
+    let start = Instant::now();
+
+    run_statement(&mut analyzer, node)?;
+
+    analyzer.profiler.as_mut().unwrap().exit(&kind, line, start.elapsed());
+
+    return Ok(());
+}
+
+
+fn get_trace_value(analyzer: &Analyzer, variable_name: &String) -> String{
+    for environment in &analyzer.environments_stack{
+        let variable = environment.variables.get(variable_name);
+        if variable != None && variable.unwrap() != &None{
+            return format_trace_value(variable.unwrap().as_ref().unwrap().value.as_ref());
+        }
+    }
+
+    return format_trace_value(None);
+}
+
+
+/* Blocks Statements Are Traced Before Their Body, Other Statements After Executing */
+fn trace_statement(
+    analyzer: &mut Analyzer, node: &StatementNode
+) -> Result<(), String>{
+
+    let mut analyzer = analyzer;
+
+    let line = node.get_line().unwrap_or(0) as u128;
+    let kind = format!("{:?}", node.statement_type.as_ref().unwrap());
+
+    let mut reads = vec![];
+    for name in node.get_read_variables_names(){
+        let value = get_trace_value(analyzer, &name);
+        reads.push((name, value));
+    }
+
+    let assigned_name = node.get_assigned_name();
+    if assigned_name == None{
+        let trace_line = format_trace_line("interpreter", line, kind, reads, None);
+        analyzer.tracer.as_mut().unwrap().trace(trace_line)?;
+
+        return dispatch_statement(&mut analyzer, node);
+    }
+    let assigned_name = assigned_name.unwrap();
+
+    let before = get_trace_value(analyzer, &assigned_name);
+    dispatch_statement(&mut analyzer, node)?;
+    let after = get_trace_value(analyzer, &assigned_name);
+
+    let trace_line = format_trace_line(
+        "interpreter", line, kind, reads, Some((assigned_name, before, after)));
+    analyzer.tracer.as_mut().unwrap().trace(trace_line)?;
+
+    return Ok(());
+}
+
+
+fn dispatch_statement(
+    analyzer: &mut Analyzer, node: &StatementNode
+) -> Result<(), String>{
+
+    let mut analyzer = analyzer;
+
+    if node.statement_type.as_ref().unwrap() == &StatementType::DefineBool{
         define_bool(
             &mut analyzer, &(node.define_bool_statement.as_ref().unwrap()))?;
     }
     else if node.statement_type.as_ref().unwrap() == &StatementType::DefineInt{
-        analyze_define_int(
-            &mut analyzer, node.define_int_statement.as_ref().unwrap().clone())?;
-
         define_int(
             &mut analyzer, node.define_int_statement.as_ref().unwrap().clone())?;
     }
     else if node.statement_type.as_ref().unwrap() == &StatementType::DefineDouble{
-        analyze_define_double(
-            &mut analyzer, node.define_double_statement.as_ref().unwrap().clone())?;
-
         define_double(
             &mut analyzer, node.define_double_statement.as_ref().unwrap().clone())?;
     }
     else if node.statement_type.as_ref().unwrap() == &StatementType::DefineChar{
-        analyze_define_char(
-            &mut analyzer, node.define_char_statement.as_ref().unwrap().clone())?;
-
         define_char(
             &mut analyzer, node.define_char_statement.as_ref().unwrap().clone())?;
     }
     else if node.statement_type.as_ref().unwrap() == &StatementType::DefineString{
-        analyze_define_string(
-            &mut analyzer, node.define_string_statement.as_ref().unwrap().clone())?;
-
         define_string(
             &mut analyzer, node.define_string_statement.as_ref().unwrap().clone())?;
     }
     else if node.statement_type.as_ref().unwrap() == &StatementType::DefineVar{
-        analyze_define_var(
-            &mut analyzer, node.define_var_statement.as_ref().unwrap().clone())?;
-
         define_var(
             &mut analyzer, node.define_var_statement.as_ref().unwrap().clone())?;
     }
     else if node.statement_type.as_ref().unwrap() == &StatementType::DefineVariable{
-        analyze_define_variable(
-            &mut analyzer, node.define_variable_statement.as_ref().unwrap().clone())?;
-
         define_variable(
             &mut analyzer, node.define_variable_statement.as_ref().unwrap().clone())?;
     }
 
     else if node.statement_type.as_ref().unwrap() == &StatementType::Print{
-        analyze_define_print(
-            &mut analyzer, node.define_print_statement.as_ref().unwrap().clone())?;
-
         define_print(
             &mut analyzer, node.define_print_statement.as_ref().unwrap().clone())?;
     }
+    else if node.statement_type.as_ref().unwrap() == &StatementType::Call{
+        define_call(
+            &mut analyzer, node.define_call_statement.as_ref().unwrap().clone())?;
+    }
 
     else if node.statement_type == Some(StatementType::DefineIf){
         execute_define_if_statement(
@@ -269,32 +411,16 @@ pub fn define_var(
 
     let mut analyzer = analyzer;
 
-    let mut node_value = execute_operation_node(
+    let node_value = execute_operation_node(
         &analyzer, statement.left.as_ref().unwrap())?;
 
+    let variable_type = statement.variable_type.as_ref().unwrap().clone();
+
     let mut variable = Variable::new();
 
     variable.name = Some(statement.name.as_ref().unwrap().value.clone());
-    if node_value.value_type.as_ref().unwrap() == &ValueType::Boolean{
-        variable.variable_type = Some(TokenType::Bool);
-    }
-    else if node_value.value_type.as_ref().unwrap() == &ValueType::Integer{
-        variable.variable_type = Some(TokenType::Int);
-    }
-    else if node_value.value_type.as_ref().unwrap() == &ValueType::Double{
-        variable.variable_type = Some(TokenType::Double);
-    }
-    else if node_value.value_type.as_ref().unwrap() == &ValueType::Character{
-        variable.variable_type = Some(TokenType::String);
-
-        node_value.value_type = Some(ValueType::String);
-        node_value.string = Some(String::from(node_value.character.unwrap()));
-        node_value.character = None;
-    }
-    else if node_value.value_type.as_ref().unwrap() == &ValueType::String{
-        variable.variable_type = Some(TokenType::String);
-    }
-    variable.value = Some(node_value);
+    variable.value = Some(convert_value(node_value, &variable_type));
+    variable.variable_type = Some(variable_type);
 
     insert_variable_into_current_environmment(&mut analyzer, variable);
 
@@ -308,39 +434,24 @@ pub fn define_variable(
 
     let mut analyzer = analyzer;
 
-    if !is_variable_exists(&analyzer, &statement.name.as_ref().unwrap().value){
-        let mut node_value = execute_operation_node(
+    /* The Checker Resolved Whether The Variable Is Defined Here And Its Type */
+    let variable_type = statement.variable_type.as_ref().unwrap().clone();
+
+    if statement.is_definition{
+        let node_value = execute_operation_node(
             &analyzer, statement.left.as_ref().unwrap())?;
 
         let mut variable = Variable::new();
 
         variable.name = Some(statement.name.as_ref().unwrap().value.clone());
-        if node_value.value_type.as_ref().unwrap() == &ValueType::Boolean{
-            variable.variable_type = Some(TokenType::Bool);
-        }
-        else if node_value.value_type.as_ref().unwrap() == &ValueType::Integer{
-            variable.variable_type = Some(TokenType::Int);
-        }
-        else if node_value.value_type.as_ref().unwrap() == &ValueType::Double{
-            variable.variable_type = Some(TokenType::Double);
-        }
-        else if node_value.value_type.as_ref().unwrap() == &ValueType::Character{
-            variable.variable_type = Some(TokenType::String);
-            node_value.value_type = Some(ValueType::String);
-            node_value.string = Some(String::from(node_value.character.unwrap()));
-            node_value.character = None;
-        }
-        else if node_value.value_type.as_ref().unwrap() == &ValueType::String{
-            variable.variable_type = Some(TokenType::String);
-        }
-        variable.value = Some(node_value);
+        variable.variable_type = Some(variable_type.clone());
+        variable.value = Some(convert_value(node_value, &variable_type));
 
         insert_variable_into_current_environmment(&mut analyzer, variable);
     }
     else{
         let name = statement.name.as_ref().unwrap().value.clone();
         let variable = get_variable(&analyzer, &name)?;
-        let variable_type = variable.variable_type.as_ref().unwrap().clone();
         let operator_type = statement.operator.as_ref().unwrap().token_type.clone();
 
         if operator_type == TokenType::Assign{
@@ -350,22 +461,8 @@ pub fn define_variable(
             let mut variable = Variable::new();
 
             variable.name = Some(statement.name.as_ref().unwrap().value.clone());
-            if node_value.value_type == Some(ValueType::Boolean){
-                variable.variable_type = Some(TokenType::Bool);
-            }
-            else if node_value.value_type == Some(ValueType::Integer){
-                variable.variable_type = Some(TokenType::Int);
-            }
-            else if node_value.value_type == Some(ValueType::Double){
-                variable.variable_type = Some(TokenType::Double);
-            }
-            else if node_value.value_type == Some(ValueType::Character){
-                variable.variable_type = Some(TokenType::String);
-            }
-            else if node_value.value_type == Some(ValueType::String){
-                variable.variable_type = Some(TokenType::String);
-            }
-            variable.value = Some(node_value);
+            variable.variable_type = Some(variable_type.clone());
+            variable.value = Some(convert_value(node_value, &variable_type));
 
             insert_variable_into_current_environmment(&mut analyzer, variable);
             return Ok(());
@@ -465,33 +562,76 @@ pub fn define_variable(
     return Ok(());
 }
 
+/* Converts A Value Into The Type Of The Variable Holding It */
+fn convert_value(value: Value, variable_type: &TokenType) -> Value{
+    let mut value = value;
+
+    if variable_type == &TokenType::Int && value.value_type == Some(ValueType::Double){
+        value.value_type = Some(ValueType::Integer);
+        value.int = Some(value.double.unwrap() as i64);
+        value.double = None;
+    }
+    else if variable_type == &TokenType::Double && value.value_type == Some(ValueType::Integer){
+        value.value_type = Some(ValueType::Double);
+        value.double = Some(value.int.unwrap() as f64);
+        value.int = None;
+    }
+    else if variable_type == &TokenType::String && value.value_type == Some(ValueType::Character){
+        value.value_type = Some(ValueType::String);
+        value.string = Some(String::from(value.character.unwrap()));
+        value.character = None;
+    }
+    else if variable_type == &TokenType::Char && value.value_type == Some(ValueType::String){
+        value.value_type = Some(ValueType::Character);
+        value.character = Some(value.string.unwrap().chars().nth(0).unwrap());
+        value.string = None;
+    }
+
+    return value;
+}
+
+fn get_print_text(value: &Value) -> String{
+    if value.value_type == Some(ValueType::Boolean){
+        return String::from(if value.boolean == Some(true) {"True"} else {"False"});
+    }
+    else if value.value_type == Some(ValueType::Integer){
+        return value.int.unwrap().to_string();
+    }
+    else if value.value_type == Some(ValueType::Double){
+        return value.double.unwrap().to_string();
+    }
+    else if value.value_type == Some(ValueType::Character){
+        return value.character.unwrap().to_string();
+    }
+    else if value.value_type == Some(ValueType::String){
+        return value.string.as_ref().unwrap().clone();
+    }
+    else if value.value_type == Some(ValueType::List){
+        return format!("{:?}", value.list.as_ref().unwrap());
+    }
+
+    return String::new();
+}
+
 pub fn define_print(
     analyzer: &Analyzer,
     statement: DefinePrintNode
 ) -> Result<(), String>{
 
-    let node_value = execute_operation_node(
-        &analyzer, statement.expression.as_ref().unwrap())?;
-
-    if node_value.value_type == Some(ValueType::Boolean){
-        if node_value.boolean == Some(true){
-            print!("True");
-        }
-        else{
-            print!("False");
-        }
-    }
-    else if node_value.value_type == Some(ValueType::Integer){
-        print!("{}", node_value.int.unwrap());
+    let mut texts = vec![];
+    for expression in statement.get_expressions(){
+        texts.push(get_print_text(&execute_operation_node(&analyzer, expression)?));
     }
-    else if node_value.value_type == Some(ValueType::Double){
-        print!("{}", node_value.double.unwrap());
-    }
-    else if node_value.value_type == Some(ValueType::Character){
-        print!("{}", node_value.character.unwrap());
+
+    let end = if statement.end != None {texts.pop().unwrap()} else {String::new()};
+    let separator = if statement.separator != None {texts.pop().unwrap()} else {String::new()};
+
+    let text = texts.join(&separator) + &end;
+    if statement.is_stderr{
+        eprint!("{}", text);
     }
-    else if node_value.value_type == Some(ValueType::String){
-        print!("{}", node_value.string.as_ref().unwrap());
+    else{
+        print!("{}", text);
     }
 
     use std::io::Write;
@@ -505,6 +645,16 @@ pub fn define_print(
     return Ok(());
 }
 
+pub fn define_call(
+    analyzer: &Analyzer,
+    statement: DefineCallNode
+) -> Result<(), String>{
+
+    execute_operation_node(&analyzer, statement.expression.as_ref().unwrap())?;
+
+    return Ok(());
+}
+
 
 fn execute_define_if_statement(
     analyzer: &mut Analyzer,
@@ -525,10 +675,6 @@ fn execute_define_if_statement(
 
         let define_if_node = statement.define_if_node.as_ref().unwrap();
 
-        analyze_if_condition(
-            &analyzer, define_if_node.condition.as_ref().unwrap(),
-            define_if_node.token.as_ref().unwrap())?;
-
         let condition_result = execute_if_condition(
             &analyzer, define_if_node.condition.as_ref().unwrap())?;
 
@@ -556,10 +702,6 @@ fn execute_define_if_statement(
                 stop_statements_execution: None,
             });
 
-            analyze_if_condition(
-                &analyzer, define_if_else_node.condition.as_ref().unwrap(),
-                define_if_else_node.token.as_ref().unwrap())?;
-
             let condition_result = execute_if_condition(
                 &analyzer, define_if_else_node.condition.as_ref().unwrap())?;
 
@@ -608,10 +750,8 @@ fn execute_define_for_loop_statement(
     let mut stop_node: Option<Value> = None;
     let mut step_node: Option<Value> = None;
 
-    /* Analyze And Execute Loop Conditions */
+    /* Execute Loop Conditions */
     {
-        analyze_for_loop_conditions(&analyzer, statement)?;
-
         if statement.start != None{
             let node_value = execute_operation_node(
                 &analyzer, statement.start.as_ref().unwrap())?;
@@ -625,6 +765,7 @@ fn execute_define_for_loop_statement(
                 character: None,
                 double: None,
                 string: None,
+                list: None,
                 string_value: None,
                 int: Some(0)
             };
@@ -647,6 +788,12 @@ fn execute_define_for_loop_statement(
 
     loop{
         /* Before Executing Loop */
+        if analyzer.limiter != None{
+            check_step(
+                analyzer.limiter.as_mut().unwrap(), "statements",
+                statement.variable.as_ref().unwrap().start_line as u128)?;
+        }
+
         if stop_node != None{
             if start_node.int.as_ref().unwrap() >=
                 stop_node.as_ref().unwrap().int.as_ref().unwrap()
@@ -675,6 +822,7 @@ fn execute_define_for_loop_statement(
                 character: None,
                 double: None,
                 string: None,
+                list: None,
                 string_value: None,
                 int: start_node.int.clone()
             });
@@ -825,6 +973,32 @@ fn execute_operation_node(
 
     match &operation_node.operator{
         Some(operator) => {
+            /* Built-in Function Call */
+            if operator == &OperatorType::Call{
+                if analyzer.limiter != None{
+                    check_call(
+                        analyzer.limiter.as_ref().unwrap(),
+                        &operation_node.value.as_ref().unwrap().value,
+                        operation_node.value.as_ref().unwrap().start_line as u128)?;
+                }
+
+                let mut arguments = vec![];
+                for argument in &operation_node.arguments{
+                    arguments.push(get_builtin_value(&execute_operation_node(analyzer, argument)?));
+                }
+
+                let result = call_builtin(&operation_node.value.as_ref().unwrap().value, arguments);
+                if result.is_err(){
+                    return Err(format!(
+                        "Engine Interpreter: Execute Error -> {}, line {}:{}.",
+                        result.unwrap_err(),
+                        operation_node.value.as_ref().unwrap().start_line,
+                        operation_node.value.as_ref().unwrap().start_pos));
+                }
+
+                return Ok(construct_builtin_value(result.unwrap()));
+            }
+
             let mut left_value: Value = Value::new();
             let mut right_value: Value = Value::new();
 
@@ -846,6 +1020,12 @@ fn execute_operation_node(
 
             /* Input Operation */
             if operator == &OperatorType::Convert{
+                if analyzer.limiter != None{
+                    check_input(
+                        analyzer.limiter.as_ref().unwrap(),
+                        operation_node.value.as_ref().unwrap().start_line as u128)?;
+                }
+
                 /* Input */
                 let mut line = String::new();
                 if std::io::stdin().read_line(&mut line).is_err(){
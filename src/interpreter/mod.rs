@@ -1,23 +1,43 @@
 mod symantic_analyzer;
 mod executes;
+mod debugger;
 
 use std::panic;
 use std::env;
 
 use crate::constants::Mode;
 use crate::file::File;
+use crate::options::ExecutionOptions;
+use crate::tracer::Tracer;
+use crate::limiter::Limiter;
+use crate::profiler::{
+    Profiler,
+    report_profile
+};
+use crate::builtins::get_exit_code;
 use crate::lexer::Lexer;
 use crate::parser::{
     Parser,
-    statement
+    parse
+};
+use crate::checker::{
+    Checker,
+    check
+};
+use crate::compiler::{
+    inline_constants,
+    fold_constants
+};
+use crate::ir::{
+    lower,
+    analyze
 };
 use symantic_analyzer::Analyzer;
+use debugger::Debugger;
 use crate::interpreter::executes::execute_statement;
 
 
-pub fn interpret() -> Result<(), String>{
-    let args: Vec<String> = env::args().collect();
-
+fn set_panic_hook(){
     panic::set_hook(Box::new(|panic_info| {
         if let Some(panic_message) = panic_info.payload().downcast_ref::<String>() {
             println!("{}", panic_message);
@@ -27,30 +47,95 @@ pub fn interpret() -> Result<(), String>{
             println!("Engine Interpreter -> Interperter Error");
         }
     }));
+}
+
 
-    let file = File::new(&args[1], Mode::Interpreter);
+fn execute_file(
+    file_path: &String, analyzer: &mut Analyzer
+) -> Result<(), String>{
+
+    let file = File::new(file_path, Mode::Interpreter);
     let lexer = Lexer::new(file, Mode::Interpreter);
     if lexer.is_err(){
         panic!(
             "Engine Interpreter -> File Error: Failed in reading file character `{}`.",
-            &args[1]);
+            file_path);
     }
 
     let mut parser = Parser::new(lexer.unwrap(), Mode::Interpreter)?;
+    let mut syntax_tree = parse(&mut parser)?;
 
-    let mut analyzer = Analyzer::new();
+    let mut checker = Checker::new(Mode::Interpreter);
+    check(&mut checker, &mut syntax_tree)?;
 
-    loop {
-        let result = statement(&mut parser, true)?;
-        let node = result.1;
-        if result.0{
-            break;
-        }
+    /*
+    * The Program Is Lowered Only To Be Analyzed, Like When Compiling It. The Syntax Tree
+    * Runs As It Is Written, So No Dead Code Is Removed Or Reported.
+    */
+    let syntax_tree_to_analyze = fold_constants(
+        &Mode::Interpreter, inline_constants(&Mode::Interpreter, syntax_tree.clone())?)?;
+    let program = lower(&Mode::Interpreter, &syntax_tree_to_analyze)?;
+
+    for warning in analyze(&Mode::Interpreter, &program)?{
+        eprintln!("{}", warning);
+    }
 
+    for node in &syntax_tree.statements{
         if node.statement_type != None{
-            execute_statement(&mut analyzer, &node)?;
+            execute_statement(analyzer, node)?;
         }
     }
 
     return Ok(());
 }
+
+
+pub fn interpret(options: &ExecutionOptions) -> Result<(), String>{
+    let args: Vec<String> = env::args().collect();
+
+    set_panic_hook();
+
+    let mut analyzer = Analyzer::new();
+    if options.trace_path != None{
+        analyzer.tracer = Some(Tracer::new(options.trace_path.as_ref().unwrap())?);
+    }
+
+    if options.has_limits(){
+        analyzer.limiter = Some(Limiter::new(options));
+    }
+    if options.profile{
+        analyzer.profiler = Some(Profiler::new(&args[1])?);
+    }
+
+    let result = execute_file(&args[1], &mut analyzer);
+    let result = if get_exit_code() != None {Ok(())} else {result};
+
+    if analyzer.profiler != None{
+        report_profile(
+            analyzer.profiler.as_ref().unwrap(),
+            options.profile_flamegraph_path.as_ref())?;
+    }
+    result?;
+
+    if analyzer.tracer.is_some(){
+        analyzer.tracer.as_mut().unwrap().flush()?;
+    }
+
+    return Ok(());
+}
+
+
+pub fn debug_code(file_path: &String) -> Result<(), String>{
+    set_panic_hook();
+
+    let mut analyzer = Analyzer::new();
+    analyzer.debugger = Some(Debugger::new(file_path)?);
+
+    println!("Engine Debugger: `{}`, type `help` for commands.", file_path);
+    execute_file(file_path, &mut analyzer)?;
+
+    println!();
+    println!("Program finished.");
+
+    return Ok(());
+}
@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+
+use crate::constants::Mode;
+
+use super::{
+    IrProgram,
+    IrInstruction,
+    IrInstructionKind
+};
+use super::cfg::{
+    ControlFlowGraph,
+    compute_liveness
+};
+
+
+/*
+* Checks The Lowered Program Before The Dead Code Is Removed, Returns The Warnings Or The First Error.
+* Only The Variables Written In The Source Are Reported, The Temporaries Are Always
+* Written Right Before They Are Read.
+*/
+pub fn analyze(mode: &Mode, program: &IrProgram) -> Result<Vec<String>, String>{
+    let cfg = ControlFlowGraph::new(program);
+
+    check_used_before_assignment(mode, program, &cfg)?;
+
+    return Ok(find_unread_assignments(mode, program, &cfg));
+}
+
+
+fn analyze_error(mode: &Mode, message: String, instruction: &IrInstruction) -> String{
+    return format!(
+        "{}: Analyze Error -> {}, line {}:{}.",
+        mode, message, instruction.start_line, instruction.start_pos);
+}
+
+
+fn analyze_warning(mode: &Mode, message: String, instruction: &IrInstruction) -> String{
+    return format!(
+        "{}: Analyze Warning -> {}, line {}:{}.",
+        mode, message, instruction.start_line, instruction.start_pos);
+}
+
+
+/*
+* A Variable Is Assigned When It Is Written Before In The Same Block, Or In A
+* Block Dominating It, Since Every Path Reaching The Read Goes Through That Block.
+*/
+fn check_used_before_assignment(
+    mode: &Mode, program: &IrProgram, cfg: &ControlFlowGraph
+) -> Result<(), String>{
+
+    let mut assigned_variables: Vec<HashSet<&String>> = vec![];
+    for block in &program.blocks{
+        let mut assigned = HashSet::new();
+        for instruction in &block.instructions{
+            let target = instruction.kind.get_target();
+            if target != None{
+                assigned.insert(target.unwrap());
+            }
+        }
+        assigned_variables.push(assigned);
+    }
+
+    for block in &cfg.reverse_postorder{
+        let mut assigned: HashSet<&String> = HashSet::new();
+
+        let mut dominator = cfg.immediate_dominators[*block];
+        while dominator != None{
+            assigned.extend(assigned_variables[dominator.unwrap()].iter());
+            dominator = cfg.immediate_dominators[dominator.unwrap()];
+        }
+
+        for instruction in &program.blocks[*block].instructions{
+            for source in instruction.kind.get_sources(){
+                let source_name = program.get_source_name(source);
+                if source_name != None && !assigned.contains(source){
+                    return Err(analyze_error(
+                        mode,
+                        format!("Variable `{}` may be used before assignment", source_name.unwrap()),
+                        instruction));
+                }
+            }
+
+            let target = instruction.kind.get_target();
+            if target != None{
+                assigned.insert(target.unwrap());
+            }
+        }
+    }
+
+    return Ok(());
+}
+
+
+/* A Value Is Never Read When The Variable Is Not Live Right After It Is Written */
+fn find_unread_assignments(
+    mode: &Mode, program: &IrProgram, cfg: &ControlFlowGraph
+) -> Vec<String>{
+
+    let liveness = compute_liveness(program, cfg);
+
+    let mut warnings = vec![];
+
+    for (index, block) in program.blocks.iter().enumerate(){
+        if !cfg.is_reachable(index){
+            continue;
+        }
+
+        let mut live = liveness.live_out[index].clone();
+        let condition = block.terminator.get_condition();
+        if condition != None{
+            live.insert(condition.unwrap().clone());
+        }
+
+        let mut block_warnings = vec![];
+        for instruction in block.instructions.iter().rev(){
+            let target = instruction.kind.get_target();
+            if target != None{
+                let source_name = program.get_source_name(target.unwrap());
+                if
+                    source_name != None && !live.contains(target.unwrap()) &&
+                    !is_loop_variable_copy(program, instruction)
+                {
+                    block_warnings.push(analyze_warning(
+                        mode,
+                        format!("Value assigned to `{}` is never read", source_name.unwrap()),
+                        instruction));
+                }
+                live.remove(target.unwrap());
+            }
+
+            for source in instruction.kind.get_sources(){
+                live.insert(source.clone());
+            }
+        }
+
+        block_warnings.reverse();
+        warnings.extend(block_warnings);
+    }
+
+    return warnings;
+}
+
+
+/* The Loop Variable Is Written Every Iteration, The Body Doesn't Have To Read It */
+fn is_loop_variable_copy(program: &IrProgram, instruction: &IrInstruction) -> bool{
+    if let IrInstructionKind::Convert{target: _, source} = &instruction.kind{
+        return program.loop_counters.contains(source);
+    }
+    return false;
+}
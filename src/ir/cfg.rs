@@ -0,0 +1,208 @@
+use std::collections::HashSet;
+
+use super::IrProgram;
+
+
+/*
+* The Edges Between The Blocks Of A Program, The Blocks Are Numbered Like In
+* The Program And The First Block Is The Entry.
+*/
+#[derive(Debug)]
+pub struct ControlFlowGraph{
+    pub successors: Vec<Vec<usize>>,
+    pub predecessors: Vec<Vec<usize>>,
+
+    /* The Reachable Blocks, Every Block Comes Before Its Successors Except On Back Edges */
+    pub reverse_postorder: Vec<usize>,
+
+    /* The Closest Block Every Path From The Entry Goes Through, None For The Entry And Unreachable Blocks */
+    pub immediate_dominators: Vec<Option<usize>>,
+}
+
+impl ControlFlowGraph{
+    pub fn new(program: &IrProgram) -> Self{
+        let blocks_count = program.blocks.len();
+
+        let mut successors = vec![];
+        let mut predecessors = vec![vec![]; blocks_count];
+        for (index, block) in program.blocks.iter().enumerate(){
+            let block_successors = block.terminator.get_successors();
+            for successor in &block_successors{
+                if !predecessors[*successor].contains(&index){
+                    predecessors[*successor].push(index);
+                }
+            }
+            successors.push(block_successors);
+        }
+
+        let mut cfg = ControlFlowGraph{
+            successors,
+            predecessors,
+            reverse_postorder: vec![],
+            immediate_dominators: vec![None; blocks_count],
+        };
+
+        if blocks_count > 0{
+            compute_reverse_postorder(&mut cfg);
+            compute_dominators(&mut cfg);
+        }
+
+        return cfg;
+    }
+
+    pub fn is_reachable(&self, block: usize) -> bool{
+        return block == 0 || self.immediate_dominators[block] != None;
+    }
+}
+
+
+fn compute_reverse_postorder(cfg: &mut ControlFlowGraph){
+    let mut visited = vec![false; cfg.successors.len()];
+    let mut postorder = vec![];
+
+    /* Every Entry Is A Block With The Index Of Its Next Successor To Visit */
+    let mut stack: Vec<(usize, usize)> = vec![(0, 0)];
+    visited[0] = true;
+
+    while !stack.is_empty(){
+        let (block, next) = stack.pop().unwrap();
+
+        if next < cfg.successors[block].len(){
+            stack.push((block, next + 1));
+
+            let successor = cfg.successors[block][next];
+            if !visited[successor]{
+                visited[successor] = true;
+                stack.push((successor, 0));
+            }
+        }
+        else{
+            postorder.push(block);
+        }
+    }
+
+    postorder.reverse();
+    cfg.reverse_postorder = postorder;
+}
+
+
+/* The Iterative Algorithm Of Cooper, Harvey And Kennedy */
+fn compute_dominators(cfg: &mut ControlFlowGraph){
+    let mut order = vec![usize::MAX; cfg.successors.len()];
+    for (index, block) in cfg.reverse_postorder.iter().enumerate(){
+        order[*block] = index;
+    }
+
+    let mut dominators: Vec<Option<usize>> = vec![None; cfg.successors.len()];
+    dominators[0] = Some(0);
+
+    let mut changed = true;
+    while changed{
+        changed = false;
+
+        for block in cfg.reverse_postorder.iter().skip(1){
+            let mut new_dominator: Option<usize> = None;
+
+            for predecessor in &cfg.predecessors[*block]{
+                if dominators[*predecessor] == None{
+                    continue;
+                }
+
+                if new_dominator == None{
+                    new_dominator = Some(*predecessor);
+                    continue;
+                }
+
+                /* Walk Up From Both Blocks Until They Meet */
+                let mut first = *predecessor;
+                let mut second = new_dominator.unwrap();
+                while first != second{
+                    while order[first] > order[second]{
+                        first = dominators[first].unwrap();
+                    }
+                    while order[second] > order[first]{
+                        second = dominators[second].unwrap();
+                    }
+                }
+                new_dominator = Some(first);
+            }
+
+            if dominators[*block] != new_dominator{
+                dominators[*block] = new_dominator;
+                changed = true;
+            }
+        }
+    }
+
+    dominators[0] = None;
+    cfg.immediate_dominators = dominators;
+}
+
+
+/* The Variables Whose Current Value May Still Be Read, At The Start And The End Of Every Block */
+#[derive(Debug)]
+pub struct Liveness{
+    pub live_in: Vec<HashSet<String>>,
+    pub live_out: Vec<HashSet<String>>,
+}
+
+
+pub fn compute_liveness(program: &IrProgram, cfg: &ControlFlowGraph) -> Liveness{
+    let blocks_count = program.blocks.len();
+
+    /* The Variables Read Before Being Written, And The Variables Written, In Every Block */
+    let mut uses: Vec<HashSet<String>> = vec![HashSet::new(); blocks_count];
+    let mut definitions: Vec<HashSet<String>> = vec![HashSet::new(); blocks_count];
+    for (index, block) in program.blocks.iter().enumerate(){
+        for instruction in &block.instructions{
+            for source in instruction.kind.get_sources(){
+                if !definitions[index].contains(source){
+                    uses[index].insert(source.clone());
+                }
+            }
+
+            let target = instruction.kind.get_target();
+            if target != None{
+                definitions[index].insert(target.unwrap().clone());
+            }
+        }
+
+        let condition = block.terminator.get_condition();
+        if condition != None && !definitions[index].contains(condition.unwrap()){
+            uses[index].insert(condition.unwrap().clone());
+        }
+    }
+
+    let mut liveness = Liveness{
+        live_in: vec![HashSet::new(); blocks_count],
+        live_out: vec![HashSet::new(); blocks_count],
+    };
+
+    /* Going Over The Blocks Backward Makes Most Values Settle In One Pass */
+    let mut changed = true;
+    while changed{
+        changed = false;
+
+        for block in cfg.reverse_postorder.iter().rev(){
+            let mut live_out = HashSet::new();
+            for successor in &cfg.successors[*block]{
+                live_out.extend(liveness.live_in[*successor].iter().cloned());
+            }
+
+            let mut live_in = uses[*block].clone();
+            for variable in &live_out{
+                if !definitions[*block].contains(variable){
+                    live_in.insert(variable.clone());
+                }
+            }
+
+            if live_in != liveness.live_in[*block]{
+                liveness.live_in[*block] = live_in;
+                changed = true;
+            }
+            liveness.live_out[*block] = live_out;
+        }
+    }
+
+    return liveness;
+}
@@ -0,0 +1,490 @@
+use std::collections::{
+    HashMap,
+    HashSet
+};
+
+use crate::constants::Mode;
+use crate::tokens::TokenType;
+use crate::syntax_tree::OperatorType;
+
+use super::{
+    IrProgram,
+    IrBlock,
+    IrInstruction,
+    IrInstructionKind,
+    IrTerminator
+};
+use super::cfg::{
+    ControlFlowGraph,
+    compute_liveness
+};
+
+
+/*
+* Removes The Code That Never Runs Or Has No Effect: Branches On Literal
+* Conditions, Blocks After `break` And `continue`, Loops Without Effects And
+* Temporaries Never Read. Returns The Program Left With A Warning For Every
+* Removed Piece Of Code Written In The Source, In The Order Of The Source.
+*/
+pub fn eliminate_dead_code(mode: &Mode, program: IrProgram) -> (IrProgram, Vec<String>){
+    let mut program = program;
+    let mut warnings: Vec<(u64, u64, String)> = vec![];
+
+    let cfg = ControlFlowGraph::new(&program);
+
+    /* Blocks Started After `break` And `continue` Have No Predecessors */
+    for block in 1..program.blocks.len(){
+        if cfg.predecessors[block].is_empty(){
+            add_unreachable_warning(
+                mode, &program, &cfg, block,
+                String::from("Unreachable code after `break` or `continue`"), &mut warnings);
+        }
+    }
+
+    for (block, condition) in fold_constant_branches(&mut program){
+        add_unreachable_warning(
+            mode, &program, &ControlFlowGraph::new(&program), block,
+            format!("Unreachable code, the condition is always `{}`", condition), &mut warnings);
+    }
+
+    program = remove_unreachable_blocks(program);
+
+    while let Some(warning) = remove_empty_loop(mode, &mut program){
+        warnings.push(warning);
+        program = remove_unreachable_blocks(program);
+    }
+
+    remove_unread_temps(&mut program);
+    program = merge_blocks(program);
+    remove_unused_variables(&mut program);
+
+    warnings.sort();
+
+    return (program, warnings.into_iter().map(|(_, _, warning)| warning).collect());
+}
+
+
+/* Reports The First Instruction Of The Unreachable Blocks Starting From The Given One */
+fn add_unreachable_warning(
+    mode: &Mode, program: &IrProgram, cfg: &ControlFlowGraph, block: usize,
+    message: String, warnings: &mut Vec<(u64, u64, String)>
+){
+    let mut visited = HashSet::new();
+    let mut stack = vec![block];
+    let mut first_instruction: Option<&IrInstruction> = None;
+
+    while !stack.is_empty(){
+        let current = stack.pop().unwrap();
+        if cfg.is_reachable(current) || !visited.insert(current){
+            continue;
+        }
+
+        let instruction = program.blocks[current].instructions.first();
+        if instruction != None && (
+            first_instruction == None ||
+            (instruction.unwrap().start_line, instruction.unwrap().start_pos) <
+                (first_instruction.unwrap().start_line, first_instruction.unwrap().start_pos))
+        {
+            first_instruction = instruction;
+        }
+
+        stack.extend(cfg.successors[current].iter());
+    }
+
+    if first_instruction == None{
+        return;
+    }
+
+    let warning = get_warning(mode, message, first_instruction.unwrap());
+    if !warnings.contains(&warning){
+        warnings.push(warning);
+    }
+}
+
+
+fn get_warning(mode: &Mode, message: String, instruction: &IrInstruction) -> (u64, u64, String){
+    return (
+        instruction.start_line,
+        instruction.start_pos,
+        format!(
+            "{}: Analyze Warning -> {}, line {}:{}.",
+            mode, message, instruction.start_line, instruction.start_pos));
+}
+
+
+/* The Literal A Temporary Holds, When It Is Written Once By A Constant Or A Copy Of One */
+fn get_constant_value(program: &IrProgram, variable: &String) -> Option<String>{
+    if program.get_source_name(variable) != None{
+        return None;
+    }
+
+    let mut definition: Option<&IrInstructionKind> = None;
+    for block in &program.blocks{
+        for instruction in &block.instructions{
+            if instruction.kind.get_target() == Some(variable){
+                if definition != None{
+                    return None;
+                }
+                definition = Some(&instruction.kind);
+            }
+        }
+    }
+
+    return match definition {
+        Some(IrInstructionKind::Constant{target: _, value}) => Some(value.clone()),
+        Some(IrInstructionKind::Convert{target: _, source}) if
+            program.get_variable_type(source) == program.get_variable_type(variable) =>
+                get_constant_value(program, source),
+        _ => None,
+    };
+}
+
+
+/* Branches On A Literal Become Jumps, Returns The Blocks No Longer Jumped To With The Literal */
+fn fold_constant_branches(program: &mut IrProgram) -> Vec<(usize, String)>{
+    let mut skipped_blocks = vec![];
+
+    for index in 0..program.blocks.len(){
+        let terminator = program.blocks[index].terminator.clone();
+        if let IrTerminator::Branch{condition, then_block, else_block} = terminator{
+            let value = get_constant_value(program, &condition);
+            if value == None{
+                continue;
+            }
+
+            let is_true = value.unwrap() == "True";
+            program.blocks[index].terminator = IrTerminator::Jump{
+                target: if is_true {then_block} else {else_block}};
+
+            skipped_blocks.push((
+                if is_true {else_block} else {then_block},
+                String::from(if is_true {"True"} else {"False"})));
+        }
+    }
+
+    return skipped_blocks;
+}
+
+
+fn remove_unreachable_blocks(program: IrProgram) -> IrProgram{
+    let cfg = ControlFlowGraph::new(&program);
+
+    let mut new_indexes: HashMap<usize, usize> = HashMap::new();
+    for index in 0..program.blocks.len(){
+        if cfg.is_reachable(index){
+            new_indexes.insert(index, new_indexes.len());
+        }
+    }
+
+    let mut program = program;
+    let blocks: Vec<IrBlock> = std::mem::take(&mut program.blocks);
+
+    for (index, block) in blocks.into_iter().enumerate(){
+        if !cfg.is_reachable(index){
+            continue;
+        }
+
+        let mut block = block;
+        block.terminator = match block.terminator {
+            IrTerminator::Jump{target} => IrTerminator::Jump{target: new_indexes[&target]},
+            IrTerminator::Branch{condition, then_block, else_block} => IrTerminator::Branch{
+                condition,
+                then_block: new_indexes[&then_block],
+                else_block: new_indexes[&else_block]},
+            IrTerminator::End => IrTerminator::End,
+        };
+
+        program.blocks.push(block);
+    }
+
+    return program;
+}
+
+
+/* Instructions That Can Fail Or Are Seen Outside Of The Program */
+fn has_effect(program: &IrProgram, kind: &IrInstructionKind) -> bool{
+    return match kind {
+        IrInstructionKind::Constant{target: _, value: _} => false,
+        IrInstructionKind::Convert{target, source} =>
+            program.get_variable_type(source) == Some(TokenType::String) &&
+            program.get_variable_type(target) != Some(TokenType::String),
+        IrInstructionKind::Operation{operator, target: _, left: _, right} =>
+            operator == &OperatorType::Mod && program.get_variable_type(right) == Some(TokenType::Int),
+        _ => true,
+    };
+}
+
+
+/*
+* A Loop Is The Target Of A Back Edge, Its Header Dominates The Block Jumping
+* Back, With Every Block Reaching That Block Without Going Through The Header.
+*/
+fn get_loops(cfg: &ControlFlowGraph) -> Vec<(usize, HashSet<usize>)>{
+    let mut loops = vec![];
+
+    for block in &cfg.reverse_postorder{
+        for successor in &cfg.successors[*block]{
+            let mut dominator = Some(*block);
+            while dominator != None && dominator != Some(*successor){
+                dominator = cfg.immediate_dominators[dominator.unwrap()];
+            }
+            if dominator == None{
+                continue;
+            }
+
+            let mut body = HashSet::from([*successor]);
+            let mut stack = vec![*block];
+            while !stack.is_empty(){
+                let current = stack.pop().unwrap();
+                if body.insert(current){
+                    stack.extend(cfg.predecessors[current].iter());
+                }
+            }
+
+            loops.push((*successor, body));
+        }
+    }
+
+    return loops;
+}
+
+
+/*
+* Removes The First Loop Without Effects Whose Values Are Not Read After It.
+* The Loop Must Count Up To Its Stop Value By A Positive Literal So It Ends.
+*/
+fn remove_empty_loop(mode: &Mode, program: &mut IrProgram) -> Option<(u64, u64, String)>{
+    let cfg = ControlFlowGraph::new(program);
+    let liveness = compute_liveness(program, &cfg);
+
+    for (header, body) in get_loops(&cfg){
+        /* The Header Compares The Counter With The Stop Value And Exits The Loop */
+        let (condition, exit_block) = match &program.blocks[header].terminator {
+            IrTerminator::Branch{condition, then_block, else_block}
+                if body.contains(then_block) && !body.contains(else_block) =>
+                    (condition.clone(), *else_block),
+            _ => continue,
+        };
+
+        let mut is_removable = true;
+        let mut written_variables: HashSet<&String> = HashSet::new();
+        for block in &body{
+            if *block != header && program.blocks[*block].terminator.get_condition() != None{
+                is_removable = false;
+            }
+            for successor in &cfg.successors[*block]{
+                if !body.contains(successor) && *successor != exit_block{
+                    is_removable = false;
+                }
+            }
+
+            for instruction in &program.blocks[*block].instructions{
+                let target = instruction.kind.get_target();
+                if target == None || has_effect(program, &instruction.kind){
+                    is_removable = false;
+                    continue;
+                }
+                written_variables.insert(target.unwrap());
+            }
+        }
+
+        if !is_removable || written_variables.iter().any(
+            |variable| liveness.live_in[exit_block].contains(*variable))
+        {
+            continue;
+        }
+
+        let comparison = program.blocks[header].instructions.iter().find(
+            |instruction| instruction.kind.get_target() == Some(&condition));
+        let (counter, stop) = match comparison.map(|instruction| &instruction.kind) {
+            Some(IrInstructionKind::Operation{operator: OperatorType::LessThan, target: _, left, right}) =>
+                (left, right),
+            _ => continue,
+        };
+
+        let mut increments = 0;
+        let mut has_positive_step = false;
+        for block in &body{
+            for instruction in &program.blocks[*block].instructions{
+                if instruction.kind.get_target() != Some(counter){
+                    continue;
+                }
+                increments += 1;
+
+                if let IrInstructionKind::Operation{operator: OperatorType::Plus, target: _, left, right} =
+                    &instruction.kind
+                {
+                    let step = get_constant_value(program, right);
+                    has_positive_step =
+                        left == counter && !written_variables.contains(right) &&
+                        step != None && step.unwrap().parse::<i64>().unwrap_or(0) > 0;
+                }
+            }
+        }
+
+        if increments != 1 || !has_positive_step || written_variables.contains(stop){
+            continue;
+        }
+
+        let warning = get_warning(
+            mode, String::from("Loop has no effect and is removed"),
+            program.blocks[header].instructions.first().unwrap());
+
+        /* The Blocks Entering The Loop Go To Its Exit Instead */
+        for block in 0..program.blocks.len(){
+            if body.contains(&block){
+                continue;
+            }
+
+            let terminator = &mut program.blocks[block].terminator;
+            *terminator = match terminator.clone() {
+                IrTerminator::Jump{target} => IrTerminator::Jump{
+                    target: if target == header {exit_block} else {target}},
+                IrTerminator::Branch{condition, then_block, else_block} => IrTerminator::Branch{
+                    condition,
+                    then_block: if then_block == header {exit_block} else {then_block},
+                    else_block: if else_block == header {exit_block} else {else_block}},
+                IrTerminator::End => IrTerminator::End,
+            };
+        }
+
+        return Some(warning);
+    }
+
+    return None;
+}
+
+
+/*
+* A Block Only Jumped To By The Block Before It Is Joined To It, And The
+* Empty Blocks Only Jumping Somewhere Else Are Skipped.
+*/
+fn merge_blocks(program: IrProgram) -> IrProgram{
+    let mut program = program;
+
+    let mut changed = true;
+    while changed{
+        changed = false;
+
+        program = remove_unreachable_blocks(program);
+        let cfg = ControlFlowGraph::new(&program);
+
+        for index in 0..program.blocks.len(){
+            let target = match program.blocks[index].terminator {
+                IrTerminator::Jump{target} => target,
+                _ => continue,
+            };
+            if target == index || target == 0{
+                continue;
+            }
+
+            if cfg.predecessors[target].len() == 1{
+                let mut instructions = std::mem::take(&mut program.blocks[target].instructions);
+                program.blocks[index].instructions.append(&mut instructions);
+                program.blocks[index].terminator = program.blocks[target].terminator.clone();
+
+                /* The Joined Block Is Left Unreachable */
+                program.blocks[target].terminator = IrTerminator::End;
+                changed = true;
+                break;
+            }
+        }
+
+        for index in 1..program.blocks.len(){
+            if changed{
+                break;
+            }
+
+            let target = match program.blocks[index].terminator {
+                IrTerminator::Jump{target} if program.blocks[index].instructions.is_empty() => target,
+                _ => continue,
+            };
+            if target == index{
+                continue;
+            }
+
+            for block in 0..program.blocks.len(){
+                let terminator = program.blocks[block].terminator.clone();
+                program.blocks[block].terminator = match terminator {
+                    IrTerminator::Jump{target: jump_target} if jump_target == index =>
+                        IrTerminator::Jump{target},
+                    IrTerminator::Branch{condition, then_block, else_block} => IrTerminator::Branch{
+                        condition,
+                        then_block: if then_block == index {target} else {then_block},
+                        else_block: if else_block == index {target} else {else_block}},
+                    terminator => terminator,
+                };
+            }
+
+            if !cfg.predecessors[index].is_empty(){
+                changed = true;
+            }
+        }
+    }
+
+    return program;
+}
+
+
+/* Instructions Without Effects Writing Temporaries That Are Never Read */
+fn remove_unread_temps(program: &mut IrProgram){
+    let mut changed = true;
+    while changed{
+        changed = false;
+
+        let cfg = ControlFlowGraph::new(program);
+        let liveness = compute_liveness(program, &cfg);
+
+        for index in 0..program.blocks.len(){
+            let mut live = liveness.live_out[index].clone();
+            let condition = program.blocks[index].terminator.get_condition();
+            if condition != None{
+                live.insert(condition.unwrap().clone());
+            }
+
+            let mut kept_instructions = vec![];
+            for instruction in program.blocks[index].instructions.iter().rev(){
+                let target = instruction.kind.get_target();
+                if
+                    target != None && !live.contains(target.unwrap()) &&
+                    program.get_source_name(target.unwrap()) == None &&
+                    !has_effect(program, &instruction.kind)
+                {
+                    changed = true;
+                    continue;
+                }
+
+                if target != None{
+                    live.remove(target.unwrap());
+                }
+                for source in instruction.kind.get_sources(){
+                    live.insert(source.clone());
+                }
+                kept_instructions.push(instruction.clone());
+            }
+
+            kept_instructions.reverse();
+            program.blocks[index].instructions = kept_instructions;
+        }
+    }
+}
+
+
+fn remove_unused_variables(program: &mut IrProgram){
+    let mut used_variables: HashSet<String> = HashSet::new();
+    for block in &program.blocks{
+        for instruction in &block.instructions{
+            if instruction.kind.get_target() != None{
+                used_variables.insert(instruction.kind.get_target().unwrap().clone());
+            }
+            for source in instruction.kind.get_sources(){
+                used_variables.insert(source.clone());
+            }
+        }
+        if block.terminator.get_condition() != None{
+            used_variables.insert(block.terminator.get_condition().unwrap().clone());
+        }
+    }
+
+    program.retain_variables(&used_variables);
+}
@@ -0,0 +1,735 @@
+use std::collections::{
+    HashMap,
+    VecDeque
+};
+
+use crate::constants::Mode;
+use crate::tokens::{
+    Token,
+    TokenType
+};
+use crate::builtins::{
+    get_builtin_type,
+    get_builtin_variable_type,
+    get_builtin_return_type
+};
+use crate::environments::{
+    Environment,
+    EnvironmentScope,
+    Variable
+};
+use crate::syntax_tree::{
+    StatementsNode,
+    StatementNode,
+    StatementType,
+    OperationNode,
+    OperatorType,
+    DefinePrintNode,
+    DefineVariableNode,
+    DefineIfStatementNode,
+    DefineForLoopStatementNode
+};
+
+use super::{
+    IrProgram,
+    IrBlock,
+    IrInstruction,
+    IrInstructionKind,
+    IrTerminator
+};
+
+
+struct IrBuilder{
+    mode: Mode,
+    program: IrProgram,
+    environments_stack: VecDeque<Environment>,
+
+    /* The Blocks `continue` And `break` Go To, For Every Enclosing Loop */
+    loops_stack: Vec<(usize, usize)>,
+
+    current_block: usize,
+
+    /* The Blocks In The Order They Are Written */
+    layout: Vec<usize>,
+
+    temp_counter: u128,
+
+    /* Position Of The Lowered Statement, Given To Its Instructions */
+    start_line: u64,
+    start_pos: u64,
+}
+
+impl IrBuilder{
+    fn new(mode: &Mode) -> Self{
+        let mut environments_stack = VecDeque::new();
+        environments_stack.push_back(Environment {
+            scope: EnvironmentScope::Main,
+            variables: HashMap::new(),
+            internal_variables: HashMap::new(),
+            stop_statements_execution: None,
+        });
+
+        let mut program = IrProgram::new();
+        program.blocks.push(IrBlock::new());
+
+        return IrBuilder{
+            mode: mode.clone(),
+            program,
+            environments_stack,
+            loops_stack: Vec::new(),
+            current_block: 0,
+            layout: vec![0],
+            temp_counter: 0,
+            start_line: 0,
+            start_pos: 0,
+        };
+    }
+
+    fn new_block(&mut self) -> usize{
+        self.program.blocks.push(IrBlock::new());
+        return self.program.blocks.len() - 1;
+    }
+
+    fn switch_to_block(&mut self, block: usize){
+        self.current_block = block;
+        self.layout.push(block);
+    }
+
+    fn terminate(&mut self, terminator: IrTerminator){
+        self.program.blocks[self.current_block].terminator = terminator;
+    }
+
+    fn emit(&mut self, kind: IrInstructionKind){
+        let instruction = IrInstruction{
+            kind,
+            start_line: self.start_line,
+            start_pos: self.start_pos,
+        };
+        self.program.blocks[self.current_block].instructions.push(instruction);
+    }
+
+    /* Instructions That Can Fail Report The Position Of Their Own Token */
+    fn emit_at(&mut self, kind: IrInstructionKind, token: &Token){
+        let instruction = IrInstruction{
+            kind,
+            start_line: token.start_line,
+            start_pos: token.start_pos,
+        };
+        self.program.blocks[self.current_block].instructions.push(instruction);
+    }
+
+    fn push_environment(&mut self, scope: EnvironmentScope){
+        self.environments_stack.push_back(Environment {
+            scope,
+            variables: HashMap::new(),
+            internal_variables: HashMap::new(),
+            stop_statements_execution: None,
+        });
+    }
+
+    fn generate_temp(&mut self, variable_type: TokenType) -> String{
+        self.temp_counter += 1;
+
+        let name = format!(
+            "temp_stack{}_variable_{}", self.environments_stack.len(), self.temp_counter);
+        self.program.add_variable(name.clone(), variable_type, None);
+
+        return name;
+    }
+
+    /*
+    * A Variable Defined Again Keeps Its Environment, Like In The Checker, And
+    * Gets A New Name Only When Its Type Changes.
+    */
+    fn define_variable(&mut self, variable_name: &String, variable_type: TokenType) -> String{
+        let mut depth = self.environments_stack.len();
+        for (index, environment) in self.environments_stack.iter().enumerate(){
+            if environment.variables.contains_key(variable_name){
+                depth = index + 1;
+                break;
+            }
+        }
+
+        let mut name = format!("stack{}_variable_{}", depth, variable_name);
+        let mut index = 1;
+        while
+            self.program.get_variable_type(&name) != None &&
+            self.program.get_variable_type(&name) != Some(variable_type.clone())
+        {
+            name = format!("stack{}_variable_{}_{}", depth, variable_name, index);
+            index += 1;
+        }
+
+        if self.program.get_variable_type(&name) == None{
+            self.program.add_variable(
+                name.clone(), variable_type.clone(), Some(variable_name.clone()));
+        }
+
+        let mut variable = Variable::new();
+        variable.name = Some(name.clone());
+        variable.variable_type = Some(variable_type);
+
+        self.environments_stack[depth - 1].variables.insert(variable_name.clone(), Some(variable));
+
+        return name;
+    }
+
+    fn get_variable(&self, variable_name: &String) -> Option<String>{
+        for environment in &self.environments_stack{
+            if environment.variables.contains_key(variable_name){
+                return environment.variables.get(variable_name).unwrap()
+                    .as_ref().unwrap().name.clone();
+            }
+        }
+
+        return None;
+    }
+
+    fn get_type(&self, name: &String) -> TokenType{
+        return self.program.get_variable_type(name).unwrap();
+    }
+
+    /* Copies The Value Into A Temp Of The Given Type, Unless It Already Has It */
+    fn convert_into(&mut self, source: &String, variable_type: &TokenType) -> String{
+        if &self.get_type(source) == variable_type{
+            return source.clone();
+        }
+
+        let temp = self.generate_temp(variable_type.clone());
+        self.emit(IrInstructionKind::Convert{target: temp.clone(), source: source.clone()});
+
+        return temp;
+    }
+
+    fn error(&self, message: String, token: &Token) -> String{
+        return format!(
+            "{}: IR Generation Error -> {}, line {}:{}.",
+            self.mode, message, token.start_line, token.start_pos);
+    }
+}
+
+
+/* Lowers A Checked Syntax Tree, After Its Constants Are Inlined */
+pub fn lower(mode: &Mode, syntax_tree: &StatementsNode) -> Result<IrProgram, String>{
+    let mut builder = IrBuilder::new(mode);
+
+    lower_statements(&mut builder, syntax_tree)?;
+
+    return Ok(reorder_blocks(builder));
+}
+
+
+/* Number The Blocks In Their Written Order, So Most Jumps Go To The Next Block */
+fn reorder_blocks(builder: IrBuilder) -> IrProgram{
+    let mut program = builder.program;
+
+    let mut new_indexes: HashMap<usize, usize> = HashMap::new();
+    for block in &builder.layout{
+        new_indexes.insert(*block, new_indexes.len());
+    }
+
+    let mut blocks: Vec<Option<IrBlock>> = program.blocks.into_iter().map(Some).collect();
+    program.blocks = Vec::new();
+
+    for block in &builder.layout{
+        let mut ir_block = blocks[*block].take().unwrap();
+
+        ir_block.terminator = match ir_block.terminator {
+            IrTerminator::Jump{target} => IrTerminator::Jump{
+                target: new_indexes[&target]},
+            IrTerminator::Branch{condition, then_block, else_block} => IrTerminator::Branch{
+                condition,
+                then_block: new_indexes[&then_block],
+                else_block: new_indexes[&else_block]},
+            IrTerminator::End => IrTerminator::End,
+        };
+
+        program.blocks.push(ir_block);
+    }
+
+    return program;
+}
+
+
+fn lower_statements(builder: &mut IrBuilder, statements: &StatementsNode) -> Result<(), String>{
+    let mut builder = builder;
+
+    for statement in &statements.statements{
+        lower_statement(&mut builder, statement)?;
+    }
+
+    return Ok(());
+}
+
+
+fn lower_statement(builder: &mut IrBuilder, statement: &StatementNode) -> Result<(), String>{
+    let mut builder = builder;
+
+    let token = statement.get_token();
+    if token != None{
+        builder.start_line = token.unwrap().start_line;
+        builder.start_pos = token.unwrap().start_pos;
+    }
+
+    if statement.statement_type == Some(StatementType::DefineBool){
+        let node = statement.define_bool_statement.as_ref().unwrap();
+        lower_definition(
+            &mut builder, node.name.as_ref().unwrap(), node.left.as_ref().unwrap(), TokenType::Bool)?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineInt){
+        let node = statement.define_int_statement.as_ref().unwrap();
+        lower_definition(
+            &mut builder, node.name.as_ref().unwrap(), node.left.as_ref().unwrap(), TokenType::Int)?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineDouble){
+        let node = statement.define_double_statement.as_ref().unwrap();
+        lower_definition(
+            &mut builder, node.name.as_ref().unwrap(), node.left.as_ref().unwrap(), TokenType::Double)?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineChar){
+        let node = statement.define_char_statement.as_ref().unwrap();
+        lower_definition(
+            &mut builder, node.name.as_ref().unwrap(), node.left.as_ref().unwrap(), TokenType::Char)?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineString){
+        let node = statement.define_string_statement.as_ref().unwrap();
+        lower_definition(
+            &mut builder, node.name.as_ref().unwrap(), node.left.as_ref().unwrap(), TokenType::String)?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineVar){
+        let node = statement.define_var_statement.as_ref().unwrap();
+        lower_definition(
+            &mut builder, node.name.as_ref().unwrap(), node.left.as_ref().unwrap(),
+            node.variable_type.clone().unwrap())?;
+    }
+    else if statement.statement_type == Some(StatementType::DefineVariable){
+        lower_define_variable(&mut builder, statement.define_variable_statement.as_ref().unwrap())?;
+    }
+
+    else if statement.statement_type == Some(StatementType::Print){
+        lower_print(&mut builder, statement.define_print_statement.as_ref().unwrap())?;
+    }
+    else if statement.statement_type == Some(StatementType::Call){
+        /* The Result Of The Call Is Discarded */
+        lower_expression(
+            &mut builder, statement.define_call_statement.as_ref().unwrap().expression.as_ref().unwrap())?;
+    }
+
+    else if statement.statement_type == Some(StatementType::DefineIf){
+        lower_if_statement(&mut builder, statement.define_if_statement.as_ref().unwrap())?;
+    }
+
+    else if statement.statement_type == Some(StatementType::DefineForLoop){
+        lower_for_loop_statement(&mut builder, statement.define_for_loop_statement.as_ref().unwrap())?;
+    }
+    else if statement.statement_type == Some(StatementType::Continue){
+        let (continue_block, _) = builder.loops_stack.last().unwrap().clone();
+        builder.terminate(IrTerminator::Jump{target: continue_block});
+
+        /* The Statements After It Are Never Reached */
+        let block = builder.new_block();
+        builder.switch_to_block(block);
+    }
+    else if statement.statement_type == Some(StatementType::Break){
+        let (_, break_block) = builder.loops_stack.last().unwrap().clone();
+        builder.terminate(IrTerminator::Jump{target: break_block});
+
+        let block = builder.new_block();
+        builder.switch_to_block(block);
+    }
+
+    return Ok(());
+}
+
+
+fn lower_definition(
+    builder: &mut IrBuilder, name: &Token, left: &OperationNode, variable_type: TokenType
+) -> Result<(), String>{
+
+    let mut builder = builder;
+
+    let source = lower_expression(&mut builder, left)?;
+
+    let variable = builder.define_variable(&name.value, variable_type);
+    builder.emit(IrInstructionKind::Convert{target: variable, source});
+
+    return Ok(());
+}
+
+
+fn lower_define_variable(
+    builder: &mut IrBuilder, statement: &DefineVariableNode
+) -> Result<(), String>{
+
+    let mut builder = builder;
+
+    let name = statement.name.as_ref().unwrap();
+    if statement.is_definition{
+        return lower_definition(
+            &mut builder, name, statement.left.as_ref().unwrap(),
+            statement.variable_type.clone().unwrap());
+    }
+
+    let source = lower_expression(&mut builder, statement.left.as_ref().unwrap())?;
+
+    let variable = builder.get_variable(&name.value);
+    if variable == None{
+        return Err(builder.error(format!("Undefined variable `{}`", name.value), name));
+    }
+    let variable = variable.unwrap();
+    let variable_type = builder.get_type(&variable);
+
+    let operator_type = statement.operator.as_ref().unwrap().token_type.clone();
+    if operator_type == TokenType::Assign{
+        builder.emit(IrInstructionKind::Convert{target: variable, source});
+        return Ok(());
+    }
+
+    let operator = if operator_type == TokenType::PlusEqual{
+        OperatorType::Plus
+    }
+    else if operator_type == TokenType::MinusEqual{
+        OperatorType::Minus
+    }
+    else if operator_type == TokenType::MulEqual{
+        OperatorType::Mul
+    }
+    else if operator_type == TokenType::DivEqual{
+        OperatorType::Div
+    }
+    else{
+        OperatorType::Mod
+    };
+
+    /* Like In The Interpreter, The Value Is Converted Into The Variable Type First */
+    let source = builder.convert_into(&source, &variable_type);
+
+    /* An Int Division Is Done On Doubles, Then Truncated Back Into The Variable */
+    if variable_type == TokenType::Int && operator == OperatorType::Div{
+        let left = builder.convert_into(&variable, &TokenType::Double);
+        let right = builder.convert_into(&source, &TokenType::Double);
+
+        let result = builder.generate_temp(TokenType::Double);
+        builder.emit(IrInstructionKind::Operation{
+            operator, target: result.clone(), left, right});
+        builder.emit(IrInstructionKind::Convert{target: variable, source: result});
+
+        return Ok(());
+    }
+
+    builder.emit(IrInstructionKind::Operation{
+        operator, target: variable.clone(), left: variable, right: source});
+
+    return Ok(());
+}
+
+
+/* Every Expression, Separator And End Is Printed By Its Own Instruction */
+fn lower_print(builder: &mut IrBuilder, statement: &DefinePrintNode) -> Result<(), String>{
+    let mut builder = builder;
+
+    let mut variables = vec![];
+    for expression in statement.get_expressions(){
+        variables.push(lower_expression(&mut builder, expression)?);
+    }
+
+    let end = if statement.end != None {variables.pop()} else {None};
+    let separator = if statement.separator != None {variables.pop()} else {None};
+
+    let mut printed_variables = vec![];
+    for (index, variable) in variables.into_iter().enumerate(){
+        if index > 0{
+            printed_variables.push(separator.clone().unwrap());
+        }
+        printed_variables.push(variable);
+    }
+    if end != None{
+        printed_variables.push(end.unwrap());
+    }
+
+    for variable in printed_variables{
+        builder.emit(IrInstructionKind::Print{source: variable, is_stderr: statement.is_stderr});
+    }
+
+    return Ok(());
+}
+
+
+fn lower_if_statement(
+    builder: &mut IrBuilder, statement: &DefineIfStatementNode
+) -> Result<(), String>{
+
+    let mut builder = builder;
+
+    let mut branches = vec![];
+    if statement.define_if_node != None{
+        let define_if_node = statement.define_if_node.as_ref().unwrap();
+        branches.push((
+            define_if_node.token.as_ref(), define_if_node.condition.as_ref().unwrap(),
+            &define_if_node.statements));
+    }
+    for define_if_else_node in &statement.define_if_else_nodes{
+        branches.push((
+            define_if_else_node.token.as_ref(), define_if_else_node.condition.as_ref().unwrap(),
+            &define_if_else_node.statements));
+    }
+
+    let end_block = builder.new_block();
+
+    /* Every Condition Is Checked In The Else Block Of The Previous One */
+    for (token, condition, statements) in branches{
+        if token != None{
+            builder.start_line = token.unwrap().start_line;
+            builder.start_pos = token.unwrap().start_pos;
+        }
+
+        let condition = lower_expression(&mut builder, condition)?;
+
+        let then_block = builder.new_block();
+        let else_block = builder.new_block();
+        builder.terminate(IrTerminator::Branch{condition, then_block, else_block});
+
+        builder.switch_to_block(then_block);
+        builder.push_environment(EnvironmentScope::If);
+        lower_statements(&mut builder, statements)?;
+        builder.environments_stack.pop_back();
+        builder.terminate(IrTerminator::Jump{target: end_block});
+
+        builder.switch_to_block(else_block);
+    }
+
+    if statement.define_else_node != None{
+        builder.push_environment(EnvironmentScope::If);
+        lower_statements(&mut builder, &statement.define_else_node.as_ref().unwrap().statements)?;
+        builder.environments_stack.pop_back();
+    }
+
+    builder.terminate(IrTerminator::Jump{target: end_block});
+    builder.switch_to_block(end_block);
+
+    return Ok(());
+}
+
+
+/*
+* The Loop Is Made Of A Header Comparing The Counter With The Stop Value, The
+* Body, An Increment Block Where `continue` Goes And An Exit Block Where `break` Goes.
+*/
+fn lower_for_loop_statement(
+    builder: &mut IrBuilder, statement: &DefineForLoopStatementNode
+) -> Result<(), String>{
+
+    let mut builder = builder;
+
+    let (start_line, start_pos) = (builder.start_line, builder.start_pos);
+
+    /* The Loop Values Are Copied, So The Counter And The Stop Don't Change With The Variables */
+    let counter = builder.generate_temp(TokenType::Int);
+    builder.program.loop_counters.insert(counter.clone());
+    if statement.start != None{
+        let start = lower_expression(&mut builder, statement.start.as_ref().unwrap())?;
+        builder.emit(IrInstructionKind::Convert{target: counter.clone(), source: start});
+    }
+    else{
+        builder.emit(IrInstructionKind::Constant{target: counter.clone(), value: String::from("0")});
+    }
+
+    let mut stop = None;
+    if statement.stop != None{
+        let result = lower_expression(&mut builder, statement.stop.as_ref().unwrap())?;
+
+        let temp = builder.generate_temp(TokenType::Int);
+        builder.emit(IrInstructionKind::Convert{target: temp.clone(), source: result});
+        stop = Some(temp);
+    }
+
+    let mut step = None;
+    if statement.step != None{
+        let result = lower_expression(&mut builder, statement.step.as_ref().unwrap())?;
+
+        let temp = builder.generate_temp(TokenType::Int);
+        builder.emit(IrInstructionKind::Convert{target: temp.clone(), source: result});
+        step = Some(temp);
+    }
+
+    let header_block = builder.new_block();
+    let body_block = builder.new_block();
+    let increment_block = builder.new_block();
+    let exit_block = builder.new_block();
+
+    builder.terminate(IrTerminator::Jump{target: header_block});
+
+    /* Header */
+    builder.switch_to_block(header_block);
+    if stop != None{
+        let condition = builder.generate_temp(TokenType::Bool);
+        builder.emit(IrInstructionKind::Operation{
+            operator: OperatorType::LessThan,
+            target: condition.clone(), left: counter.clone(), right: stop.unwrap()});
+        builder.terminate(IrTerminator::Branch{
+            condition, then_block: body_block, else_block: exit_block});
+    }
+    else{
+        builder.terminate(IrTerminator::Jump{target: body_block});
+    }
+
+    /* Body */
+    builder.switch_to_block(body_block);
+    builder.push_environment(EnvironmentScope::ForLoop);
+
+    if statement.variable != None{
+        let variable = builder.define_variable(
+            &statement.variable.as_ref().unwrap().value, TokenType::Int);
+        builder.emit(IrInstructionKind::Convert{target: variable, source: counter.clone()});
+    }
+
+    builder.loops_stack.push((increment_block, exit_block));
+    lower_statements(&mut builder, &statement.statements)?;
+    builder.loops_stack.pop();
+
+    builder.environments_stack.pop_back();
+    builder.terminate(IrTerminator::Jump{target: increment_block});
+
+    /* Increment, At The Position Of The Loop */
+    builder.switch_to_block(increment_block);
+    builder.start_line = start_line;
+    builder.start_pos = start_pos;
+
+    let step = if step != None {step.unwrap()} else {
+        let temp = builder.generate_temp(TokenType::Int);
+        builder.emit(IrInstructionKind::Constant{target: temp.clone(), value: String::from("1")});
+        temp
+    };
+    builder.emit(IrInstructionKind::Operation{
+        operator: OperatorType::Plus, target: counter.clone(), left: counter, right: step});
+    builder.terminate(IrTerminator::Jump{target: header_block});
+
+    builder.switch_to_block(exit_block);
+
+    return Ok(());
+}
+
+
+/* Returns The Variable Holding The Expression Result */
+fn lower_expression(builder: &mut IrBuilder, operation_node: &OperationNode) -> Result<String, String>{
+    let mut builder = builder;
+
+    if operation_node.operator == None{
+        let token = operation_node.value.as_ref().unwrap();
+
+        if token.token_type == TokenType::Variable{
+            let variable = builder.get_variable(&token.value);
+            if variable == None{
+                return Err(builder.error(format!("Undefined variable `{}`", token.value), token));
+            }
+            return Ok(variable.unwrap());
+        }
+
+        let builtin_type = get_builtin_type(&token.token_type);
+        if builtin_type == None{
+            return Err(builder.error(format!("Unknown value `{}`", token.value), token));
+        }
+
+        let temp = builder.generate_temp(get_builtin_variable_type(&builtin_type.unwrap()));
+        builder.emit(IrInstructionKind::Constant{target: temp.clone(), value: token.value.clone()});
+
+        return Ok(temp);
+    }
+    let operator = operation_node.operator.clone().unwrap();
+
+    /* Built-in Function Call */
+    if operator == OperatorType::Call{
+        let mut arguments = vec![];
+        let mut arguments_types = vec![];
+        for argument in &operation_node.arguments{
+            let argument = lower_expression(&mut builder, argument)?;
+            arguments_types.push(get_builtin_type(&builder.get_type(&argument)).unwrap());
+            arguments.push(argument);
+        }
+
+        let function_token = operation_node.value.as_ref().unwrap();
+        let return_type = get_builtin_return_type(&function_token.value, &arguments_types);
+        if return_type.is_err(){
+            return Err(builder.error(return_type.unwrap_err(), function_token));
+        }
+
+        let temp = builder.generate_temp(get_builtin_variable_type(&return_type.unwrap()));
+        builder.emit_at(
+            IrInstructionKind::Call{
+                function: function_token.value.clone(), target: temp.clone(), arguments},
+            function_token);
+
+        return Ok(temp);
+    }
+
+    let mut left = None;
+    let mut right = None;
+    if operation_node.left != None{
+        left = Some(lower_expression(&mut builder, operation_node.left.as_ref().unwrap())?);
+    }
+    if operation_node.right != None{
+        right = Some(lower_expression(&mut builder, operation_node.right.as_ref().unwrap())?);
+    }
+
+    if left != None && right == None{
+        return Ok(left.unwrap());
+    }
+    else if right != None && left == None{
+        return Ok(right.unwrap());
+    }
+
+    let operator_token = operation_node.value.as_ref().unwrap();
+
+    /* Input, Read As A String Then Converted Into The Requested Type */
+    if operator == OperatorType::Convert{
+        let line = builder.generate_temp(TokenType::String);
+        builder.emit_at(IrInstructionKind::Input{target: line.clone()}, operator_token);
+
+        if operator_token.token_type == TokenType::String{
+            return Ok(line);
+        }
+
+        let temp = builder.generate_temp(operator_token.token_type.clone());
+        builder.emit_at(IrInstructionKind::Convert{target: temp.clone(), source: line}, operator_token);
+
+        return Ok(temp);
+    }
+
+    let left = left.unwrap();
+    let right = right.unwrap();
+    let left_type = builder.get_type(&left);
+    let right_type = builder.get_type(&right);
+
+    let is_arithmetic =
+        operator == OperatorType::Plus || operator == OperatorType::Minus ||
+        operator == OperatorType::Mul || operator == OperatorType::Div ||
+        operator == OperatorType::Mod;
+
+    /* Both Operands Get The Same Type Before The Operation */
+    let (operands_type, result_type) = if
+        left_type == TokenType::String || right_type == TokenType::String ||
+        left_type == TokenType::Char || right_type == TokenType::Char
+    {
+        (TokenType::String, TokenType::String)
+    }
+    else if
+        left_type == TokenType::Double || right_type == TokenType::Double ||
+        (left_type == TokenType::Int && operator == OperatorType::Div)
+    {
+        (TokenType::Double, if is_arithmetic {TokenType::Double} else {TokenType::Bool})
+    }
+    else if left_type == TokenType::Int{
+        (TokenType::Int, if is_arithmetic {TokenType::Int} else {TokenType::Bool})
+    }
+    else{
+        (TokenType::Bool, TokenType::Bool)
+    };
+
+    let left = builder.convert_into(&left, &operands_type);
+    let right = builder.convert_into(&right, &operands_type);
+
+    let temp = builder.generate_temp(result_type);
+    builder.emit(IrInstructionKind::Operation{operator, target: temp.clone(), left, right});
+
+    return Ok(temp);
+}
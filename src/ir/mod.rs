@@ -0,0 +1,304 @@
+mod lowering;
+mod cfg;
+mod analysis;
+mod dead_code;
+
+use std::collections::{
+    HashMap,
+    HashSet
+};
+
+use crate::tokens::TokenType;
+use crate::syntax_tree::OperatorType;
+
+pub use lowering::lower;
+pub use analysis::analyze;
+pub use dead_code::eliminate_dead_code;
+
+
+/*
+* The Typed Three Address Code Shared By The Backends, Every Instruction Reads
+* And Writes Named Variables, And The Control Flow Is Made Of Basic Blocks Ending
+* With An Explicit Jump.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct IrProgram{
+    /* Every Variable And Temporary With Its Type (`Bool`, `Int`, `Double`, `Char`, `String`) */
+    pub variables: Vec<IrVariable>,
+    variables_indexes: HashMap<String, usize>,
+
+    /* The Program Starts In The First Block, Blocks Are In Their Generation Order */
+    pub blocks: Vec<IrBlock>,
+
+    /* Temporaries Counting The `for` Loops, Copied Into The Loop Variable Every Iteration */
+    pub loop_counters: HashSet<String>,
+}
+
+impl IrProgram{
+    pub fn new() -> Self{
+        return IrProgram{
+            variables: Vec::new(),
+            variables_indexes: HashMap::new(),
+            blocks: Vec::new(),
+            loop_counters: HashSet::new(),
+        };
+    }
+
+    pub fn add_variable(
+        &mut self, name: String, variable_type: TokenType, source_name: Option<String>
+    ){
+        self.variables_indexes.insert(name.clone(), self.variables.len());
+        self.variables.push(IrVariable{name, variable_type, source_name});
+    }
+
+    pub fn get_variable_type(&self, name: &String) -> Option<TokenType>{
+        let index = self.variables_indexes.get(name);
+        if index == None{
+            return None;
+        }
+
+        return Some(self.variables[*index.unwrap()].variable_type.clone());
+    }
+
+    /* Keeps The Given Variables In Their Order */
+    pub fn retain_variables(&mut self, names: &HashSet<String>){
+        self.variables.retain(|variable| names.contains(&variable.name));
+
+        self.variables_indexes = HashMap::new();
+        for (index, variable) in self.variables.iter().enumerate(){
+            self.variables_indexes.insert(variable.name.clone(), index);
+        }
+    }
+
+    /* The Name Written In The Source, Temporaries Have None */
+    pub fn get_source_name(&self, name: &String) -> Option<String>{
+        let index = self.variables_indexes.get(name);
+        if index == None{
+            return None;
+        }
+
+        return self.variables[*index.unwrap()].source_name.clone();
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IrVariable{
+    pub name: String,
+    pub variable_type: TokenType,
+    pub source_name: Option<String>,
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IrBlock{
+    pub instructions: Vec<IrInstruction>,
+    pub terminator: IrTerminator,
+}
+
+impl IrBlock{
+    pub fn new() -> Self{
+        return IrBlock{
+            instructions: Vec::new(),
+            terminator: IrTerminator::End,
+        };
+    }
+}
+
+
+/* The Source Position Is Kept For The Errors Reported When The Program Runs */
+#[derive(Debug, Clone, PartialEq)]
+pub struct IrInstruction{
+    pub kind: IrInstructionKind,
+    pub start_line: u64,
+    pub start_pos: u64,
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IrInstructionKind{
+    /* target = literal, The Literal Has The Type Of The Target */
+    Constant{target: String, value: String},
+
+    /* target = source, Converted Into The Type Of The Target */
+    Convert{target: String, source: String},
+
+    /* target = left operator right, Both Operands Already Have The Same Type */
+    Operation{operator: OperatorType, target: String, left: String, right: String},
+
+    /* target = function(arguments) */
+    Call{function: String, target: String, arguments: Vec<String>},
+
+    /* target = The Next Line Of The Standard Input */
+    Input{target: String},
+
+    Print{source: String, is_stderr: bool},
+}
+
+impl IrInstructionKind{
+    /* The Variable Written By The Instruction */
+    pub fn get_target(&self) -> Option<&String>{
+        return match self {
+            IrInstructionKind::Constant{target, value: _} => Some(target),
+            IrInstructionKind::Convert{target, source: _} => Some(target),
+            IrInstructionKind::Operation{operator: _, target, left: _, right: _} => Some(target),
+            IrInstructionKind::Call{function: _, target, arguments: _} => Some(target),
+            IrInstructionKind::Input{target} => Some(target),
+            IrInstructionKind::Print{source: _, is_stderr: _} => None,
+        };
+    }
+
+    /* The Variables Read By The Instruction */
+    pub fn get_sources(&self) -> Vec<&String>{
+        return match self {
+            IrInstructionKind::Constant{target: _, value: _} => vec![],
+            IrInstructionKind::Convert{target: _, source} => vec![source],
+            IrInstructionKind::Operation{operator: _, target: _, left, right} => vec![left, right],
+            IrInstructionKind::Call{function: _, target: _, arguments} => arguments.iter().collect(),
+            IrInstructionKind::Input{target: _} => vec![],
+            IrInstructionKind::Print{source, is_stderr: _} => vec![source],
+        };
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IrTerminator{
+    Jump{target: usize},
+
+    /* Goes To `then_block` When The Bool Condition Is True */
+    Branch{condition: String, then_block: usize, else_block: usize},
+
+    End,
+}
+
+impl IrTerminator{
+    pub fn get_successors(&self) -> Vec<usize>{
+        return match self {
+            IrTerminator::Jump{target} => vec![*target],
+            IrTerminator::Branch{condition: _, then_block, else_block} => vec![*then_block, *else_block],
+            IrTerminator::End => vec![],
+        };
+    }
+
+    /* The Variable Read To Choose The Next Block */
+    pub fn get_condition(&self) -> Option<&String>{
+        return match self {
+            IrTerminator::Branch{condition, then_block: _, else_block: _} => Some(condition),
+            _ => None,
+        };
+    }
+}
+
+
+pub fn get_type_name(variable_type: &TokenType) -> &'static str{
+    if variable_type == &TokenType::Bool{
+        return "bool";
+    }
+    else if variable_type == &TokenType::Int{
+        return "int";
+    }
+    else if variable_type == &TokenType::Double{
+        return "double";
+    }
+    else if variable_type == &TokenType::Char{
+        return "char";
+    }
+    else if variable_type == &TokenType::List{
+        return "list";
+    }
+    return "string";
+}
+
+
+/* The Value A Variable Holds Before It Is Assigned */
+pub fn get_default_value(variable_type: &TokenType) -> String{
+    if variable_type == &TokenType::Bool{
+        return String::from("False");
+    }
+    else if variable_type == &TokenType::Int || variable_type == &TokenType::Double{
+        return String::from("0");
+    }
+    else if variable_type == &TokenType::List{
+        return String::from("[]");
+    }
+    return String::new();
+}
+
+
+pub fn get_operator_symbol(operator: &OperatorType) -> &'static str{
+    return match operator {
+        OperatorType::Plus => "+",
+        OperatorType::Minus => "-",
+        OperatorType::Mul => "*",
+        OperatorType::Div => "/",
+        OperatorType::Mod => "%",
+        OperatorType::Equal => "==",
+        OperatorType::GreaterThanOrEqual => ">=",
+        OperatorType::GreaterThan => ">",
+        OperatorType::LessThanOrEqual => "<=",
+        OperatorType::LessThan => "<",
+        OperatorType::NotEqual => "!=",
+        OperatorType::Or => "||",
+        OperatorType::And => "&&",
+        OperatorType::Convert | OperatorType::Call => "",
+    };
+}
+
+
+/* The Text Shown By `--emit=ir` */
+pub fn get_ir_text(program: &IrProgram) -> String{
+    let mut text = String::new();
+
+    for variable in &program.variables{
+        text.push_str(&format!("{} {}\n", get_type_name(&variable.variable_type), variable.name));
+    }
+
+    for (index, block) in program.blocks.iter().enumerate(){
+        text.push_str(&format!("\nblock_{}:\n", index));
+
+        for instruction in &block.instructions{
+            text.push_str(&format!(
+                "    {:<60} # line {}:{}\n",
+                get_instruction_text(program, &instruction.kind),
+                instruction.start_line, instruction.start_pos));
+        }
+
+        text.push_str(&format!("    {}\n", match &block.terminator {
+            IrTerminator::Jump{target} => format!("goto block_{}", target),
+            IrTerminator::Branch{condition, then_block, else_block} => format!(
+                "if {} goto block_{} else block_{}", condition, then_block, else_block),
+            IrTerminator::End => String::from("end"),
+        }));
+    }
+
+    return text;
+}
+
+
+fn get_instruction_text(program: &IrProgram, kind: &IrInstructionKind) -> String{
+    return match kind {
+        IrInstructionKind::Constant{target, value} => {
+            let variable_type = program.get_variable_type(target).unwrap();
+            if variable_type == TokenType::Char{
+                format!("{} = {:?}", target, value.chars().nth(0).unwrap_or(' '))
+            }
+            else if variable_type == TokenType::String{
+                format!("{} = {:?}", target, value)
+            }
+            else{
+                format!("{} = {}", target, value)
+            }
+        },
+        IrInstructionKind::Convert{target, source} => format!(
+            "{} = {}({})",
+            target, get_type_name(&program.get_variable_type(target).unwrap()), source),
+        IrInstructionKind::Operation{operator, target, left, right} => format!(
+            "{} = {} {} {}", target, left, get_operator_symbol(operator), right),
+        IrInstructionKind::Call{function, target, arguments} => format!(
+            "{} = {}({})", target, function, arguments.join(", ")),
+        IrInstructionKind::Input{target} => format!("{} = input()", target),
+        IrInstructionKind::Print{source, is_stderr} => format!(
+            "{} {}", if *is_stderr {"eprint"} else {"print"}, source),
+    };
+}
@@ -21,6 +21,10 @@ pub struct Lexer{
 }
 
 impl Lexer{
+    pub fn get_file_path(&self) -> String{
+        return self.file.file_path.clone();
+    }
+
     pub fn new(file: File, mode: Mode) -> Result<Self, std::io::Error>{
         let mut file = file;
         return Ok(Lexer{
@@ -111,6 +115,20 @@ fn get_variable_token(lexer: &mut Lexer, line: u64, position: u64) -> Result<Tok
         }
     }
 
+    /* Qualified Names, `utils.helper` Or The Module Path `lib.utils` */
+    while get_token_type(&_variable) == TokenType::Variable &&
+        current(&mut lexer).to_string() == "." &&
+        (peek(&mut lexer, 1).is_alpha() || peek(&mut lexer, 1).to_string() == "_")
+    {
+        _variable += &next(&mut lexer);
+
+        while current(&mut lexer).is_alpha() || current(&mut lexer).is_digit() ||
+            current(&mut lexer).to_string() == "_"
+        {
+            _variable += &next(&mut lexer);
+        }
+    }
+
     return Ok(Token{
         token_type: get_token_type(&_variable),
         start_line: line,
@@ -159,7 +177,8 @@ fn get_string_token(lexer: &mut Lexer, line: u64, position: u64) -> Result<Token
         else if current(&mut lexer).to_string() == &qotation_type {
             next(&mut lexer);
 
-            if _string.len() > 1{
+            /* Only One Character Quoted Is A Char, Empty Quotes Are An Empty String */
+            if _string.len() > 1 || _string.is_empty(){
                 return Ok(Token{
                     token_type: TokenType::StringSequence,
                     start_line: line,
@@ -0,0 +1,112 @@
+use std::time::{
+    Duration,
+    Instant
+};
+
+use crate::options::ExecutionOptions;
+use crate::builtins::{
+    is_file_function,
+    is_input_function,
+    set_memory_limit
+};
+
+
+/* Stops Untrusted Programs With A Diagnostic Instead Of Hanging */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Limiter{
+    max_steps: Option<u128>,
+    max_memory: Option<usize>,
+    timeout: Option<Duration>,
+    disable_input: bool,
+    disable_files: bool,
+
+    steps: u128,
+    start: Instant,
+}
+
+impl Limiter{
+    pub fn new(options: &ExecutionOptions) -> Self{
+        /* The Built-in Functions Check Their Results Size Before Creating Them */
+        set_memory_limit(options.max_memory);
+
+        return Limiter{
+            max_steps: options.max_steps,
+            max_memory: options.max_memory,
+            timeout: options.timeout_milliseconds.map(|timeout| Duration::from_millis(timeout)),
+            disable_input: options.disable_input,
+            disable_files: options.disable_files,
+
+            steps: 0,
+            start: Instant::now(),
+        };
+    }
+
+    pub fn has_memory_limit(&self) -> bool{
+        return self.max_memory != None;
+    }
+}
+
+
+/* Called Before Every Statement, Loop Iteration Or Instruction */
+pub fn check_step(
+    limiter: &mut Limiter, step_name: &str, line: u128
+) -> Result<(), String>{
+
+    limiter.steps += 1;
+
+    if limiter.max_steps != None && limiter.steps > limiter.max_steps.unwrap(){
+        return Err(format!(
+            "Engine Limits: Steps Limit -> Executed more than {} {}, line: {}.",
+            limiter.max_steps.unwrap(), step_name, line));
+    }
+
+    if limiter.timeout != None && limiter.start.elapsed() > limiter.timeout.unwrap(){
+        return Err(format!(
+            "Engine Limits: Timeout -> Execution took more than {} milliseconds, line: {}.",
+            limiter.timeout.unwrap().as_millis(), line));
+    }
+
+    return Ok(());
+}
+
+
+pub fn check_memory(
+    limiter: &Limiter, memory: usize, line: u128
+) -> Result<(), String>{
+
+    if limiter.max_memory != None && memory > limiter.max_memory.unwrap(){
+        return Err(format!(
+            "Engine Limits: Memory Limit -> Variables hold {} bytes, more than the {} bytes limit, line: {}.",
+            memory, limiter.max_memory.unwrap(), line));
+    }
+
+    return Ok(());
+}
+
+
+pub fn check_input(limiter: &Limiter, line: u128) -> Result<(), String>{
+    if limiter.disable_input{
+        return Err(format!(
+            "Engine Limits: Input Disabled -> `input` is not allowed, line: {}.",
+            line));
+    }
+
+    return Ok(());
+}
+
+
+pub fn check_call(limiter: &Limiter, function: &str, line: u128) -> Result<(), String>{
+    if limiter.disable_input && is_input_function(function){
+        return Err(format!(
+            "Engine Limits: Input Disabled -> `{}` is not allowed, line: {}.",
+            function, line));
+    }
+
+    if limiter.disable_files && is_file_function(function){
+        return Err(format!(
+            "Engine Limits: Files Disabled -> `{}` is not allowed, line: {}.",
+            function, line));
+    }
+
+    return Ok(());
+}
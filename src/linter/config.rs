@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+
+use crate::constants::Mode;
+use crate::tokens::{
+    Token,
+    TokenType
+};
+
+
+pub const CONFIG_FILE_NAME: &'static str = "engine.lint";
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintRule{
+    UnusedVariables,
+    Shadowing,
+    SelfAssignment,
+    SelfComparison,
+    ZeroStep,
+    NeedlessConversion,
+}
+
+pub const LINT_RULES: [LintRule; 6] = [
+    LintRule::UnusedVariables,
+    LintRule::Shadowing,
+    LintRule::SelfAssignment,
+    LintRule::SelfComparison,
+    LintRule::ZeroStep,
+    LintRule::NeedlessConversion,
+];
+
+impl LintRule{
+    pub fn get_name(&self) -> &'static str{
+        match self {
+            Self::UnusedVariables => "unused_variables",
+            Self::Shadowing => "shadowing",
+            Self::SelfAssignment => "self_assignment",
+            Self::SelfComparison => "self_comparison",
+            Self::ZeroStep => "zero_step",
+            Self::NeedlessConversion => "needless_conversion",
+        }
+    }
+}
+
+
+fn get_lint_rule(name: &str) -> Option<LintRule>{
+    return LINT_RULES.iter().find(|rule| rule.get_name() == name).cloned();
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LintLevel{
+    Allow,
+    Warn,
+    Deny,
+}
+
+
+fn get_lint_level(name: &str) -> Option<LintLevel>{
+    if name == "allow"{
+        return Some(LintLevel::Allow);
+    }
+    else if name == "warn"{
+        return Some(LintLevel::Warn);
+    }
+    else if name == "deny"{
+        return Some(LintLevel::Deny);
+    }
+
+    return None;
+}
+
+
+/*
+* The Level Of Every Rule, From The Project Config File, And The Levels Set By
+* The Inline Comments For A Single Line.
+*/
+#[derive(Debug)]
+pub struct LintConfig{
+    pub mode: Mode,
+    levels: HashMap<LintRule, LintLevel>,
+
+    /* (Line, Rule) -> Level */
+    inline_levels: HashMap<(u64, LintRule), LintLevel>,
+}
+
+impl LintConfig{
+    pub fn new(mode: Mode) -> Self{
+        return LintConfig{
+            mode,
+            levels: LINT_RULES.iter().map(|rule| (*rule, LintLevel::Warn)).collect(),
+            inline_levels: HashMap::new(),
+        };
+    }
+
+    pub fn get_level(&self, rule: LintRule, line: u64) -> LintLevel{
+        let inline_level = self.inline_levels.get(&(line, rule));
+        if inline_level != None{
+            return *inline_level.unwrap();
+        }
+
+        return *self.levels.get(&rule).unwrap();
+    }
+}
+
+
+/* The Config File In The Folder Of The Linted File Or The Closest Parent Folder */
+pub fn find_config_path(file_path: &String) -> Option<String>{
+    let file_path = std::fs::canonicalize(file_path);
+    if file_path.is_err(){
+        return None;
+    }
+    let file_path = file_path.unwrap();
+
+    let mut folder = file_path.parent();
+    while folder != None{
+        let config_path = folder.unwrap().join(CONFIG_FILE_NAME);
+        if config_path.is_file(){
+            return Some(config_path.to_string_lossy().to_string());
+        }
+        folder = folder.unwrap().parent();
+    }
+
+    return None;
+}
+
+
+/*
+* Every Line Sets The Level Of A Rule, `#` Starts A Comment
+*     unused_variables = allow
+*     shadowing = deny
+*/
+pub fn read_config_file(config: &mut LintConfig, config_path: &String) -> Result<(), String>{
+    let text = std::fs::read_to_string(config_path);
+    if text.is_err(){
+        return Err(format!(
+            "{}: Config Error -> Can't read the config file `{}`.",
+            config.mode, config_path));
+    }
+
+    for (index, line) in text.unwrap().lines().enumerate(){
+        let line_number = index + 1;
+        let line = line.split("#").next().unwrap().trim();
+        if line.is_empty(){
+            continue;
+        }
+
+        let parts: Vec<&str> = line.split("=").map(|part| part.trim()).collect();
+        if parts.len() != 2{
+            return Err(format!(
+                "{}: Config Error -> Expected `rule = level` in `{}`, line {}.",
+                config.mode, config_path, line_number));
+        }
+
+        let rule = get_lint_rule(parts[0]);
+        if rule == None{
+            return Err(format!(
+                "{}: Config Error -> Unknown lint rule `{}` in `{}`, line {}.",
+                config.mode, parts[0], config_path, line_number));
+        }
+
+        let level = get_lint_level(parts[1]);
+        if level == None{
+            return Err(format!(
+                "{}: Config Error -> Unknown lint level `{}` in `{}`, expected `allow`, `warn` or `deny`, line {}.",
+                config.mode, parts[1], config_path, line_number));
+        }
+
+        config.levels.insert(rule.unwrap(), level.unwrap());
+    }
+
+    return Ok(());
+}
+
+
+fn is_code_token(token: &Token) -> bool{
+    return token.token_type != TokenType::Space &&
+        token.token_type != TokenType::NewLine &&
+        token.token_type != TokenType::SingleLineComment &&
+        token.token_type != TokenType::MultiLineComment &&
+        token.token_type != TokenType::Eof;
+}
+
+
+/*
+* `# engine:allow(rule)`, `# engine:warn(rule)` And `# engine:deny(rule, ...)` Set
+* The Level Of The Rules For The Code On The Same Line, Or For The Next Line Of
+* Code When The Comment Is Alone On Its Line.
+*/
+pub fn read_inline_levels(config: &mut LintConfig, tokens: &Vec<Token>) -> Result<(), String>{
+    for (index, token) in tokens.iter().enumerate(){
+        if
+            token.token_type != TokenType::SingleLineComment &&
+            token.token_type != TokenType::MultiLineComment
+        {
+            continue;
+        }
+
+        let is_trailing = tokens[..index].iter().rev()
+            .take_while(|previous| previous.start_line == token.start_line)
+            .any(|previous| is_code_token(previous));
+
+        let line = if is_trailing{
+            token.start_line
+        }
+        else{
+            let next_code_token = tokens[index + 1..].iter().find(|next| is_code_token(next));
+            if next_code_token == None {continue;}
+            next_code_token.unwrap().start_line
+        };
+
+        let mut comment = token.value.as_str();
+        while comment.contains("engine:"){
+            comment = &comment[comment.find("engine:").unwrap() + "engine:".len()..];
+
+            let open_parenthes = comment.find("(");
+            let close_parenthes = comment.find(")");
+            if open_parenthes == None || close_parenthes == None || close_parenthes < open_parenthes{
+                break;
+            }
+
+            let level_name = comment[..open_parenthes.unwrap()].trim();
+            let level = get_lint_level(level_name);
+            if level == None{
+                return Err(format!(
+                    "{}: Lint Error -> Unknown lint level `{}`, expected `allow`, `warn` or `deny`, line {}:{}.",
+                    config.mode, level_name, token.start_line, token.start_pos));
+            }
+
+            for rule_name in comment[open_parenthes.unwrap() + 1..close_parenthes.unwrap()].split(","){
+                let rule = get_lint_rule(rule_name.trim());
+                if rule == None{
+                    return Err(format!(
+                        "{}: Lint Error -> Unknown lint rule `{}`, line {}:{}.",
+                        config.mode, rule_name.trim(), token.start_line, token.start_pos));
+                }
+
+                config.inline_levels.insert((line, rule.unwrap()), level.unwrap());
+            }
+
+            comment = &comment[close_parenthes.unwrap() + 1..];
+        }
+    }
+
+    return Ok(());
+}
+
@@ -0,0 +1,120 @@
+mod config;
+mod rules;
+
+use std::panic;
+
+use crate::constants::Mode;
+use crate::file::File;
+use crate::tokens::{
+    Token,
+    TokenType
+};
+use crate::lexer::{
+    Lexer,
+    next_token
+};
+use crate::parser::{
+    Parser,
+    parse
+};
+use crate::checker::{
+    Checker,
+    check
+};
+use config::{
+    LintConfig,
+    LintLevel,
+    find_config_path,
+    read_config_file,
+    read_inline_levels
+};
+use rules::find_lints;
+
+
+/* Every Token Of The File, Including The Comments The Parser Skips */
+fn read_tokens(file_path: &String) -> Result<Vec<Token>, String>{
+    let file = File::new(file_path, Mode::Linter);
+    let lexer = Lexer::new(file, Mode::Linter);
+    if lexer.is_err(){
+        return Err(format!(
+            "{}: File Error -> Failed in reading file `{}`.", Mode::Linter, file_path));
+    }
+    let mut lexer = lexer.unwrap();
+
+    let mut tokens = vec![];
+    loop{
+        let token = next_token(&mut lexer)?;
+        let is_eof = token.token_type == TokenType::Eof;
+        tokens.push(token);
+
+        if is_eof{
+            return Ok(tokens);
+        }
+    }
+}
+
+
+/*
+* Reports The Code That Is Valid But Likely A Mistake, The Program Must Pass The
+* Checker First. Every Rule Is Allowed, Reported As A Warning Or Denied, Denied
+* Rules Make The Lint Fail.
+*/
+pub fn lint(file_path: &String, config_path: Option<&String>) -> Result<(), String>{
+    panic::set_hook(Box::new(|panic_info| {
+        if let Some(panic_message) = panic_info.payload().downcast_ref::<String>() {
+            println!("{}", panic_message);
+        } else if let Some(panic_message) = panic_info.payload().downcast_ref::<&str>() {
+            println!("{}", panic_message);
+        } else {
+            println!("Engine Linter -> Linter Error {}", panic_info);
+        }
+    }));
+
+    let mut config = LintConfig::new(Mode::Linter);
+
+    let config_path = if config_path != None {config_path.cloned()} else {find_config_path(file_path)};
+    if config_path != None{
+        read_config_file(&mut config, config_path.as_ref().unwrap())?;
+    }
+
+    let file = File::new(file_path, Mode::Linter);
+    let lexer = Lexer::new(file, Mode::Linter);
+    if lexer.is_err(){
+        return Err(format!(
+            "{}: File Error -> Failed in reading file `{}`.", Mode::Linter, file_path));
+    }
+
+    let mut parser = Parser::new(lexer.unwrap(), Mode::Linter)?;
+    let mut syntax_tree = parse(&mut parser)?;
+
+    let mut checker = Checker::new(Mode::Linter);
+    check(&mut checker, &mut syntax_tree)?;
+
+    let tokens = read_tokens(file_path)?;
+    read_inline_levels(&mut config, &tokens)?;
+
+    let mut denied_count = 0;
+    for finding in find_lints(&syntax_tree, &tokens){
+        let level = config.get_level(finding.rule, finding.line);
+        if level == LintLevel::Allow{
+            continue;
+        }
+
+        let level_name = if level == LintLevel::Deny{
+            denied_count += 1;
+            "Lint Error"
+        } else {"Lint Warning"};
+
+        eprintln!(
+            "{}: {} -> {} ({}), line {}:{}.",
+            config.mode, level_name, finding.message, finding.rule.get_name(),
+            finding.line, finding.pos);
+    }
+
+    if denied_count > 0{
+        return Err(format!(
+            "{}: Lint Error -> Denied lint findings: {}.", config.mode, denied_count));
+    }
+
+    return Ok(());
+}
@@ -0,0 +1,376 @@
+use std::collections::{
+    HashMap,
+    VecDeque
+};
+
+use crate::tokens::{
+    Token,
+    TokenType
+};
+use crate::builtins::is_pure_function;
+use crate::syntax_tree::{
+    StatementsNode,
+    StatementNode,
+    StatementType,
+    DefineIfStatementNode,
+    DefineForLoopStatementNode,
+    OperationNode,
+    OperatorType,
+};
+
+use super::config::LintRule;
+
+
+#[derive(Debug, Clone)]
+pub struct Finding{
+    pub rule: LintRule,
+    pub message: String,
+    pub line: u64,
+    pub pos: u64,
+}
+
+
+#[derive(Debug)]
+struct Definition{
+    token: Token,
+    is_used: bool,
+    is_imported: bool,
+    is_loop_variable: bool,
+}
+
+
+/*
+* Walks The Checked Syntax Tree With The Same Scopes As The Checker, A Definition
+* Goes Into The Innermost Environment And A Name Is Resolved From The Innermost
+* Environment Outward.
+*/
+#[derive(Debug)]
+struct Linter{
+    /* Variable Name -> Index Of Its Definition */
+    environments_stack: VecDeque<HashMap<String, usize>>,
+    definitions: Vec<Definition>,
+
+    /* (Line, Position) -> Type Of Every Token Of The Linted File */
+    source_tokens_types: HashMap<(u64, u64), TokenType>,
+
+    /* Findings Inside Imported Modules Are Reported When Linting Those Files */
+    is_imported: bool,
+    findings: Vec<Finding>,
+}
+
+
+pub fn find_lints(syntax_tree: &StatementsNode, source_tokens: &Vec<Token>) -> Vec<Finding>{
+    let mut linter = Linter{
+        environments_stack: VecDeque::from([HashMap::new()]),
+        definitions: vec![],
+        source_tokens_types: source_tokens.iter().map(
+            |token| ((token.start_line, token.start_pos), token.token_type.clone())).collect(),
+        is_imported: false,
+        findings: vec![],
+    };
+
+    lint_statements(&mut linter, syntax_tree);
+
+    /* Variables Starting With `_` Are Meant To Be Unused, Loops Can Only Count */
+    for definition in &linter.definitions{
+        if
+            definition.is_used || definition.is_imported || definition.is_loop_variable ||
+            definition.token.value.starts_with("_")
+        {
+            continue;
+        }
+
+        linter.findings.push(Finding{
+            rule: LintRule::UnusedVariables,
+            message: format!("Variable `{}` is never used", definition.token.value),
+            line: definition.token.start_line,
+            pos: definition.token.start_pos,
+        });
+    }
+
+    let mut findings = linter.findings;
+    findings.sort_by_key(|finding| (finding.line, finding.pos));
+
+    return findings;
+}
+
+
+fn add_finding(linter: &mut Linter, rule: LintRule, message: String, token: &Token){
+    if linter.is_imported{
+        return;
+    }
+
+    linter.findings.push(Finding{
+        rule,
+        message,
+        line: token.start_line,
+        pos: token.start_pos,
+    });
+}
+
+
+fn define_variable(linter: &mut Linter, token: &Token, is_loop_variable: bool){
+    let is_shadowing = linter.environments_stack.iter().rev().skip(1).any(
+        |environment| environment.contains_key(&token.value));
+    if is_shadowing{
+        add_finding(
+            linter,
+            LintRule::Shadowing,
+            format!("Variable `{}` shadows a variable of an outer scope", token.value),
+            token);
+    }
+
+    linter.definitions.push(Definition{
+        token: token.clone(),
+        is_used: false,
+        is_imported: linter.is_imported,
+        is_loop_variable,
+    });
+
+    let index = linter.definitions.len() - 1;
+    linter.environments_stack.back_mut().unwrap().insert(token.value.clone(), index);
+}
+
+
+fn use_variable(linter: &mut Linter, name: &String){
+    for environment in linter.environments_stack.iter().rev(){
+        if environment.contains_key(name){
+            linter.definitions[*environment.get(name).unwrap()].is_used = true;
+            return;
+        }
+    }
+}
+
+
+fn lint_statements(linter: &mut Linter, statements: &StatementsNode){
+    for statement in &statements.statements{
+        lint_statement(linter, statement);
+    }
+}
+
+
+fn lint_statement(linter: &mut Linter, statement: &StatementNode){
+    let is_imported = linter.is_imported;
+    linter.is_imported = is_imported || statement.is_imported;
+
+    if statement.statement_type == Some(StatementType::DefineIf){
+        lint_define_if_statement(linter, statement.define_if_statement.as_ref().unwrap());
+    }
+    else if statement.statement_type == Some(StatementType::DefineForLoop){
+        lint_define_for_loop_statement(linter, statement.define_for_loop_statement.as_ref().unwrap());
+    }
+    else{
+        for expression in statement.get_expressions(){
+            lint_expression(linter, expression);
+        }
+
+        let name_token = statement.get_assigned_token();
+        if statement.statement_type == Some(StatementType::DefineVariable){
+            let define_variable_node = statement.define_variable_statement.as_ref().unwrap();
+
+            if define_variable_node.is_definition{
+                define_variable(linter, name_token.unwrap(), false);
+            }
+            else if
+                define_variable_node.operator.as_ref().unwrap().token_type == TokenType::Assign &&
+                is_variable_node(define_variable_node.left.as_ref().unwrap(), &name_token.unwrap().value)
+            {
+                add_finding(
+                    linter,
+                    LintRule::SelfAssignment,
+                    format!("Variable `{}` is assigned to itself", name_token.unwrap().value),
+                    name_token.unwrap());
+            }
+        }
+        else if name_token != None{
+            define_variable(linter, name_token.unwrap(), false);
+        }
+    }
+
+    linter.is_imported = is_imported;
+}
+
+
+fn lint_define_if_statement(linter: &mut Linter, statement: &DefineIfStatementNode){
+    let define_if_node = statement.define_if_node.as_ref().unwrap();
+
+    let mut branches = vec![(define_if_node.condition.as_ref(), &define_if_node.statements)];
+    for define_if_else_node in &statement.define_if_else_nodes{
+        branches.push((define_if_else_node.condition.as_ref(), &define_if_else_node.statements));
+    }
+    if statement.define_else_node != None{
+        branches.push((None, &statement.define_else_node.as_ref().unwrap().statements));
+    }
+
+    for (condition, statements) in branches{
+        if condition != None{
+            lint_expression(linter, condition.unwrap());
+        }
+
+        linter.environments_stack.push_back(HashMap::new());
+        lint_statements(linter, statements);
+        linter.environments_stack.pop_back();
+    }
+}
+
+
+fn lint_define_for_loop_statement(linter: &mut Linter, statement: &DefineForLoopStatementNode){
+    for expression in [&statement.start, &statement.stop, &statement.step]{
+        if expression != &None{
+            lint_expression(linter, expression.as_ref().unwrap());
+        }
+    }
+
+    /* The Step Is Written As A Literal, `-0` Is Lexed As A Single Number */
+    if statement.step != None{
+        let step = statement.step.as_ref().unwrap();
+        if
+            step.operator == None && step.value != None &&
+            step.value.as_ref().unwrap().token_type == TokenType::IntNumber &&
+            step.value.as_ref().unwrap().value.parse::<i64>() == Ok(0)
+        {
+            add_finding(
+                linter,
+                LintRule::ZeroStep,
+                String::from("Loop step is `0`, the loop variable never changes"),
+                step.value.as_ref().unwrap());
+        }
+    }
+
+    linter.environments_stack.push_back(HashMap::new());
+
+    if statement.variable != None{
+        define_variable(linter, statement.variable.as_ref().unwrap(), true);
+    }
+    lint_statements(linter, &statement.statements);
+
+    linter.environments_stack.pop_back();
+}
+
+
+fn lint_expression(linter: &mut Linter, expression: &OperationNode){
+    if
+        expression.operator == None && expression.value != None &&
+        expression.value.as_ref().unwrap().token_type == TokenType::Variable
+    {
+        use_variable(linter, &expression.value.as_ref().unwrap().value);
+    }
+
+    if expression.operator != None && is_comparison(expression.operator.as_ref().unwrap()){
+        if
+            expression.left != None && expression.right != None &&
+            is_pure_expression(expression.left.as_ref().unwrap()) &&
+            is_same_expression(expression.left.as_ref().unwrap(), expression.right.as_ref().unwrap())
+        {
+            let operator = expression.operator.as_ref().unwrap();
+            let result = if
+                operator == &OperatorType::Equal ||
+                operator == &OperatorType::GreaterThanOrEqual ||
+                operator == &OperatorType::LessThanOrEqual
+            {"True"} else {"False"};
+
+            add_finding(
+                linter,
+                LintRule::SelfComparison,
+                format!("Both sides of the comparison are the same, it is always `{}`", result),
+                expression.value.as_ref().unwrap());
+        }
+    }
+
+    /* `input()` Without `as` Gets A `string` Token At The Position Of `input` */
+    if expression.operator == Some(OperatorType::Convert){
+        let convert_token = expression.value.as_ref().unwrap();
+        let source_token_type = linter.source_tokens_types.get(
+            &(convert_token.start_line, convert_token.start_pos));
+
+        if convert_token.token_type == TokenType::String && source_token_type == Some(&TokenType::String){
+            add_finding(
+                linter,
+                LintRule::NeedlessConversion,
+                String::from("`input()` already returns a `string`, the `as string` conversion is needless"),
+                convert_token);
+        }
+    }
+
+    if expression.left != None{
+        lint_expression(linter, expression.left.as_ref().unwrap());
+    }
+    if expression.right != None{
+        lint_expression(linter, expression.right.as_ref().unwrap());
+    }
+    for argument in &expression.arguments{
+        lint_expression(linter, argument);
+    }
+}
+
+
+fn is_comparison(operator: &OperatorType) -> bool{
+    return operator == &OperatorType::Equal ||
+        operator == &OperatorType::NotEqual ||
+        operator == &OperatorType::GreaterThanOrEqual ||
+        operator == &OperatorType::GreaterThan ||
+        operator == &OperatorType::LessThanOrEqual ||
+        operator == &OperatorType::LessThan;
+}
+
+
+fn is_variable_node(expression: &OperationNode, name: &String) -> bool{
+    return expression.operator == None && expression.value != None &&
+        expression.value.as_ref().unwrap().token_type == TokenType::Variable &&
+        &expression.value.as_ref().unwrap().value == name;
+}
+
+
+/* Reading The Input Or Calling A Function With Side Effects Gives Another Value Every Time */
+fn is_pure_expression(expression: &OperationNode) -> bool{
+    if expression.operator == Some(OperatorType::Convert){
+        return false;
+    }
+    else if
+        expression.operator == Some(OperatorType::Call) &&
+        !is_pure_function(&expression.value.as_ref().unwrap().value)
+    {
+        return false;
+    }
+
+    return (expression.left == None || is_pure_expression(expression.left.as_ref().unwrap())) &&
+        (expression.right == None || is_pure_expression(expression.right.as_ref().unwrap())) &&
+        expression.arguments.iter().all(|argument| is_pure_expression(argument));
+}
+
+
+/* Compares The Expressions Ignoring The Positions Of Their Tokens */
+fn is_same_expression(first: &OperationNode, second: &OperationNode) -> bool{
+    if first.operator != second.operator || first.arguments.len() != second.arguments.len(){
+        return false;
+    }
+
+    if first.value != None || second.value != None{
+        if first.value == None || second.value == None{
+            return false;
+        }
+
+        let first_token = first.value.as_ref().unwrap();
+        let second_token = second.value.as_ref().unwrap();
+        if first_token.token_type != second_token.token_type || first_token.value != second_token.value{
+            return false;
+        }
+    }
+
+    let is_same_left = if first.left == None || second.left == None{
+        first.left == None && second.left == None
+    }
+    else{
+        is_same_expression(first.left.as_ref().unwrap(), second.left.as_ref().unwrap())
+    };
+
+    let is_same_right = if first.right == None || second.right == None{
+        first.right == None && second.right == None
+    }
+    else{
+        is_same_expression(first.right.as_ref().unwrap(), second.right.as_ref().unwrap())
+    };
+
+    return is_same_left && is_same_right && first.arguments.iter().zip(second.arguments.iter()).all(
+        |(first_argument, second_argument)| is_same_expression(first_argument, second_argument));
+}
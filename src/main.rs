@@ -5,11 +5,21 @@ mod tokens;
 mod lexer;
 mod syntax_tree;
 mod parser;
+mod modules;
+mod checker;
 mod environments;
+mod builtins;
+mod debugger;
+mod options;
+mod tracer;
+mod profiler;
+mod limiter;
 
+mod ir;
 mod compiler;
 mod interpreter;
 mod vm;
+mod linter;
 
 use clap::{
     Arg,
@@ -18,13 +28,35 @@ use clap::{
 };
 
 use crate::compiler::compile;
-use crate::interpreter::interpret;
-use crate::vm::execute_byte_code;
+use crate::builtins::{
+    set_program_arguments,
+    get_exit_code
+};
+use crate::options::{
+    ExecutionOptions,
+    CompileOptions
+};
+use crate::interpreter::{
+    interpret,
+    debug_code
+};
+use crate::vm::{
+    execute_byte_code,
+    execute_byte_code_legacy,
+    debug_byte_code
+};
+use crate::linter::lint;
 
 
 fn parse_args() -> ArgMatches{
     let file_path_arg = Arg::new("file-path");
 
+    let script_arguments_arg = Arg::new("script-arguments")
+        .num_args(0..)
+        .last(true)
+        .required(false)
+        .help("Arguments passed to the program after `--`, read with `args`");
+
     let executable_arg = Arg::new("executable")
         .long("executable")
         .short('e')
@@ -35,6 +67,16 @@ fn parse_args() -> ArgMatches{
         .required(false)
         .conflicts_with("byte-code");
 
+    let native_arg = Arg::new("native")
+        .long("native")
+        .value_parser(clap::value_parser!(bool))
+        .default_value("false")
+        .default_missing_value("true")
+        .num_args(0)
+        .required(false)
+        .conflicts_with_all(["executable", "byte-code"])
+        .help("Compile into an executable from x86-64 assembly linked with the C library, without rustc");
+
     let byte_code_arg = Arg::new("byte-code")
         .long("byte-code")
         .short('b')
@@ -62,13 +104,193 @@ fn parse_args() -> ArgMatches{
         .required(false)
         .conflicts_with("executable");
 
+    let legacy_virtual_machine_arg = Arg::new("legacy-virtual-machine")
+        .long("legacy-vm")
+        .value_parser(clap::value_parser!(bool))
+        .default_value("false")
+        .default_missing_value("true")
+        .num_args(0)
+        .required(false)
+        .requires("virtual-machine");
+
+    let optimization_level_arg = Arg::new("optimization-level")
+        .short('O')
+        .value_parser(clap::value_parser!(u8).range(0..=1))
+        .default_value("1")
+        .num_args(1)
+        .required(false)
+        .help("Byte code optimization level, -O0 disables the optimizer");
+
+    let emit_arg = Arg::new("emit")
+        .long("emit")
+        .value_name("FORMAT")
+        .value_parser(["ir", "asm", "c", "wat"])
+        .num_args(1)
+        .required(false)
+        .conflicts_with_all(["executable", "byte-code", "analyze-code", "virtual-machine", "native"])
+        .help("Print the program lowered into the intermediate representation shared by the compiler backends, or the native backend assembly, or a WebAssembly text module run by WASI runtimes, or write it as a C99 file with its runtime header");
+
+    let cc_arg = Arg::new("cc")
+        .long("cc")
+        .value_parser(clap::value_parser!(bool))
+        .default_value("false")
+        .default_missing_value("true")
+        .num_args(0)
+        .required(false)
+        .requires("emit")
+        .help("Compile the C file written by `--emit=c` into an executable with the system `cc`");
+
+    let keep_generated_arg = Arg::new("keep-generated")
+        .long("keep-generated")
+        .value_parser(clap::value_parser!(bool))
+        .default_value("false")
+        .default_missing_value("true")
+        .num_args(0)
+        .required(false)
+        .requires("executable")
+        .conflicts_with_all(["byte-code", "native"])
+        .help("Keep the Rust file generated when compiling into an executable");
+
+    let trace_arg = Arg::new("trace")
+        .long("trace")
+        .value_name("TRACE_FILE")
+        .num_args(0..=1)
+        .default_missing_value("-")
+        .required(false)
+        .conflicts_with_all(["executable", "byte-code", "analyze-code", "legacy-virtual-machine"])
+        .help("Log every executed statement or instruction into a file, or stderr when no file is given");
+
+    let profile_arg = Arg::new("profile")
+        .long("profile")
+        .value_parser(clap::value_parser!(bool))
+        .default_value("false")
+        .default_missing_value("true")
+        .num_args(0)
+        .required(false)
+        .conflicts_with_all(["executable", "byte-code", "analyze-code", "legacy-virtual-machine"])
+        .help("Count and time every executed line and instruction, and print the hot spots at exit");
+
+    let profile_flamegraph_arg = Arg::new("profile-flamegraph")
+        .long("profile-flamegraph")
+        .value_name("STACKS_FILE")
+        .num_args(1)
+        .required(false)
+        .requires("profile")
+        .help("Write the profile collapsed stacks into a file readable by flamegraph tools");
+
+    let max_steps_arg = Arg::new("max-steps")
+        .long("max-steps")
+        .value_name("STEPS")
+        .value_parser(clap::value_parser!(u128))
+        .num_args(1)
+        .required(false)
+        .conflicts_with("legacy-virtual-machine")
+        .help("Stop after executing more statements, loop iterations or instructions than the limit");
+
+    let max_memory_arg = Arg::new("max-memory")
+        .long("max-memory")
+        .value_name("BYTES")
+        .value_parser(clap::value_parser!(usize))
+        .num_args(1)
+        .required(false)
+        .conflicts_with("legacy-virtual-machine")
+        .help("Stop when the strings held by the variables take more bytes than the limit");
+
+    let timeout_arg = Arg::new("timeout")
+        .long("timeout")
+        .value_name("MILLISECONDS")
+        .value_parser(clap::value_parser!(u64))
+        .num_args(1)
+        .required(false)
+        .conflicts_with("legacy-virtual-machine")
+        .help("Stop when the execution takes more time than the limit");
+
+    let disable_input_arg = Arg::new("disable-input")
+        .long("disable-input")
+        .value_parser(clap::value_parser!(bool))
+        .default_value("false")
+        .default_missing_value("true")
+        .num_args(0)
+        .required(false)
+        .conflicts_with("legacy-virtual-machine")
+        .help("Stop the program when it calls `input`");
+
+    let disable_files_arg = Arg::new("disable-files")
+        .long("disable-files")
+        .value_parser(clap::value_parser!(bool))
+        .default_value("false")
+        .default_missing_value("true")
+        .num_args(0)
+        .required(false)
+        .conflicts_with("legacy-virtual-machine")
+        .help("Stop the program when it calls a file function");
+
+    let snapshot_arg = Arg::new("snapshot")
+        .long("snapshot")
+        .value_name("SNAPSHOT_FILE")
+        .num_args(1)
+        .required(false)
+        .requires_all(["virtual-machine", "snapshot-after"])
+        .conflicts_with("legacy-virtual-machine")
+        .help("Pause the VM and write its state into a file");
+
+    let snapshot_after_arg = Arg::new("snapshot-after")
+        .long("snapshot-after")
+        .value_name("INSTRUCTIONS")
+        .value_parser(clap::value_parser!(u128))
+        .num_args(1)
+        .required(false)
+        .requires("snapshot")
+        .help("Number of executed instructions before pausing the VM");
+
+    let resume_arg = Arg::new("resume")
+        .long("resume")
+        .value_name("SNAPSHOT_FILE")
+        .num_args(1)
+        .required(false)
+        .requires("virtual-machine")
+        .conflicts_with("legacy-virtual-machine")
+        .help("Resume the VM from a snapshot of the same byte code file");
+
     let args = Command::new("Engine").args([
         file_path_arg,
         executable_arg,
+        native_arg,
         byte_code_arg,
         analyze_arg,
-        virtual_machine_arg
+        virtual_machine_arg,
+        legacy_virtual_machine_arg,
+        optimization_level_arg,
+        emit_arg,
+        cc_arg,
+        keep_generated_arg,
+        trace_arg,
+        profile_arg,
+        profile_flamegraph_arg,
+        max_steps_arg,
+        max_memory_arg,
+        timeout_arg,
+        disable_input_arg,
+        disable_files_arg,
+        snapshot_arg,
+        snapshot_after_arg,
+        resume_arg,
+        script_arguments_arg
     ])
+        .subcommand(
+            Command::new("debug")
+                .about("Debug an engine file through the interpreter or a byte code file through the VM.")
+                .arg(Arg::new("file-path").required(true)))
+        .subcommand(
+            Command::new("lint")
+                .about("Report the valid code that is likely a mistake, with rules allowed, warned or denied.")
+                .arg(Arg::new("file-path").required(true))
+                .arg(Arg::new("config")
+                    .long("config")
+                    .value_name("CONFIG_FILE")
+                    .num_args(1)
+                    .required(false)
+                    .help("Rules levels file, by default `engine.lint` in the file folder or its parents")))
         .about("Engine Programming Language.")
         .long_about("Engine Programming Language Ecosystem.");
     return args.get_matches();
@@ -78,6 +300,26 @@ fn parse_args() -> ArgMatches{
 fn main() {
     let matches = parse_args();
 
+    if let Some(debug_matches) = matches.subcommand_matches("debug"){
+        let file_path = debug_matches.get_one::<String>("file-path").unwrap();
+
+        let result = if file_path.ends_with(".byte"){
+            debug_byte_code(file_path)
+        } else {
+            debug_code(file_path)
+        };
+
+        exit_with_result(result);
+        return;
+    }
+
+    if let Some(lint_matches) = matches.subcommand_matches("lint"){
+        let file_path = lint_matches.get_one::<String>("file-path").unwrap();
+
+        exit_with_result(lint(file_path, lint_matches.get_one::<String>("config")));
+        return;
+    }
+
     let generate_byte_code = if
         matches.contains_id("byte-code") &&
         matches.get_one::<bool>("byte-code").unwrap().clone()
@@ -88,24 +330,77 @@ fn main() {
         matches.get_one::<bool>("executable").unwrap().clone()
     {true} else {false};
 
+    let native_code = matches.get_one::<bool>("native").unwrap().clone();
+    let keep_generated_code = matches.get_one::<bool>("keep-generated").unwrap().clone();
+
     let analyze_code = if
         matches.contains_id("analyze-code") &&
         matches.get_one::<bool>("analyze-code").unwrap().clone()
     {true} else {false};
 
-    let result = if executable || generate_byte_code{
-        compile(generate_byte_code, analyze_code)
-    } else if analyze_code{
-        compile(false, analyze_code)
+    let optimization_level = matches.get_one::<u8>("optimization-level").unwrap().clone();
+
+    let mut options = ExecutionOptions::new();
+    options.trace_path = matches.get_one::<String>("trace").cloned();
+    options.profile = matches.get_one::<bool>("profile").unwrap().clone();
+    options.profile_flamegraph_path = matches.get_one::<String>("profile-flamegraph").cloned();
+    options.max_steps = matches.get_one::<u128>("max-steps").cloned();
+    options.max_memory = matches.get_one::<usize>("max-memory").cloned();
+    options.timeout_milliseconds = matches.get_one::<u64>("timeout").cloned();
+    options.disable_input = matches.get_one::<bool>("disable-input").unwrap().clone();
+    options.disable_files = matches.get_one::<bool>("disable-files").unwrap().clone();
+    options.snapshot_path = matches.get_one::<String>("snapshot").cloned();
+    options.snapshot_after = matches.get_one::<u128>("snapshot-after").cloned();
+    options.resume_path = matches.get_one::<String>("resume").cloned();
+
+    set_program_arguments(matches.get_many::<String>("script-arguments").map(
+        |arguments| arguments.cloned().collect()).unwrap_or(vec![]));
+
+    let emit_format = matches.get_one::<String>("emit").cloned();
+    let compile_c_code = matches.get_one::<bool>("cc").unwrap().clone();
+
+    let result = if compile_c_code && emit_format != Some(String::from("c")){
+        Err(String::from("Engine Compiler: Compiler Error -> `--cc` compiles the file written by `--emit=c`."))
+    } else if executable || generate_byte_code || native_code{
+        let mut compile_options = CompileOptions::new();
+        compile_options.generate_byte_code = generate_byte_code;
+        compile_options.analyze_code = analyze_code;
+        compile_options.optimization_level = optimization_level;
+        compile_options.native_code = native_code;
+        compile_options.keep_generated_code = keep_generated_code;
+
+        compile(&compile_options)
+    } else if analyze_code || emit_format != None{
+        let mut compile_options = CompileOptions::new();
+        compile_options.analyze_code = analyze_code;
+        compile_options.optimization_level = optimization_level;
+        compile_options.emit_format = emit_format;
+        compile_options.compile_c_code = compile_c_code;
+
+        compile(&compile_options)
     } else if
         matches.contains_id("virtual-machine") &&
         matches.get_one::<bool>("virtual-machine").unwrap().clone()
     {
-        execute_byte_code()
+        if matches.get_one::<bool>("legacy-virtual-machine").unwrap().clone(){
+            execute_byte_code_legacy()
+        } else {
+            execute_byte_code(&options)
+        }
     } else {
-        interpret()
+        interpret(&options)
     };
 
+    exit_with_result(result);
+}
+
+
+/* `exit` Stops The Program Through An Error, It Is Not Reported */
+fn exit_with_result(result: Result<(), String>){
+    if get_exit_code() != None{
+        std::process::exit(get_exit_code().unwrap());
+    }
+
     if result.is_err(){
         panic!("{}", result.unwrap_err());
     }
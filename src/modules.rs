@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::constants::Mode;
+use crate::lexer::Lexer;
+use crate::tokens::{
+    Token,
+    TokenType
+};
+
+
+/* A Source File, Its Variables Are Renamed With Its Prefix Except For The Main File */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Module{
+    pub file_path: String,
+    pub prefix: Option<String>,
+
+    /* Namespace Used Inside This Module -> Imported Module Prefix */
+    pub namespaces: HashMap<String, String>,
+}
+
+
+/* The Importer State, Restored When The Imported Module Ends */
+#[derive(Debug)]
+struct Importer{
+    lexer: Lexer,
+    current_token: Token,
+    module: Module,
+}
+
+
+#[derive(Debug)]
+pub struct Modules{
+    pub current_module: Module,
+    importers_stack: Vec<Importer>,
+
+    /* Canonical File Path -> Prefix Of Every Module Already Imported */
+    imported_modules: HashMap<String, String>,
+}
+
+impl Modules{
+    pub fn new(file_path: &String) -> Self{
+        return Modules{
+            current_module: Module{
+                file_path: canonical_path(file_path),
+                prefix: None,
+                namespaces: HashMap::new(),
+            },
+            importers_stack: Vec::new(),
+            imported_modules: HashMap::new(),
+        };
+    }
+
+    pub fn is_importing(&self) -> bool{
+        return self.importers_stack.len() > 0;
+    }
+}
+
+
+fn canonical_path(file_path: &String) -> String{
+    let path = std::fs::canonicalize(file_path);
+    if path.is_err(){
+        return file_path.clone();
+    }
+    return path.unwrap().to_string_lossy().to_string();
+}
+
+
+fn import_error(mode: &Mode, message: String, token: &Token) -> String{
+    return format!(
+        "{}: Import Error -> {}, line {}:{}.",
+        mode, message, token.start_line, token.start_pos);
+}
+
+
+/* `"lib/utils.en"` Or `lib.utils`, Relative To The Importing File */
+fn resolve_module_path(
+    modules: &Modules, import_token: &Token
+) -> String{
+
+    let relative_path = if import_token.token_type == TokenType::Variable{
+        import_token.value.replace(".", "/") + ".en"
+    } else {
+        import_token.value.clone()
+    };
+
+    let importer_folder = Path::new(&modules.current_module.file_path).parent();
+    if importer_folder == None{
+        return relative_path;
+    }
+
+    return importer_folder.unwrap().join(relative_path).to_string_lossy().to_string();
+}
+
+
+fn get_unique_prefix(modules: &Modules, file_path: &String) -> String{
+    let stem = Path::new(file_path).file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or(String::from("module"));
+
+    let mut prefix = stem.clone();
+    let mut counter = 2;
+    while modules.imported_modules.values().any(|used_prefix| used_prefix == &prefix){
+        prefix = format!("{}_{}", stem, counter);
+        counter += 1;
+    }
+
+    return prefix;
+}
+
+
+/*
+    Start Reading The Imported Module, Returns False When It Was Already Imported.
+    `current_token` Is The Token After The Import Statement.
+*/
+pub fn import_module(
+    modules: &mut Modules,
+    lexer: &mut Lexer,
+    current_token: &mut Token,
+    import_token: &Token,
+    namespace: Option<String>,
+    mode: &Mode
+) -> Result<bool, String>{
+
+    let file_path = resolve_module_path(modules, import_token);
+    if !Path::new(&file_path).is_file(){
+        return Err(import_error(
+            mode, format!("Can't find the module `{}`", file_path), import_token));
+    }
+    let file_path = canonical_path(&file_path);
+
+    if file_path == modules.current_module.file_path ||
+        modules.importers_stack.iter().any(|importer| importer.module.file_path == file_path)
+    {
+        let mut cycle: Vec<String> = modules.importers_stack.iter().map(
+            |importer| importer.module.file_path.clone()).collect();
+        cycle.push(modules.current_module.file_path.clone());
+        cycle.push(file_path);
+
+        return Err(import_error(
+            mode, format!("Import cycle `{}`", cycle.join(" -> ")), import_token));
+    }
+
+    let namespace = namespace.unwrap_or(
+        Path::new(&file_path).file_stem().unwrap().to_string_lossy().to_string());
+    if modules.current_module.namespaces.contains_key(&namespace){
+        return Err(import_error(
+            mode, format!("Module `{}` is already imported", namespace), import_token));
+    }
+
+    if modules.imported_modules.contains_key(&file_path){
+        let prefix = modules.imported_modules.get(&file_path).unwrap().clone();
+        modules.current_module.namespaces.insert(namespace, prefix);
+
+        return Ok(false);
+    }
+
+    let prefix = get_unique_prefix(modules, &file_path);
+    modules.imported_modules.insert(file_path.clone(), prefix.clone());
+    modules.current_module.namespaces.insert(namespace, prefix.clone());
+
+    let file = crate::file::File::new(&file_path, mode.clone());
+    let module_lexer = Lexer::new(file, mode.clone());
+    if module_lexer.is_err(){
+        return Err(import_error(
+            mode, format!("Failed in reading the module `{}`", file_path), import_token));
+    }
+
+    let importer_lexer = std::mem::replace(lexer, module_lexer.unwrap());
+    let importer_module = std::mem::replace(&mut modules.current_module, Module{
+        file_path,
+        prefix: Some(prefix),
+        namespaces: HashMap::new(),
+    });
+
+    modules.importers_stack.push(Importer{
+        lexer: importer_lexer,
+        current_token: current_token.clone(),
+        module: importer_module,
+    });
+
+    return Ok(true);
+}
+
+
+/* Continue Reading The Importer After The Imported Module Ends */
+pub fn end_module(
+    modules: &mut Modules, lexer: &mut Lexer, current_token: &mut Token
+){
+    let importer = modules.importers_stack.pop().unwrap();
+
+    *lexer = importer.lexer;
+    *current_token = importer.current_token;
+    modules.current_module = importer.module;
+}
+
+
+/* Rename Variables Of Imported Modules And Resolve `namespace.variable` Names */
+pub fn resolve_variable_token(
+    modules: &Modules, token: Token, mode: &Mode
+) -> Result<Token, String>{
+
+    if token.token_type != TokenType::Variable{
+        return Ok(token);
+    }
+    let mut token = token;
+
+    let parts: Vec<&str> = token.value.split(".").collect();
+    if parts.len() == 1{
+        if modules.current_module.prefix != None{
+            token.value = format!(
+                "{}__{}", modules.current_module.prefix.as_ref().unwrap(), token.value);
+        }
+        return Ok(token);
+    }
+
+    let prefix = modules.current_module.namespaces.get(parts[0]);
+    if parts.len() != 2 || prefix == None{
+        return Err(import_error(
+            mode, format!("Unknown module `{}`", parts[..parts.len() - 1].join(".")), &token));
+    }
+
+    token.value = format!("{}__{}", prefix.unwrap(), parts[1]);
+    return Ok(token);
+}
+
+
+/* A Variable Followed By `(` Is A Call Of A Built-in Function, Which Is Not Renamed */
+pub fn get_function_token(modules: &Modules, token: Token) -> Token{
+    let mut token = token;
+    token.token_type = TokenType::Function;
+
+    if modules.current_module.prefix != None{
+        let module_prefix = format!("{}__", modules.current_module.prefix.as_ref().unwrap());
+        if token.value.starts_with(&module_prefix){
+            token.value = String::from(&token.value[module_prefix.len()..]);
+        }
+    }
+
+    return token;
+}
+
+
+/* Variables Of Imported Modules Are Already Renamed When They Are Compared By Name */
+pub fn is_variable_named(modules: &Modules, token: &Token, name: &str) -> bool{
+    if token.token_type != TokenType::Variable{
+        return false;
+    }
+
+    if modules.current_module.prefix != None{
+        return token.value == format!("{}__{}", modules.current_module.prefix.as_ref().unwrap(), name);
+    }
+    return token.value == name;
+}
@@ -0,0 +1,86 @@
+/* Options Shared By The Interpreter And The VM While Executing */
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutionOptions{
+    /* `-` Writes The Trace Into Stderr */
+    pub trace_path: Option<String>,
+
+    pub profile: bool,
+    pub profile_flamegraph_path: Option<String>,
+
+    /* Sandbox Limits */
+    pub max_steps: Option<u128>,
+    pub max_memory: Option<usize>,
+    pub timeout_milliseconds: Option<u64>,
+    pub disable_input: bool,
+    pub disable_files: bool,
+
+    /* VM Snapshots */
+    pub snapshot_path: Option<String>,
+    pub snapshot_after: Option<u128>,
+    pub resume_path: Option<String>,
+}
+
+impl ExecutionOptions{
+    pub fn new() -> Self{
+        return ExecutionOptions{
+            trace_path: None,
+
+            profile: false,
+            profile_flamegraph_path: None,
+
+            max_steps: None,
+            max_memory: None,
+            timeout_milliseconds: None,
+            disable_input: false,
+            disable_files: false,
+
+            snapshot_path: None,
+            snapshot_after: None,
+            resume_path: None,
+        };
+    }
+
+    pub fn has_limits(&self) -> bool{
+        return
+            self.max_steps != None ||
+            self.max_memory != None ||
+            self.timeout_milliseconds != None ||
+            self.disable_input ||
+            self.disable_files;
+    }
+}
+
+
+/* Options Choosing What The Compiler Generates From The Lowered Program */
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompileOptions{
+    pub generate_byte_code: bool,
+    pub analyze_code: bool,
+
+    /* `0` Disables The Byte Code Optimizer */
+    pub optimization_level: u8,
+
+    /* `ir`, `asm`, `wat` Or `c`, Printed Or Written Instead Of The Executable */
+    pub emit_format: Option<String>,
+    pub compile_c_code: bool,
+
+    pub native_code: bool,
+    pub keep_generated_code: bool,
+}
+
+impl CompileOptions{
+    pub fn new() -> Self{
+        return CompileOptions{
+            generate_byte_code: false,
+            analyze_code: false,
+
+            optimization_level: 1,
+
+            emit_format: None,
+            compile_c_code: false,
+
+            native_code: false,
+            keep_generated_code: false,
+        };
+    }
+}
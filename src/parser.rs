@@ -11,6 +11,14 @@ use crate::tokens::{
     Token,
     TokenType
 };
+use crate::modules::{
+    Modules,
+    import_module,
+    end_module,
+    resolve_variable_token,
+    get_function_token,
+    is_variable_named
+};
 use crate::syntax_tree::{
     StatementsNode,
     construct_expression_node,
@@ -22,6 +30,7 @@ use crate::syntax_tree::{
     DefineVarNode,
     DefineVariableNode,
     DefinePrintNode,
+    DefineCallNode,
     DefineIfStatementNode,
     StatementNode,
     StatementType,
@@ -31,6 +40,8 @@ use crate::syntax_tree::{
     DefineForLoopStatementNode,
     DefineContinueStatementNode,
     DefineBreakStatementNode,
+    OperationNode,
+    OperatorType,
 };
 
 
@@ -38,18 +49,26 @@ use crate::syntax_tree::{
 pub struct Parser{
     pub lexer: Lexer,
     pub current_token: Token,
-    pub mode: Mode
+    pub mode: Mode,
+    pub modules: Modules,
+
+    /* Import Statements Tokens Are Not Renamed */
+    is_reading_import: bool,
 }
 
 impl Parser{
     pub fn new(lexer: Lexer, mode: Mode) -> Result<Self, String>{
         let mut lexer = lexer;
-        let current_token = next_token(&mut lexer)?;
+        let modules = Modules::new(&lexer.get_file_path());
+        let current_token = resolve_variable_token(
+            &modules, next_token(&mut lexer)?, &mode)?;
 
         return Ok(Parser{
             lexer,
             current_token,
-            mode
+            mode,
+            modules,
+            is_reading_import: false,
         })
     }
 }
@@ -118,16 +137,69 @@ pub fn statement(
         return Ok((result.0, node));
     }
 
+    /* Constants Are Only Defined In The Top Level */
+    else if parser.current_token.token_type == TokenType::Const{
+        if !return_error_if_not_matched{
+            return Err(format!(
+                "{}: Syntax Error -> {}, line {}:{}.",
+                parser.mode,
+                "Constants must be in the top level of the file",
+                parser.current_token.start_line,
+                parser.current_token.start_pos));
+        }
+        _move(&mut parser)?;
+
+        bypass(&mut parser, vec![
+            TokenType::Space,
+            TokenType::MultiLineComment,
+        ])?;
+        _match(&mut parser, vec![
+            TokenType::Bool,
+            TokenType::Int,
+            TokenType::Double,
+            TokenType::Char,
+            TokenType::String,
+            TokenType::Var,
+        ])?;
+
+        let result = statement(&mut parser, return_error_if_not_matched)?;
+        let mut node = result.1;
+        node.is_constant = true;
+
+        return Ok((result.0, node));
+    }
+
+    /* A Variable Followed By `(` Is A Function Call */
     else if parser.current_token.token_type == TokenType::Variable{
+        let name_token = parser.current_token.clone();
+        _move(&mut parser)?;
+
+        bypass(&mut parser, vec![
+            TokenType::Space,
+            TokenType::MultiLineComment,
+        ])?;
+        if parser.current_token.token_type == TokenType::OpenParenthes{
+            node.statement_type = Some(StatementType::Call);
+
+            let result = define_call(&mut parser, name_token)?;
+            node.define_call_statement = Some(result.1);
+
+            return Ok((result.0, node));
+        }
+
         node.statement_type = Some(StatementType::DefineVariable);
 
-        let result = define_variable(&mut parser)?;
+        let result = define_variable(&mut parser, name_token)?;
         node.define_variable_statement = Some(result.1);
 
         return Ok((result.0, node));
     }
 
-    else if parser.current_token.token_type == TokenType::Print{
+    else if
+        parser.current_token.token_type == TokenType::Print ||
+        parser.current_token.token_type == TokenType::EPrint ||
+        parser.current_token.token_type == TokenType::PrintF
+    {
         node.statement_type = Some(StatementType::Print);
 
         let result = define_print(&mut parser)?;
@@ -135,7 +207,6 @@ pub fn statement(
 
         return Ok((result.0, node));
     }
-
     else if parser.current_token.token_type == TokenType::If{
         node.statement_type = Some(StatementType::DefineIf);
 
@@ -183,7 +254,33 @@ pub fn statement(
         return Ok((false, node));
     }
 
+    /* Imports And The End Of Imported Modules Are Only Handled In The Top Level */
+    else if parser.current_token.token_type == TokenType::Import{
+        if !return_error_if_not_matched{
+            return Err(format!(
+                "{}: Syntax Error -> {}, line {}:{}.",
+                parser.mode,
+                "Imports must be in the top level of the file",
+                parser.current_token.start_line,
+                parser.current_token.start_pos));
+        }
+
+        define_import(&mut parser)?;
+
+        node.statement_type = Some(StatementType::Discarded);
+
+        return Ok((false, node));
+    }
+
     else if parser.current_token.token_type == TokenType::Eof{
+        if return_error_if_not_matched && parser.modules.is_importing(){
+            end_module(&mut parser.modules, &mut parser.lexer, &mut parser.current_token);
+
+            node.statement_type = Some(StatementType::Discarded);
+
+            return Ok((false, node));
+        }
+
         return Ok((true, node));
     }
 
@@ -208,7 +305,7 @@ fn statements(
     let mut syntax_tree = StatementsNode::new();
 
     loop {
-        let statement_node = statement(&mut parser, return_error_if_not_matched)?;
+        let mut statement_node = statement(&mut parser, return_error_if_not_matched)?;
         if statement_node.0{
             break;
         }
@@ -217,6 +314,9 @@ fn statements(
             continue;
         }
 
+        /* The Imported Module Ends At Its Last Token, After Its Last Statement */
+        statement_node.1.is_imported = parser.modules.is_importing();
+
         syntax_tree.statements.push_back(statement_node.1);
     }
     return Ok(syntax_tree);
@@ -224,7 +324,15 @@ fn statements(
 
 
 fn _move(parser: &mut Parser) -> Result<(), String>{
-    parser.current_token = next_token(&mut parser.lexer)?;
+    let token = next_token(&mut parser.lexer)?;
+
+    if parser.is_reading_import{
+        parser.current_token = token;
+    }
+    else{
+        parser.current_token = resolve_variable_token(&parser.modules, token, &parser.mode)?;
+    }
+
     return Ok(());
 }
 
@@ -405,6 +513,22 @@ fn match_expression(
             tokens_array.push_back(default_convert_to_token);
         }
     }
+    else if parser.current_token.token_type == TokenType::Variable{
+        let variable_token = parser.current_token.clone();
+        _move(&mut parser)?;
+
+        bypass(&mut parser, vec![
+            TokenType::Space,
+            TokenType::MultiLineComment,
+        ])?;
+        if parser.current_token.token_type == TokenType::OpenParenthes{
+            tokens_array.push_back(get_function_token(&parser.modules, variable_token));
+            match_call_arguments(&mut parser, &mut tokens_array)?;
+        }
+        else{
+            tokens_array.push_back(variable_token);
+        }
+    }
     else{
         tokens_array.push_back(parser.current_token.clone());
         _move(&mut parser)?;
@@ -451,6 +575,55 @@ fn match_expression(
 }
 
 
+/* The Parentheses And The Arguments Separated By Commas, After The Function Name */
+fn match_call_arguments(
+    parser: &mut Parser, tokens_array: &mut VecDeque<Token>
+) -> Result<(), String>{
+
+    let mut parser = parser;
+    let mut tokens_array = tokens_array;
+
+    _match(&mut parser, vec![
+        TokenType::OpenParenthes
+    ])?;
+    tokens_array.push_back(parser.current_token.clone());
+    _move(&mut parser)?;
+
+    bypass(&mut parser, vec![
+        TokenType::Space,
+        TokenType::SingleLineComment,
+        TokenType::MultiLineComment,
+        TokenType::NewLine
+    ])?;
+
+    while !_is_matched_with(&mut parser, vec![
+        TokenType::CloseParenthes
+    ]){
+        match_expression(&mut parser, true, &mut tokens_array)?;
+
+        _match(&mut parser, vec![
+            TokenType::Comma,
+            TokenType::CloseParenthes
+        ])?;
+        if parser.current_token.token_type == TokenType::Comma{
+            tokens_array.push_back(parser.current_token.clone());
+            _move(&mut parser)?;
+
+            bypass(&mut parser, vec![
+                TokenType::Space,
+                TokenType::SingleLineComment,
+                TokenType::MultiLineComment,
+                TokenType::NewLine
+            ])?;
+        }
+    }
+    tokens_array.push_back(parser.current_token.clone());
+    _move(&mut parser)?;
+
+    return Ok(());
+}
+
+
 fn define_bool(parser: &mut Parser) -> Result<(bool, DefineBoolNode), String>{
     let mut parser = parser;
     let mut tokens_array: VecDeque<Token> = VecDeque::new();
@@ -699,16 +872,16 @@ fn define_var(parser: &mut Parser) -> Result<(bool, DefineVarNode), String>{
 }
 
 
+/* The Variable Name Is Already Matched */
 fn define_variable(
-    parser: &mut Parser
+    parser: &mut Parser, name_token: Token
 ) -> Result<(bool, DefineVariableNode), String>{
 
     let mut parser = parser;
     let mut tokens_array: VecDeque<Token> = VecDeque::new();
     let mut node = DefineVariableNode::new();
 
-    node.name = Some(parser.current_token.clone());
-    _move(&mut parser)?;
+    node.name = Some(name_token);
 
     bypass(&mut parser, vec![
         TokenType::Space,
@@ -740,10 +913,14 @@ fn define_variable(
 }
 
 
+/* `print` And `eprint` Take Expressions Then `sep` And `end`, `printf` Takes A Format */
 fn define_print(parser: &mut Parser) -> Result<(bool, DefinePrintNode), String>{
     let mut parser = parser;
     let mut node = DefinePrintNode::new();
 
+    let print_token = parser.current_token.clone();
+    node.token = Some(print_token.clone());
+    node.is_stderr = print_token.token_type == TokenType::EPrint;
     _move(&mut parser)?;
 
     bypass(&mut parser, vec![
@@ -757,20 +934,141 @@ fn define_print(parser: &mut Parser) -> Result<(bool, DefinePrintNode), String>{
 
     bypass(&mut parser, vec![
         TokenType::Space,
+        TokenType::SingleLineComment,
         TokenType::MultiLineComment,
+        TokenType::NewLine
     ])?;
-    let mut tokens_array: VecDeque<Token> = VecDeque::new();
-    match_expression(&mut parser, false, &mut tokens_array)?;
-    node.expression = Some(construct_expression_node(&mut tokens_array));
+
+    /* Match Arguments Separated By Commas */
+    let mut arguments: Vec<OperationNode> = vec![];
+    while !_is_matched_with(&mut parser, vec![
+        TokenType::CloseParenthes
+    ]){
+        let mut tokens_array: VecDeque<Token> = VecDeque::new();
+        match_expression(&mut parser, true, &mut tokens_array)?;
+
+        let keyword_token = tokens_array.front().unwrap().clone();
+        let is_keyword = print_token.token_type != TokenType::PrintF &&
+            tokens_array.len() == 1 &&
+            _is_matched_with(&mut parser, vec![TokenType::Assign]) &&
+            (
+                is_variable_named(&parser.modules, &keyword_token, "sep") ||
+                is_variable_named(&parser.modules, &keyword_token, "end")
+            );
+
+        if is_keyword{
+            _move(&mut parser)?;
+
+            let mut tokens_array: VecDeque<Token> = VecDeque::new();
+            match_expression(&mut parser, true, &mut tokens_array)?;
+
+            let is_separator = is_variable_named(&parser.modules, &keyword_token, "sep");
+            if (is_separator && node.separator != None) || (!is_separator && node.end != None){
+                return Err(format!(
+                    "{}: Syntax Error -> {}, line {}:{}.",
+                    parser.mode,
+                    "Print keyword argument is repeated",
+                    keyword_token.start_line,
+                    keyword_token.start_pos));
+            }
+
+            if is_separator{
+                node.separator = Some(construct_expression_node(&mut tokens_array));
+            }
+            else{
+                node.end = Some(construct_expression_node(&mut tokens_array));
+            }
+        }
+        else if node.separator != None || node.end != None{
+            return Err(format!(
+                "{}: Syntax Error -> {}, line {}:{}.",
+                parser.mode,
+                "Print expressions must be before `sep` and `end`",
+                keyword_token.start_line,
+                keyword_token.start_pos));
+        }
+        else{
+            arguments.push(construct_expression_node(&mut tokens_array));
+        }
+
+        _match(&mut parser, vec![
+            TokenType::Comma,
+            TokenType::CloseParenthes
+        ])?;
+        if parser.current_token.token_type == TokenType::Comma{
+            _move(&mut parser)?;
+
+            bypass(&mut parser, vec![
+                TokenType::Space,
+                TokenType::SingleLineComment,
+                TokenType::MultiLineComment,
+                TokenType::NewLine
+            ])?;
+        }
+    }
+    _move(&mut parser)?;
+
+    /* `printf` Prints The Result Of `format` */
+    if print_token.token_type == TokenType::PrintF{
+        let mut format_node = OperationNode::new();
+        format_node.operator = Some(OperatorType::Call);
+        format_node.value = Some(Token{
+            token_type: TokenType::Function,
+            start_line: print_token.start_line,
+            start_pos: print_token.start_pos,
+            value: String::from("format")
+        });
+        format_node.arguments = arguments;
+
+        arguments = vec![format_node];
+    }
+
+    /* Expressions Are Separated By A Space By Default */
+    if arguments.len() > 1 && node.separator == None{
+        node.separator = Some(construct_expression_node(&mut VecDeque::from([Token{
+            token_type: TokenType::StringSequence,
+            start_line: print_token.start_line,
+            start_pos: print_token.start_pos,
+            value: String::from(" ")
+        }])));
+    }
+    node.expressions = arguments;
+
+    return Ok((false, node));
+}
+
+
+/* A Function Called For Its Side Effects, Its Result Is Discarded, The Name Is Already Matched */
+fn define_call(
+    parser: &mut Parser, name_token: Token
+) -> Result<(bool, DefineCallNode), String>{
+
+    let mut parser = parser;
+    let mut node = DefineCallNode::new();
+
+    let function_token = get_function_token(&parser.modules, name_token);
+
+    let mut tokens_array: VecDeque<Token> = VecDeque::from([function_token.clone()]);
+    match_call_arguments(&mut parser, &mut tokens_array)?;
 
     bypass(&mut parser, vec![
         TokenType::Space,
         TokenType::MultiLineComment,
     ])?;
-    _match(&mut parser, vec![
-        TokenType::CloseParenthes,
-    ])?;
-    _move(&mut parser)?;
+    if !_is_matched_with(&mut parser, vec![
+        TokenType::NewLine,
+        TokenType::SingleLineComment,
+        TokenType::CloseBracket,
+        TokenType::Eof
+    ]){
+        return Err(format!(
+            "{}: Syntax Error -> {}, line {}:{}.",
+            parser.mode,
+            "Only function calls can be used as statements",
+            function_token.start_line,
+            function_token.start_pos));
+    }
+    node.expression = Some(construct_expression_node(&mut tokens_array));
 
     return Ok((false, node));
 }
@@ -1207,3 +1505,84 @@ fn define_break_statement(
 
     return Ok((false, node));
 }
+
+
+/* import "lib/utils.en" as utils */
+fn define_import(parser: &mut Parser) -> Result<(), String>{
+    let mut parser = parser;
+
+    parser.is_reading_import = true;
+    _move(&mut parser)?;
+
+    bypass(&mut parser, vec![
+        TokenType::Space,
+        TokenType::MultiLineComment
+    ])?;
+    _match(&mut parser, vec![
+        TokenType::StringSequence,
+        TokenType::Variable
+    ])?;
+
+    let import_token = parser.current_token.clone();
+    _move(&mut parser)?;
+
+    let mut namespace: Option<String> = None;
+
+    if _is_matched_with(&mut parser, vec![TokenType::Space, TokenType::MultiLineComment]){
+        bypass(&mut parser, vec![
+            TokenType::Space,
+            TokenType::MultiLineComment
+        ])?;
+
+        if parser.current_token.token_type == TokenType::As{
+            _move(&mut parser)?;
+
+            bypass(&mut parser, vec![
+                TokenType::Space,
+                TokenType::MultiLineComment
+            ])?;
+            _match(&mut parser, vec![TokenType::Variable])?;
+
+            if parser.current_token.value.contains("."){
+                return Err(format!(
+                    "{}: Syntax Error -> {}, line {}:{}.",
+                    parser.mode,
+                    format!("Invalid module name `{}`", parser.current_token.value),
+                    parser.current_token.start_line,
+                    parser.current_token.start_pos));
+            }
+
+            namespace = Some(parser.current_token.value.clone());
+            _move(&mut parser)?;
+        }
+    }
+
+    if _is_matched_with(&mut parser, vec![
+        TokenType::Space,
+        TokenType::SingleLineComment,
+        TokenType::MultiLineComment
+    ]){
+        bypass(&mut parser, vec![
+            TokenType::Space,
+            TokenType::SingleLineComment,
+            TokenType::MultiLineComment
+        ])?;
+    }
+    _match(&mut parser, vec![
+        TokenType::NewLine,
+        TokenType::Eof
+    ])?;
+
+    parser.is_reading_import = false;
+
+    let is_imported = import_module(
+        &mut parser.modules, &mut parser.lexer, &mut parser.current_token,
+        &import_token, namespace, &parser.mode)?;
+
+    /* Read The First Token Of The Imported Module */
+    if is_imported{
+        _move(&mut parser)?;
+    }
+
+    return Ok(());
+}
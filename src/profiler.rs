@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::time::Duration;
+
+
+const REPORT_MAX_ENTRIES: usize = 20;
+
+
+#[derive(Debug, Clone, PartialEq)]
+struct ProfileEntry{
+    label: String,
+    count: u128,
+    time: Duration,
+}
+
+impl ProfileEntry{
+    fn new(label: String) -> Self{
+        return ProfileEntry{
+            label,
+            count: 0,
+            time: Duration::ZERO,
+        };
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+struct ProfileFrame{
+    name: String,
+    children_time: Duration,
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profiler{
+    source_lines: Vec<String>,
+
+    lines: HashMap<u128, ProfileEntry>,
+    instructions: HashMap<String, ProfileEntry>,
+
+    /* Self Time In Microseconds Of Every Collapsed Stack */
+    stacks: HashMap<String, u128>,
+    frames: Vec<ProfileFrame>,
+}
+
+impl Profiler{
+    pub fn new(file_path: &String) -> Result<Self, String>{
+        let source = std::fs::read_to_string(file_path);
+        if source.is_err(){
+            return Err(format!(
+                "Engine Profiler: File Error -> Can't open the file `{}`.",
+                file_path));
+        }
+
+        return Ok(Profiler{
+            source_lines: source.unwrap().lines().map(|line| String::from(line.trim())).collect(),
+            lines: HashMap::new(),
+            instructions: HashMap::new(),
+            stacks: HashMap::new(),
+            frames: vec![ProfileFrame{
+                name: String::from("main"),
+                children_time: Duration::ZERO,
+            }],
+        });
+    }
+
+    pub fn enter(&mut self, kind: &String, line: u128){
+        self.frames.push(ProfileFrame{
+            name: format!("{}:{}", kind, line),
+            children_time: Duration::ZERO,
+        });
+    }
+
+    /* Time Is Cumulative, It Includes The Nested Statements Of Blocks */
+    pub fn exit(&mut self, kind: &String, line: u128, time: Duration){
+        let source_line = self.source_lines.get((line as usize).wrapping_sub(1)).cloned();
+
+        let line_entry = self.lines.entry(line).or_insert(
+            ProfileEntry::new(source_line.unwrap_or(String::new())));
+        line_entry.count += 1;
+        line_entry.time += time;
+
+        let instruction_entry = self.instructions.entry(kind.clone()).or_insert(
+            ProfileEntry::new(kind.clone()));
+        instruction_entry.count += 1;
+        instruction_entry.time += time;
+
+        let stack: Vec<&str> = self.frames.iter().map(|frame| frame.name.as_str()).collect();
+        let stack = stack.join(";");
+
+        let frame = self.frames.pop().unwrap();
+        let self_time = time.saturating_sub(frame.children_time);
+        *self.stacks.entry(stack).or_insert(0) += self_time.as_micros();
+
+        if self.frames.len() > 0{
+            self.frames.last_mut().unwrap().children_time += time;
+        }
+    }
+}
+
+
+fn sorted_entries<K>(entries: &HashMap<K, ProfileEntry>) -> Vec<(&K, &ProfileEntry)>{
+    let mut entries: Vec<(&K, &ProfileEntry)> = entries.iter().collect();
+    entries.sort_by(|first, second| {
+        second.1.time.cmp(&first.1.time).then(second.1.count.cmp(&first.1.count))
+    });
+    return entries;
+}
+
+
+/* Hot Spots Report Into Stderr, And The Collapsed Stacks For Flamegraph Tools */
+pub fn report_profile(
+    profiler: &Profiler, flamegraph_path: Option<&String>
+) -> Result<(), String>{
+
+    let mut report = String::new();
+
+    report.push_str("\nEngine Profile: Lines\n");
+    report.push_str(&format!("{:>12} {:>14}  {:>6}  {}\n", "count", "time(ms)", "line", "source"));
+    for (line, entry) in sorted_entries(&profiler.lines).into_iter().take(REPORT_MAX_ENTRIES){
+        report.push_str(&format!(
+            "{:>12} {:>14.3}  {:>6}  {}\n",
+            entry.count, entry.time.as_secs_f64() * 1000.0, line, entry.label));
+    }
+
+    report.push_str("\nEngine Profile: Instructions\n");
+    report.push_str(&format!("{:>12} {:>14}  {}\n", "count", "time(ms)", "instruction"));
+    for (_, entry) in sorted_entries(&profiler.instructions).into_iter().take(REPORT_MAX_ENTRIES){
+        report.push_str(&format!(
+            "{:>12} {:>14.3}  {}\n",
+            entry.count, entry.time.as_secs_f64() * 1000.0, entry.label));
+    }
+
+    if std::io::stderr().write_all(report.as_bytes()).is_err(){
+        return Err(String::from(
+            "Engine Profiler: Error -> Failed to write the report."));
+    }
+
+    if flamegraph_path == None{
+        return Ok(());
+    }
+    let flamegraph_path = flamegraph_path.unwrap();
+
+    let mut stacks: Vec<(&String, &u128)> = profiler.stacks.iter().collect();
+    stacks.sort();
+
+    let mut collapsed_stacks = String::new();
+    for (stack, time) in stacks{
+        collapsed_stacks.push_str(&format!("{} {}\n", stack, time));
+    }
+
+    if std::fs::write(flamegraph_path, collapsed_stacks).is_err(){
+        return Err(format!(
+            "Engine Profiler: File Error -> Can't write the file `{}`.",
+            flamegraph_path));
+    }
+
+    return Ok(());
+}
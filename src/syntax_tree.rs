@@ -1,12 +1,14 @@
 use std::collections::{
     VecDeque,
-    HashMap
+    HashMap,
+    HashSet
 };
 
 use crate::tokens::{
     Token,
     TokenType
 };
+use crate::builtins::is_pure_function;
 
 
 #[derive(Debug, Clone, PartialEq)]
@@ -22,6 +24,7 @@ pub enum StatementType{
     DefineVar,
 
     Print,
+    Call,
 
     DefineIf,
 
@@ -40,6 +43,7 @@ pub enum OperatorType{
     Mod,
 
     Convert,
+    Call,
 
     Equal,
     GreaterThanOrEqual,
@@ -59,6 +63,12 @@ pub struct OperationNode{
     pub operator: Option<OperatorType>,
     pub left: Option<Box<OperationNode>>,
     pub right: Option<Box<OperationNode>>,
+
+    /* Call Arguments, The Value Is The Function Token */
+    pub arguments: Vec<OperationNode>,
+
+    /* Set By The Checker, The Type Of The Result (`IntNumber`, `StringSequence`, ...) */
+    pub result_type: Option<TokenType>,
 }
 
 impl OperationNode{
@@ -67,9 +77,118 @@ impl OperationNode{
             value: None,
             operator: None,
             left: None,
-            right: None
+            right: None,
+            arguments: Vec::new(),
+            result_type: None
         };
     }
+
+    pub fn get_variables_names(&self) -> Vec<String>{
+        let mut names = vec![];
+
+        if self.left != None{
+            names.append(&mut self.left.as_ref().unwrap().get_variables_names());
+        }
+
+        if self.value != None && self.value.as_ref().unwrap().token_type == TokenType::Variable{
+            names.push(self.value.as_ref().unwrap().value.clone());
+        }
+
+        if self.right != None{
+            names.append(&mut self.right.as_ref().unwrap().get_variables_names());
+        }
+
+        for argument in &self.arguments{
+            names.append(&mut argument.get_variables_names());
+        }
+
+        return names;
+    }
+
+    pub fn get_first_token(&self) -> Option<&Token>{
+        if self.left != None{
+            let token = self.left.as_ref().unwrap().get_first_token();
+            if token != None{
+                return token;
+            }
+        }
+
+        if self.value != None{
+            return self.value.as_ref();
+        }
+
+        if self.right != None{
+            return self.right.as_ref().unwrap().get_first_token();
+        }
+
+        return None;
+    }
+
+    /* The First Token That Can't Be Evaluated At Compile Time */
+    pub fn get_non_constant_token(&self, constants: &HashSet<String>) -> Option<Token>{
+        if self.left != None{
+            let token = self.left.as_ref().unwrap().get_non_constant_token(constants);
+            if token != None{
+                return token;
+            }
+        }
+
+        if self.value != None{
+            let token = self.value.as_ref().unwrap();
+
+            /* `input()` Is Parsed As A Conversion Into Its Type */
+            if self.operator == Some(OperatorType::Convert){
+                return Some(Token{
+                    token_type: TokenType::Input,
+                    start_line: token.start_line,
+                    start_pos: token.start_pos,
+                    value: String::from("input")
+                });
+            }
+            else if token.token_type == TokenType::Function && !is_pure_function(&token.value){
+                return Some(token.clone());
+            }
+            else if
+                token.token_type == TokenType::Variable &&
+                !constants.contains(&token.value)
+            {
+                return Some(token.clone());
+            }
+        }
+
+        if self.right != None{
+            let token = self.right.as_ref().unwrap().get_non_constant_token(constants);
+            if token != None{
+                return token;
+            }
+        }
+
+        for argument in &self.arguments{
+            let token = argument.get_non_constant_token(constants);
+            if token != None{
+                return token;
+            }
+        }
+
+        return None;
+    }
+
+    /* The Value Of A Condition Made Of A Single `True` Or `False` */
+    pub fn get_constant_condition(&self) -> Option<bool>{
+        if self.left != None || self.right != None || self.value == None{
+            return None;
+        }
+
+        let token_type = &self.value.as_ref().unwrap().token_type;
+        if token_type == &TokenType::True{
+            return Some(true);
+        }
+        else if token_type == &TokenType::False{
+            return Some(false);
+        }
+
+        return None;
+    }
 }
 
 
@@ -163,13 +282,19 @@ pub struct DefineVariableNode{
     pub name: Option<Token>,
     pub operator: Option<Token>,
     pub left: Option<OperationNode>,
+
+    /* Set By The Checker, The Variable Type And If It Is Defined In The Current Scope */
+    pub variable_type: Option<TokenType>,
+    pub is_definition: bool,
 }
 impl DefineVariableNode{
     pub fn new() -> Self{
         return DefineVariableNode{
             name: None,
             operator: None,
-            left: None
+            left: None,
+            variable_type: None,
+            is_definition: false
         };
     }
 }
@@ -180,13 +305,17 @@ pub struct DefineVarNode{
     pub name: Option<Token>,
     pub operator: Option<Token>,
     pub left: Option<OperationNode>,
+
+    /* Set By The Checker, The Type Inferred From The Expression */
+    pub variable_type: Option<TokenType>,
 }
 impl DefineVarNode{
     pub fn new() -> Self{
         return DefineVarNode{
             name: None,
             operator: None,
-            left: None
+            left: None,
+            variable_type: None
         };
     }
 }
@@ -194,11 +323,49 @@ impl DefineVarNode{
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DefinePrintNode{
-    pub expression: Option<OperationNode>,
+    pub token: Option<Token>,
+    pub expressions: Vec<OperationNode>,
+
+    /* Printed Between The Expressions And After Them */
+    pub separator: Option<OperationNode>,
+    pub end: Option<OperationNode>,
+
+    pub is_stderr: bool,
 }
 impl DefinePrintNode{
     pub fn new() -> Self{
         return DefinePrintNode{
+            token: None,
+            expressions: Vec::new(),
+
+            separator: None,
+            end: None,
+
+            is_stderr: false,
+        };
+    }
+
+    /* Expressions In The Order They Are Evaluated */
+    pub fn get_expressions(&self) -> Vec<&OperationNode>{
+        let mut expressions: Vec<&OperationNode> = self.expressions.iter().collect();
+        if self.separator != None{
+            expressions.push(self.separator.as_ref().unwrap());
+        }
+        if self.end != None{
+            expressions.push(self.end.as_ref().unwrap());
+        }
+        return expressions;
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefineCallNode{
+    pub expression: Option<OperationNode>,
+}
+impl DefineCallNode{
+    pub fn new() -> Self{
+        return DefineCallNode{
             expression: None,
         };
     }
@@ -345,12 +512,19 @@ pub struct StatementNode{
     pub define_var_statement: Option<DefineVarNode>,
 
     pub define_print_statement: Option<DefinePrintNode>,
+    pub define_call_statement: Option<DefineCallNode>,
 
     pub define_if_statement: Option<DefineIfStatementNode>,
 
     pub define_for_loop_statement: Option<DefineForLoopStatementNode>,
     pub define_continue_statement: Option<DefineContinueStatementNode>,
     pub define_break_statement: Option<DefineBreakStatementNode>,
+
+    /* Defined With `const`, Evaluated At Compile Time */
+    pub is_constant: bool,
+
+    /* Read From An Imported Module, Its Lines Refer To That File */
+    pub is_imported: bool,
 }
 
 impl StatementNode{
@@ -367,14 +541,173 @@ impl StatementNode{
             define_var_statement: None,
 
             define_print_statement: None,
+            define_call_statement: None,
 
             define_if_statement: None,
 
             define_for_loop_statement: None,
             define_continue_statement: None,
             define_break_statement: None,
+
+            is_constant: false,
+            is_imported: false,
         };
     }
+
+    /* Source Line Of The Statement, Taken From Its First Known Token */
+    pub fn get_line(&self) -> Option<u64>{
+        return self.get_token().map(|token| token.start_line);
+    }
+
+    /* The First Known Token Of The Statement */
+    pub fn get_token(&self) -> Option<&Token>{
+        let token = if self.statement_type == Some(StatementType::DefineBool){
+            self.define_bool_statement.as_ref().unwrap().name.as_ref()
+        }
+        else if self.statement_type == Some(StatementType::DefineInt){
+            self.define_int_statement.as_ref().unwrap().name.as_ref()
+        }
+        else if self.statement_type == Some(StatementType::DefineDouble){
+            self.define_double_statement.as_ref().unwrap().name.as_ref()
+        }
+        else if self.statement_type == Some(StatementType::DefineChar){
+            self.define_char_statement.as_ref().unwrap().name.as_ref()
+        }
+        else if self.statement_type == Some(StatementType::DefineString){
+            self.define_string_statement.as_ref().unwrap().name.as_ref()
+        }
+        else if self.statement_type == Some(StatementType::DefineVariable){
+            self.define_variable_statement.as_ref().unwrap().name.as_ref()
+        }
+        else if self.statement_type == Some(StatementType::DefineVar){
+            self.define_var_statement.as_ref().unwrap().name.as_ref()
+        }
+        else if self.statement_type == Some(StatementType::Print){
+            self.define_print_statement.as_ref().unwrap().token.as_ref()
+        }
+        else if self.statement_type == Some(StatementType::Call){
+            let expression = &self.define_call_statement.as_ref().unwrap().expression;
+            if expression != &None {expression.as_ref().unwrap().get_first_token()} else {None}
+        }
+        else if self.statement_type == Some(StatementType::DefineIf){
+            let define_if_node = &self.define_if_statement.as_ref().unwrap().define_if_node;
+            if define_if_node != &None {define_if_node.as_ref().unwrap().token.as_ref()} else {None}
+        }
+        else if self.statement_type == Some(StatementType::DefineForLoop){
+            self.define_for_loop_statement.as_ref().unwrap().variable.as_ref()
+        }
+        else if self.statement_type == Some(StatementType::Continue){
+            self.define_continue_statement.as_ref().unwrap()
+                .meta.get("continue-token").unwrap_or(&None).as_ref()
+        }
+        else if self.statement_type == Some(StatementType::Break){
+            self.define_break_statement.as_ref().unwrap()
+                .meta.get("break-token").unwrap_or(&None).as_ref()
+        }
+        else {None};
+
+        return token;
+    }
+
+    /* The Token Of The Variable Defined Or Reassigned By The Statement */
+    pub fn get_assigned_token(&self) -> Option<&Token>{
+        let token = if self.statement_type == Some(StatementType::DefineBool){
+            self.define_bool_statement.as_ref().unwrap().name.as_ref()
+        }
+        else if self.statement_type == Some(StatementType::DefineInt){
+            self.define_int_statement.as_ref().unwrap().name.as_ref()
+        }
+        else if self.statement_type == Some(StatementType::DefineDouble){
+            self.define_double_statement.as_ref().unwrap().name.as_ref()
+        }
+        else if self.statement_type == Some(StatementType::DefineChar){
+            self.define_char_statement.as_ref().unwrap().name.as_ref()
+        }
+        else if self.statement_type == Some(StatementType::DefineString){
+            self.define_string_statement.as_ref().unwrap().name.as_ref()
+        }
+        else if self.statement_type == Some(StatementType::DefineVariable){
+            self.define_variable_statement.as_ref().unwrap().name.as_ref()
+        }
+        else if self.statement_type == Some(StatementType::DefineVar){
+            self.define_var_statement.as_ref().unwrap().name.as_ref()
+        }
+        else {None};
+
+        return token;
+    }
+
+    /* The Variable Defined Or Reassigned By The Statement */
+    pub fn get_assigned_name(&self) -> Option<String>{
+        let token = self.get_assigned_token();
+
+        return token.map(|token| token.value.clone());
+    }
+
+    /* The Statement Own Expressions, Not Its Blocks */
+    pub fn get_expressions(&self) -> Vec<&OperationNode>{
+        let mut expressions: Vec<&Option<OperationNode>> = vec![];
+
+        if self.statement_type == Some(StatementType::DefineBool){
+            expressions.push(&self.define_bool_statement.as_ref().unwrap().left);
+        }
+        else if self.statement_type == Some(StatementType::DefineInt){
+            expressions.push(&self.define_int_statement.as_ref().unwrap().left);
+        }
+        else if self.statement_type == Some(StatementType::DefineDouble){
+            expressions.push(&self.define_double_statement.as_ref().unwrap().left);
+        }
+        else if self.statement_type == Some(StatementType::DefineChar){
+            expressions.push(&self.define_char_statement.as_ref().unwrap().left);
+        }
+        else if self.statement_type == Some(StatementType::DefineString){
+            expressions.push(&self.define_string_statement.as_ref().unwrap().left);
+        }
+        else if self.statement_type == Some(StatementType::DefineVariable){
+            expressions.push(&self.define_variable_statement.as_ref().unwrap().left);
+        }
+        else if self.statement_type == Some(StatementType::DefineVar){
+            expressions.push(&self.define_var_statement.as_ref().unwrap().left);
+        }
+        else if self.statement_type == Some(StatementType::Print){
+            return self.define_print_statement.as_ref().unwrap().get_expressions();
+        }
+        else if self.statement_type == Some(StatementType::Call){
+            expressions.push(&self.define_call_statement.as_ref().unwrap().expression);
+        }
+        else if self.statement_type == Some(StatementType::DefineIf){
+            let define_if_node = &self.define_if_statement.as_ref().unwrap().define_if_node;
+            if define_if_node != &None{
+                expressions.push(&define_if_node.as_ref().unwrap().condition);
+            }
+        }
+        else if self.statement_type == Some(StatementType::DefineForLoop){
+            let for_loop = self.define_for_loop_statement.as_ref().unwrap();
+            expressions.push(&for_loop.start);
+            expressions.push(&for_loop.stop);
+            expressions.push(&for_loop.step);
+        }
+
+        return expressions.into_iter()
+            .filter(|expression| expression != &&None)
+            .map(|expression| expression.as_ref().unwrap())
+            .collect();
+    }
+
+    /* Variables Read By The Statement Own Expressions, Not Its Blocks */
+    pub fn get_read_variables_names(&self) -> Vec<String>{
+        let mut names: Vec<String> = vec![];
+
+        for expression in self.get_expressions(){
+            for name in expression.get_variables_names(){
+                if !names.contains(&name){
+                    names.push(name);
+                }
+            }
+        }
+
+        return names;
+    }
 }
 
 
@@ -401,6 +734,7 @@ pub enum InstructionType {
 
     Print,
     Input,
+    Call,
 
     If,
     Else,
@@ -441,6 +775,7 @@ pub struct OperationInstruction{
 #[derive(Debug, Clone, PartialEq)]
 pub struct PrintInstruction{
     pub variable_name: Option<String>,
+    pub is_stderr: bool,
     pub line: Option<u128>,
 }
 
@@ -452,6 +787,15 @@ pub struct InputInstruction{
 }
 
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallInstruction{
+    pub function_name: Option<String>,
+    pub assign_to_variable: Option<String>,
+    pub arguments: Vec<String>,
+    pub line: Option<u128>,
+}
+
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct IfInstruction{
     pub variable_name: Option<String>,
@@ -481,6 +825,7 @@ pub struct Instruction{
     pub operation_instruction: Option<OperationInstruction>,
     pub print_instruction: Option<PrintInstruction>,
     pub input_instruction: Option<InputInstruction>,
+    pub call_instruction: Option<CallInstruction>,
 
     pub if_instruction: Option<IfInstruction>,
     pub else_instruction: Option<ElseInstruction>,
@@ -712,7 +1057,7 @@ fn __nine_precedence_expression(tokens: &mut VecDeque<Token>) -> OperationNode{
 }
 
 
-/** [ True False Number Character StringSequence Null Variable () ! ] */
+/** [ True False Number Character StringSequence Null Variable () ! Function() ] */
 fn __last_expression(tokens: &mut VecDeque<Token>) -> OperationNode{
     let mut node = OperationNode::new();
 
@@ -749,6 +1094,24 @@ fn __last_expression(tokens: &mut VecDeque<Token>) -> OperationNode{
         node.operator = Some(OperatorType::Convert);
         node.value = Some(tokens.pop_front().unwrap());
     }
+    else if maybe_token.token_type == TokenType::Function{
+        node.operator = Some(OperatorType::Call);
+        node.value = Some(maybe_token.clone());
+
+        /* Arguments Are Separated By Commas Inside The Parentheses */
+        tokens.pop_front().unwrap();
+        loop{
+            let next_token = tokens.pop_front().unwrap();
+            if next_token.token_type == TokenType::CloseParenthes{
+                break;
+            }
+            else if next_token.token_type != TokenType::Comma{
+                tokens.push_front(next_token);
+            }
+
+            node.arguments.push(__first_precedence_expression(tokens));
+        }
+    }
 
     return node;
 }
@@ -12,7 +12,10 @@ pub enum TokenType{
     Double,
     Char,
     String,
+    /* The Type Of The `list<string>` Results, Lists Have No Literal Or Keyword */
+    List,
     Var,
+    Const,
 
     As,
 
@@ -53,7 +56,10 @@ pub enum TokenType{
     Variable,
 
     Print,
+    EPrint,
+    PrintF,
     Input,
+    Function,
 
     If,
     Else,
@@ -64,6 +70,8 @@ pub enum TokenType{
     Continue,
     Break,
 
+    Import,
+
     BadToken,
 }
 
@@ -96,6 +104,9 @@ pub fn get_token_type(variable: &String) -> TokenType{
     else if variable == "var"{
         return TokenType::Var
     }
+    else if variable == "const"{
+        return TokenType::Const
+    }
 
     else if variable == "as"{
         return TokenType::As;
@@ -114,6 +125,12 @@ pub fn get_token_type(variable: &String) -> TokenType{
     else if variable == "print"{
         return TokenType::Print
     }
+    else if variable == "eprint"{
+        return TokenType::EPrint
+    }
+    else if variable == "printf"{
+        return TokenType::PrintF
+    }
 
     else if variable == "if"{
         return TokenType::If
@@ -135,5 +152,9 @@ pub fn get_token_type(variable: &String) -> TokenType{
         return TokenType::Break;
     }
 
+    else if variable == "import"{
+        return TokenType::Import;
+    }
+
     return TokenType::Variable;
 }
@@ -0,0 +1,114 @@
+use std::io::{
+    Write,
+    BufWriter
+};
+
+use crate::environments::{
+    Value,
+    ValueType
+};
+
+
+pub struct Tracer{
+    output: BufWriter<Box<dyn Write>>,
+}
+
+impl std::fmt::Debug for Tracer{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Tracer")
+    }
+}
+
+impl Tracer{
+    pub fn new(trace_path: &String) -> Result<Self, String>{
+        if trace_path == "-"{
+            return Ok(Tracer{
+                output: BufWriter::new(Box::new(std::io::stderr())),
+            });
+        }
+
+        let file = std::fs::File::create(trace_path);
+        if file.is_err(){
+            return Err(format!(
+                "Engine Tracer: File Error -> Can't create the trace file `{}`.",
+                trace_path));
+        }
+
+        return Ok(Tracer{
+            output: BufWriter::new(Box::new(file.unwrap())),
+        });
+    }
+
+    pub fn trace(&mut self, line: String) -> Result<(), String>{
+        if writeln!(self.output, "{}", line).is_err(){
+            return Err(String::from(
+                "Engine Tracer: File Error -> Failed to write the trace."));
+        }
+        return Ok(());
+    }
+
+    pub fn flush(&mut self) -> Result<(), String>{
+        if self.output.flush().is_err(){
+            return Err(String::from(
+                "Engine Tracer: File Error -> Failed to write the trace."));
+        }
+        return Ok(());
+    }
+}
+
+
+/* Same Format As The VM Values Debug Format, So Traces Can Be Compared */
+pub fn format_trace_value(value: Option<&Value>) -> String{
+    if value == None{
+        return String::from("<undefined>");
+    }
+    let value = value.unwrap();
+
+    if value.value_type == Some(ValueType::Boolean){
+        return format!("Bool({:?})", value.boolean.unwrap());
+    }
+    else if value.value_type == Some(ValueType::Integer){
+        return format!("Int({:?})", value.int.unwrap());
+    }
+    else if value.value_type == Some(ValueType::Double){
+        return format!("Double({:?})", value.double.unwrap());
+    }
+    else if value.value_type == Some(ValueType::Character){
+        return format!("Char({:?})", value.character.unwrap());
+    }
+    else if value.value_type == Some(ValueType::String){
+        return format!("String({:?})", value.string.as_ref().unwrap());
+    }
+    else if value.value_type == Some(ValueType::List){
+        return format!("List({:?})", value.list.as_ref().unwrap());
+    }
+
+    return String::from("<undefined>");
+}
+
+
+/*
+    Trace Line Format:
+        <mode>:<line>:<kind>[ <read>=<value>]*[ => <assigned>: <before> -> <after>]
+*/
+pub fn format_trace_line(
+    mode: &str,
+    line: u128,
+    kind: String,
+    reads: Vec<(String, String)>,
+    assigned: Option<(String, String, String)>
+) -> String{
+
+    let mut trace_line = format!("{mode}:{line}:{kind}");
+
+    for (name, value) in reads{
+        trace_line.push_str(&format!(" {name}={value}"));
+    }
+
+    if assigned != None{
+        let (name, before, after) = assigned.unwrap();
+        trace_line.push_str(&format!(" => {name}: {before} -> {after}"));
+    }
+
+    return trace_line;
+}
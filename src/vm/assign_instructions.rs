@@ -31,6 +31,12 @@ pub fn execute_assign_instruction(
     else if instruction.assign_type == Some(TokenType::String){
         execute_assign_string_instruction(current_line, environment, instruction)?;
     }
+    else if instruction.assign_type == Some(TokenType::List){
+        return Err(format!(
+            "Engine VM: Assign Instruction -> {}, instruction line: {}, line: {}.",
+            "Lists are only supported by the VM, run the byte code without `--legacy-vm`",
+            instruction.line.unwrap(), current_line));
+    }
     else if instruction.assign_type == Some(TokenType::BadToken){
         return Err(format!(
             "Engine VM: Assign Instruction -> {} `{:?}`, instruction line: {}, line: {}.",
@@ -68,6 +74,7 @@ fn execute_assign_bool_instruction(
             double: None,
             int: None,
             string: None,
+            list: None,
             string_value: None
         })
     };
@@ -107,6 +114,7 @@ fn execute_assign_int_instruction(
             double: None,
             int: Some(value),
             string: None,
+            list: None,
             string_value: None
         })
     };
@@ -146,6 +154,7 @@ fn execute_assign_double_instruction(
             double: Some(value),
             int: None,
             string: None,
+            list: None,
             string_value: None
         })
     };
@@ -193,6 +202,7 @@ fn execute_assign_char_instruction(
             double: None,
             int: None,
             string: None,
+            list: None,
             string_value: None
         })
     };
@@ -266,6 +276,7 @@ fn execute_assign_string_instruction(
             double: None,
             int: None,
             string: Some(String::from(new_value)),
+            list: None,
             string_value: None
         })
     };
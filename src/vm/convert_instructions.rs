@@ -128,6 +128,7 @@ fn execute_convert_to_bool(
             double: None,
             int: None,
             string: None,
+            list: None,
             string_value: None
         })
     };
@@ -227,6 +228,7 @@ fn execute_convert_to_int(
                 }
             ),
             string: None,
+            list: None,
             string_value: None
         })
     };
@@ -326,6 +328,7 @@ fn execute_convert_to_double(
             ),
             int: None,
             string: None,
+            list: None,
             string_value: None
         })
     };
@@ -429,6 +432,7 @@ fn execute_convert_to_char(
             double: None,
             int: None,
             string: None,
+            list: None,
             string_value: None
         })
     };
@@ -512,6 +516,7 @@ fn execute_convert_to_string(
                     String::from(from_variable.value.as_ref().unwrap().character.as_ref().unwrap().clone())
                 }
             ),
+            list: None,
             string_value: None
         })
     };
@@ -0,0 +1,239 @@
+use std::collections::BTreeSet;
+
+use crate::debugger::{
+    DebugCommand,
+    DEBUG_HELP,
+    read_debug_command,
+    is_variable_name,
+    print_debug_expression
+};
+use crate::builtins::construct_builtin_value;
+use crate::vm::op_codes::{
+    OpCode,
+    load_program
+};
+use crate::vm::machine::{
+    Machine,
+    execute_op_code,
+    get_builtin_argument
+};
+
+
+#[derive(Debug)]
+struct VMDebugger{
+    breakpoints: BTreeSet<u128>,
+    watch_list: Vec<String>,
+}
+
+
+fn get_register_display_name(machine: &Machine, register: usize) -> String{
+    return machine.program.registers_names[register].trim_matches('"').to_string();
+}
+
+
+/* Registers Matching A Name, Either The Full Register Name Or The Engine Variable Name */
+fn find_registers(machine: &Machine, name: &String) -> Vec<usize>{
+    let mut registers = vec![];
+    let variable_suffix = format!("_variable_{}", name);
+
+    for register in 0..machine.registers.len(){
+        let register_name = get_register_display_name(machine, register);
+        if &register_name == name ||
+            (register_name.starts_with("stack") && register_name.ends_with(&variable_suffix))
+        {
+            registers.push(register);
+        }
+    }
+
+    return registers;
+}
+
+
+fn print_register(machine: &Machine, register: usize){
+    let register_name = get_register_display_name(machine, register);
+    let value = machine.registers[register].as_ref();
+
+    if value == None{
+        println!("    {} = <undefined>", register_name);
+    }
+    else{
+        println!(
+            "    {}: {:?} = {}",
+            register_name, value.unwrap().get_type(), value.unwrap());
+    }
+}
+
+
+/* Registers Are Named By Stack Depth, The Deepest Defined One Is The Current Variable */
+fn get_variable_value(machine: &Machine, name: &String) -> Option<crate::environments::Value>{
+    let mut value = None;
+    for register in find_registers(machine, name){
+        if machine.registers[register] != None{
+            value = machine.registers[register].as_ref();
+        }
+    }
+
+    if value == None{
+        return None;
+    }
+    return Some(construct_builtin_value(get_builtin_argument(value.unwrap())));
+}
+
+
+fn print_variable(machine: &Machine, name: &String){
+    if !is_variable_name(name){
+        print_debug_expression(name, &|variable_name| get_variable_value(machine, variable_name));
+        return;
+    }
+
+    let registers = find_registers(machine, name);
+    if registers.len() == 0{
+        println!("    {} = <not found>", name);
+        return;
+    }
+
+    for register in registers{
+        print_register(machine, register);
+    }
+}
+
+
+fn print_current_instruction(machine: &Machine){
+    println!(
+        "=> {}",
+        machine.program.instructions_texts[machine.pc]);
+}
+
+
+fn print_state(machine: &Machine, debugger: &VMDebugger){
+    print_current_instruction(machine);
+
+    if debugger.watch_list.len() > 0{
+        println!("Watch:");
+        for name in &debugger.watch_list{
+            print_variable(machine, name);
+        }
+    }
+}
+
+
+fn is_breakpoint(machine: &Machine, debugger: &VMDebugger) -> bool{
+    return debugger.breakpoints.contains(&machine.program.instructions_lines[machine.pc]);
+}
+
+
+/* Execute Until The Stop Condition Or A Breakpoint, Returns False When The Program Ends */
+fn run_until(
+    machine: &mut Machine,
+    debugger: &VMDebugger,
+    stop_condition: &dyn Fn(&Machine) -> bool
+) -> Result<bool, String>{
+
+    loop{
+        if !execute_op_code(machine)?{
+            return Ok(false);
+        }
+
+        if stop_condition(machine) || is_breakpoint(machine, debugger){
+            return Ok(true);
+        }
+    }
+}
+
+
+pub fn debug_byte_code(file_path: &String) -> Result<(), String>{
+    let program = load_program(file_path)?;
+
+    let mut machine = Machine::new(program);
+    let mut debugger = VMDebugger{
+        breakpoints: BTreeSet::new(),
+        watch_list: vec![],
+    };
+
+    println!("Engine Debugger: `{}`, type `help` for commands.", file_path);
+    print_state(&machine, &debugger);
+
+    loop{
+        let command = read_debug_command()?;
+
+        let is_running = if command == DebugCommand::Step{
+            run_until(&mut machine, &debugger, &|_| true)?
+        }
+        else if command == DebugCommand::Next{
+            let start_pc = machine.pc;
+            run_until(&mut machine, &debugger, &|machine| machine.pc > start_pc)?
+        }
+        else if command == DebugCommand::Continue{
+            run_until(&mut machine, &debugger, &|_| false)?
+        }
+        else{
+            if let DebugCommand::Break(line) = command{
+                let exists = machine.program.instructions_lines.contains(&line);
+                if exists{
+                    debugger.breakpoints.insert(line);
+                    println!("Breakpoint set at instruction line {}.", line);
+                }
+                else{
+                    println!("There is no instruction at line {}.", line);
+                }
+            }
+            else if let DebugCommand::Delete(line) = command{
+                if debugger.breakpoints.remove(&line){
+                    println!("Breakpoint at instruction line {} removed.", line);
+                }
+                else{
+                    println!("There is no breakpoint at instruction line {}.", line);
+                }
+            }
+            else if command == DebugCommand::Breakpoints{
+                for line in &debugger.breakpoints{
+                    println!("    {}", line);
+                }
+            }
+            else if let DebugCommand::Print(name) = command{
+                print_variable(&machine, &name);
+            }
+            else if command == DebugCommand::Variables{
+                for register in 0..machine.registers.len(){
+                    if machine.registers[register] != None{
+                        print_register(&machine, register);
+                    }
+                }
+            }
+            else if let DebugCommand::Watch(name) = command{
+                if !debugger.watch_list.contains(&name){
+                    debugger.watch_list.push(name);
+                }
+            }
+            else if let DebugCommand::Unwatch(name) = command{
+                debugger.watch_list.retain(|watched_name| watched_name != &name);
+            }
+            else if command == DebugCommand::List{
+                print_current_instruction(&machine);
+            }
+            else if command == DebugCommand::Help{
+                println!("{}", DEBUG_HELP);
+            }
+            else if command == DebugCommand::Quit{
+                machine.flush()?;
+                return Ok(());
+            }
+            continue;
+        };
+
+        machine.flush()?;
+        println!();
+
+        if !is_running{
+            println!("Program finished.");
+            return Ok(());
+        }
+
+        if is_breakpoint(&machine, &debugger) &&
+            machine.program.op_codes[machine.pc] != OpCode::End
+        {
+            println!("Breakpoint at instruction line {}.", machine.program.instructions_lines[machine.pc]);
+        }
+        print_state(&machine, &debugger);
+    }
+}
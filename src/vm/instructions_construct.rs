@@ -8,6 +8,7 @@ use crate::syntax_tree::{
     ConvertInstruction,
     OperationInstruction,
     InputInstruction,
+    CallInstruction,
     IfInstruction,
     ElseInstruction,
     GoToInstruction
@@ -44,7 +45,8 @@ fn construct_assign_instruction(
         TokenType::Int} else if assign_type == "double" {
         TokenType::Double} else if assign_type == "char" {
         TokenType::Char} else if assign_type == "string" {
-        TokenType::String} else {TokenType::BadToken};
+        TokenType::String} else if assign_type == "list" {
+        TokenType::List} else {TokenType::BadToken};
 
     /* Retrieve Variable Name */
     let (variable_name, variable_value) = split_line(line, current_line, Some(instruction_line))?;
@@ -71,7 +73,8 @@ fn construct_convert_instruction(
         TokenType::Int} else if convert_type == "double" {
         TokenType::Double} else if convert_type == "char" {
         TokenType::Char} else if convert_type == "string" {
-        TokenType::String} else {TokenType::BadToken};
+        TokenType::String} else if convert_type == "list" {
+        TokenType::List} else {TokenType::BadToken};
 
     /* Retrieve Convert To Variable Name */
     let (convert_to_variable_name, convert_from_variable_name) = split_line(
@@ -98,12 +101,30 @@ fn construct_input_instruction(
 
 
 fn construct_print_instruction(
-    line: String, instruction_line: u128
+    line: String, current_line: u128, instruction_line: u128
 ) -> Result<PrintInstruction, String>{
 
+    /* `"variable"` Prints Into Stdout And `"variable":stderr` Into Stderr */
+    let splitted_line = line.rsplit_once("\":");
+    if splitted_line != None{
+        if splitted_line.unwrap().1 != "stderr"{
+            return Err(format!(
+                "Engine VM: Instruction Construction -> {}, instruction line: {}, line: {}.",
+                format!("Unknown print stream `{}`", splitted_line.unwrap().1),
+                instruction_line, current_line));
+        }
+
+        return Ok(PrintInstruction{
+            line: Some(instruction_line),
+            variable_name: Some(format!("{}\"", splitted_line.unwrap().0)),
+            is_stderr: true
+        });
+    }
+
     return Ok(PrintInstruction{
         line: Some(instruction_line),
-        variable_name: Some(line)
+        variable_name: Some(line),
+        is_stderr: false
     });
 }
 
@@ -127,7 +148,9 @@ fn construct_operation_instruction(
         TokenType::GreaterThan} else if convert_type == "GreaterThanOrEqual" {
         TokenType::GreaterThanOrEqual} else if convert_type == "LessThan" {
         TokenType::LessThan} else if convert_type == "LessThanOrEqual" {
-        TokenType::LessThanOrEqual} else {TokenType::BadToken};
+        TokenType::LessThanOrEqual} else if convert_type == "Equal" {
+        TokenType::Equal} else if convert_type == "NotEqual" {
+        TokenType::NotEqual} else {TokenType::BadToken};
 
     /* Retrieve Assign To Variable Name */
     let (assign_to_variable, line) = split_line(
@@ -147,6 +170,28 @@ fn construct_operation_instruction(
 }
 
 
+fn construct_call_instruction(
+    line: String, current_line: u128, instruction_line: u128
+) -> Result<CallInstruction, String>{
+
+    /* Retrieve Function Name */
+    let (function_name, line) = split_line(
+        line, current_line, Some(instruction_line))?;
+
+    /* Retrieve Assign To Variable Name And Arguments, Variables Names Have No `:` */
+    let mut variables_names: Vec<String> = line.split(":").map(
+        |variable_name| String::from(variable_name)).collect();
+    let assign_to_variable = variables_names.remove(0);
+
+    return Ok(CallInstruction{
+        function_name: Some(function_name),
+        assign_to_variable: Some(assign_to_variable),
+        arguments: variables_names,
+        line: Some(instruction_line)
+    });
+}
+
+
 fn construct_if_instruction(
     line: String, current_line: u128, instruction_line: u128
 ) -> Result<IfInstruction, String>{
@@ -234,6 +279,7 @@ pub fn construct_instruction(
             operation_instruction: None,
             print_instruction: None,
             input_instruction: None,
+            call_instruction: None,
             if_instruction: None,
             else_instruction: None,
             goto_instruction: None
@@ -250,6 +296,7 @@ pub fn construct_instruction(
             operation_instruction: None,
             print_instruction: None,
             input_instruction: None,
+            call_instruction: None,
             if_instruction: None,
             else_instruction: None,
             goto_instruction: None
@@ -266,6 +313,7 @@ pub fn construct_instruction(
             operation_instruction: None,
             print_instruction: None,
             input_instruction: Some(input_instruction_node),
+            call_instruction: None,
             if_instruction: None,
             else_instruction: None,
             goto_instruction: None
@@ -273,7 +321,7 @@ pub fn construct_instruction(
     }
     else if instruction_str == "Print"{
         let print_instruction_node = construct_print_instruction(
-            line, line_counter)?;
+            line, current_line, line_counter)?;
 
         return Ok((Instruction {
             instruction_type: Some(InstructionType::Print),
@@ -282,6 +330,7 @@ pub fn construct_instruction(
             operation_instruction: None,
             print_instruction: Some(print_instruction_node),
             input_instruction: None,
+            call_instruction: None,
             if_instruction: None,
             else_instruction: None,
             goto_instruction: None
@@ -298,6 +347,24 @@ pub fn construct_instruction(
             operation_instruction: Some(operation_instruction_node),
             print_instruction: None,
             input_instruction: None,
+            call_instruction: None,
+            if_instruction: None,
+            else_instruction: None,
+            goto_instruction: None
+        }, line_counter));
+    }
+    else if instruction_str == "Call"{
+        let call_instruction_node = construct_call_instruction(
+            line, current_line, line_counter)?;
+
+        return Ok((Instruction{
+            instruction_type: Some(InstructionType::Call),
+            assign_instruction: None,
+            convert_instruction: None,
+            operation_instruction: None,
+            print_instruction: None,
+            input_instruction: None,
+            call_instruction: Some(call_instruction_node),
             if_instruction: None,
             else_instruction: None,
             goto_instruction: None
@@ -314,6 +381,7 @@ pub fn construct_instruction(
             operation_instruction: None,
             print_instruction: None,
             input_instruction: None,
+            call_instruction: None,
             if_instruction: Some(if_instruction_node),
             else_instruction: None,
             goto_instruction: None
@@ -329,6 +397,7 @@ pub fn construct_instruction(
             operation_instruction: None,
             print_instruction: None,
             input_instruction: None,
+            call_instruction: None,
             if_instruction: None,
             else_instruction: Some(else_instruction_node),
             goto_instruction: None
@@ -345,6 +414,7 @@ pub fn construct_instruction(
             operation_instruction: None,
             print_instruction: None,
             input_instruction: None,
+            call_instruction: None,
             if_instruction: None,
             else_instruction: None,
             goto_instruction: Some(goto_instruction_node)
@@ -358,6 +428,7 @@ pub fn construct_instruction(
             operation_instruction: None,
             print_instruction: None,
             input_instruction: None,
+            call_instruction: None,
             if_instruction: None,
             else_instruction: None,
             goto_instruction: None
@@ -1,6 +1,12 @@
 use std::io::Write;
 
 use crate::tokens::TokenType;
+use crate::builtins::{
+    call_builtin,
+    get_builtin_value,
+    get_builtin_variable_type,
+    construct_builtin_value
+};
 use crate::environments::{
     Environment,
     Variable,
@@ -13,6 +19,7 @@ use crate::syntax_tree::{
     InstructionType,
     PrintInstruction,
     InputInstruction,
+    CallInstruction,
     IfInstruction,
     GoToInstruction
 };
@@ -54,6 +61,11 @@ pub fn execute_instruction(
             current_line, &mut environment,
             instruction.input_instruction.unwrap())?;
     }
+    else if instruction.instruction_type == Some(InstructionType::Call){
+        execute_call_instruction(
+            current_line, &mut environment,
+            instruction.call_instruction.unwrap())?;
+    }
     else if instruction.instruction_type == Some(InstructionType::If){
         return execute_if_instruction(
             current_line, &mut environment,
@@ -87,25 +99,31 @@ fn execute_print_instruction(
 
     let value = variable.as_ref().unwrap().as_ref().unwrap().value.as_ref().unwrap();
 
-    if value.value_type == Some(ValueType::Boolean){
-        if value.boolean == Some(true){
-            print!("True");
-        }
-        else{
-            print!("False");
-        }
+    let text = if value.value_type == Some(ValueType::Boolean){
+        String::from(if value.boolean == Some(true) {"True"} else {"False"})
     }
     else if value.value_type == Some(ValueType::Integer){
-        print!("{}", value.int.unwrap());
+        value.int.unwrap().to_string()
     }
     else if value.value_type == Some(ValueType::Double){
-        print!("{}", value.double.unwrap());
+        value.double.unwrap().to_string()
     }
     else if value.value_type == Some(ValueType::Character){
-        print!("{}", value.character.unwrap());
+        value.character.unwrap().to_string()
     }
     else if value.value_type == Some(ValueType::String){
-        print!("{}", value.string.as_ref().unwrap());
+        value.string.as_ref().unwrap().clone()
+    }
+    else if value.value_type == Some(ValueType::List){
+        format!("{:?}", value.list.as_ref().unwrap())
+    }
+    else {String::new()};
+
+    if instruction.is_stderr{
+        eprint!("{}", text);
+    }
+    else{
+        print!("{}", text);
     }
 
     /* Flush Stdin */
@@ -156,6 +174,7 @@ fn execute_input_instruction(
             double: None,
             int: None,
             string: Some(String::from(line.trim_end())),
+            list: None,
             string_value: None
         })
     };
@@ -168,6 +187,49 @@ fn execute_input_instruction(
 }
 
 
+fn execute_call_instruction(
+    current_line: u128,
+    environment: &mut Environment,
+    instruction: CallInstruction
+) -> Result<(), String>{
+
+    let mut arguments = vec![];
+    for argument in &instruction.arguments{
+        let variable = environment.variables.get(argument);
+        if variable == None{
+            return Err(format!(
+                "Engine VM: Call Instruction -> {}, instruction line: {}, line: {}.",
+                format!("Variable `{}` is undefined", argument),
+                instruction.line.unwrap(), current_line));
+        }
+
+        arguments.push(get_builtin_value(
+            variable.unwrap().as_ref().unwrap().value.as_ref().unwrap()));
+    }
+
+    let result = call_builtin(instruction.function_name.as_ref().unwrap(), arguments);
+    if result.is_err(){
+        return Err(format!(
+            "Engine VM: Call Instruction -> {}, instruction line: {}, line: {}.",
+            result.unwrap_err(), instruction.line.unwrap(), current_line));
+    }
+    let result = result.unwrap();
+
+    let variable = Variable{
+        is_reasigned: false,
+        variable_type: Some(get_builtin_variable_type(&result.get_type())),
+        name: Some(instruction.assign_to_variable.as_ref().unwrap().clone()),
+        value: Some(construct_builtin_value(result))
+    };
+
+    environment.variables.insert(
+        instruction.assign_to_variable.as_ref().unwrap().clone(),
+        Some(variable));
+
+    return Ok(());
+}
+
+
 fn execute_if_instruction(
     current_line: u128,
     environment: &mut Environment,
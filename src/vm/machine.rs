@@ -0,0 +1,696 @@
+use std::io::{
+    Write,
+    BufWriter,
+    Stdout
+};
+
+use std::time::Instant;
+
+use crate::tokens::TokenType;
+use crate::profiler::Profiler;
+use crate::builtins::{
+    BuiltinValue,
+    is_input_function,
+    call_builtin
+};
+use crate::limiter::{
+    Limiter,
+    check_step,
+    check_memory,
+    check_input,
+    check_call
+};
+use crate::tracer::{
+    Tracer,
+    format_trace_line
+};
+use crate::vm::op_codes::{
+    Value,
+    OpCode,
+    Program
+};
+
+
+#[derive(Debug)]
+pub struct Machine{
+    pub program: Program,
+    pub registers: Vec<Option<Value>>,
+    pub pc: usize,
+    pub tracer: Option<Tracer>,
+    pub profiler: Option<Profiler>,
+    pub limiter: Option<Limiter>,
+    memory: usize,
+    output: BufWriter<Stdout>,
+}
+
+impl Machine{
+    pub fn new(program: Program) -> Self{
+        let registers = vec![None; program.registers_names.len()];
+
+        return Machine{
+            program,
+            registers,
+            pc: 0,
+            tracer: None,
+            profiler: None,
+            limiter: None,
+            memory: 0,
+            output: BufWriter::new(std::io::stdout()),
+        };
+    }
+
+    pub fn flush(&mut self) -> Result<(), String>{
+        if self.output.flush().is_err(){
+            return Err(format!(
+                "Engine VM: Print Instruction -> {}, instruction line: {}, line: {}.",
+                "Failed to print to console",
+                self.program.instructions_lines[self.pc], self.program.file_lines[self.pc]));
+        }
+
+        if self.tracer.is_some(){
+            self.tracer.as_mut().unwrap().flush()?;
+        }
+        return Ok(());
+    }
+
+    fn error(&self, instruction_name: &str, message: String) -> String{
+        return format!(
+            "Engine VM: {} Instruction -> {}, instruction line: {}, line: {}.",
+            instruction_name, message,
+            self.program.instructions_lines[self.pc], self.program.file_lines[self.pc]);
+    }
+
+    fn get_register(
+        &self, instruction_name: &str, register: usize
+    ) -> Result<&Value, String>{
+
+        let value = self.registers[register].as_ref();
+        if value == None{
+            return Err(self.error(instruction_name, format!(
+                "Variable `{}` is undefined",
+                self.program.registers_names[register])));
+        }
+
+        return Ok(value.unwrap());
+    }
+}
+
+
+/* Execute The Current Op Code, Returns False When The Program Ends */
+pub fn execute_op_code(machine: &mut Machine) -> Result<bool, String>{
+    if machine.limiter != None{
+        return limit_op_code(machine);
+    }
+
+    return measure_op_code(machine);
+}
+
+
+fn measure_op_code(machine: &mut Machine) -> Result<bool, String>{
+    if machine.profiler != None{
+        return profile_op_code(machine);
+    }
+
+    return run_op_code(machine);
+}
+
+
+fn get_register_memory(machine: &Machine, register: usize) -> usize{
+    if let Some(Value::String(value)) = &machine.registers[register]{
+        return value.len();
+    }
+    else if let Some(Value::List(value)) = &machine.registers[register]{
+        return value.iter().map(|item| item.len()).sum();
+    }
+    return 0;
+}
+
+
+/* The Memory Of Every Register, When The Registers Are Restored From A Snapshot */
+pub fn compute_memory(machine: &mut Machine) -> Result<(), String>{
+    machine.memory = (0..machine.registers.len()).map(
+        |register| get_register_memory(machine, register)).sum();
+
+    if machine.limiter != None && machine.limiter.as_ref().unwrap().has_memory_limit(){
+        check_memory(
+            machine.limiter.as_ref().unwrap(), machine.memory,
+            machine.program.instructions_lines[machine.pc])?;
+    }
+
+    return Ok(());
+}
+
+
+/* The Memory Is Updated With The Assigned Register Only, Other Registers Don't Change */
+fn limit_op_code(machine: &mut Machine) -> Result<bool, String>{
+    let line = machine.program.instructions_lines[machine.pc];
+    check_step(machine.limiter.as_mut().unwrap(), "instructions", line)?;
+
+    if !machine.limiter.as_ref().unwrap().has_memory_limit(){
+        return measure_op_code(machine);
+    }
+
+    let (_, _, assigned_register) = get_op_code_operands(&machine.program.op_codes[machine.pc]);
+    let before = assigned_register.map(|register| get_register_memory(machine, register));
+
+    let is_running = measure_op_code(machine)?;
+
+    if before != None{
+        let after = get_register_memory(machine, assigned_register.unwrap());
+        machine.memory = machine.memory - before.unwrap() + after;
+
+        check_memory(machine.limiter.as_ref().unwrap(), machine.memory, line)?;
+    }
+
+    return Ok(is_running);
+}
+
+
+fn run_op_code(machine: &mut Machine) -> Result<bool, String>{
+    if machine.tracer.is_some(){
+        return trace_op_code(machine);
+    }
+
+    return dispatch_op_code(machine);
+}
+
+
+fn profile_op_code(machine: &mut Machine) -> Result<bool, String>{
+    let pc = machine.pc;
+    let line = machine.program.file_lines[pc];
+    let (kind, _, _) = get_op_code_operands(&machine.program.op_codes[pc]);
+
+    machine.profiler.as_mut().unwrap().enter(&kind, line);
+    let start = Instant::now();
+
+    let is_running = run_op_code(machine)?;
+
+    machine.profiler.as_mut().unwrap().exit(&kind, line, start.elapsed());
+
+    return Ok(is_running);
+}
+
+
+fn get_trace_value(machine: &Machine, register: usize) -> (String, String){
+    let name = machine.program.registers_names[register].trim_matches('"').to_string();
+    let value = machine.registers[register].as_ref();
+
+    if value == None{
+        return (name, String::from("<undefined>"));
+    }
+    return (name, format!("{:?}", value.unwrap()));
+}
+
+
+/* Trace Kind, Read Registers And Assigned Register Of An Op Code */
+fn get_op_code_operands(op_code: &OpCode) -> (String, Vec<usize>, Option<usize>){
+    return match op_code {
+        OpCode::Assign{register, value: _} =>
+            (String::from("Assign"), vec![], Some(*register)),
+        OpCode::Convert{convertion_type, convert_to, convert_from} =>
+            (format!("Convert.{:?}", convertion_type), vec![*convert_from], Some(*convert_to)),
+        OpCode::Operation{operation_type, assign_to, left, right} =>
+            (format!("Operation.{:?}", operation_type), vec![*left, *right], Some(*assign_to)),
+        OpCode::Print{register, is_stderr: _} =>
+            (String::from("Print"), vec![*register], None),
+        OpCode::Input{register} =>
+            (String::from("Input"), vec![], Some(*register)),
+        OpCode::Call{function, assign_to, arguments} =>
+            (format!("Call.{}", function), arguments.clone(), Some(*assign_to)),
+        OpCode::If{register, goto: _} =>
+            (String::from("If"), vec![*register], None),
+        OpCode::Else => (String::from("Else"), vec![], None),
+        OpCode::GoTo{goto: _} => (String::from("GoTo"), vec![], None),
+        OpCode::End => (String::from("End"), vec![], None),
+    };
+}
+
+
+fn trace_op_code(machine: &mut Machine) -> Result<bool, String>{
+    let pc = machine.pc;
+    let line = machine.program.instructions_lines[pc];
+    let is_jump = match &machine.program.op_codes[pc] {
+        OpCode::If{register: _, goto: _} | OpCode::GoTo{goto: _} => true,
+        _ => false,
+    };
+
+    let (kind, read_registers, assigned_register) =
+        get_op_code_operands(&machine.program.op_codes[pc]);
+
+    let mut reads = vec![];
+    for register in read_registers{
+        reads.push(get_trace_value(machine, register));
+    }
+    let before = assigned_register.map(|register| get_trace_value(machine, register));
+
+    let is_running = dispatch_op_code(machine)?;
+
+    let assigned = if before == None {None} else {
+        let (name, before) = before.unwrap();
+        let (_, after) = get_trace_value(machine, assigned_register.unwrap());
+        Some((name, before, after))
+    };
+
+    let mut trace_line = format_trace_line("vm", line, kind, reads, assigned);
+    if is_jump{
+        trace_line.push_str(&format!(
+            " => next {}", machine.program.instructions_lines[machine.pc]));
+    }
+    machine.tracer.as_mut().unwrap().trace(trace_line)?;
+
+    return Ok(is_running);
+}
+
+
+fn dispatch_op_code(machine: &mut Machine) -> Result<bool, String>{
+    let pc = machine.pc;
+
+    match &machine.program.op_codes[pc] {
+        OpCode::Assign{register, value} => {
+            machine.registers[*register] = Some(value.clone());
+        },
+        OpCode::Convert{convertion_type, convert_to, convert_from} => {
+            let value = execute_convert(
+                machine, convertion_type, *convert_to, *convert_from)?;
+            machine.registers[*convert_to] = Some(value);
+        },
+        OpCode::Operation{operation_type, assign_to, left, right} => {
+            let value = execute_operation(
+                machine, operation_type, *assign_to, *left, *right)?;
+            machine.registers[*assign_to] = Some(value);
+        },
+        OpCode::Print{register, is_stderr} => {
+            let value = machine.get_register("Print", *register)?.to_string();
+
+            /* Stderr Is Not Buffered, The Output Printed Before Is Flushed First */
+            let result = if *is_stderr {
+                machine.output.flush().and_then(|_| std::io::stderr().write_all(value.as_bytes()))
+            } else {
+                machine.output.write_all(value.as_bytes())
+            };
+            if result.is_err(){
+                return Err(machine.error("Print", String::from("Failed to print to console")));
+            }
+        },
+        OpCode::Input{register} => {
+            let register = *register;
+            machine.get_register("Input", register)?;
+            if machine.limiter != None{
+                check_input(
+                    machine.limiter.as_ref().unwrap(), machine.program.instructions_lines[pc])?;
+            }
+            machine.flush()?;
+
+            let mut line = String::new();
+            if std::io::stdin().read_line(&mut line).is_err(){
+                return Err(machine.error("Input", String::from("Failed to read line")));
+            }
+
+            machine.registers[register] = Some(Value::String(String::from(line.trim_end())));
+        },
+        OpCode::Call{function, assign_to, arguments} => {
+            if machine.limiter != None{
+                check_call(
+                    machine.limiter.as_ref().unwrap(), function,
+                    machine.program.instructions_lines[pc])?;
+            }
+            /* Show The Printed Prompt Before Reading */
+            if is_input_function(function) && machine.output.flush().is_err(){
+                return Err(machine.error("Call", String::from("Failed to print to console")));
+            }
+            let value = execute_call(machine, function, arguments)?;
+            machine.registers[*assign_to] = Some(value);
+        },
+        OpCode::If{register, goto} => {
+            let value = machine.get_register("If", *register)?;
+            if let Value::Bool(condition) = value{
+                if !condition{
+                    machine.pc = *goto;
+                    return Ok(true);
+                }
+            }
+            else{
+                return Err(machine.error("If", format!(
+                    "Variable `{}` value must be of type `bool`",
+                    machine.program.registers_names[*register])));
+            }
+        },
+        OpCode::Else => {},
+        OpCode::GoTo{goto} => {
+            machine.pc = *goto;
+            return Ok(true);
+        },
+        OpCode::End => {
+            return Ok(false);
+        },
+    }
+
+    machine.pc += 1;
+    return Ok(true);
+}
+
+
+/* Returns False When The Machine Is Paused After `pause_after` Op Codes Before The Program Ends */
+pub fn run_machine(machine: &mut Machine, pause_after: Option<u128>) -> Result<bool, String>{
+    let mut executed_op_codes: u128 = 0;
+
+    loop{
+        if pause_after != None && executed_op_codes >= pause_after.unwrap(){
+            machine.flush()?;
+            return Ok(false);
+        }
+
+        let result = execute_op_code(machine);
+        if result.is_err(){
+            machine.flush()?;
+            return Err(result.unwrap_err());
+        }
+        if !result.unwrap(){
+            break;
+        }
+
+        executed_op_codes += 1;
+    }
+
+    machine.flush()?;
+
+    return Ok(true);
+}
+
+
+pub fn get_builtin_argument(value: &Value) -> BuiltinValue{
+    return match value {
+        Value::Bool(value) => BuiltinValue::Bool(*value),
+        Value::Int(value) => BuiltinValue::Int(*value),
+        Value::Double(value) => BuiltinValue::Double(*value),
+        Value::Char(value) => BuiltinValue::Char(*value),
+        Value::String(value) => BuiltinValue::String(value.clone()),
+        Value::List(value) => BuiltinValue::List(value.clone()),
+    };
+}
+
+
+fn execute_call(
+    machine: &Machine, function: &String, arguments: &Vec<usize>
+) -> Result<Value, String>{
+
+    let mut builtin_arguments = vec![];
+    for register in arguments{
+        builtin_arguments.push(get_builtin_argument(machine.get_register("Call", *register)?));
+    }
+
+    let result = call_builtin(function, builtin_arguments);
+    if result.is_err(){
+        return Err(machine.error("Call", result.unwrap_err()));
+    }
+
+    return Ok(match result.unwrap() {
+        BuiltinValue::Bool(value) => Value::Bool(value),
+        BuiltinValue::Int(value) => Value::Int(value),
+        BuiltinValue::Double(value) => Value::Double(value),
+        BuiltinValue::Char(value) => Value::Char(value),
+        BuiltinValue::String(value) => Value::String(value),
+        BuiltinValue::List(value) => Value::List(value),
+    });
+}
+
+
+fn execute_convert(
+    machine: &Machine,
+    convertion_type: &TokenType,
+    convert_to: usize,
+    convert_from: usize
+) -> Result<Value, String>{
+
+    let from_value = machine.get_register("Convert", convert_from)?;
+    let to_value = machine.get_register("Convert", convert_to)?;
+    let to_name = &machine.program.registers_names[convert_to];
+
+    if convertion_type == &TokenType::Bool{
+        if to_value.get_type() != TokenType::Bool{
+            return Err(machine.error("Convert", format!(
+                "Can't convert type `{:?}` to `Bool`", to_value.get_type())));
+        }
+
+        return match from_value {
+            Value::Bool(value) => Ok(Value::Bool(*value)),
+            Value::String(value) => {
+                let value = value.parse::<bool>();
+                if value.is_err(){
+                    return Err(machine.error("Convert", String::from(
+                        "Can't convert type `String` to type `Bool`")));
+                }
+                Ok(Value::Bool(value.unwrap()))
+            },
+            _ => Err(machine.error("Convert", format!(
+                "Can't convert `{}` to `Bool`", from_value))),
+        };
+    }
+    else if convertion_type == &TokenType::Int || convertion_type == &TokenType::Double{
+        if to_value.get_type() != TokenType::Int && to_value.get_type() != TokenType::Double{
+            return Err(machine.error("Convert", format!(
+                "Variable `{}` is not of type `Int` or `Double`", to_name)));
+        }
+
+        let is_int = convertion_type == &TokenType::Int;
+        let type_name = if is_int {"Int"} else {"Double"};
+
+        return match from_value {
+            Value::Int(value) => Ok(
+                if is_int {Value::Int(*value)} else {Value::Double(*value as f64)}),
+            Value::Double(value) => Ok(
+                if is_int {Value::Int(*value as i64)} else {Value::Double(*value)}),
+            Value::String(value) => {
+                if is_int{
+                    let value = value.parse::<i64>();
+                    if value.is_err(){
+                        return Err(machine.error("Convert", String::from(
+                            "Can't convert type `String` to type `Int`")));
+                    }
+                    Ok(Value::Int(value.unwrap()))
+                }
+                else{
+                    let value = value.parse::<f64>();
+                    if value.is_err(){
+                        return Err(machine.error("Convert", String::from(
+                            "Can't convert type `String` to type `Double`")));
+                    }
+                    Ok(Value::Double(value.unwrap()))
+                }
+            },
+            Value::Bool(value) => Err(machine.error("Convert", format!(
+                "Can't convert `{}` to `{}`", value, type_name))),
+            _ => Err(machine.error("Convert", format!(
+                "Can't convert `{}` to `{}`", from_value, type_name))),
+        };
+    }
+    else if convertion_type == &TokenType::Char{
+        if to_value.get_type() != TokenType::Char && to_value.get_type() != TokenType::String{
+            return Err(machine.error("Convert", format!(
+                "Variable `{}` is not of type `Char` or `String`", to_name)));
+        }
+
+        return match from_value {
+            Value::Char(value) => Ok(Value::Char(*value)),
+            Value::String(value) => {
+                if value.len() > 1{
+                    Ok(Value::Char(value.chars().nth(0).unwrap()))
+                }
+                else{
+                    let value = value.parse::<char>();
+                    if value.is_err(){
+                        return Err(machine.error("Convert", String::from(
+                            "Can't convert type `String` to type `Char`")));
+                    }
+                    Ok(Value::Char(value.unwrap()))
+                }
+            },
+            Value::Bool(value) => Err(machine.error("Convert", format!(
+                "Can't convert `{}` to `Char`", value))),
+            _ => Err(machine.error("Convert", format!(
+                "Can't convert `{}` to `Char`", from_value))),
+        };
+    }
+    else if convertion_type == &TokenType::String{
+        if from_value.get_type() != TokenType::Char && from_value.get_type() != TokenType::String{
+            return Err(machine.error("Convert", format!(
+                "Can't convert from type `{:?}` to `String`", from_value.get_type())));
+        }
+        else if to_value.get_type() != TokenType::Char && to_value.get_type() != TokenType::String{
+            return Err(machine.error("Convert", format!(
+                "Variable `{}` is not of type `Char` or `String`", to_name)));
+        }
+
+        return Ok(Value::String(from_value.to_string()));
+    }
+    else if convertion_type == &TokenType::List{
+        if from_value.get_type() != TokenType::List || to_value.get_type() != TokenType::List{
+            return Err(machine.error("Convert", format!(
+                "Can't convert from type `{:?}` to `List`", from_value.get_type())));
+        }
+
+        return Ok(from_value.clone());
+    }
+
+    return Err(machine.error("Convert", format!(
+        "Unknown Conversion type `{:?}`", convertion_type)));
+}
+
+
+fn get_operation_name(operation_type: &TokenType) -> &'static str{
+    return match operation_type {
+        TokenType::Plus => "Plus",
+        TokenType::Minus => "Minus",
+        TokenType::Mul => "Mul",
+        TokenType::Div => "Div",
+        TokenType::Mod => "Mod",
+        TokenType::And => "And",
+        TokenType::Or => "Or",
+        TokenType::GreaterThan => "GreaterThan",
+        TokenType::GreaterThanOrEqual => "GreaterThanOrEqual",
+        TokenType::LessThan => "LessThan",
+        TokenType::LessThanOrEqual => "LessThanOrEqual",
+        TokenType::Equal => "Equal",
+        TokenType::NotEqual => "NotEqual",
+        _ => "Unknown",
+    };
+}
+
+
+fn execute_operation(
+    machine: &Machine,
+    operation_type: &TokenType,
+    assign_to: usize,
+    left: usize,
+    right: usize
+) -> Result<Value, String>{
+
+    let assign_to_value = machine.get_register("Operation", assign_to)?;
+    let left_value = machine.get_register("Operation", left)?;
+    let right_value = machine.get_register("Operation", right)?;
+
+    let operation_name = get_operation_name(operation_type);
+    let assign_type = assign_to_value.get_type();
+
+    let type_error = |assign_type: String| machine.error("Operation", format!(
+        "Can't do operation `{}` to type {} and `{:?}` and `{:?}`",
+        operation_name, assign_type, left_value.get_type(), right_value.get_type()));
+
+    /* Arithmetic Operations */
+    if
+        operation_type == &TokenType::Plus || operation_type == &TokenType::Minus ||
+        operation_type == &TokenType::Mul || operation_type == &TokenType::Div ||
+        operation_type == &TokenType::Mod
+    {
+        return match (&assign_type, left_value, right_value) {
+            (TokenType::String, Value::String(left), Value::String(right))
+                if operation_type == &TokenType::Plus =>
+            {
+                Ok(Value::String(left.clone() + right))
+            },
+            (TokenType::Int, Value::Int(left), Value::Int(right))
+                if operation_type != &TokenType::Div =>
+            {
+                if operation_type == &TokenType::Plus{
+                    Ok(Value::Int(left.wrapping_add(*right)))
+                }
+                else if operation_type == &TokenType::Minus{
+                    Ok(Value::Int(left.wrapping_sub(*right)))
+                }
+                else if operation_type == &TokenType::Mul{
+                    Ok(Value::Int(left.wrapping_mul(*right)))
+                }
+                else{
+                    if *right == 0{
+                        return Err(machine.error("Operation", String::from("Division by zero")));
+                    }
+                    Ok(Value::Int(left.wrapping_rem(*right)))
+                }
+            },
+            (TokenType::Double, Value::Double(left), Value::Double(right)) => {
+                if operation_type == &TokenType::Plus{
+                    Ok(Value::Double(left + right))
+                }
+                else if operation_type == &TokenType::Minus{
+                    Ok(Value::Double(left - right))
+                }
+                else if operation_type == &TokenType::Mul{
+                    Ok(Value::Double(left * right))
+                }
+                else if operation_type == &TokenType::Div{
+                    Ok(Value::Double(left / right))
+                }
+                else{
+                    Ok(Value::Double(left % right))
+                }
+            },
+            _ => Err(type_error(format!("`{:?}`", assign_type))),
+        };
+    }
+
+    if assign_type != TokenType::Bool{
+        return Err(type_error(format!("`{:?}`", assign_type)));
+    }
+
+    /* Logical Operations */
+    if operation_type == &TokenType::And || operation_type == &TokenType::Or{
+        return match (left_value, right_value) {
+            (Value::Bool(left), Value::Bool(right)) => Ok(Value::Bool(
+                if operation_type == &TokenType::And {*left && *right} else {*left || *right})),
+            _ => Err(type_error(String::from("Bool"))),
+        };
+    }
+
+    /* Equality Operations */
+    if operation_type == &TokenType::Equal || operation_type == &TokenType::NotEqual{
+        let is_equal = match (left_value, right_value) {
+            (Value::Int(left), Value::Int(right)) => left == right,
+            (Value::Int(left), Value::Double(right)) => (*left as f64) == *right,
+            (Value::Double(left), Value::Int(right)) => *left == (*right as f64),
+            (Value::Double(left), Value::Double(right)) => left == right,
+            (Value::Bool(left), Value::Bool(right)) => left == right,
+            (Value::Char(left), Value::Char(right)) => left == right,
+            (Value::String(left), Value::String(right)) => left == right,
+            _ => return Err(type_error(String::from("Bool"))),
+        };
+
+        return Ok(Value::Bool(
+            if operation_type == &TokenType::Equal {is_equal} else {!is_equal}));
+    }
+
+    /* Comparison Operations, Integers Are Compared Without Rounding Them Into Doubles */
+    if let (Value::Int(left), Value::Int(right)) = (left_value, right_value){
+        let ordering = left.cmp(right);
+
+        return Ok(Value::Bool(if operation_type == &TokenType::GreaterThan{
+            ordering.is_gt()} else if operation_type == &TokenType::GreaterThanOrEqual{
+            ordering.is_ge()} else if operation_type == &TokenType::LessThan{
+            ordering.is_lt()} else if operation_type == &TokenType::LessThanOrEqual{
+            ordering.is_le()} else {
+            return Err(machine.error("Operation", format!(
+                "Unknown Operation type `{:?}`", operation_type)));
+        }));
+    }
+
+    let left_number = match left_value {
+        Value::Int(value) => *value as f64,
+        Value::Double(value) => *value,
+        _ => return Err(type_error(String::from("Bool"))),
+    };
+    let right_number = match right_value {
+        Value::Int(value) => *value as f64,
+        Value::Double(value) => *value,
+        _ => return Err(type_error(String::from("Bool"))),
+    };
+
+    let result = if operation_type == &TokenType::GreaterThan{
+        left_number > right_number} else if operation_type == &TokenType::GreaterThanOrEqual{
+        left_number >= right_number} else if operation_type == &TokenType::LessThan{
+        left_number < right_number} else if operation_type == &TokenType::LessThanOrEqual{
+        left_number <= right_number} else {
+        return Err(machine.error("Operation", format!(
+            "Unknown Operation type `{:?}`", operation_type)));
+    };
+
+    return Ok(Value::Bool(result));
+}
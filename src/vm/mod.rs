@@ -3,6 +3,10 @@ mod assign_instructions;
 mod convert_instructions;
 mod operation_instructions;
 mod instructions_executers;
+mod op_codes;
+mod machine;
+mod debugger;
+mod snapshot;
 
 use std::collections::HashMap;
 use std::panic;
@@ -14,12 +18,30 @@ use crate::environments::{
 };
 
 use crate::syntax_tree::InstructionType;
+use crate::builtins::get_exit_code;
 use crate::file::File;
+use crate::options::ExecutionOptions;
+use crate::tracer::Tracer;
+use crate::limiter::Limiter;
+use crate::profiler::{
+    Profiler,
+    report_profile
+};
 use instructions_construct::construct_instruction;
 use instructions_executers::execute_instruction;
+use op_codes::load_program;
+use machine::{
+    Machine,
+    run_machine
+};
+use debugger::debug_byte_code as start_debug_byte_code;
+use snapshot::{
+    write_snapshot,
+    load_snapshot
+};
 
 
-pub fn execute_byte_code() -> Result<(), String>{
+fn set_panic_hook(){
     panic::set_hook(Box::new(|panic_info| {
         if let Some(panic_message) = panic_info.payload().downcast_ref::<String>() {
             println!("{}", panic_message);
@@ -29,6 +51,61 @@ pub fn execute_byte_code() -> Result<(), String>{
             println!("EngineVM -> VM Error");
         }
     }));
+}
+
+
+pub fn execute_byte_code(options: &ExecutionOptions) -> Result<(), String>{
+    set_panic_hook();
+
+    let args: Vec<String> = env::args().collect();
+
+    let program = load_program(&args[1])?;
+
+    let mut machine = Machine::new(program);
+    if options.trace_path != None{
+        machine.tracer = Some(Tracer::new(options.trace_path.as_ref().unwrap())?);
+    }
+
+    if options.has_limits(){
+        machine.limiter = Some(Limiter::new(options));
+    }
+    if options.profile{
+        machine.profiler = Some(Profiler::new(&args[1])?);
+    }
+
+    if options.resume_path != None{
+        load_snapshot(&mut machine, &args[1], options.resume_path.as_ref().unwrap())?;
+    }
+
+    let pause_after = if options.snapshot_path != None {options.snapshot_after} else {None};
+    let result = run_machine(&mut machine, pause_after);
+    let result = if get_exit_code() != None {machine.flush().map(|_| true)} else {result};
+
+    if machine.profiler != None{
+        report_profile(
+            machine.profiler.as_ref().unwrap(),
+            options.profile_flamegraph_path.as_ref())?;
+    }
+
+    /* Paused Before The End */
+    if !result?{
+        write_snapshot(&machine, &args[1], options.snapshot_path.as_ref().unwrap())?;
+    }
+
+    return Ok(());
+}
+
+
+pub fn debug_byte_code(file_path: &String) -> Result<(), String>{
+    set_panic_hook();
+
+    return start_debug_byte_code(file_path);
+}
+
+
+/* The Old Instructions Executers, Kept To Compare Against The Machine */
+pub fn execute_byte_code_legacy() -> Result<(), String>{
+    set_panic_hook();
 
     let args: Vec<String> = env::args().collect();
 
@@ -99,6 +176,9 @@ pub fn execute_byte_code() -> Result<(), String>{
         /* Execute Line */
         let result = execute_instruction(
             current_line, &mut environment, instruction);
+        if result.is_err() && get_exit_code() != None{
+            break;
+        }
         if result.is_err(){
             panic!("{}", result.unwrap_err());
         }
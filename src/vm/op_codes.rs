@@ -0,0 +1,448 @@
+use std::collections::HashMap;
+
+use crate::file::File;
+use crate::tokens::TokenType;
+use crate::builtins::is_builtin_function;
+use crate::syntax_tree::{
+    Instruction,
+    InstructionType
+};
+use crate::vm::instructions_construct::construct_instruction;
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value{
+    Bool(bool),
+    Int(i64),
+    Double(f64),
+    Char(char),
+    String(String),
+    List(Vec<String>),
+}
+
+impl Value{
+    pub fn get_type(&self) -> TokenType{
+        return match self {
+            Value::Bool(_) => TokenType::Bool,
+            Value::Int(_) => TokenType::Int,
+            Value::Double(_) => TokenType::Double,
+            Value::Char(_) => TokenType::Char,
+            Value::String(_) => TokenType::String,
+            Value::List(_) => TokenType::List,
+        };
+    }
+}
+
+impl std::fmt::Display for Value{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Bool(value) => write!(f, "{}", if *value {"True"} else {"False"}),
+            Value::Int(value) => write!(f, "{}", value),
+            Value::Double(value) => write!(f, "{}", value),
+            Value::Char(value) => write!(f, "{}", value),
+            Value::String(value) => write!(f, "{}", value),
+            Value::List(value) => write!(f, "{:?}", value),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpCode{
+    Assign{register: usize, value: Value},
+    Convert{convertion_type: TokenType, convert_to: usize, convert_from: usize},
+    Operation{operation_type: TokenType, assign_to: usize, left: usize, right: usize},
+    Print{register: usize, is_stderr: bool},
+    Input{register: usize},
+    Call{function: String, assign_to: usize, arguments: Vec<usize>},
+    If{register: usize, goto: usize},
+    Else,
+    GoTo{goto: usize},
+    End,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct Program{
+    pub op_codes: Vec<OpCode>,
+
+    /* Instruction Line And File Line Of Every Op Code, Used In Errors */
+    pub instructions_lines: Vec<u128>,
+    pub file_lines: Vec<u128>,
+    pub instructions_texts: Vec<String>,
+
+    pub registers_names: Vec<String>,
+}
+
+impl Program{
+    fn get_register(
+        &mut self, registers: &mut HashMap<String, usize>, variable_name: &String
+    ) -> usize{
+
+        if registers.contains_key(variable_name){
+            return registers.get(variable_name).unwrap().clone();
+        }
+
+        let register = self.registers_names.len();
+        self.registers_names.push(variable_name.clone());
+        registers.insert(variable_name.clone(), register);
+
+        return register;
+    }
+}
+
+
+pub fn construct_value(
+    assign_type: &TokenType, value: String, instruction_line: u128, current_line: u128
+) -> Result<Value, String>{
+
+    if assign_type == &TokenType::Bool{
+        if value != "True" && value != "False"{
+            return Err(format!(
+                "Engine VM: Assign Instruction -> {} `{:?}`, instruction line: {}, line: {}.",
+                "Invalid Bool value", value, instruction_line, current_line));
+        }
+        return Ok(Value::Bool(value == "True"));
+    }
+    else if assign_type == &TokenType::Int{
+        let int_value = value.parse::<i64>();
+        if int_value.is_err(){
+            return Err(format!(
+                "Engine VM: Assign Instruction -> {} `{:?}`, instruction line: {}, line: {}.",
+                "Invalid Int value", value, instruction_line, current_line));
+        }
+        return Ok(Value::Int(int_value.unwrap()));
+    }
+    else if assign_type == &TokenType::Double{
+        let double_value = value.parse::<f64>();
+        if double_value.is_err(){
+            return Err(format!(
+                "Engine VM: Assign Instruction -> {} `{:?}`, instruction line: {}, line: {}.",
+                "Invalid Double value", value, instruction_line, current_line));
+        }
+        return Ok(Value::Double(double_value.unwrap()));
+    }
+    else if assign_type == &TokenType::Char{
+        let char_value = if value.len() < 3 {'\0'} else {
+            if value == "'\\n'"{
+                '\n'
+            }
+            else if value == "'\\t'"{
+                '\t'
+            }
+            else if value == "'\''"{
+                '\''
+            }
+            else if value == "'\\\\'"{
+                '\\'
+            }
+            else{
+                value.chars().nth(1).unwrap()
+            }
+        };
+        return Ok(Value::Char(char_value));
+    }
+    else if assign_type == &TokenType::String{
+        if value.len() < 2{
+            return Err(format!(
+                "Engine VM: Assign Instruction -> {} `{:?}`, instruction line: {}, line: {}.",
+                "Invalid String", value, instruction_line, current_line));
+        }
+        let value = &value[1..value.len()-1];
+
+        let mut new_value = String::new();
+        let mut characters = value.chars().peekable();
+
+        while let Some(ch) = characters.next(){
+            new_value.push(read_escaped_character(ch, &mut characters));
+        }
+
+        return Ok(Value::String(new_value));
+    }
+    else if assign_type == &TokenType::List{
+        let items = read_list_items(&value);
+        if items == None{
+            return Err(format!(
+                "Engine VM: Assign Instruction -> {} `{:?}`, instruction line: {}, line: {}.",
+                "Invalid List", value, instruction_line, current_line));
+        }
+        return Ok(Value::List(items.unwrap()));
+    }
+
+    return Err(format!(
+        "Engine VM: Assign Instruction -> {} `{:?}`, instruction line: {}, line: {}.",
+        "Unknown Assign type", assign_type, instruction_line, current_line));
+}
+
+
+/* `\n`, `\t`, `\\` And `\"` Are Read As One Character */
+fn read_escaped_character(ch: char, characters: &mut std::iter::Peekable<std::str::Chars>) -> char{
+    if ch != '\\'{
+        return ch;
+    }
+
+    let escaped = match characters.peek(){
+        Some('n') => '\n',
+        Some('t') => '\t',
+        Some('\\') => '\\',
+        Some('"') => '"',
+        _ => return ch
+    };
+    characters.next();
+
+    return escaped;
+}
+
+
+/* `["first", "second"]`, The Items Are Escaped Like The Strings */
+fn read_list_items(value: &String) -> Option<Vec<String>>{
+    if value.len() < 2 || !value.starts_with('[') || !value.ends_with(']'){
+        return None;
+    }
+
+    let mut items = vec![];
+    let mut characters = value[1..value.len()-1].chars().peekable();
+
+    while characters.peek() != None{
+        if items.len() > 0 && (characters.next() != Some(',') || characters.next() != Some(' ')){
+            return None;
+        }
+        if characters.next() != Some('"'){
+            return None;
+        }
+
+        let mut item = String::new();
+        loop{
+            let ch = characters.next();
+            if ch == None{
+                return None;
+            }
+            else if ch == Some('"'){
+                break;
+            }
+            item.push(read_escaped_character(ch.unwrap(), &mut characters));
+        }
+        items.push(item);
+    }
+
+    return Some(items);
+}
+
+
+fn construct_op_code(
+    program: &mut Program,
+    registers: &mut HashMap<String, usize>,
+    instruction: Instruction,
+    instruction_line: u128,
+    current_line: u128
+) -> Result<(OpCode, Option<u128>), String>{
+
+    let instruction_type = instruction.instruction_type.unwrap();
+
+    if instruction_type == InstructionType::Assign{
+        let instruction = instruction.assign_instruction.unwrap();
+
+        let register = program.get_register(
+            registers, instruction.variable_name.as_ref().unwrap());
+        let value = construct_value(
+            instruction.assign_type.as_ref().unwrap(), instruction.value.unwrap(),
+            instruction_line, current_line)?;
+
+        return Ok((OpCode::Assign{register, value}, None));
+    }
+    else if instruction_type == InstructionType::Convert{
+        let instruction = instruction.convert_instruction.unwrap();
+
+        if instruction.convertion_type == Some(TokenType::BadToken){
+            return Err(format!(
+                "Engine VM: Convert Instruction -> {} `{:?}`, instruction line: {}, line: {}.",
+                "Unknown Conversion type",
+                instruction.convertion_type.as_ref().unwrap(),
+                instruction_line, current_line));
+        }
+
+        let convert_to = program.get_register(
+            registers, instruction.convert_to.as_ref().unwrap());
+        let convert_from = program.get_register(
+            registers, instruction.convert_from.as_ref().unwrap());
+
+        return Ok((OpCode::Convert{
+            convertion_type: instruction.convertion_type.unwrap(),
+            convert_to,
+            convert_from
+        }, None));
+    }
+    else if instruction_type == InstructionType::Operation{
+        let instruction = instruction.operation_instruction.unwrap();
+
+        if instruction.operation_type == Some(TokenType::BadToken){
+            return Err(format!(
+                "Engine VM: Operation Instruction -> {}, instruction line: {}, line: {}.",
+                "Unknown Operation type", instruction_line, current_line));
+        }
+
+        let assign_to = program.get_register(
+            registers, instruction.assign_to_variable.as_ref().unwrap());
+        let left = program.get_register(
+            registers, instruction.left_variable.as_ref().unwrap());
+        let right = program.get_register(
+            registers, instruction.right_variable.as_ref().unwrap());
+
+        return Ok((OpCode::Operation{
+            operation_type: instruction.operation_type.unwrap(),
+            assign_to,
+            left,
+            right
+        }, None));
+    }
+    else if instruction_type == InstructionType::Print{
+        let instruction = instruction.print_instruction.unwrap();
+
+        let register = program.get_register(
+            registers, instruction.variable_name.as_ref().unwrap());
+
+        return Ok((OpCode::Print{register, is_stderr: instruction.is_stderr}, None));
+    }
+    else if instruction_type == InstructionType::Input{
+        let instruction = instruction.input_instruction.unwrap();
+
+        let register = program.get_register(
+            registers, instruction.variable_name.as_ref().unwrap());
+
+        return Ok((OpCode::Input{register}, None));
+    }
+    else if instruction_type == InstructionType::Call{
+        let instruction = instruction.call_instruction.unwrap();
+
+        if !is_builtin_function(instruction.function_name.as_ref().unwrap()){
+            return Err(format!(
+                "Engine VM: Call Instruction -> {} `{}`, instruction line: {}, line: {}.",
+                "Unknown function",
+                instruction.function_name.as_ref().unwrap(),
+                instruction_line, current_line));
+        }
+
+        let assign_to = program.get_register(
+            registers, instruction.assign_to_variable.as_ref().unwrap());
+
+        let mut arguments = vec![];
+        for argument in &instruction.arguments{
+            arguments.push(program.get_register(registers, argument));
+        }
+
+        return Ok((OpCode::Call{
+            function: instruction.function_name.unwrap(),
+            assign_to,
+            arguments
+        }, None));
+    }
+    else if instruction_type == InstructionType::If{
+        let instruction = instruction.if_instruction.unwrap();
+
+        let register = program.get_register(
+            registers, instruction.variable_name.as_ref().unwrap());
+
+        /* Goto Is Resolved After All Instructions Are Loaded */
+        return Ok((
+            OpCode::If{register, goto: 0},
+            instruction.condition_fail_goto_line));
+    }
+    else if instruction_type == InstructionType::GoTo{
+        let instruction = instruction.goto_instruction.unwrap();
+
+        return Ok((OpCode::GoTo{goto: 0}, instruction.goto_line));
+    }
+    else if instruction_type == InstructionType::Else{
+        return Ok((OpCode::Else, None));
+    }
+
+    return Ok((OpCode::End, None));
+}
+
+
+pub fn load_program(file_path: &String) -> Result<Program, String>{
+    let mut file = File::open_byte_file(file_path);
+
+    let info = file.read_byte_code_line();
+    if info.is_err(){
+        return Err(format!(
+            "Engine VM: File Error -> {}, line: 1:1",
+            info.unwrap_err()));
+    }
+
+    let mut program = Program{
+        op_codes: Vec::new(),
+        instructions_lines: Vec::new(),
+        file_lines: Vec::new(),
+        instructions_texts: Vec::new(),
+        registers_names: Vec::new(),
+    };
+
+    let mut registers: HashMap<String, usize> = HashMap::new();
+    let mut op_codes_indexes: HashMap<u128, usize> = HashMap::new();
+    let mut goto_lines: Vec<(usize, u128)> = Vec::new();
+
+    let mut current_line: u128 = 1;
+
+    loop{
+        /* Read Instruction Line */
+        let line = file.read_byte_code_line();
+        if line.is_err(){
+            return Err(format!(
+                "Engine VM: File Error -> {}, line: {}:1",
+                line.unwrap_err(), current_line));
+        }
+        current_line += 1;
+
+        let line = line.unwrap();
+        if line.1 == 0{
+            break;
+        }
+
+        /* Construct Instruction */
+        let instruction_text = String::from(line.0.trim_end());
+        let (instruction, instruction_line) = construct_instruction(line.0, current_line)?;
+        let is_end = instruction.instruction_type == Some(InstructionType::End);
+
+        let (op_code, goto_line) = construct_op_code(
+            &mut program, &mut registers, instruction, instruction_line, current_line)?;
+
+        if goto_line != None{
+            goto_lines.push((program.op_codes.len(), goto_line.unwrap()));
+        }
+
+        op_codes_indexes.insert(instruction_line, program.op_codes.len());
+        program.op_codes.push(op_code);
+        program.instructions_lines.push(instruction_line);
+        program.file_lines.push(current_line);
+        program.instructions_texts.push(instruction_text);
+
+        if is_end{
+            break;
+        }
+    }
+
+    /* Make Sure The Program Always Ends */
+    if program.op_codes.last() != Some(&OpCode::End){
+        program.op_codes.push(OpCode::End);
+        program.instructions_lines.push(0);
+        program.file_lines.push(current_line);
+        program.instructions_texts.push(String::from("End:"));
+    }
+    let end_index = program.op_codes.len() - 1;
+
+    /* Resolve Go To Lines Into Op Codes Indexes */
+    for (index, goto_line) in goto_lines{
+        let goto_index = op_codes_indexes.get(&goto_line).cloned().unwrap_or(end_index);
+
+        let op_code = &mut program.op_codes[index];
+        if let OpCode::If{register: _, goto} = op_code{
+            *goto = goto_index;
+        }
+        else if let OpCode::GoTo{goto} = op_code{
+            *goto = goto_index;
+        }
+    }
+
+    return Ok(program);
+}
@@ -148,6 +148,7 @@ fn execute_plus_operation(
                 double: None,
                 int: None,
                 string: Some(left_value + &right_value),
+                list: None,
                 string_value: None,
             })
         };
@@ -183,6 +184,7 @@ fn execute_plus_operation(
                 double: None,
                 int: Some(left_value + right_value),
                 string: None,
+                list: None,
                 string_value: None
             })
         };
@@ -218,6 +220,7 @@ fn execute_plus_operation(
                 double: Some(left_value + right_value),
                 int: None,
                 string: None,
+                list: None,
                 string_value: None
             })
         };
@@ -315,6 +318,7 @@ fn execute_minus_operation(
                 double: None,
                 int: Some(left_value - right_value),
                 string: None,
+                list: None,
                 string_value: None
             })
         };
@@ -350,6 +354,7 @@ fn execute_minus_operation(
                 double: Some(left_value - right_value),
                 int: None,
                 string: None,
+                list: None,
                 string_value: None
             })
         };
@@ -447,6 +452,7 @@ fn execute_mul_operation(
                 double: None,
                 int: Some(left_value * right_value),
                 string: None,
+                list: None,
                 string_value: None
             })
         };
@@ -482,6 +488,7 @@ fn execute_mul_operation(
                 double: Some(left_value * right_value),
                 int: None,
                 string: None,
+                list: None,
                 string_value: None
             })
         };
@@ -576,6 +583,7 @@ fn execute_div_operation(
             double: Some(left_value / right_value),
             int: None,
             string: None,
+            list: None,
             string_value: None
         })
     };
@@ -672,6 +680,7 @@ fn execute_mod_operation(
                 double: None,
                 int: Some(left_value % right_value),
                 string: None,
+                list: None,
                 string_value: None
             })
         };
@@ -707,6 +716,7 @@ fn execute_mod_operation(
                 double: Some(left_value % right_value),
                 int: None,
                 string: None,
+                list: None,
                 string_value: None
             })
         };
@@ -802,6 +812,7 @@ fn execute_and_operation(
             double: None,
             int: None,
             string: None,
+            list: None,
             string_value: None
         })
     };
@@ -896,6 +907,7 @@ fn execute_or_operation(
             double: None,
             int: None,
             string: None,
+            list: None,
             string_value: None
         })
     };
@@ -997,6 +1009,7 @@ fn execute_greater_than_operation(
             double: None,
             int: None,
             string: None,
+            list: None,
             string_value: None
         })
     };
@@ -1098,6 +1111,7 @@ fn execute_greater_than_or_equal_operation(
             double: None,
             int: None,
             string: None,
+            list: None,
             string_value: None
         })
     };
@@ -1199,6 +1213,7 @@ fn execute_less_than_operation(
             double: None,
             int: None,
             string: None,
+            list: None,
             string_value: None
         })
     };
@@ -1256,7 +1271,7 @@ fn execute_less_than_or_equal_operation(
     }
     let right_variable = right_variable.as_ref().unwrap().as_ref().unwrap();
 
-    if assign_to_variable.variable_type == Some(TokenType::Bool){
+    if assign_to_variable.variable_type != Some(TokenType::Bool){
         return Err(format!(
             "Engine VM: Operation Instruction -> {}, instruction line: {}, line: {}.",
             format!(
@@ -1300,6 +1315,7 @@ fn execute_less_than_or_equal_operation(
             double: None,
             int: None,
             string: None,
+            list: None,
             string_value: None
         })
     };
@@ -1357,7 +1373,7 @@ fn execute_equal_operation(
     }
     let right_variable = right_variable.as_ref().unwrap().as_ref().unwrap();
 
-    if assign_to_variable.variable_type == Some(TokenType::Bool){
+    if assign_to_variable.variable_type != Some(TokenType::Bool){
         return Err(format!(
             "Engine VM: Operation Instruction -> {}, instruction line: {}, line: {}.",
             format!(
@@ -1400,6 +1416,7 @@ fn execute_equal_operation(
             double: None,
             int: None,
             string: None,
+            list: None,
             string_value: None
         })
     };
@@ -1457,7 +1474,7 @@ fn execute_not_equal_operation(
     }
     let right_variable = right_variable.as_ref().unwrap().as_ref().unwrap();
 
-    if assign_to_variable.variable_type == Some(TokenType::Bool){
+    if assign_to_variable.variable_type != Some(TokenType::Bool){
         return Err(format!(
             "Engine VM: Operation Instruction -> {}, instruction line: {}, line: {}.",
             format!(
@@ -1501,6 +1518,7 @@ fn execute_not_equal_operation(
             double: None,
             int: None,
             string: None,
+            list: None,
             string_value: None
         })
     };
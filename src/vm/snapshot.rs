@@ -0,0 +1,271 @@
+use crate::tokens::TokenType;
+use crate::vm::op_codes::{
+    Value,
+    construct_value
+};
+use crate::vm::machine::{
+    Machine,
+    compute_memory
+};
+use crate::builtins::{
+    get_file_error,
+    set_file_error,
+    get_input_eof,
+    set_input_eof
+};
+
+
+const SNAPSHOT_HEADER: &'static str = "0:EngineSnapshot:v0.1.0";
+
+
+/*
+    Snapshot Format:
+        0:EngineSnapshot:v0.1.0
+        1:Program:<byte code file checksum>
+        2:PC:<instruction line>
+        N:Builtin:file_error:string:value
+        N:Builtin:input_eof:bool:value
+        N:Register:"name":type:value
+
+    Loops State Lives In The Registers, So The Registers, The PC And The State Kept
+    By The Built-in Functions Between Calls Are The Full State.
+*/
+
+
+/* FNV-1a, Stable Between Runs And Rust Versions */
+fn get_checksum(file_path: &String) -> Result<String, String>{
+    let content = std::fs::read(file_path);
+    if content.is_err(){
+        return Err(format!(
+            "Engine VM: Snapshot Error -> Can't open the file `{}`.",
+            file_path));
+    }
+
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in content.unwrap(){
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+
+    return Ok(format!("{:016x}", hash));
+}
+
+
+fn get_escaped_string(value: &String) -> String{
+    let mut new_value = String::new();
+    for ch in value.chars(){
+        if ch == '\n'{
+            new_value.push_str("\\n");
+        }
+        else if ch == '\t'{
+            new_value.push_str("\\t");
+        }
+        else if ch == '\\'{
+            new_value.push_str("\\\\");
+        }
+        else if ch == '"'{
+            new_value.push_str("\\\"");
+        }
+        else{
+            new_value.push(ch);
+        }
+    }
+
+    return new_value;
+}
+
+
+fn format_value(value: &Value) -> String{
+    return match value {
+        Value::Bool(value) => format!("bool:{}", if *value {"True"} else {"False"}),
+        Value::Int(value) => format!("int:{}", value),
+        Value::Double(value) => format!("double:{:?}", value),
+        Value::Char(value) => {
+            if *value == '\n'{
+                String::from("char:'\\n'")
+            }
+            else if *value == '\t'{
+                String::from("char:'\\t'")
+            }
+            else if *value == '\\'{
+                String::from("char:'\\\\'")
+            }
+            else{
+                format!("char:'{}'", value)
+            }
+        },
+        Value::String(value) => format!("string:\"{}\"", get_escaped_string(value)),
+        Value::List(value) => {
+            let items: Vec<String> = value.iter().map(
+                |item| format!("\"{}\"", get_escaped_string(item))).collect();
+            format!("list:[{}]", items.join(", "))
+        },
+    };
+}
+
+
+fn parse_value_type(value_type: &str) -> Option<TokenType>{
+    if value_type == "bool"{
+        return Some(TokenType::Bool);
+    }
+    else if value_type == "int"{
+        return Some(TokenType::Int);
+    }
+    else if value_type == "double"{
+        return Some(TokenType::Double);
+    }
+    else if value_type == "char"{
+        return Some(TokenType::Char);
+    }
+    else if value_type == "string"{
+        return Some(TokenType::String);
+    }
+    else if value_type == "list"{
+        return Some(TokenType::List);
+    }
+
+    return None;
+}
+
+
+pub fn write_snapshot(
+    machine: &Machine, file_path: &String, snapshot_path: &String
+) -> Result<(), String>{
+
+    let mut lines = vec![
+        String::from(SNAPSHOT_HEADER),
+        format!("1:Program:{}", get_checksum(file_path)?),
+        format!("2:PC:{}", machine.program.instructions_lines[machine.pc]),
+        format!("3:Builtin:file_error:{}", format_value(&Value::String(get_file_error()))),
+        format!("4:Builtin:input_eof:{}", format_value(&Value::Bool(get_input_eof()))),
+    ];
+
+    for register in 0..machine.registers.len(){
+        if machine.registers[register] == None{
+            continue;
+        }
+
+        lines.push(format!(
+            "{}:Register:{}:{}",
+            lines.len(),
+            machine.program.registers_names[register],
+            format_value(machine.registers[register].as_ref().unwrap())));
+    }
+
+    let mut content = lines.join("\n");
+    content.push('\n');
+
+    if std::fs::write(snapshot_path, content).is_err(){
+        return Err(format!(
+            "Engine VM: Snapshot Error -> Can't write the file `{}`.",
+            snapshot_path));
+    }
+
+    return Ok(());
+}
+
+
+fn load_builtin_state(
+    name: &str, value_type: &str, value: &str, line: u128
+) -> Result<(), String>{
+
+    let expected_type = if name == "file_error" {"string"} else {"bool"};
+    if (name != "file_error" && name != "input_eof") || value_type != expected_type{
+        return Err(format!(
+            "Engine VM: Snapshot Error -> Unknown built-in state `{}`, line: {}.",
+            name, line));
+    }
+
+    let builtin_value = construct_value(
+        parse_value_type(value_type).as_ref().unwrap(), String::from(value), 0, line);
+    if builtin_value.is_err(){
+        return Err(format!(
+            "Engine VM: Snapshot Error -> Invalid value `{}`, line: {}.",
+            value, line));
+    }
+
+    match builtin_value.unwrap() {
+        Value::String(file_error) => set_file_error(file_error),
+        Value::Bool(input_eof) => set_input_eof(input_eof),
+        _ => {},
+    }
+
+    return Ok(());
+}
+
+
+pub fn load_snapshot(
+    machine: &mut Machine, file_path: &String, snapshot_path: &String
+) -> Result<(), String>{
+
+    let content = std::fs::read_to_string(snapshot_path);
+    if content.is_err(){
+        return Err(format!(
+            "Engine VM: Snapshot Error -> Can't open the file `{}`.",
+            snapshot_path));
+    }
+    let content = content.unwrap();
+    let mut lines = content.lines();
+
+    if lines.next() != Some(SNAPSHOT_HEADER){
+        return Err(String::from(
+            "Engine VM: Snapshot Error -> Invalid snapshot header, line: 1."));
+    }
+
+    if lines.next() != Some(format!("1:Program:{}", get_checksum(file_path)?).as_str()){
+        return Err(format!(
+            "Engine VM: Snapshot Error -> {} `{}`, line: 2.",
+            "The snapshot was taken from a different byte code than", file_path));
+    }
+
+    let pc_line = lines.next().unwrap_or("").strip_prefix("2:PC:").map(
+        |line| line.parse::<u128>().ok()).flatten();
+    let pc = pc_line.map(
+        |line| machine.program.instructions_lines.iter().position(|instruction_line| instruction_line == &line)
+    ).flatten();
+    if pc == None{
+        return Err(String::from(
+            "Engine VM: Snapshot Error -> Invalid program counter, line: 3."));
+    }
+    machine.pc = pc.unwrap();
+
+    for (index, line) in lines.enumerate(){
+        let current_line = index as u128 + 4;
+
+        let parts: Vec<&str> = line.splitn(5, ':').collect();
+        if parts.len() == 5 && parts[1] == "Builtin"{
+            load_builtin_state(parts[2], parts[3], parts[4], current_line)?;
+            continue;
+        }
+
+        if parts.len() != 5 || parts[1] != "Register"{
+            return Err(format!(
+                "Engine VM: Snapshot Error -> Invalid register, line: {}.",
+                current_line));
+        }
+
+        let register = machine.program.registers_names.iter().position(
+            |register_name| register_name == parts[2]);
+        let value_type = parse_value_type(parts[3]);
+        if register == None || value_type == None{
+            return Err(format!(
+                "Engine VM: Snapshot Error -> Unknown register `{}`, line: {}.",
+                parts[2], current_line));
+        }
+
+        let value = construct_value(
+            value_type.as_ref().unwrap(), String::from(parts[4]), 0, current_line);
+        if value.is_err(){
+            return Err(format!(
+                "Engine VM: Snapshot Error -> Invalid value `{}`, line: {}.",
+                parts[4], current_line));
+        }
+
+        machine.registers[register.unwrap()] = Some(value.unwrap());
+    }
+
+    /* The Limits Count The Restored Strings Like The Ones Assigned Before The Snapshot */
+    compute_memory(machine)?;
+
+    return Ok(());
+}
@@ -0,0 +1,133 @@
+/* Programs And Helpers Shared By The Tests Running The Engine Binary */
+#![allow(dead_code)]
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{
+    Command,
+    Output,
+    Stdio
+};
+
+
+/* Programs Without Built-in Functions, With The Input They Read, Run By Every Backend */
+pub const PROGRAMS: [(&'static str, &'static str); 6] = [
+(
+"int total = 0
+for k in (0, 4, 1){
+    w = k * 2
+    if w > 2{
+        int z = w + 1
+        total += z
+    }
+    else{
+        total -= 1
+    }
+}
+var s = \"a\\\"b?\\\\\" + 'c' + \"??=d\"
+s += 'é'
+char c = \"yes\"
+bool b = (3 > 2) && 1.5 <= 1
+print(total, s, c, b, 10 % 3, end=\"\\n\")
+",
+""
+),
+
+(
+"double d = 0
+for k in (1, 200, 1){
+    d = k / 7
+    print(d, \" \")
+    d = k * k * k * k * k * k * k
+    double e = d / 13
+    double f = k / 1000000000
+    double g = 0 - k
+    print(d, \" \", e, \" \", f, \" \", g, \"\\n\")
+}
+int i = 7
+i /= 2
+int t = 2.9
+print(i, \" \", t, \"\\n\")
+",
+""
+),
+
+(
+"int big = 922337203685477580 * 10 + 7
+big += 1
+int m = 0 - 7
+print(big, \" \", m % 3, \" \", 7 % (0 - 3), \"\\n\")
+",
+""
+),
+
+(
+"string name = input()
+int n = input() as int
+double d = input() as double
+bool b = input() as bool
+char c = input() as char
+print(name, \"|\", n * 2, \"|\", d, \"|\", b, \"|\", c, \"\\n\")
+eprint(\"done\\n\")
+",
+"engine  \n-42\n.5e-3\ntrue\n€uro\n"
+),
+
+(
+"for k in (0, 6, 1){
+    double x = input() as double
+    int y = input() as int
+    double r = x % y
+    print(x, \" \", r, \" \", x * y, \"\\n\")
+}
+",
+"2.4703282292062328e-324\n3\n1e23\n7\n-inf\n2\n0.1\n-3\n123456789.125e-300\n1\nNaN\n5\n"
+),
+
+(
+"int n = input() as int
+print(\"before\\n\")
+int d = input() as int
+print(n % d, \"\\n\")
+",
+"4\nfour\n"
+),
+];
+
+
+pub fn engine(args: &[&str]) -> Output{
+    return Command::new(env!("CARGO_BIN_EXE_engine")).args(args).output().unwrap();
+}
+
+
+pub fn run(program_path: &str, args: &[&str], input: &str) -> Output{
+    let mut child = Command::new(program_path)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    /* The Program Can Stop Before Reading All Of It */
+    let _ = child.stdin.take().unwrap().write_all(input.as_bytes());
+
+    return child.wait_with_output().unwrap();
+}
+
+
+pub fn get_text(output: &Output) -> String{
+    return format!(
+        "{}{}", String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+}
+
+
+pub fn write_program(folder_name: &str, program: &str) -> (PathBuf, String){
+    let folder = std::env::temp_dir().join(format!("{}_{}", folder_name, std::process::id()));
+    std::fs::create_dir_all(&folder).unwrap();
+
+    let source_path = folder.join("program.en");
+    std::fs::write(&source_path, program).unwrap();
+
+    return (folder, String::from(source_path.to_str().unwrap()));
+}
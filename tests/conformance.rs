@@ -0,0 +1,624 @@
+use std::process::{
+    Command,
+    Output
+};
+
+mod common;
+
+use common::{
+    PROGRAMS,
+    engine,
+    run,
+    get_text,
+    write_program
+};
+
+
+/* Programs Every Mode Accepts, Each Mode Must Print The Same Output */
+const ACCEPTED_PROGRAMS: [&'static str; 9] = [
+"var c = 'a'
+c += \"bc\"
+x = 'z'
+x += 'y'
+string s = \"hello\"
+s = 'q'
+print(c, x, s, end=\"\\n\")
+",
+
+"int i = 5
+i = 2.7
+double d = 1
+d += 2
+y = 3
+print(i, d, y, end=\"\\n\")
+",
+
+"int total = 0
+for k in (0, 4, 1){
+    w = k * 2
+    if w > 2{
+        int z = w + 1
+        total += z
+    }
+    else{
+        total -= 1
+    }
+}
+print(total, end=\"\\n\")
+",
+
+"const int MAX = 10
+const var NAME = \"engine\"
+if MAX > 5{
+    print(NAME, len(NAME) + MAX, end=\"\\n\")
+}
+print(\"done\\n\")
+",
+
+"int i = 7
+i /= 2
+bool b = i > 2 && i < 5
+int count = 0
+for k in (0, 10, 1){
+    if k == 2{
+        continue
+    }
+    else if k == 6{
+        break
+    }
+    count += k
+}
+print(i, b, count, end=\"\\n\")
+",
+
+"int day = 60 * 60 * 24
+var s = \"ab\" + 'c' + \"d\"
+bool b = (3 > 2) && 1.5 <= 1
+double h = 7 / 2
+int t = 2.9
+char c = \"yes\"
+print(day, s, b, h, t, c, (1 + 2) * (day - 86399), 10 % 3, end=\"\\n\")
+",
+
+"string find = \"héllo\"
+int max = 3
+int len = len (find)
+len += max(max, 7)
+print(len, find, max, end=\"\\n\")
+",
+
+"int E = 3
+const double TAU = PI * 2
+double area = PI * E * E
+print(E, TAU, area > 28, round(log(2.718281828459045)), end=\"\\n\")
+",
+
+"string s = \"ü\\tü\\\\ü\\\"ü\\\" é\\\\\"
+print(s, \"é\\n\")
+",
+];
+
+
+/* Programs Every Mode Rejects Before Running Them, With The Same Error */
+const REJECTED_PROGRAMS: [(&'static str, &'static str); 16] = [
+    (
+        "print(\"never\")\nint x = \"ab\"\n",
+        "Syntax Error -> Can't assign `StringSequence` to Int, line 2:5."
+    ),
+    (
+        "x = 1\nx = \"ab\"\n",
+        "Syntax Error -> Can't assign `StringSequence` to `Int`, line 2:1."
+    ),
+    (
+        "if 1{\n    print(1)\n}\nprint(2)\n",
+        "Analyze Error -> if condition must be `Bool`, line 1:1."
+    ),
+    (
+        "print(q)\n",
+        "Syntax Error -> Undefined variable `q`, line 1:7."
+    ),
+    (
+        "break\n",
+        "Analyze Error -> Use of `break` statement outside of Loop statement is invalid, line 1:1."
+    ),
+    (
+        "if True{\n    int z = 1\n}\nprint(z)\n",
+        "Syntax Error -> Undefined variable `z`, line 4:7."
+    ),
+    (
+        "bool b = True\nb += True\n",
+        "Syntax Error -> Invalid operation `PlusEqual` on `\"b\"` which has type `Bool`, line 2:1."
+    ),
+    (
+        "const int A = 1\nA = 2\n",
+        "Syntax Error -> Constant `A` can't be reassigned, line 2:1."
+    ),
+    (
+        "int x = 1\nif x == 1{\n    string x = \"a\"\n}\nprint(x)\n",
+        "Analyze Error -> Variable `x` may be used before assignment, line 5:1."
+    ),
+    (
+        "print(\"never\")\nint x = 10 % (5 - 5)\n",
+        "Analyze Error -> Division by zero in constant expression, line 2:12."
+    ),
+    (
+        "print(\"never\")\nprint(999999999 * 999999999 * 999999999)\n",
+        "Analyze Error -> Integer overflow in constant expression, line 2:17."
+    ),
+    (
+        "print(\"never\")\nconst int X = 5 % 0\nprint(X)\n",
+        "Analyze Error -> Division by zero in constant expression, line 2:17."
+    ),
+    (
+        "print(\"never\")\nconst int X = 922337203685477580 * 10 + 8\nprint(X)\n",
+        "Analyze Error -> Integer overflow in constant expression, line 2:39."
+    ),
+    (
+        "double PI = 3\nconst double TAU = PI * 2\n",
+        "Syntax Error -> Constant `TAU` must be initialized with a constant expression, `PI` is not constant, line 2:20."
+    ),
+    (
+        "print(\"never\")\nvar lines = read_lines(\"data.txt\")\nlines = \"ab\"\n",
+        "Syntax Error -> Can't assign `StringSequence` to `List`, line 3:1."
+    ),
+    (
+        "print(\"never\")\nvar lines = read_lines(\"data.txt\")\nprint(lines + lines)\n",
+        "Syntax Error -> Invalid operation Plus on List types, line 3:13."
+    ),
+];
+
+
+/* `{path}` Is Replaced By A File Of The Test Folder */
+const LIST_PROGRAM: &'static str = "var lines = read_lines(\"{path}\")
+print(len(lines), end=\"\\n\")
+for i in (0, len(lines), 1){
+    print(i, get(lines, i), sep=\": \", end=\"\\n\")
+}
+var missing = read_lines(\"{path}.missing\")
+print(lines, missing, len(missing), end=\"\\n\")
+lines = missing
+print(len(lines), format(\"{}\", read_lines(\"{path}\")), end=\"\\n\")
+print(get(lines, 0))
+";
+
+
+/* A Program With Every Warning Found By The Dataflow Analyses, And The Warnings */
+const WARNED_PROGRAM: &'static str = "int unused = 3
+int total = 0
+for i in (0, 5, 1){
+    if i == 3{
+        break
+        print(i)
+    }
+    total += i
+}
+print(total)
+";
+
+/* Branches On Constants And Loops Without Effects Are Removed */
+const DEAD_CODE_PROGRAM: &'static str = "const bool DEBUG = False
+if DEBUG{
+    print(\"debug\")
+}
+for i in (0, 1000, 1){
+    int square = i * i
+}
+if True{
+    print(\"yes\")
+}
+else{
+    print(\"no\")
+}
+";
+
+const DEAD_CODE_WARNINGS: [&'static str; 4] = [
+    "Analyze Warning -> Value assigned to `square` is never read, line 6:9.",
+    "Analyze Warning -> Unreachable code, the condition is always `False`, line 3:5.",
+    "Analyze Warning -> Loop has no effect and is removed, line 5:5.",
+    "Analyze Warning -> Unreachable code, the condition is always `True`, line 12:5.",
+];
+
+/* The Interpreter Runs The Program As It Is Written, Without Removing The Dead Code */
+const WARNINGS: [&'static str; 2] = [
+    "Analyze Warning -> Value assigned to `unused` is never read, line 1:5.",
+    "Analyze Warning -> Unreachable code after `break` or `continue`, line 6:9.",
+];
+
+
+/* The Program Output, Without The Warnings Printed Before Running It */
+fn get_program_text(output: &Output) -> String{
+    let text = get_text(output);
+
+    return text.lines()
+        .filter(|line| !line.contains(" Analyze Warning -> "))
+        .map(|line| format!("{}\n", line))
+        .collect();
+}
+
+
+fn run_in_every_mode(source_path: &String) -> Vec<(&'static str, Output)>{
+    let mut outputs = vec![];
+
+    outputs.push(("interpreter", engine(&[source_path])));
+
+    let output = engine(&[source_path, "--byte-code", "-O0"]);
+    if output.status.success(){
+        outputs.push(("vm", engine(&[&format!("{}.byte", source_path), "--vm"])));
+    }
+    else{
+        outputs.push(("vm", output));
+    }
+
+    let output = engine(&[source_path, "--executable"]);
+    if output.status.success(){
+        let executable_path = source_path.strip_suffix(".en").unwrap();
+        outputs.push(("executable", Command::new(executable_path).output().unwrap()));
+    }
+    else{
+        outputs.push(("executable", output));
+    }
+
+    return outputs;
+}
+
+
+#[test]
+fn every_mode_accepts_the_same_programs(){
+    for (index, program) in ACCEPTED_PROGRAMS.iter().enumerate(){
+        let (folder, source_path) = write_program(
+            &format!("engine_conformance_accepted_{}", index), program);
+
+        let outputs = run_in_every_mode(&source_path);
+        let expected_output = get_program_text(&outputs[0].1);
+
+        for (mode, output) in &outputs{
+            assert!(
+                output.status.success(),
+                "{} rejected program {}: {}", mode, index, get_text(output));
+            assert_eq!(
+                get_program_text(output), expected_output, "{} output of program {}", mode, index);
+        }
+
+        std::fs::remove_dir_all(&folder).unwrap();
+    }
+}
+
+
+/* The VM Output Of The Byte Code Generated With Every Optimization Level */
+#[test]
+fn optimized_byte_code_prints_the_same_output(){
+    let mut is_any_optimized = false;
+    for (index, program) in ACCEPTED_PROGRAMS.iter().enumerate(){
+        let (folder, source_path) = write_program(
+            &format!("engine_conformance_optimized_{}", index), program);
+        let byte_code_path = format!("{}.byte", source_path);
+
+        let mut outputs = vec![];
+        for optimization_level in ["-O0", "-O1"]{
+            let output = engine(&[&source_path, "--byte-code", optimization_level]);
+            assert!(
+                output.status.success(),
+                "{} rejected program {}: {}", optimization_level, index, get_text(&output));
+
+            let output = engine(&[&byte_code_path, "--vm"]);
+            assert!(
+                output.status.success(),
+                "{} byte code of program {} failed: {}", optimization_level, index, get_text(&output));
+
+            outputs.push((std::fs::read_to_string(&byte_code_path).unwrap(), get_text(&output)));
+        }
+
+        assert_eq!(outputs[1].1, outputs[0].1, "optimized output of program {}", index);
+        assert!(
+            outputs[1].0.lines().count() <= outputs[0].0.lines().count(),
+            "optimized byte code of program {} is longer", index);
+        is_any_optimized |= outputs[1].0.lines().count() < outputs[0].0.lines().count();
+
+        std::fs::remove_dir_all(&folder).unwrap();
+    }
+    assert!(is_any_optimized, "`-O1` didn't remove any instruction");
+}
+
+
+/* The Legacy Instructions Executers Run The Same Byte Code As The Machine */
+#[test]
+fn legacy_vm_prints_the_same_output(){
+    for (index, program) in ACCEPTED_PROGRAMS.iter().enumerate(){
+        let (folder, source_path) = write_program(
+            &format!("engine_conformance_legacy_{}", index), program);
+        let byte_code_path = format!("{}.byte", source_path);
+
+        for optimization_level in ["-O0", "-O1"]{
+            let output = engine(&[&source_path, "--byte-code", optimization_level]);
+            assert!(output.status.success(), "program {}: {}", index, get_text(&output));
+
+            let output = engine(&[&byte_code_path, "--vm"]);
+            let legacy_output = engine(&[&byte_code_path, "--vm", "--legacy-vm"]);
+
+            assert!(output.status.success(), "program {}: {}", index, get_text(&output));
+            assert!(legacy_output.status.success(), "program {}: {}", index, get_text(&legacy_output));
+            assert_eq!(
+                get_text(&legacy_output), get_text(&output),
+                "legacy output of program {} at {}", index, optimization_level);
+        }
+
+        std::fs::remove_dir_all(&folder).unwrap();
+    }
+}
+
+
+/* Integers Wrap Around And Dividing By Zero Stops The Compiled Programs Like The VM */
+#[test]
+fn executables_print_like_the_vm(){
+    for (index, (program, input)) in PROGRAMS.iter().enumerate(){
+        let (folder, source_path) = write_program(
+            &format!("engine_conformance_executable_{}", index), program);
+
+        let output = engine(&[&source_path, "--byte-code", "-O0"]);
+        assert!(output.status.success(), "{}", get_text(&output));
+        let vm_output = run(
+            env!("CARGO_BIN_EXE_engine"), &[&format!("{}.byte", source_path), "--vm"], input);
+
+        let output = engine(&[&source_path, "--executable"]);
+        assert!(output.status.success(), "compiler rejected program {}: {}", index, get_text(&output));
+
+        let executable_output = run(folder.join("program").to_str().unwrap(), &[], input);
+
+        if vm_output.status.success(){
+            assert!(executable_output.status.success(), "program {}: {}", index, get_text(&executable_output));
+            assert_eq!(get_text(&executable_output), get_text(&vm_output), "output of program {}", index);
+        }
+        else{
+            assert_eq!(executable_output.status.code(), Some(101), "exit code of program {}", index);
+            assert_eq!(
+                String::from_utf8_lossy(&executable_output.stdout).lines().next(),
+                String::from_utf8_lossy(&vm_output.stdout).lines().next(),
+                "first line of program {}", index);
+        }
+
+        std::fs::remove_dir_all(&folder).unwrap();
+    }
+
+    let (folder, source_path) = write_program(
+        "engine_conformance_executable_zero", "int n = input() as int\nprint(10 % n, \"\\n\")\n");
+
+    let output = engine(&[&source_path, "--executable"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+
+    let output = run(folder.join("program").to_str().unwrap(), &[], "0\n");
+    assert_eq!(output.status.code(), Some(101));
+    assert_eq!(get_text(&output), "Engine Compiler: Compiler Error -> Division by zero, line: 2:1\n");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn every_mode_rejects_the_same_programs(){
+    for (index, (program, error)) in REJECTED_PROGRAMS.iter().enumerate(){
+        let (folder, source_path) = write_program(
+            &format!("engine_conformance_rejected_{}", index), program);
+
+        for (mode, output) in &run_in_every_mode(&source_path){
+            let text = get_text(output);
+
+            /* The Message Is Prefixed By The Mode Name, `Engine Interpreter: ...` */
+            let message = text.lines()
+                .find(|line| line.contains(" Error -> "))
+                .map(|line| line.splitn(2, ": ").nth(1).unwrap());
+
+            assert!(!output.status.success(), "{} accepted program {}", mode, index);
+            assert!(!text.contains("never"), "{} ran program {} before rejecting it", mode, index);
+            assert_eq!(message, Some(*error), "{} error of program {}", mode, index);
+        }
+
+        std::fs::remove_dir_all(&folder).unwrap();
+    }
+}
+
+
+#[test]
+fn lists_are_read_like_in_every_mode(){
+    let (folder, source_path) = write_program("engine_conformance_lists", "");
+
+    let text_path = folder.join("lines.txt");
+    std::fs::write(&text_path, "one, \"two\"\n\\three\n\nfour").unwrap();
+    std::fs::write(
+        &source_path, LIST_PROGRAM.replace("{path}", text_path.to_str().unwrap())).unwrap();
+
+    let items = "[\"one, \\\"two\\\"\", \"\\\\three\", \"\", \"four\"]";
+    let expected_output = format!(
+        "4\n0: one, \"two\"\n1: \\three\n2: \n3: four\n{} [] 0\n0 {}\n", items, items);
+
+    for (mode, output) in &run_in_every_mode(&source_path){
+        let text = get_program_text(output);
+
+        assert!(!output.status.success(), "{} didn't stop at `get`", mode);
+        assert!(text.starts_with(&expected_output), "{} output: {}", mode, text);
+        assert!(
+            text[expected_output.len()..].contains("`get` index 0 is out of bounds for 0 items"),
+            "{} error: {}", mode, text);
+    }
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+/* The Arguments After `--`, Or After The Executable Name */
+#[test]
+fn program_arguments_are_a_list_in_every_mode(){
+    let (folder, source_path) = write_program(
+        "engine_conformance_arguments",
+        "var arguments = args()\nprint(len(arguments), arguments, get(arguments, 1), end=\"\\n\")\n");
+
+    let output = engine(&[&source_path, "--byte-code"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+    let output = engine(&[&source_path, "--executable"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+
+    let byte_code_path = format!("{}.byte", source_path);
+    let outputs = [
+        engine(&[&source_path, "--", "a", "b c"]),
+        engine(&[&byte_code_path, "--vm", "--", "a", "b c"]),
+        run(folder.join("program").to_str().unwrap(), &["a", "b c"], ""),
+    ];
+    for output in &outputs{
+        assert_eq!(get_program_text(output), "2 [\"a\", \"b c\"] b c\n");
+    }
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn emit_ir_prints_the_lowered_program(){
+    let (folder, source_path) = write_program(
+        "engine_conformance_ir", "int total = 0\nfor k in (0, 3, 1){\n    total += k\n}\nprint(total)\n");
+
+    let output = engine(&[&source_path, "--emit=ir"]);
+    let text = get_text(&output);
+
+    assert!(output.status.success(), "{}", text);
+    assert!(text.contains("int stack1_variable_total"), "{}", text);
+    assert!(text.contains("stack1_variable_total = stack1_variable_total + stack2_variable_k"), "{}", text);
+    assert!(text.contains("goto block_"), "{}", text);
+    assert!(text.trim_end().ends_with("end"), "{}", text);
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn constant_expressions_are_folded(){
+    let (folder, source_path) = write_program(
+        "engine_conformance_folded", "const int HOURS = 24\nint x = 60 * 60 * HOURS\nprint(x, \"a\" + 'b')\n");
+
+    let output = engine(&[&source_path, "--emit=ir"]);
+    let text = get_text(&output);
+
+    assert!(output.status.success(), "{}", text);
+    assert!(text.contains("= 86400"), "{}", text);
+    assert!(text.contains("= \"ab\""), "{}", text);
+    assert!(!text.contains(" * "), "{}", text);
+    assert!(!text.contains(" + "), "{}", text);
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+/* Doubles Divided By Zero Are `inf` And `NaN` Like At Runtime, Not Constant Errors */
+#[test]
+fn constant_double_division_by_zero_is_not_an_error(){
+    let (folder, source_path) = write_program(
+        "engine_conformance_double_zero", "double x = 1.0 / 0\nprint(x, 1 / 0, 0.0 / 0, 1.5 % 0, end=\"\\n\")\n");
+
+    for (mode, output) in run_in_every_mode(&source_path){
+        assert!(output.status.success(), "{} rejected the program: {}", mode, get_text(&output));
+        assert_eq!(get_program_text(&output), "inf inf NaN NaN\n", "{} output", mode);
+    }
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn dead_code_is_removed_with_a_warning(){
+    let (folder, source_path) = write_program("engine_conformance_dead_code", DEAD_CODE_PROGRAM);
+
+    let output = engine(&[&source_path, "--emit=ir"]);
+    let text = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{}", get_text(&output));
+    assert!(!text.contains("\"debug\""), "{}", text);
+    assert!(!text.contains("\"no\""), "{}", text);
+    assert!(!text.contains(" < "), "{}", text);
+    assert!(!text.contains("goto"), "{}", text);
+
+    let warnings: Vec<&str> = std::str::from_utf8(&output.stderr).unwrap().lines()
+        .map(|line| line.splitn(2, ": ").nth(1).unwrap())
+        .collect();
+    assert_eq!(warnings, DEAD_CODE_WARNINGS);
+
+    let output = engine(&[&source_path]);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "yes");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn every_mode_reports_the_same_warnings(){
+    let (folder, source_path) = write_program("engine_conformance_warned", WARNED_PROGRAM);
+
+    let outputs = [
+        ("interpreter", engine(&[&source_path])),
+        ("byte code generator", engine(&[&source_path, "--byte-code"])),
+        ("compiler", engine(&[&source_path, "--executable"])),
+        ("analyzer", engine(&[&source_path, "--analyze-code"])),
+    ];
+
+    for (mode, output) in &outputs{
+        assert!(output.status.success(), "{} rejected the program: {}", mode, get_text(output));
+
+        let warnings: Vec<&str> = std::str::from_utf8(&output.stderr).unwrap().lines()
+            .map(|line| line.splitn(2, ": ").nth(1).unwrap())
+            .collect();
+        if *mode == "interpreter"{
+            assert_eq!(warnings, WARNINGS[..1], "{} warnings", mode);
+        }
+        else{
+            assert_eq!(warnings, WARNINGS, "{} warnings", mode);
+        }
+    }
+
+    assert_eq!(String::from_utf8_lossy(&outputs[0].1.stdout), "3");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+/* The Loop Variable Is Written Every Iteration, Counting Loops Don't Have To Read It */
+#[test]
+fn unread_loop_variables_are_not_warned(){
+    let (folder, source_path) = write_program(
+        "engine_conformance_loop_variable", "int total = 0\nfor i in (0, 3, 1){\n    total += 2\n}\nprint(total, end=\"\\n\")\n");
+
+    let outputs = [
+        ("interpreter", engine(&[&source_path])),
+        ("byte code generator", engine(&[&source_path, "--byte-code"])),
+        ("compiler", engine(&[&source_path, "--executable"])),
+        ("analyzer", engine(&[&source_path, "--analyze-code"])),
+    ];
+
+    for (mode, output) in &outputs{
+        assert!(output.status.success(), "{} rejected the program: {}", mode, get_text(output));
+        assert_eq!(String::from_utf8_lossy(&output.stderr), "", "{} warnings", mode);
+    }
+
+    assert_eq!(String::from_utf8_lossy(&outputs[0].1.stdout), "6\n");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn files_without_a_folder_are_generated_in_the_current_folder(){
+    let (folder, _) = write_program("engine_conformance_current_folder", ACCEPTED_PROGRAMS[0]);
+
+    for (arguments, generated_file) in [
+        (["--byte-code"], "program.en.byte"),
+        (["--emit=c"], "program.c"),
+    ]{
+        let output = Command::new(env!("CARGO_BIN_EXE_engine"))
+            .arg("program.en")
+            .args(arguments)
+            .current_dir(&folder)
+            .output()
+            .unwrap();
+
+        assert!(output.status.success(), "{}", get_text(&output));
+        assert!(folder.join(generated_file).exists(), "{} wasn't generated", generated_file);
+    }
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,94 @@
+use std::process::Output;
+
+mod common;
+
+use common::{
+    engine,
+    run,
+    get_text,
+    write_program
+};
+
+
+const PROGRAM: &'static str = "int total = 5
+string s = \"ab\"
+total = total + 1
+print(total, s, end=\"\\n\")
+";
+
+
+fn debug(file_path: &str, commands: &str) -> Output{
+    return run(env!("CARGO_BIN_EXE_engine"), &["debug", file_path], commands);
+}
+
+
+#[test]
+fn interpreter_debugger_evaluates_expressions(){
+    let (folder, source_path) = write_program("engine_debugger_interpreter", PROGRAM);
+
+    let output = debug(&source_path, "n\nn\np total\np (total * 2) - 3 > 5\np total / 0\np total % 0\nw s + \"c\"\nn\nq\n");
+    let text = get_text(&output);
+
+    assert!(text.contains("    total: Int = 5\n"), "{}", text);
+    assert!(text.contains("    (total * 2) - 3 > 5 = True\n"), "{}", text);
+    assert!(text.contains("Watch:\n    s + \"c\" = abc\n"), "{}", text);
+    assert!(text.contains("    total / 0 = inf\n"), "{}", text);
+    assert!(text.contains("    total % 0 = <Division by zero>\n"), "{}", text);
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn vm_debugger_evaluates_expressions(){
+    let (folder, source_path) = write_program("engine_debugger_vm", PROGRAM);
+
+    let output = engine(&[&source_path, "-b"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+
+    let byte_code_path = format!("{}.byte", source_path);
+    let output = debug(&byte_code_path, "n\nn\np total + 1\np total + \"cd\"\nq\n");
+    let text = get_text(&output);
+
+    assert!(text.contains("    total + 1 = 7\n"), "{}", text);
+    assert!(text.contains("    total + \"cd\" = <can't do `+` on `Integer` and `String`>\n"), "{}", text);
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn extra_arguments_are_rejected(){
+    let (folder, source_path) = write_program("engine_debugger_arguments", PROGRAM);
+
+    let output = debug(&source_path, "p total 1\nw total +\nstep now\nq\n");
+    let text = get_text(&output);
+
+    assert!(text.contains("Invalid expression `total 1`: expected an operator, found `1`.\n"), "{}", text);
+    assert!(text.contains("Invalid expression `total +`: expected a value at the end.\n"), "{}", text);
+    assert!(text.contains("Unknown command `step now`, type `help` for commands.\n"), "{}", text);
+    assert!(!text.contains("Watch:"), "{}", text);
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+/* The Expressions Are Read By The Lexer Of The Programs */
+#[test]
+fn expressions_are_read_like_the_programs(){
+    let (folder, source_path) = write_program(
+        "engine_debugger_lexer", "int total = 5\ndouble E = 2\nprint(total, E, end=\"\\n\")\n");
+
+    let output = debug(&source_path, "n\nn\np total-1\np -1 + total\np PI > 3\np E * 2\np \"é\" + 'x'\np \"ab\np total = 1\nq\n");
+    let text = get_text(&output);
+
+    assert!(text.contains("    total-1 = 4\n"), "{}", text);
+    assert!(text.contains("    -1 + total = 4\n"), "{}", text);
+    assert!(text.contains("    PI > 3 = True\n"), "{}", text);
+    assert!(text.contains("    E * 2 = 4\n"), "{}", text);
+    assert!(text.contains("    \"é\" + 'x' = éx\n"), "{}", text);
+    assert!(text.contains("Invalid expression `\"ab`: End of file reached.\n"), "{}", text);
+    assert!(text.contains("Invalid expression `total = 1`: unexpected `=`.\n"), "{}", text);
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,205 @@
+use std::process::Command;
+
+mod common;
+
+use common::{
+    PROGRAMS,
+    engine,
+    run,
+    get_text,
+    write_program
+};
+
+
+#[test]
+fn c_executables_print_like_the_vm(){
+    for (index, (program, input)) in PROGRAMS.iter().enumerate(){
+        let (folder, source_path) = write_program(&format!("engine_emit_c_{}", index), program);
+
+        let output = engine(&[&source_path, "--byte-code", "-O0"]);
+        assert!(output.status.success(), "{}", get_text(&output));
+        let vm_output = run(
+            env!("CARGO_BIN_EXE_engine"), &[&format!("{}.byte", source_path), "--vm"], input);
+
+        let output = engine(&[&source_path, "--emit=c", "--cc"]);
+        assert!(output.status.success(), "C backend rejected program {}: {}", index, get_text(&output));
+
+        let c_output = run(folder.join("program").to_str().unwrap(), &[], input);
+
+        assert_eq!(
+            String::from_utf8_lossy(&c_output.stdout).lines().next(),
+            String::from_utf8_lossy(&vm_output.stdout).lines().next(),
+            "first line of program {}", index);
+
+        if vm_output.status.success(){
+            assert!(c_output.status.success(), "program {}: {}", index, get_text(&c_output));
+            assert_eq!(get_text(&c_output), get_text(&vm_output), "output of program {}", index);
+        }
+        else{
+            assert_eq!(c_output.status.code(), Some(101), "exit code of program {}", index);
+        }
+
+        std::fs::remove_dir_all(&folder).unwrap();
+    }
+}
+
+
+#[test]
+fn emit_c_writes_the_file_and_its_runtime_header(){
+    let (folder, source_path) = write_program(
+        "engine_emit_c_files", "int n = input() as int\nprint(10 % n, \"\\n\")\n");
+
+    let output = engine(&[&source_path, "--emit=c"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+
+    let c_code = std::fs::read_to_string(folder.join("program.c")).unwrap();
+    assert!(c_code.starts_with("#include \"engine_runtime.h\""), "{}", c_code);
+    assert!(folder.join("engine_runtime.h").exists());
+    assert!(!folder.join("program").exists(), "the C file is compiled without `--cc`");
+
+    /* The Generated File Is Plain C99, Compiled Here Without The Engine */
+    let output = Command::new("cc")
+        .args(["-std=c99", "-pedantic", "-o", "program", "program.c", "-lm"])
+        .current_dir(&folder)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", get_text(&output));
+
+    let executable_path = folder.join("program");
+    let executable_path = executable_path.to_str().unwrap();
+
+    let output = run(executable_path, &[], "x\n");
+    assert_eq!(output.status.code(), Some(101));
+    assert_eq!(
+        get_text(&output),
+        "Engine Compiler: Convert Error -> can't convert from `String` to `Int`, line: 1:20\n");
+
+    let output = run(executable_path, &[], "0\n");
+    assert_eq!(output.status.code(), Some(101));
+    assert!(get_text(&output).contains("Division by zero, line: 2:"), "{}", get_text(&output));
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+const BUILTINS_PROGRAM: &'static str = "string name = \"héllo wörld\"
+print(len(name), \" \", substring(name, 1, 5), \" \", find(name, \"wö\"), \" \", find(name, \"zz\"), \"\\n\")
+print(replace(name, \"ö\", \"o\"), \" \", replace(\"aaaa\", \"aa\", \"b\"), \" \", replace(\"abc\", \"b\", \"\"), \"\\n\")
+print(split(\"a,b,c\", \",\", 1), \" \", split(\"aaa\", \"aa\", 1), \" \", split(\",x,\", \",\", 2), \"|\\n\")
+print(\"[\", trim(\"  x y \\t\\n\"), \"] [\", trim(\" \"), \"] [\", trim(\"\u{3000}é\u{a0}\"), \"]\\n\")
+print(to_upper(\"abc Z1\"), \" \", to_lower(\"ABC z1\"), \"\\n\")
+print(starts_with(name, \"hé\"), \" \", ends_with(name, \"ld\"), \" \", contains(name, \"lo w\"), \" \", contains(name, \"\"), \"\\n\")
+print(repeat(\"ab\", 3), \" [\", repeat(\"ab\", 0), \"] \", repeat('x', 2), \" \", len('é'), \" \", find(\"a-b\", '-'), \"\\n\")
+print(abs(-5), \" \", abs(-2.5), \" \", min(3, 7.5), \" \", max(3, 7), \" \", pow(2, 10), \" \", pow(2.0, 0.5), \"\\n\")
+print(sqrt(2), \" \", floor(2.7), \" \", ceil(2.1), \" \", round(-2.5), \" \", sin(PI / 6), \" \", log(E), \"\\n\")
+name = trim(name + \"  \")
+int n = input() as int
+if n == 1{
+    print(substring(name, 3, 20))
+}
+if n == 2{
+    print(replace(name, \"\", \"x\"))
+}
+if n == 3{
+    print(split(name, \" \", 5))
+}
+if n == 4{
+    print(repeat(name, 0 - 2))
+}
+if n == 5{
+    print(pow(n, 50))
+}
+if n == 6{
+    print(ceil(input() as double))
+}
+print(name, \"\\n\")
+";
+
+
+#[test]
+fn string_and_math_functions_run_like_the_vm(){
+    let (folder, source_path) = write_program("engine_emit_c_builtins", BUILTINS_PROGRAM);
+
+    let output = engine(&[&source_path, "--byte-code", "-O0"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+    let output = engine(&[&source_path, "--emit=c", "--cc"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+
+    let executable_path = folder.join("program");
+    let executable_path = executable_path.to_str().unwrap();
+
+    for input in ["0\n", "1\n", "2\n", "3\n", "4\n", "5\n", "6\n1e20\n"]{
+        let vm_output = run(
+            env!("CARGO_BIN_EXE_engine"), &[&format!("{}.byte", source_path), "--vm"], input);
+        let c_output = run(executable_path, &[], input);
+        let vm_text = get_text(&vm_output);
+        let c_text = get_text(&c_output);
+
+        if vm_output.status.success(){
+            assert!(c_output.status.success(), "input {:?}: {}", input, c_text);
+            assert_eq!(c_text, vm_text, "output of input {:?}", input);
+            continue;
+        }
+
+        /* The VM Names Its Instruction Where The Executable Names The Line */
+        let vm_message = vm_text.lines().last().unwrap().split(", instruction line").next().unwrap();
+        let vm_message = vm_message.replace("Engine VM: Call Instruction -> ", "");
+        assert_eq!(c_output.status.code(), Some(101), "exit code of input {:?}", input);
+        assert!(
+            c_text.lines().last().unwrap().starts_with(
+                &format!("Engine Compiler: Builtin Error -> {}, line: ", vm_message)),
+            "input {:?}: {}", input, c_text);
+    }
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn case_of_non_ascii_text_is_an_error(){
+    let (folder, source_path) = write_program(
+        "engine_emit_c_case", "print(to_upper(\"abc\"), \"\\n\")\nprint(to_lower(\"ÀB\"), \"\\n\")\n");
+
+    let output = engine(&[&source_path, "--emit=c", "--cc"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+
+    let output = run(folder.join("program").to_str().unwrap(), &[], "");
+    assert_eq!(output.status.code(), Some(101));
+    assert_eq!(
+        get_text(&output),
+        "ABC \nEngine Compiler: Builtin Error -> `to_lower` only supports ASCII text in the C backend, line: 2:7\n");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn other_builtin_functions_are_rejected_before_writing_the_files(){
+    let (folder, source_path) = write_program(
+        "engine_emit_c_rejected", "print(len(\"abc\"))\nprint(read_file(\"data.txt\"))\n");
+
+    let output = engine(&[&source_path, "--emit=c", "--cc"]);
+
+    assert!(!output.status.success());
+    assert!(
+        get_text(&output).contains(
+            "Code Generation Error -> Function `read_file` is not supported by the C backend, line: 2:7"),
+        "{}", get_text(&output));
+    assert!(!folder.join("program.c").exists());
+    assert!(!folder.join("engine_runtime.h").exists());
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn cc_requires_emit_c(){
+    let (folder, source_path) = write_program("engine_emit_c_cc", "print(1)\n");
+
+    let output = engine(&[&source_path, "--emit=ir", "--cc"]);
+
+    assert!(!output.status.success());
+    assert!(get_text(&output).contains("`--cc` compiles the file written by `--emit=c`"), "{}", get_text(&output));
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,175 @@
+use std::process::Command;
+
+mod common;
+
+use common::{
+    PROGRAMS,
+    engine,
+    run,
+    get_text,
+    write_program
+};
+
+
+/* `WASM_RUNTIME` Names A Program Given The Module Path, Otherwise Wasmtime Or Wasmer */
+fn get_wasm_runtime() -> Option<(String, Vec<&'static str>)>{
+    let runtime = std::env::var("WASM_RUNTIME");
+    if runtime.is_ok() && !runtime.as_ref().unwrap().is_empty(){
+        return Some((runtime.unwrap(), vec![]));
+    }
+
+    for runtime in ["wasmtime", "wasmer"]{
+        let output = Command::new(runtime).arg("--version").output();
+        if output.is_ok() && output.unwrap().status.success(){
+            return Some((String::from(runtime), vec!["run"]));
+        }
+    }
+
+    return None;
+}
+
+
+/* Assembled By The `wat` Crate Then Checked By The Validator Of The Runtimes */
+fn validate_wat_module(text: &str) -> Result<(), String>{
+    let binary = wat::parse_str(text);
+    if binary.is_err(){
+        return Err(binary.unwrap_err().to_string());
+    }
+
+    if let Err(error) = wasmparser::Validator::new().validate_all(&binary.unwrap()){
+        return Err(error.to_string());
+    }
+
+    return Ok(());
+}
+
+
+#[test]
+fn wat_modules_are_well_formed(){
+    for (index, (program, _)) in PROGRAMS.iter().enumerate(){
+        let (folder, source_path) = write_program(&format!("engine_emit_wat_valid_{}", index), program);
+
+        let output = engine(&[&source_path, "--emit=wat"]);
+        assert!(output.status.success(), "WebAssembly backend rejected program {}: {}", index, get_text(&output));
+
+        let text = String::from_utf8_lossy(&output.stdout);
+        let result = validate_wat_module(&text);
+        assert!(result.is_ok(), "module of program {}: {}", index, result.unwrap_err());
+
+        std::fs::remove_dir_all(&folder).unwrap();
+    }
+}
+
+
+#[test]
+fn wat_validation_finds_broken_modules(){
+    let (folder, source_path) = write_program("engine_emit_wat_broken", PROGRAMS[0].0);
+
+    let output = engine(&[&source_path, "--emit=wat"]);
+    let text = String::from_utf8_lossy(&output.stdout).to_string();
+    assert!(validate_wat_module(&text).is_ok());
+
+    for (from, to) in [
+        ("call $alloc", "call $allocate"),
+        ("local.get $size", "local.get $length_of_size"),
+        ("    end)", "    )"),
+        ("i32.add", "i64.add"),
+        ("br $grow", "br $grown"),
+    ]{
+        assert!(text.contains(from), "`{}` isn't in the module", from);
+        assert!(validate_wat_module(&text.replacen(from, to, 1)).is_err(), "`{}` wasn't found", to);
+    }
+    assert!(validate_wat_module(&text[..text.len() - 2]).is_err());
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+/* Runs The Modules When A Runtime Is Installed, The Test Is Skipped Otherwise */
+#[test]
+fn wasm_modules_print_like_the_vm(){
+    let runtime = get_wasm_runtime();
+    if runtime == None{
+        eprintln!("No WebAssembly runtime found, install wasmtime or wasmer or set `WASM_RUNTIME` to run the modules.");
+        return;
+    }
+    let (runtime, runtime_args) = runtime.unwrap();
+
+    for (index, (program, input)) in PROGRAMS.iter().enumerate(){
+        let (folder, source_path) = write_program(&format!("engine_emit_wat_{}", index), program);
+
+        let output = engine(&[&source_path, "--byte-code", "-O0"]);
+        assert!(output.status.success(), "{}", get_text(&output));
+        let vm_output = run(
+            env!("CARGO_BIN_EXE_engine"), &[&format!("{}.byte", source_path), "--vm"], input);
+
+        let output = engine(&[&source_path, "--emit=wat"]);
+        assert!(output.status.success(), "WebAssembly backend rejected program {}: {}", index, get_text(&output));
+
+        let module_path = folder.join("program.wat");
+        std::fs::write(&module_path, &output.stdout).unwrap();
+
+        let mut args = runtime_args.clone();
+        args.push(module_path.to_str().unwrap());
+        let wasm_output = run(&runtime, &args, input);
+
+        assert_eq!(
+            String::from_utf8_lossy(&wasm_output.stdout).lines().next(),
+            String::from_utf8_lossy(&vm_output.stdout).lines().next(),
+            "first line of program {}", index);
+
+        if vm_output.status.success(){
+            assert!(wasm_output.status.success(), "program {}: {}", index, get_text(&wasm_output));
+            assert_eq!(get_text(&wasm_output), get_text(&vm_output), "output of program {}", index);
+        }
+        else{
+            assert_eq!(wasm_output.status.code(), Some(101), "exit code of program {}", index);
+            assert!(
+                get_text(&wasm_output).ends_with(
+                    "Engine Compiler: Convert Error -> can't convert from `String` to `Int`, line: 3:20\n"),
+                "{}", get_text(&wasm_output));
+        }
+
+        std::fs::remove_dir_all(&folder).unwrap();
+    }
+}
+
+
+#[test]
+fn emit_wat_prints_a_wasi_module(){
+    let (folder, source_path) = write_program("engine_emit_wat_module", "string s = input()\nprint(s, \"!\\n\")\n");
+
+    let output = engine(&[&source_path, "--emit=wat"]);
+    let text = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{}", get_text(&output));
+    assert!(text.starts_with("(module"), "{}", text);
+    assert!(text.contains("(import \"wasi_snapshot_preview1\" \"fd_write\""), "{}", text);
+    assert!(text.contains("(import \"wasi_snapshot_preview1\" \"fd_read\""), "{}", text);
+    assert!(text.contains("(export \"_start\")"), "{}", text);
+    assert!(text.contains("(export \"memory\")"), "{}", text);
+    assert!(text.contains("call $input"), "{}", text);
+    assert!(text.contains("(data (i32.const 12288) \"C\\00\\00\\00Engine Compiler:"), "{}", text);
+    assert!(text.contains("\\02\\00\\00\\00!\\0a"), "{}", text);
+    assert_eq!(text.matches('(').count(), text.matches(')').count());
+    assert_eq!(std::fs::read_dir(&folder).unwrap().count(), 1, "only the source is in the folder");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn builtin_functions_are_rejected_by_the_wasm_backend(){
+    let (folder, source_path) = write_program(
+        "engine_emit_wat_builtins", "string s = \"engine\"\nprint(len(s))\n");
+
+    let output = engine(&[&source_path, "--emit=wat"]);
+
+    assert!(!output.status.success());
+    assert!(
+        get_text(&output).contains(
+            "Code Generation Error -> Function `len` is not supported by the WebAssembly backend, line: 2:7"),
+        "{}", get_text(&output));
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use std::process::Command;
+
+use common::{
+    engine,
+    get_text
+};
+
+
+#[test]
+fn import_cycles_are_rejected(){
+    let folder = std::env::temp_dir().join(format!("engine_imports_cycle_{}", std::process::id()));
+    std::fs::create_dir_all(&folder).unwrap();
+
+    std::fs::write(folder.join("first.en"), "import second\nint x = 1\nprint(x)\n").unwrap();
+    std::fs::write(folder.join("second.en"), "import first\nint y = 2\n").unwrap();
+
+    let first_path = folder.join("first.en");
+    let second_path = folder.join("second.en");
+    let expected_error = format!(
+        "Import Error -> Import cycle `{} -> {} -> {}`, line 1:8.",
+        first_path.display(), second_path.display(), first_path.display());
+
+    let output = engine(&[first_path.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(101), "{}", get_text(&output));
+    assert!(get_text(&output).starts_with("Engine Interpreter: "), "{}", get_text(&output));
+    assert!(get_text(&output).contains(&expected_error), "{}", get_text(&output));
+
+    let output = engine(&[first_path.to_str().unwrap(), "-b"]);
+    assert_eq!(output.status.code(), Some(101), "{}", get_text(&output));
+    assert!(get_text(&output).contains(&expected_error), "{}", get_text(&output));
+    assert!(!folder.join("first.en.byte").exists());
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn imported_variables_are_used_in_every_mode(){
+    let folder = std::env::temp_dir().join(format!("engine_imports_used_{}", std::process::id()));
+    std::fs::create_dir_all(&folder).unwrap();
+
+    /* `helper` Is Defined In Both Files, The Module Uses A Built-in Function */
+    std::fs::write(
+        folder.join("utils.en"), "string name = \"utils\"\nint helper = 7\nint size = len(name) + helper\n").unwrap();
+    std::fs::write(
+        folder.join("main.en"),
+        "import utils\nint helper = utils.helper * 2\nprint(helper, utils.size, to_upper(utils.name), end=\"\\n\")\n"
+    ).unwrap();
+
+    let main_path = folder.join("main.en");
+    let main_path = main_path.to_str().unwrap();
+
+    let output = engine(&[main_path]);
+    assert!(output.status.success(), "{}", get_text(&output));
+    assert_eq!(get_text(&output), "14 12 UTILS\n");
+
+    let output = engine(&[main_path, "-b"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+    let output = engine(&[&format!("{}.byte", main_path), "--vm"]);
+    assert_eq!(get_text(&output), "14 12 UTILS\n");
+
+    let output = engine(&[main_path, "-e"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+    let output = Command::new(folder.join("main")).output().unwrap();
+    assert_eq!(get_text(&output), "14 12 UTILS\n");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,109 @@
+use std::process::Command;
+
+mod common;
+
+use common::{
+    engine,
+    get_text,
+    write_program
+};
+
+
+const PROGRAM: &'static str = "int x = 3
+string s = \"engine\"
+print(x * 2, s, end=\"\\n\")
+";
+
+
+#[test]
+fn generated_rust_file_is_removed_by_default(){
+    let (folder, source_path) = write_program("engine_keep_generated_default", PROGRAM);
+
+    let output = engine(&[&source_path, "-e"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+    assert!(!folder.join("program.rs").exists());
+
+    let output = Command::new(folder.join("program")).output().unwrap();
+    assert_eq!(get_text(&output), "6 engine\n");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn keep_generated_keeps_the_rust_file(){
+    let (folder, source_path) = write_program("engine_keep_generated_kept", PROGRAM);
+
+    let output = engine(&[&source_path, "-e", "--keep-generated"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+
+    let rust_code = std::fs::read_to_string(folder.join("program.rs")).unwrap();
+    assert!(rust_code.contains("fn main(){"), "{}", rust_code);
+
+    let output = Command::new(folder.join("program")).output().unwrap();
+    assert_eq!(get_text(&output), "6 engine\n");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn rustc_failures_are_reported(){
+    let (folder, source_path) = write_program("engine_keep_generated_failure", PROGRAM);
+
+    /* `rustc` Can't Write The Executable Over A Folder */
+    std::fs::create_dir_all(folder.join("program")).unwrap();
+
+    let output = engine(&[&source_path, "-e", "--keep-generated"]);
+    assert!(!output.status.success());
+    assert!(
+        get_text(&output).starts_with("Engine Compiler: Compiler Error -> `rustc` failed to compile"),
+        "{}", get_text(&output));
+    assert!(folder.join("program.rs").exists(), "the kept file is left for inspection");
+
+    std::fs::remove_file(folder.join("program.rs")).unwrap();
+
+    let output = engine(&[&source_path, "-e"]);
+    assert!(!output.status.success());
+    assert!(get_text(&output).contains("error"), "{}", get_text(&output));
+    assert!(!folder.join("program.rs").exists());
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn rustc_diagnostics_point_at_the_engine_line(){
+    let (folder, source_path) = write_program(
+        "engine_keep_generated_diagnostic", "int y = input_int()\nint z = y + 1\nprint(z)\n");
+
+    /* Debug Builds Add Invalid Rust After The Instructions Of The Line */
+    let output = Command::new(env!("CARGO_BIN_EXE_engine"))
+        .args([&source_path, "-e", "--keep-generated"])
+        .env("ENGINE_INJECT_RUST_ERROR", "2")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let text = get_text(&output);
+    assert!(text.starts_with("Engine Compiler: Compiler Error -> `rustc` failed to compile"), "{}", text);
+    assert!(
+        text.contains("error[E0308]: mismatched types: expected `i64`, found `&str`, line: 2:5\n"),
+        "{}", text);
+    assert!(!text.contains("program.rs:"), "{}", text);
+    assert!(folder.join("program.rs").exists(), "the kept file is left for inspection");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn keep_generated_requires_the_executable_mode(){
+    let (folder, source_path) = write_program("engine_keep_generated_mode", PROGRAM);
+
+    let output = engine(&[&source_path, "--byte-code", "--keep-generated"]);
+    assert!(!output.status.success());
+    assert!(!folder.join("program.rs").exists());
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,139 @@
+use std::process::Output;
+
+mod common;
+
+use common::{
+    engine,
+    run,
+    get_text,
+    write_program
+};
+
+
+const LOOP_PROGRAM: &'static str = "int total = 0
+for i in (0, 1000, 1){
+    total = total + i
+}
+print(total)
+";
+
+const MEMORY_PROGRAM: &'static str = "string s = \"abcdefghij\"
+for i in (0, 20, 1){
+    s = s + s
+}
+print(len(s))
+";
+
+
+fn assert_stopped(output: &Output, message: &str){
+    assert_eq!(output.status.code(), Some(101), "{}", get_text(output));
+    assert!(get_text(output).starts_with(message), "{}", get_text(output));
+}
+
+
+#[test]
+fn steps_limit_stops_the_program(){
+    let (folder, source_path) = write_program("engine_limits_steps", LOOP_PROGRAM);
+
+    let output = engine(&[&source_path, "--max-steps", "100"]);
+    assert_stopped(&output, "Engine Limits: Steps Limit -> Executed more than 100 statements, line: 2.\n");
+
+    let output = engine(&[&source_path, "-b"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+
+    let byte_code_path = format!("{}.byte", source_path);
+    let output = engine(&[&byte_code_path, "--vm", "--max-steps", "100"]);
+    assert_stopped(&output, "Engine Limits: Steps Limit -> Executed more than 100 instructions, line: ");
+
+    let output = engine(&[&source_path, "--max-steps", "100000"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "499500");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn memory_limit_stops_the_program(){
+    let (folder, source_path) = write_program("engine_limits_memory", MEMORY_PROGRAM);
+
+    let output = engine(&[&source_path, "--max-memory", "1000"]);
+    assert_stopped(
+        &output,
+        "Engine Limits: Memory Limit -> Variables hold 1280 bytes, more than the 1000 bytes limit, line: 3.\n");
+
+    let output = engine(&[&source_path, "-b"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+
+    let byte_code_path = format!("{}.byte", source_path);
+    let output = engine(&[&byte_code_path, "--vm", "--max-memory", "1000"]);
+    assert_stopped(
+        &output,
+        "Engine Limits: Memory Limit -> Variables hold 1280 bytes, more than the 1000 bytes limit, line: ");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn string_functions_check_the_memory_limit_first(){
+    let (folder, source_path) = write_program(
+        "engine_limits_string_functions",
+        "string s = repeat(\"ab\", 400)\nprint(len(replace(s, \"a\", \"xyz\")))\nprint(len(repeat(s, 100000000)))\n");
+
+    let output = engine(&[&source_path, "--max-memory", "1600"]);
+    assert_stopped(&output, "1600Engine Interpreter: Execute Error -> `repeat` needs more than the 1600 bytes memory limit, line 3:11.\n");
+
+    let output = engine(&[&source_path, "--max-memory", "1599"]);
+    assert_stopped(&output, "Engine Interpreter: Execute Error -> `replace` needs more than the 1599 bytes memory limit, line 2:11.\n");
+
+    let output = engine(&[&source_path, "-b"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+
+    let byte_code_path = format!("{}.byte", source_path);
+    let output = engine(&[&byte_code_path, "--vm", "--max-memory", "5000"]);
+    assert_stopped(&output, "1600Engine VM: Call Instruction -> `repeat` needs more than the 5000 bytes memory limit");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn file_functions_check_the_memory_limit_first(){
+    let (folder, source_path) = write_program("engine_limits_file_functions", "");
+
+    let text_path = folder.join("text.txt");
+    std::fs::write(&text_path, "line\n".repeat(1000)).unwrap();
+
+    let program = format!(
+        "print(len(read_lines(\"{0}\")), end=\"\\n\")\nprint(len(read_file(\"{0}\")))\n",
+        text_path.display());
+    std::fs::write(&source_path, program).unwrap();
+
+    let output = engine(&[&source_path, "--max-memory", "5000"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+    assert_eq!(get_text(&output), "1000\n5000");
+
+    let output = engine(&[&source_path, "--max-memory", "4999"]);
+    assert_stopped(&output, "Engine Interpreter: Execute Error -> `read_lines` needs more than the 4999 bytes memory limit, line 1:11.\n");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn input_is_not_read_past_the_memory_limit(){
+    let (folder, source_path) = write_program(
+        "engine_limits_input", "string text = read_all_stdin()\nprint(len(text))\n");
+
+    let input = "line\n".repeat(100000);
+
+    let output = run(env!("CARGO_BIN_EXE_engine"), &[&source_path, "--max-memory", "500000"], &input);
+    assert!(output.status.success(), "{}", get_text(&output));
+    assert_eq!(get_text(&output), "500000");
+
+    let output = run(env!("CARGO_BIN_EXE_engine"), &[&source_path, "--max-memory", "1000"], &input);
+    assert_stopped(&output, "Engine Interpreter: Execute Error -> `read_all_stdin` needs more than the 1000 bytes memory limit, line 1:15.\n");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,127 @@
+use std::process::Output;
+
+mod common;
+
+use common::{
+    engine,
+    write_program
+};
+
+
+/* A Valid Program With One Finding For Every Rule */
+const LINTED_PROGRAM: &'static str = "int total = 0
+int unused = 5
+int _ignored = 1
+for i in (0, 10, 0){
+    int total = i
+    print(total)
+}
+total = total
+if total == total{
+    print(\"same\\n\")
+}
+string name = input() as string
+print(name)
+";
+
+const FINDINGS: [&'static str; 6] = [
+    "Lint Warning -> Variable `unused` is never used (unused_variables), line 2:5.",
+    "Lint Warning -> Loop step is `0`, the loop variable never changes (zero_step), line 4:18.",
+    "Lint Warning -> Variable `total` shadows a variable of an outer scope (shadowing), line 5:9.",
+    "Lint Warning -> Variable `total` is assigned to itself (self_assignment), line 8:1.",
+    "Lint Warning -> Both sides of the comparison are the same, it is always `True` (self_comparison), line 9:10.",
+    "Lint Warning -> `input()` already returns a `string`, the `as string` conversion is needless (needless_conversion), line 12:26.",
+];
+
+
+fn get_findings(output: &Output) -> Vec<String>{
+    return String::from_utf8_lossy(&output.stderr).lines()
+        .map(|line| String::from(line.splitn(2, ": ").nth(1).unwrap()))
+        .collect();
+}
+
+
+#[test]
+fn every_rule_is_reported_as_a_warning(){
+    let (folder, source_path) = write_program("engine_lint_rules", LINTED_PROGRAM);
+
+    let output = engine(&["lint", &source_path]);
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+    assert_eq!(get_findings(&output), FINDINGS);
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn config_file_sets_the_rules_levels(){
+    let (folder, source_path) = write_program("engine_lint_config", LINTED_PROGRAM);
+    std::fs::write(
+        folder.join("engine.lint"),
+        "# Project Rules\nunused_variables = allow\nzero_step = deny\n").unwrap();
+
+    let output = engine(&["lint", &source_path]);
+    let findings = get_findings(&output);
+
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stdout).contains("Lint Error -> Denied lint findings: 1."),
+        "{}", String::from_utf8_lossy(&output.stdout));
+    assert_eq!(findings.len(), 5);
+    assert_eq!(
+        findings[0],
+        "Lint Error -> Loop step is `0`, the loop variable never changes (zero_step), line 4:18.");
+
+    std::fs::write(folder.join("engine.lint"), "unused = allow\n").unwrap();
+
+    let output = engine(&["lint", &source_path]);
+    assert!(
+        String::from_utf8_lossy(&output.stdout).contains("Config Error -> Unknown lint rule `unused`"),
+        "{}", String::from_utf8_lossy(&output.stdout));
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn inline_comments_set_the_level_of_their_line(){
+    let (folder, source_path) = write_program("engine_lint_inline", "int a = 1
+# engine:allow(self_assignment)
+a = a
+int b = 2   /* engine:deny(unused_variables) */
+if a == a{  # engine:allow(self_comparison)
+    print(a)
+}
+print(\"\\n\")
+");
+
+    let output = engine(&["lint", &source_path]);
+
+    assert!(!output.status.success());
+    assert_eq!(
+        get_findings(&output),
+        ["Lint Error -> Variable `b` is never used (unused_variables), line 4:5."]);
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn loop_variables_are_not_reported_as_unused(){
+    let (folder, source_path) = write_program("engine_lint_loop_variable", "for i in (0, 3, 1){
+    int count = 1
+    print(\"again\\n\")
+}
+print(\"\\n\")
+");
+
+    let output = engine(&["lint", &source_path]);
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+    assert_eq!(
+        get_findings(&output),
+        ["Lint Warning -> Variable `count` is never used (unused_variables), line 2:9."]);
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
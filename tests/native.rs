@@ -0,0 +1,166 @@
+/* The Native Backend Is Rejected On Other Platforms */
+#![cfg(all(target_arch = "x86_64", target_os = "linux"))]
+
+mod common;
+
+use common::{
+    PROGRAMS,
+    engine,
+    run,
+    get_text,
+    write_program
+};
+
+
+#[test]
+fn native_executables_print_like_the_vm(){
+    for (index, (program, input)) in PROGRAMS.iter().enumerate(){
+        let (folder, source_path) = write_program(&format!("engine_native_{}", index), program);
+
+        let output = engine(&[&source_path, "--byte-code", "-O0"]);
+        assert!(output.status.success(), "{}", get_text(&output));
+        let vm_output = run(
+            env!("CARGO_BIN_EXE_engine"), &[&format!("{}.byte", source_path), "--vm"], input);
+
+        let output = engine(&[&source_path, "--native"]);
+        assert!(output.status.success(), "native rejected program {}: {}", index, get_text(&output));
+        assert!(!folder.join("program.s").exists(), "assembly file of program {} is kept", index);
+
+        let native_output = run(folder.join("program").to_str().unwrap(), &[], input);
+
+        assert_eq!(
+            String::from_utf8_lossy(&native_output.stdout).lines().next(),
+            String::from_utf8_lossy(&vm_output.stdout).lines().next(),
+            "first line of program {}", index);
+
+        if vm_output.status.success(){
+            assert!(native_output.status.success(), "program {}: {}", index, get_text(&native_output));
+            assert_eq!(
+                get_text(&native_output), get_text(&vm_output), "output of program {}", index);
+        }
+        else{
+            assert_eq!(native_output.status.code(), Some(101), "exit code of program {}", index);
+        }
+
+        std::fs::remove_dir_all(&folder).unwrap();
+    }
+}
+
+
+#[test]
+fn native_runtime_errors_report_the_line(){
+    let (folder, source_path) = write_program(
+        "engine_native_errors", "int n = input() as int\nprint(10 % n, \"\\n\")\n");
+
+    let output = engine(&[&source_path, "--native"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+
+    let executable_path = folder.join("program");
+    let executable_path = executable_path.to_str().unwrap();
+
+    let output = run(executable_path, &[], "x\n");
+    assert_eq!(output.status.code(), Some(101));
+    assert_eq!(
+        get_text(&output),
+        "Engine Compiler: Convert Error -> can't convert from `String` to `Int`, line: 1:20\n");
+
+    let output = run(executable_path, &[], "0\n");
+    assert_eq!(output.status.code(), Some(101));
+    assert!(get_text(&output).contains("Division by zero, line: 2:"), "{}", get_text(&output));
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+const MATH_PROGRAM: &'static str = "int n = input() as int
+double d = input() as double
+print(abs(n - 5), \" \", abs(d), \" \", min(n, d), \" \", max(n, 7), \" \", min(n, -4), \"\\n\")
+print(pow(2, 10), \" \", pow(d, 0.5), \" \", pow(n, 0), \" \", pow(-3, 3), \"\\n\")
+print(sqrt(2), \" \", floor(2.7), \" \", ceil(2.1), \" \", round(-2.5), \" \", floor(n), \"\\n\")
+print(sin(PI / 6), \" \", cos(0), \" \", tan(1), \" \", log(E), \" \", exp(1), \"\\n\")
+if n == 1{
+    print(abs(0 - pow(2, 62) * 2))
+}
+if n == 2{
+    print(pow(n, 0 - n))
+}
+if n == 3{
+    print(pow(n, 50))
+}
+print(round(d), \"\\n\")
+";
+
+
+#[test]
+fn math_functions_run_like_the_vm(){
+    let (folder, source_path) = write_program("engine_native_math", MATH_PROGRAM);
+
+    let output = engine(&[&source_path, "--byte-code", "-O0"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+    let output = engine(&[&source_path, "--native"]);
+    assert!(output.status.success(), "{}", get_text(&output));
+
+    let executable_path = folder.join("program");
+    let executable_path = executable_path.to_str().unwrap();
+
+    for input in ["0\n2.5\n", "1\n0\n", "2\n0\n", "3\n0\n", "4\n1e300\n", "5\n-inf\n", "6\nnan\n"]{
+        let vm_output = run(
+            env!("CARGO_BIN_EXE_engine"), &[&format!("{}.byte", source_path), "--vm"], input);
+        let native_output = run(executable_path, &[], input);
+        let vm_text = get_text(&vm_output);
+        let native_text = get_text(&native_output);
+
+        if vm_output.status.success(){
+            assert!(native_output.status.success(), "input {:?}: {}", input, native_text);
+            assert_eq!(native_text, vm_text, "output of input {:?}", input);
+            continue;
+        }
+
+        /* The VM Names Its Instruction Where The Executable Names The Line */
+        let vm_message = vm_text.lines().last().unwrap().split(", instruction line").next().unwrap();
+        let vm_message = vm_message.replace("Engine VM: Call Instruction -> ", "");
+        assert_eq!(native_output.status.code(), Some(101), "exit code of input {:?}", input);
+        assert!(
+            native_text.lines().last().unwrap().starts_with(
+                &format!("Engine Compiler: Builtin Error -> {}, line: ", vm_message)),
+            "input {:?}: {}", input, native_text);
+    }
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn other_builtin_functions_are_rejected_by_the_native_backend(){
+    let (folder, source_path) = write_program(
+        "engine_native_builtins", "string s = \"engine\"\nprint(len(s))\n");
+
+    let output = engine(&[&source_path, "--native"]);
+
+    assert!(!output.status.success());
+    assert!(
+        get_text(&output).contains(
+            "Code Generation Error -> Function `len` is not supported by the native backend, line: 2:7"),
+        "{}", get_text(&output));
+    assert!(!folder.join("program").exists());
+    assert!(!folder.join("program.s").exists());
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn emit_asm_prints_the_assembly(){
+    let (folder, source_path) = write_program("engine_native_asm", "int x = 41\nx += 1\nprint(x)\n");
+
+    let output = engine(&[&source_path, "--emit=asm"]);
+    let text = get_text(&output);
+
+    assert!(output.status.success(), "{}", text);
+    assert!(text.contains(".intel_syntax noprefix"), "{}", text);
+    assert!(text.contains("main:"), "{}", text);
+    assert!(text.contains("call engine_print_int"), "{}", text);
+    assert!(!folder.join("program.s").exists());
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
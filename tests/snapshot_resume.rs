@@ -0,0 +1,208 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+
+const PROGRAM: &'static str = "string name = \"engine\"
+int total = 0
+for i in (0, 10, 1) {
+    total = total + i
+    if total > 20 {
+        print(\"big \")
+    }
+    else {
+        print(\"small \")
+    }
+    print(total)
+    print(\"\\n\")
+}
+print(name)
+";
+
+
+/* `file_error` And `input_eof` Are Read After The Snapshot Is Taken In The Loop */
+const BUILTIN_STATE_PROGRAM: &'static str = "string content = read_file(\"/engine_snapshot_missing_folder/missing.txt\")
+string line = input_line()
+int total = 0
+for i in (0, 10, 1){
+    total = total + i
+}
+print(total, file_error(), input_eof(), end=\"\\n\")
+";
+
+
+/* `big` Is Assigned Before The Snapshot, Only `small` Is Assigned After The Resume */
+const MEMORY_PROGRAM: &'static str = "string big = \"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\"
+int total = 0
+for i in (0, 10, 1){
+    total = total + i
+}
+string small = \"b\"
+print(total, small, len(big), end=\"\\n\")
+";
+
+
+/* `{path}` Is Deleted Before The Resume, So `lines` Is Only Restored From The Snapshot */
+const LIST_PROGRAM: &'static str = "bool written = write_file(\"{path}\", \"one, \\\"two\\\"\\n\\\\three\\n\")
+var lines = read_lines(\"{path}\")
+int total = 0
+for i in (0, 10, 1){
+    total = total + i
+}
+print(total, len(lines), lines, end=\"\\n\")
+";
+
+
+fn engine(args: &[&str]) -> String{
+    let output = Command::new(env!("CARGO_BIN_EXE_engine")).args(args).output().unwrap();
+    assert!(
+        output.status.success(),
+        "engine {:?} failed: {}{}",
+        args, String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+
+    return String::from_utf8(output.stdout).unwrap();
+}
+
+
+fn compile_program(folder_name: &str) -> (PathBuf, String){
+    return compile(folder_name, PROGRAM);
+}
+
+
+fn compile(folder_name: &str, program: &str) -> (PathBuf, String){
+    let folder = std::env::temp_dir().join(folder_name);
+    std::fs::create_dir_all(&folder).unwrap();
+
+    let source_path = folder.join("program.en");
+    std::fs::write(&source_path, program).unwrap();
+
+    engine(&[source_path.to_str().unwrap(), "--byte-code"]);
+
+    let byte_code_path = folder.join("program.en.byte");
+    assert!(byte_code_path.exists());
+
+    return (folder, String::from(byte_code_path.to_str().unwrap()));
+}
+
+
+#[test]
+fn resumed_run_matches_uninterrupted_run(){
+    let (folder, byte_code_path) = compile_program(
+        &format!("engine_snapshot_resume_{}", std::process::id()));
+
+    let expected_output = engine(&[&byte_code_path, "--vm"]);
+    assert!(expected_output.ends_with("engine"));
+
+    for pause_after in [1, 7, 25, 60, 100]{
+        let snapshot_path = folder.join(format!("program_{}.snapshot", pause_after));
+        let snapshot_path = snapshot_path.to_str().unwrap();
+
+        let mut output = engine(&[
+            &byte_code_path, "--vm",
+            "--snapshot", snapshot_path, "--snapshot-after", &pause_after.to_string()]);
+        output.push_str(&engine(&[&byte_code_path, "--vm", "--resume", snapshot_path]));
+
+        assert_eq!(output, expected_output, "paused after {} instructions", pause_after);
+    }
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn snapshot_can_be_paused_again_after_resume(){
+    let (folder, byte_code_path) = compile_program(
+        &format!("engine_snapshot_chain_{}", std::process::id()));
+
+    let expected_output = engine(&[&byte_code_path, "--vm"]);
+
+    let first_snapshot = folder.join("first.snapshot");
+    let first_snapshot = first_snapshot.to_str().unwrap();
+    let second_snapshot = folder.join("second.snapshot");
+    let second_snapshot = second_snapshot.to_str().unwrap();
+
+    let mut output = engine(&[
+        &byte_code_path, "--vm", "--snapshot", first_snapshot, "--snapshot-after", "20"]);
+    output.push_str(&engine(&[
+        &byte_code_path, "--vm", "--resume", first_snapshot,
+        "--snapshot", second_snapshot, "--snapshot-after", "20"]));
+    output.push_str(&engine(&[&byte_code_path, "--vm", "--resume", second_snapshot]));
+
+    assert_eq!(output, expected_output);
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn builtin_state_is_restored_on_resume(){
+    let (folder, byte_code_path) = compile(
+        &format!("engine_snapshot_builtins_{}", std::process::id()), BUILTIN_STATE_PROGRAM);
+
+    let expected_output = engine(&[&byte_code_path, "--vm"]);
+    assert!(expected_output.contains("`read_file` failed for"), "{}", expected_output);
+    assert!(expected_output.ends_with(" True\n"), "{}", expected_output);
+
+    let snapshot_path = folder.join("program.snapshot");
+    let snapshot_path = snapshot_path.to_str().unwrap();
+
+    let mut output = engine(&[
+        &byte_code_path, "--vm", "--snapshot", snapshot_path, "--snapshot-after", "20"]);
+    output.push_str(&engine(&[&byte_code_path, "--vm", "--resume", snapshot_path]));
+
+    assert_eq!(output, expected_output);
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn resumed_strings_count_in_the_memory_limit(){
+    let (folder, byte_code_path) = compile(
+        &format!("engine_snapshot_memory_{}", std::process::id()), MEMORY_PROGRAM);
+
+    let snapshot_path = folder.join("program.snapshot");
+    let snapshot_path = snapshot_path.to_str().unwrap();
+
+    engine(&[&byte_code_path, "--vm", "--snapshot", snapshot_path, "--snapshot-after", "8"]);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_engine"))
+        .args([&byte_code_path, "--vm", "--resume", snapshot_path, "--max-memory", "50"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stdout).starts_with(
+            "Engine Limits: Memory Limit -> Variables hold 100 bytes, more than the 50 bytes limit"),
+        "{}", String::from_utf8_lossy(&output.stdout));
+
+    let output = engine(&[&byte_code_path, "--vm", "--resume", snapshot_path, "--max-memory", "1000"]);
+    assert_eq!(output, "45 b 100\n");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+
+#[test]
+fn list_registers_are_restored_on_resume(){
+    let folder_name = format!("engine_snapshot_list_{}", std::process::id());
+    let text_path = std::env::temp_dir().join(&folder_name).join("lines.txt");
+    let (folder, byte_code_path) = compile(
+        &folder_name, &LIST_PROGRAM.replace("{path}", text_path.to_str().unwrap()));
+
+    let expected_output = engine(&[&byte_code_path, "--vm"]);
+    assert_eq!(expected_output, "45 2 [\"one, \\\"two\\\"\", \"\\\\three\"]\n");
+
+    let snapshot_path = folder.join("program.snapshot");
+    let snapshot_path = snapshot_path.to_str().unwrap();
+
+    let mut output = engine(&[
+        &byte_code_path, "--vm", "--snapshot", snapshot_path, "--snapshot-after", "20"]);
+    assert!(std::fs::read_to_string(snapshot_path).unwrap().contains(":list:[\"one, "));
+
+    std::fs::remove_file(&text_path).unwrap();
+    output.push_str(&engine(&[&byte_code_path, "--vm", "--resume", snapshot_path]));
+
+    assert_eq!(output, expected_output);
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}